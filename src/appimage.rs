@@ -0,0 +1,216 @@
+use crate::game_sources::{GameSource, SourceResult};
+use crate::model::AppEntry;
+use directories::BaseDirs;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+/// Directories searched when `appimage_dirs` isn't set in the config
+pub fn default_appimage_dirs() -> Vec<String> {
+    vec!["~/Applications".to_string(), "~/.local/bin".to_string()]
+}
+
+/// Executable AppImages in the configured directories
+pub struct AppImageSource {
+    dirs: Vec<String>,
+}
+
+impl AppImageSource {
+    /// `dirs` may start with `~` for the home directory
+    pub fn new(dirs: &[String]) -> Self {
+        Self {
+            dirs: dirs.to_vec(),
+        }
+    }
+}
+
+impl GameSource for AppImageSource {
+    fn name(&self) -> &'static str {
+        "AppImage"
+    }
+
+    fn scan(&self) -> SourceResult {
+        Ok(scan_appimage_games(&self.dirs).into())
+    }
+
+    fn is_available(&self) -> bool {
+        self.dirs
+            .iter()
+            .filter_map(|dir| expand_home(dir))
+            .any(|dir| dir.is_dir())
+    }
+}
+
+/// Scan the given directories for executable `*.AppImage` files
+fn scan_appimage_games(dirs: &[String]) -> Vec<AppEntry> {
+    let mut games = Vec::new();
+
+    for dir in dirs.iter().filter_map(|dir| expand_home(dir)) {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if is_appimage(&path) && is_executable(&path) {
+                games.push(process_appimage(&path));
+            }
+        }
+    }
+
+    games
+}
+
+fn expand_home(dir: &str) -> Option<PathBuf> {
+    match dir.strip_prefix('~') {
+        Some(rest) => {
+            let base_dirs = BaseDirs::new()?;
+            Some(base_dirs.home_dir().join(rest.trim_start_matches('/')))
+        }
+        None => Some(PathBuf::from(dir)),
+    }
+}
+
+fn is_appimage(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("appimage"))
+}
+
+/// AppImages without the exec bit can't be started and are usually half downloaded
+fn is_executable(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+fn process_appimage(path: &Path) -> AppEntry {
+    let title = appimage_title(path);
+    let launch_key = format!(
+        "appimage:{}",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    );
+
+    tracing::info!("Discovered AppImage: '{}'", title);
+
+    AppEntry::new(title, appimage_exec(path), find_icon(path))
+        .with_launch_key(launch_key)
+        .with_executable(Some(path.to_string_lossy().to_string()))
+}
+
+/// Name from a file name like `Celeste-1.4.0.0-x86_64.AppImage`: everything before
+/// the first version or architecture part, with `_` read as a space. The first part
+/// always counts as name, for games like `2048`.
+fn appimage_title(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    let name_parts: Vec<&str> = stem
+        .split(['-', ' '])
+        .filter(|part| !part.is_empty())
+        .enumerate()
+        .take_while(|(i, part)| *i == 0 || (!is_version_part(part) && !is_arch_part(part)))
+        .map(|(_, part)| part)
+        .collect();
+
+    let title = if name_parts.is_empty() {
+        stem.clone()
+    } else {
+        name_parts.join(" ")
+    };
+    title.replace('_', " ").trim().to_string()
+}
+
+fn is_version_part(part: &str) -> bool {
+    let digits = part
+        .strip_prefix(['v', 'V'])
+        .filter(|rest| !rest.is_empty())
+        .unwrap_or(part);
+    digits.starts_with(|c: char| c.is_ascii_digit())
+}
+
+fn is_arch_part(part: &str) -> bool {
+    matches!(
+        part.to_ascii_lowercase().as_str(),
+        "x86_64" | "amd64" | "x64" | "aarch64" | "arm64" | "armhf" | "i386" | "i686" | "linux"
+    )
+}
+
+/// AppImages run as their own executable; the path is single-quoted for `sh -c`
+fn appimage_exec(path: &Path) -> String {
+    format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"))
+}
+
+/// Icon saved next to the AppImage under the same name, as AppImageLauncher and
+/// hand-made setups do. The icon embedded in the image itself would need the
+/// SquashFS payload to be mounted or extracted.
+fn find_icon(path: &Path) -> Option<String> {
+    ["png", "svg", "jpg", "jpeg", "webp"]
+        .iter()
+        .find_map(|ext| {
+            let icon_path = path.with_extension(ext);
+            icon_path
+                .exists()
+                .then(|| icon_path.to_string_lossy().to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_appimage_title_from_file_name() {
+        let title = |name: &str| appimage_title(Path::new(name));
+
+        assert_eq!(title("Celeste-1.4.0.0-x86_64.AppImage"), "Celeste");
+        assert_eq!(title("Super_Tux_Kart-x86_64.AppImage"), "Super Tux Kart");
+        assert_eq!(title("Mindustry-v146-linux.appimage"), "Mindustry");
+        assert_eq!(title("Dead-Cells-amd64.AppImage"), "Dead Cells");
+        assert_eq!(title("Vampire Survivors.AppImage"), "Vampire Survivors");
+        assert_eq!(title("2048-x86_64.AppImage"), "2048");
+    }
+
+    #[test]
+    fn test_appimage_exec_quotes_path() {
+        assert_eq!(
+            appimage_exec(Path::new("/home/me/Applications/Celeste.AppImage")),
+            "'/home/me/Applications/Celeste.AppImage'"
+        );
+        assert_eq!(
+            appimage_exec(Path::new("/home/me/My Games/Baba's Game.AppImage")),
+            "'/home/me/My Games/Baba'\\''s Game.AppImage'"
+        );
+    }
+
+    #[test]
+    fn test_scan_only_lists_executable_appimages() {
+        let dir = std::env::temp_dir().join(format!("launcher_test_appimage_{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let create = |name: &str, mode: u32| {
+            let path = dir.join(name);
+            fs::write(&path, b"").unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(mode)).unwrap();
+        };
+        create("Celeste-x86_64.AppImage", 0o755);
+        create("Partial-x86_64.AppImage", 0o644);
+        create("notes.txt", 0o755);
+        fs::write(dir.join("Celeste-x86_64.png"), b"").unwrap();
+
+        let games = scan_appimage_games(&[dir.to_string_lossy().to_string()]);
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].name, "Celeste");
+        assert_eq!(
+            games[0].launch_key.as_deref(),
+            Some("appimage:Celeste-x86_64.AppImage")
+        );
+        assert_eq!(
+            games[0].icon,
+            Some(dir.join("Celeste-x86_64.png").to_string_lossy().to_string())
+        );
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -8,6 +8,11 @@ pub fn get_default_icon() -> Option<Vec<u8>> {
     Asset::get("icon.svg").map(|f| f.data.into_owned())
 }
 
+/// Bundled UI sound effect, e.g. `navigate.wav`
+pub fn get_ui_sound(name: &str) -> Option<Vec<u8>> {
+    Asset::get(&format!("sounds/{}", name)).map(|f| f.data.into_owned())
+}
+
 pub fn get_sansation_font() -> Option<Vec<u8>> {
     Asset::get("Sansation-Regular.ttf").map(|f| f.data.into_owned())
 }
@@ -0,0 +1,150 @@
+//! Suspending the system after a long time without input, following a short
+//! on-screen countdown that any input cancels.
+
+use std::time::{Duration, Instant};
+
+/// How long the countdown is shown before suspending
+pub const SUSPEND_COUNTDOWN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleState {
+    Active,
+    /// The countdown overlay is shown
+    CountingDown {
+        seconds_left: u64,
+    },
+    /// The countdown ran out; suspend now
+    Suspend,
+}
+
+#[derive(Debug)]
+pub struct IdleSuspend {
+    last_activity: Instant,
+    countdown_started: Option<Instant>,
+}
+
+impl IdleSuspend {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_activity: now,
+            countdown_started: None,
+        }
+    }
+
+    /// Restarts the idle timer; returns true if this cancelled a running countdown.
+    pub fn activity(&mut self, now: Instant) -> bool {
+        self.last_activity = now;
+        self.countdown_started.take().is_some()
+    }
+
+    /// Advances the timer. `timeout` is the idle time before the countdown starts
+    /// (`None` when disabled); while `blocked` (game running, update in progress)
+    /// the launcher counts as active.
+    pub fn tick(&mut self, now: Instant, timeout: Option<Duration>, blocked: bool) -> IdleState {
+        let Some(timeout) = timeout.filter(|_| !blocked) else {
+            self.activity(now);
+            return IdleState::Active;
+        };
+
+        let started = match self.countdown_started {
+            Some(started) => started,
+            None if now.saturating_duration_since(self.last_activity) >= timeout => {
+                self.countdown_started = Some(now);
+                now
+            }
+            None => return IdleState::Active,
+        };
+
+        let elapsed = now.saturating_duration_since(started);
+        if elapsed >= SUSPEND_COUNTDOWN {
+            // Start over after waking up
+            self.activity(now);
+            return IdleState::Suspend;
+        }
+        let left = SUSPEND_COUNTDOWN - elapsed;
+        IdleState::CountingDown {
+            seconds_left: left.as_secs() + u64::from(left.subsec_nanos() > 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Option<Duration> = Some(Duration::from_secs(600));
+
+    fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    #[test]
+    fn test_countdown_then_suspend() {
+        let start = Instant::now();
+        let mut idle = IdleSuspend::new(start);
+
+        assert_eq!(
+            idle.tick(start + secs(599), TIMEOUT, false),
+            IdleState::Active
+        );
+        assert_eq!(
+            idle.tick(start + secs(600), TIMEOUT, false),
+            IdleState::CountingDown { seconds_left: 30 }
+        );
+        assert_eq!(
+            idle.tick(start + secs(621), TIMEOUT, false),
+            IdleState::CountingDown { seconds_left: 9 }
+        );
+        assert_eq!(
+            idle.tick(start + secs(630), TIMEOUT, false),
+            IdleState::Suspend
+        );
+        // The timer restarts after suspending
+        assert_eq!(
+            idle.tick(start + secs(631), TIMEOUT, false),
+            IdleState::Active
+        );
+    }
+
+    #[test]
+    fn test_input_cancels_countdown() {
+        let start = Instant::now();
+        let mut idle = IdleSuspend::new(start);
+
+        assert!(!idle.activity(start + secs(10)));
+        assert_eq!(
+            idle.tick(start + secs(609), TIMEOUT, false),
+            IdleState::Active
+        );
+        assert!(matches!(
+            idle.tick(start + secs(610), TIMEOUT, false),
+            IdleState::CountingDown { .. }
+        ));
+
+        assert!(idle.activity(start + secs(615)));
+        assert_eq!(
+            idle.tick(start + secs(640), TIMEOUT, false),
+            IdleState::Active
+        );
+    }
+
+    #[test]
+    fn test_never_suspends_while_blocked_or_disabled() {
+        let start = Instant::now();
+        let mut idle = IdleSuspend::new(start);
+
+        assert_eq!(
+            idle.tick(start + secs(3600), None, false),
+            IdleState::Active
+        );
+        assert_eq!(
+            idle.tick(start + secs(7200), TIMEOUT, true),
+            IdleState::Active
+        );
+        // A blocked tick counts as activity, so the full timeout applies again
+        assert_eq!(
+            idle.tick(start + secs(7300), TIMEOUT, false),
+            IdleState::Active
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Starting the launcher with the desktop session through an XDG autostart entry,
+//! for setups that boot straight into the launcher.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::desktop_shortcut::quote_exec_arg;
+
+const AUTOSTART_FILE_NAME: &str = "rhincotv.desktop";
+
+/// `autostart/rhincotv.desktop` below the given config directory
+pub fn autostart_path(config_dir: &Path) -> PathBuf {
+    config_dir.join("autostart").join(AUTOSTART_FILE_NAME)
+}
+
+/// Builds the autostart entry that runs `exe`
+pub fn autostart_entry(exe: &Path) -> String {
+    format!(
+        "[Desktop Entry]\nType=Application\nName=RhincoTV\nComment=TV launcher for games and apps\nExec={}\nTerminal=false\nX-GNOME-Autostart-enabled=true\n",
+        quote_exec_arg(&exe.to_string_lossy())
+    )
+}
+
+/// Whether the launcher's autostart entry exists in the user's config directory
+pub fn is_autostart_enabled() -> bool {
+    user_config_dir().is_some_and(|dir| autostart_path(&dir).is_file())
+}
+
+/// Writes the autostart entry for the running executable, or removes it.
+pub fn set_autostart(enabled: bool) -> Result<(), String> {
+    let config_dir = user_config_dir().ok_or("Could not determine the config directory")?;
+    if !enabled {
+        return disable_autostart(&config_dir);
+    }
+    let exe = std::env::current_exe()
+        .map_err(|err| format!("Could not determine the launcher's path: {}", err))?;
+    enable_autostart(&config_dir, &exe).map(|_| ())
+}
+
+/// `$XDG_CONFIG_HOME`, usually `~/.config`
+fn user_config_dir() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+fn enable_autostart(config_dir: &Path, exe: &Path) -> Result<PathBuf, String> {
+    let path = autostart_path(config_dir);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+    }
+    fs::write(&path, autostart_entry(exe))
+        .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+    Ok(path)
+}
+
+/// Removes the entry; nothing to do when it doesn't exist
+fn disable_autostart(config_dir: &Path) -> Result<(), String> {
+    let path = autostart_path(config_dir);
+    match fs::remove_file(&path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => {
+            Err(format!("Failed to remove {}: {}", path.display(), err))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autostart_entry_runs_executable() {
+        assert_eq!(
+            autostart_entry(Path::new("/opt/rhinco tv/rhinco-tv")),
+            "[Desktop Entry]
+Type=Application
+Name=RhincoTV
+Comment=TV launcher for games and apps
+Exec=\"/opt/rhinco tv/rhinco-tv\"
+Terminal=false
+X-GNOME-Autostart-enabled=true
+"
+        );
+    }
+
+    #[test]
+    fn test_enable_and_disable_autostart() {
+        let config_dir =
+            std::env::temp_dir().join(format!("rhinco-tv-autostart-{}", uuid::Uuid::new_v4()));
+        let exe = Path::new("/usr/bin/rhinco-tv");
+
+        let path = enable_autostart(&config_dir, exe).unwrap();
+        assert_eq!(path, config_dir.join("autostart/rhincotv.desktop"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), autostart_entry(exe));
+
+        disable_autostart(&config_dir).unwrap();
+        assert!(!path.exists());
+        // Already disabled
+        assert!(disable_autostart(&config_dir).is_ok());
+
+        let _ = fs::remove_dir_all(config_dir);
+    }
+}
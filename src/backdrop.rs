@@ -0,0 +1,230 @@
+//! Blurred hero artwork of the selected game, shown full screen behind the rows
+//! and crossfaded when the selection moves to another game.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::focus_animation::ease_out_cubic;
+use crate::image_cache::ImageCache;
+use crate::model::LauncherItem;
+use crate::steamgriddb::SteamGridDbClient;
+use crate::store_page::steam_appid;
+
+/// Length of the crossfade between two backdrops
+pub const BACKDROP_FADE_DURATION: Duration = Duration::from_millis(400);
+/// Opacity of a fully shown backdrop; the dark background beneath keeps text readable
+pub const BACKDROP_OPACITY: f32 = 0.35;
+
+/// Stored width of a backdrop; it's blurred anyway, so a small image suffices
+const BACKDROP_WIDTH: u32 = 640;
+const BACKDROP_BLUR_SIGMA: f32 = 6.0;
+const STEAM_HERO_URL: &str = "https://cdn.cloudflare.steamstatic.com/steam/apps";
+
+/// What to show behind the rows for the selected item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackdropSource {
+    /// The item's downloaded hero image
+    Hero(PathBuf),
+    /// A game whose hero image wasn't looked up yet
+    Fetch,
+    /// The regular background: nothing selected, not a game, or no hero art
+    Pattern,
+}
+
+/// Picks the backdrop of `item` from the hero images looked up so far. Entries
+/// of `hero_art` are `None` while the lookup runs and when it found nothing.
+pub fn backdrop_source(
+    item: Option<&LauncherItem>,
+    is_game: bool,
+    hero_art: &HashMap<Uuid, Option<PathBuf>>,
+) -> BackdropSource {
+    let Some(item) = item.filter(|_| is_game) else {
+        return BackdropSource::Pattern;
+    };
+    match hero_art.get(&item.id) {
+        Some(Some(path)) => BackdropSource::Hero(path.clone()),
+        Some(None) => BackdropSource::Pattern,
+        None => BackdropSource::Fetch,
+    }
+}
+
+/// Downloads the hero image of a game: Steam's library hero for Steam games,
+/// otherwise SteamGridDB's (needs an API key). `None` if neither has one.
+pub fn fetch_hero_art(
+    cache: &ImageCache,
+    sgdb_client: &SteamGridDbClient,
+    item: &LauncherItem,
+) -> Option<PathBuf> {
+    // Kept apart from the covers, which are stored under the plain game name
+    let heroes = ImageCache {
+        cache_dir: cache.cache_dir.join("heroes"),
+        ..cache.clone()
+    };
+    if let Some(path) = heroes.find_existing_image(&item.name) {
+        return Some(path);
+    }
+    fs::create_dir_all(&heroes.cache_dir).ok()?;
+
+    let steam_url = steam_appid(item)
+        .or_else(|| item.steam_appid.clone())
+        .map(|appid| format!("{}/{}/library_hero.jpg", STEAM_HERO_URL, appid));
+    let save = |url: &str| {
+        heroes
+            .save_blurred_image(&item.name, url, BACKDROP_WIDTH, BACKDROP_BLUR_SIGMA)
+            .ok()
+    };
+
+    steam_url.as_deref().and_then(save).or_else(|| {
+        if !sgdb_client.has_api_key() {
+            return None;
+        }
+        let sgdb_id = sgdb_client.search_game(&item.name).ok().flatten()?;
+        let heroes = sgdb_client.get_heroes_for_game(sgdb_id).ok()?;
+        save(&heroes.first()?.url)
+    })
+}
+
+/// The shown backdrop and, during a crossfade, the one fading out
+pub struct Backdrop {
+    previous: Option<PathBuf>,
+    current: Option<PathBuf>,
+    started_at: Option<Instant>,
+    /// Time of the last rendered frame
+    now: Instant,
+}
+
+impl Backdrop {
+    pub fn new() -> Self {
+        Self {
+            previous: None,
+            current: None,
+            started_at: None,
+            now: Instant::now(),
+        }
+    }
+
+    /// Fades to `image` (`None` for no backdrop); with `reduce_motion` it switches
+    /// right away.
+    pub fn show(&mut self, image: Option<PathBuf>, now: Instant, reduce_motion: bool) {
+        if self.current == image {
+            return;
+        }
+
+        self.previous = self.current.take().filter(|_| !reduce_motion);
+        self.current = image;
+        self.now = now;
+        self.started_at = (!reduce_motion).then_some(now);
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Advances to `now`, finishing the crossfade once its duration has passed
+    pub fn tick(&mut self, now: Instant) {
+        self.now = now;
+        if self.progress() >= 1.0 {
+            self.started_at = None;
+            self.previous = None;
+        }
+    }
+
+    fn progress(&self) -> f32 {
+        let Some(started_at) = self.started_at else {
+            return 1.0;
+        };
+        let elapsed = self.now.saturating_duration_since(started_at);
+        ease_out_cubic(elapsed.as_secs_f32() / BACKDROP_FADE_DURATION.as_secs_f32())
+    }
+
+    /// Images to draw from bottom to top with their opacity
+    pub fn layers(&self) -> Vec<(&PathBuf, f32)> {
+        let progress = self.progress();
+        self.previous
+            .iter()
+            .map(|path| (path, BACKDROP_OPACITY * (1.0 - progress)))
+            .chain(
+                self.current
+                    .iter()
+                    .map(|path| (path, BACKDROP_OPACITY * progress)),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(name: &str) -> LauncherItem {
+        LauncherItem {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_backdrop_source_follows_available_art() {
+        let celeste = game("Celeste");
+        let hades = game("Hades");
+        let portal = game("Portal");
+        let hero = PathBuf::from("/cache/grids/heroes/Celeste.jpg");
+        let hero_art = HashMap::from([(celeste.id, Some(hero.clone())), (hades.id, None)]);
+
+        assert_eq!(
+            backdrop_source(Some(&celeste), true, &hero_art),
+            BackdropSource::Hero(hero)
+        );
+        // Looked up without success, or still being looked up
+        assert_eq!(
+            backdrop_source(Some(&hades), true, &hero_art),
+            BackdropSource::Pattern
+        );
+        assert_eq!(
+            backdrop_source(Some(&portal), true, &hero_art),
+            BackdropSource::Fetch
+        );
+        // Apps and system entries have no hero art
+        assert_eq!(
+            backdrop_source(Some(&portal), false, &hero_art),
+            BackdropSource::Pattern
+        );
+        assert_eq!(
+            backdrop_source(None, true, &hero_art),
+            BackdropSource::Pattern
+        );
+    }
+
+    #[test]
+    fn test_backdrop_crossfade() {
+        let start = Instant::now();
+        let first = PathBuf::from("first.jpg");
+        let second = PathBuf::from("second.jpg");
+        let mut backdrop = Backdrop::new();
+
+        backdrop.show(Some(first.clone()), start, false);
+        backdrop.tick(start + BACKDROP_FADE_DURATION);
+        assert!(!backdrop.is_animating());
+        assert_eq!(backdrop.layers(), vec![(&first, BACKDROP_OPACITY)]);
+
+        let switched = start + BACKDROP_FADE_DURATION;
+        backdrop.show(Some(second.clone()), switched, false);
+        assert!(backdrop.is_animating());
+        assert_eq!(
+            backdrop.layers(),
+            vec![(&first, BACKDROP_OPACITY), (&second, 0.0)]
+        );
+
+        backdrop.tick(switched + BACKDROP_FADE_DURATION);
+        assert_eq!(backdrop.layers(), vec![(&second, BACKDROP_OPACITY)]);
+
+        // Reduced motion switches without fading
+        backdrop.show(None, switched, true);
+        assert!(!backdrop.is_animating());
+        assert!(backdrop.layers().is_empty());
+    }
+}
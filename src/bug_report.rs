@@ -0,0 +1,130 @@
+//! Bundling the app version, system information and recent log lines into one
+//! text file that can be attached to a bug report.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+
+use crate::log_buffer::LogLine;
+use crate::system_info::GamingSystemInfo;
+
+const REDACTED: &str = "[redacted]";
+
+/// Timestamped file name, e.g. `rhinco-tv-bug-report-2024-05-01_20-15-03.txt`
+pub fn bug_report_file_name(time: DateTime<Local>) -> String {
+    format!(
+        "rhinco-tv-bug-report-{}.txt",
+        time.format("%Y-%m-%d_%H-%M-%S")
+    )
+}
+
+/// The report text. Every occurrence of `api_key` is replaced, so the SteamGridDB
+/// key doesn't leak through a logged request or error message.
+pub fn build_bug_report(
+    version: &str,
+    created: DateTime<Local>,
+    info: &GamingSystemInfo,
+    log: &[LogLine],
+    api_key: Option<&str>,
+) -> String {
+    let mut report = format!(
+        "rhinco-tv bug report\n\nVersion: {}\nCreated: {}\n\n== System ==\n{:#?}\n\n== Log ({} lines) ==\n",
+        version,
+        created.format("%Y-%m-%d %H:%M:%S %z"),
+        info,
+        log.len()
+    );
+    for line in log {
+        report.push_str(&format!(
+            "{} {:>5} {}\n",
+            line.timestamp.format("%H:%M:%S"),
+            line.level.as_str(),
+            line.message
+        ));
+    }
+
+    match api_key.map(str::trim).filter(|key| !key.is_empty()) {
+        Some(key) => report.replace(key, REDACTED),
+        None => report,
+    }
+}
+
+/// Writes the report into the home directory and returns its path
+pub fn save_bug_report(report: &str, created: DateTime<Local>) -> Result<PathBuf, String> {
+    let user_dirs = directories::UserDirs::new();
+    let dir = user_dirs
+        .as_ref()
+        .map(|dirs| dirs.home_dir())
+        .ok_or("Could not determine the home directory")?;
+    write_bug_report(dir, report, created)
+}
+
+fn write_bug_report(dir: &Path, report: &str, created: DateTime<Local>) -> Result<PathBuf, String> {
+    let path = dir.join(bug_report_file_name(created));
+    fs::write(&path, report).map_err(|err| format!("Failed to save bug report: {}", err))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tracing::Level;
+
+    fn line(level: Level, message: &str) -> LogLine {
+        LogLine {
+            timestamp: Local.with_ymd_and_hms(2024, 5, 1, 20, 15, 3).unwrap(),
+            level,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_bug_report_sections_and_redaction() {
+        let created = Local.with_ymd_and_hms(2024, 5, 1, 20, 15, 3).unwrap();
+        let info = GamingSystemInfo {
+            os_name: "Arch Linux".to_string(),
+            kernel_version: "6.9.1-arch1-1".to_string(),
+            ..Default::default()
+        };
+        let log = [
+            line(Level::INFO, "Loaded 42 games"),
+            line(
+                Level::WARN,
+                "SGDB request with key abc123secret failed: timed out",
+            ),
+        ];
+
+        let report = build_bug_report("1.4.0", created, &info, &log, Some("abc123secret"));
+        assert!(report.contains("Version: 1.4.0"));
+        assert!(report.contains("== System =="));
+        assert!(report.contains("Arch Linux"));
+        assert!(report.contains("6.9.1-arch1-1"));
+        assert!(report.contains("== Log (2 lines) =="));
+        assert!(report.contains("20:15:03  INFO Loaded 42 games"));
+        assert!(!report.contains("abc123secret"));
+        assert!(report.contains("SGDB request with key [redacted] failed"));
+
+        // No key configured: nothing to redact
+        let report = build_bug_report("1.4.0", created, &info, &log, None);
+        assert!(report.contains("abc123secret"));
+        assert!(!report.contains(REDACTED));
+    }
+
+    #[test]
+    fn test_write_bug_report() {
+        let created = Local.with_ymd_and_hms(2024, 5, 1, 20, 15, 3).unwrap();
+        let dir = std::env::temp_dir().join(format!("rhinco-bug-report-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = write_bug_report(&dir, "report", created).unwrap();
+        assert_eq!(
+            path,
+            dir.join("rhinco-tv-bug-report-2024-05-01_20-15-03.txt")
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "report");
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
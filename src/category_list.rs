@@ -2,12 +2,14 @@ use iced::widget::Id;
 use uuid::Uuid;
 
 use crate::model::LauncherItem;
+use crate::sort_mode::SortMode;
 
 #[derive(Debug, Clone)]
 pub struct CategoryList {
     pub items: Vec<LauncherItem>,
     pub selected_index: usize,
     pub scroll_id: Id,
+    pub sort_mode: SortMode,
 }
 
 impl CategoryList {
@@ -16,6 +18,7 @@ impl CategoryList {
             items,
             selected_index: 0,
             scroll_id: Id::unique(),
+            sort_mode: SortMode::default(),
         }
     }
 
@@ -37,19 +40,31 @@ impl CategoryList {
         self.items.get(self.selected_index)
     }
 
-    pub fn move_left(&mut self) -> bool {
+    /// Selects the previous item; from the first one it stays there, or with
+    /// `wrap` jumps to the last one. Returns whether the selection changed.
+    pub fn move_left(&mut self, wrap: bool) -> bool {
         if !self.items.is_empty() && self.selected_index > 0 {
             self.selected_index -= 1;
             return true;
         }
+        if wrap && self.items.len() > 1 {
+            self.selected_index = self.items.len() - 1;
+            return true;
+        }
         false
     }
 
-    pub fn move_right(&mut self) -> bool {
+    /// Selects the next item; from the last one it stays there, or with `wrap`
+    /// jumps to the first one. Returns whether the selection changed.
+    pub fn move_right(&mut self, wrap: bool) -> bool {
         if !self.items.is_empty() && self.selected_index + 1 < self.items.len() {
             self.selected_index += 1;
             return true;
         }
+        if wrap && self.items.len() > 1 {
+            self.selected_index = 0;
+            return true;
+        }
         false
     }
 
@@ -112,8 +127,61 @@ impl CategoryList {
     }
 
     pub fn sort_inplace(&mut self) {
-        Self::sort_items(&mut self.items);
+        match self.sort_mode {
+            SortMode::Recent => Self::sort_items(&mut self.items),
+            SortMode::Alphabetical => self
+                .items
+                .sort_by_cached_key(|item| item.name.to_lowercase()),
+        }
     }
+
+    /// Re-sorts the items in the new mode, keeping the selected item selected.
+    pub fn set_sort_mode(&mut self, mode: SortMode) {
+        let selected = self.get_selected().map(|item| item.id);
+        self.sort_mode = mode;
+        self.sort_inplace();
+        if let Some(id) = selected {
+            self.select_item_by_id(id);
+        }
+    }
+
+    pub fn select_item_by_id(&mut self, id: Uuid) {
+        if let Some(index) = self.items.iter().position(|item| item.id == id) {
+            self.selected_index = index;
+        }
+    }
+}
+
+/// Builds the quick launch list from the favorite items of the given lists,
+/// ordered like every other category (most recently started first).
+pub fn collect_favorites(lists: &[&CategoryList]) -> Vec<LauncherItem> {
+    let mut favorites: Vec<LauncherItem> = lists
+        .iter()
+        .flat_map(|list| list.items.iter())
+        .filter(|item| item.favorite)
+        .cloned()
+        .collect();
+    CategoryList::sort_items(&mut favorites);
+    favorites
+}
+
+/// Builds the recent row from the launched items of the given lists,
+/// most recently started first and capped at `limit`.
+pub fn collect_recent(lists: &[&CategoryList], limit: usize) -> Vec<LauncherItem> {
+    let mut recent: Vec<LauncherItem> = lists
+        .iter()
+        .flat_map(|list| list.items.iter())
+        .filter(|item| item.last_started.is_some())
+        .cloned()
+        .collect();
+    CategoryList::sort_items(&mut recent);
+    recent.truncate(limit);
+    recent
+}
+
+/// The most recently started item of the given lists
+pub fn most_recent(lists: &[&CategoryList]) -> Option<LauncherItem> {
+    collect_recent(lists, 1).into_iter().next()
 }
 
 #[cfg(test)]
@@ -136,6 +204,14 @@ mod tests {
         }
     }
 
+    fn favorite(name: &str) -> LauncherItem {
+        LauncherItem {
+            name: name.to_string(),
+            favorite: true,
+            ..Default::default()
+        }
+    }
+
     fn names(list: &CategoryList) -> Vec<&str> {
         list.items.iter().map(|i| i.name.as_str()).collect()
     }
@@ -157,26 +233,53 @@ mod tests {
         let mut list = CategoryList::new(vec![item("A"), item("B"), item("C")]);
 
         // Can't move left from start
-        assert!(!list.move_left());
+        assert!(!list.move_left(false));
         assert_eq!(list.selected_index, 0);
 
         // Move right twice
-        assert!(list.move_right());
-        assert!(list.move_right());
+        assert!(list.move_right(false));
+        assert!(list.move_right(false));
         assert_eq!(list.selected_index, 2);
 
         // Can't move right from end
-        assert!(!list.move_right());
+        assert!(!list.move_right(false));
         assert_eq!(list.selected_index, 2);
 
         // Move left
-        assert!(list.move_left());
+        assert!(list.move_left(false));
         assert_eq!(list.selected_index, 1);
 
         // Empty list - no movement
         let mut empty = CategoryList::new(Vec::new());
-        assert!(!empty.move_left());
-        assert!(!empty.move_right());
+        assert!(!empty.move_left(false));
+        assert!(!empty.move_right(false));
+    }
+
+    #[test]
+    fn test_move_left_right_wraps_around() {
+        let mut list = CategoryList::new(vec![item("A"), item("B"), item("C")]);
+
+        // Left from the start jumps to the end
+        assert!(list.move_left(true));
+        assert_eq!(list.selected_index, 2);
+
+        // Right from the end jumps back to the start
+        assert!(list.move_right(true));
+        assert_eq!(list.selected_index, 0);
+
+        // Inside the row wrapping makes no difference
+        assert!(list.move_right(true));
+        assert_eq!(list.selected_index, 1);
+
+        // A single item has nowhere to wrap to
+        let mut single = CategoryList::new(vec![item("A")]);
+        assert!(!single.move_left(true));
+        assert!(!single.move_right(true));
+        assert_eq!(single.selected_index, 0);
+
+        let mut empty = CategoryList::new(Vec::new());
+        assert!(!empty.move_left(true));
+        assert!(!empty.move_right(true));
     }
 
     #[test]
@@ -290,4 +393,96 @@ mod tests {
         list.sort_inplace();
         assert_eq!(names(&list), vec!["Apple", "banana", "zebra"]);
     }
+
+    #[test]
+    fn test_set_sort_mode_only_resorts_its_list() {
+        let played = || {
+            vec![
+                item("Apple"),
+                item_with_timestamp("Zelda", 2000),
+                item_with_timestamp("Doom", 1000),
+            ]
+        };
+        let mut games = CategoryList::new(played());
+        let mut apps = CategoryList::new(played());
+        games.sort_inplace();
+        apps.sort_inplace();
+
+        games.selected_index = 1;
+        games.set_sort_mode(SortMode::Alphabetical);
+        assert_eq!(names(&games), vec!["Apple", "Doom", "Zelda"]);
+        // The selected item stays selected at its new position
+        assert_eq!(games.get_selected().unwrap().name, "Doom");
+
+        assert_eq!(apps.sort_mode, SortMode::Recent);
+        assert_eq!(names(&apps), vec!["Zelda", "Doom", "Apple"]);
+    }
+
+    #[test]
+    fn test_collect_favorites_combines_all_lists() {
+        let games = CategoryList::new(vec![favorite("Zelda"), item("Doom")]);
+        let apps = CategoryList::new(vec![item("Browser"), favorite("Kodi")]);
+        let system = CategoryList::new(vec![favorite("Suspend")]);
+
+        let favorites = collect_favorites(&[&games, &apps, &system]);
+        let names: Vec<&str> = favorites.iter().map(|i| i.name.as_str()).collect();
+
+        assert_eq!(names, vec!["Kodi", "Suspend", "Zelda"]);
+    }
+
+    #[test]
+    fn test_collect_favorites_orders_recently_started_first() {
+        let mut recent = favorite("Recent");
+        recent.last_started = Some(2000);
+        let games = CategoryList::new(vec![favorite("Alpha"), recent]);
+        let apps = CategoryList::new(Vec::new());
+
+        let favorites = collect_favorites(&[&games, &apps]);
+
+        assert_eq!(favorites[0].name, "Recent");
+        assert_eq!(favorites[1].name, "Alpha");
+    }
+
+    #[test]
+    fn test_collect_favorites_empty_without_favorites() {
+        let games = CategoryList::new(vec![item("Doom")]);
+        assert!(collect_favorites(&[&games]).is_empty());
+    }
+
+    #[test]
+    fn test_collect_recent() {
+        let games = CategoryList::new(vec![
+            item_with_timestamp("Old Game", 100),
+            item("Never Played"),
+            item_with_timestamp("New Game", 300),
+        ]);
+        let apps = CategoryList::new(vec![
+            item_with_timestamp("Browser", 200),
+            item("Unused App"),
+        ]);
+
+        let recent = CategoryList::new(collect_recent(&[&games, &apps], 10));
+        assert_eq!(names(&recent), vec!["New Game", "Browser", "Old Game"]);
+
+        let capped = CategoryList::new(collect_recent(&[&games, &apps], 2));
+        assert_eq!(names(&capped), vec!["New Game", "Browser"]);
+
+        assert!(collect_recent(&[&games, &apps], 0).is_empty());
+        assert!(collect_recent(&[&CategoryList::new(vec![item("A")])], 5).is_empty());
+    }
+
+    #[test]
+    fn test_most_recent_across_categories() {
+        let games = CategoryList::new(vec![
+            item_with_timestamp("Old Game", 100),
+            item("Never Played"),
+        ]);
+        let apps = CategoryList::new(vec![item_with_timestamp("Browser", 200)]);
+
+        let latest = most_recent(&[&games, &apps]).map(|item| item.name);
+        assert_eq!(latest.as_deref(), Some("Browser"));
+
+        let never_played = CategoryList::new(vec![item("Never Played")]);
+        assert!(most_recent(&[&never_played]).is_none());
+    }
 }
@@ -0,0 +1,82 @@
+//! Starting external processes behind a trait, so launches and system commands
+//! can be checked in tests without running anything.
+
+use std::io;
+use std::process::{Child, Command};
+
+use crate::launcher::{launch_app, launch_app_capturing_stderr, LaunchError};
+
+pub trait CommandRunner {
+    /// Starts `program` with `args` without waiting for it
+    fn spawn(&self, program: &str, args: &[&str]) -> io::Result<()>;
+
+    /// Starts an exec line through `sh -c`, see [`launch_app`]. Returns the pid and,
+    /// with `capture_stderr`, the child for watching its error output.
+    fn launch(&self, exec: &str, capture_stderr: bool)
+        -> Result<(u32, Option<Child>), LaunchError>;
+}
+
+/// Runs commands for real
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn spawn(&self, program: &str, args: &[&str]) -> io::Result<()> {
+        Command::new(program).args(args).spawn().map(|_| ())
+    }
+
+    fn launch(
+        &self,
+        exec: &str,
+        capture_stderr: bool,
+    ) -> Result<(u32, Option<Child>), LaunchError> {
+        if capture_stderr {
+            launch_app_capturing_stderr(exec).map(|child| (child.id(), Some(child)))
+        } else {
+            launch_app(exec).map(|pid| (pid, None))
+        }
+    }
+}
+
+/// Records commands instead of running them; clones share the record
+#[cfg(test)]
+#[derive(Debug, Clone, Default)]
+pub struct FakeRunner {
+    issued: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[cfg(test)]
+impl FakeRunner {
+    /// Command lines issued so far, programs joined with their arguments
+    pub fn issued(&self) -> Vec<String> {
+        self.issued.lock().unwrap().clone()
+    }
+
+    fn record(&self, command: String) {
+        self.issued.lock().unwrap().push(command);
+    }
+}
+
+#[cfg(test)]
+impl CommandRunner for FakeRunner {
+    fn spawn(&self, program: &str, args: &[&str]) -> io::Result<()> {
+        self.record(
+            std::iter::once(program)
+                .chain(args.iter().copied())
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        Ok(())
+    }
+
+    fn launch(
+        &self,
+        exec: &str,
+        _capture_stderr: bool,
+    ) -> Result<(u32, Option<Child>), LaunchError> {
+        if exec.trim().is_empty() {
+            return Err(LaunchError::EmptyCommand);
+        }
+        self.record(exec.to_string());
+        Ok((4242, None))
+    }
+}
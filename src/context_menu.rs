@@ -0,0 +1,309 @@
+use crate::launcher::{supports_launch_options, supports_launch_prefix, GAMESCOPE_16_9_PREFIX};
+use crate::model::{Category, LauncherAction, LauncherItem};
+use crate::store_page::{steam_appid, store_page};
+
+/// A single entry of the item context menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextMenuEntry {
+    Launch,
+    ToggleFavorite {
+        is_favorite: bool,
+    },
+    ToggleForce16x9 {
+        enabled: bool,
+    },
+    /// Edit the item's Steam-style launch options
+    LaunchOptions,
+    StorePage,
+    /// Developer, genres and description from the Steam store
+    Details,
+    /// Enter a SteamGridDB game ID when the automatic cover is wrong
+    FixArtwork,
+    /// Use the store's own image instead of SteamGridDB art, or go back
+    ToggleSourceArt {
+        enabled: bool,
+    },
+    /// Write a `.desktop` file so the game shows up in the system menu
+    CreateShortcut,
+    /// Puts the resolved launch command on the clipboard for troubleshooting
+    CopyLaunchCommand,
+    /// Opens the submenu of desktop entry actions
+    Actions,
+    RemoveEntry,
+    QuitLauncher,
+    Close,
+}
+
+impl ContextMenuEntry {
+    pub fn label(self) -> &'static str {
+        match self {
+            ContextMenuEntry::Launch => "Launch",
+            ContextMenuEntry::ToggleFavorite { is_favorite: false } => "Add to Favorites",
+            ContextMenuEntry::ToggleFavorite { is_favorite: true } => "Remove from Favorites",
+            ContextMenuEntry::ToggleForce16x9 { enabled: false } => "Force 16:9",
+            ContextMenuEntry::ToggleForce16x9 { enabled: true } => "Disable Force 16:9",
+            ContextMenuEntry::LaunchOptions => "Launch Options",
+            ContextMenuEntry::StorePage => "Store Page",
+            ContextMenuEntry::Details => "Game Details",
+            ContextMenuEntry::FixArtwork => "Fix Artwork",
+            ContextMenuEntry::ToggleSourceArt { enabled: false } => "Use Store Artwork",
+            ContextMenuEntry::ToggleSourceArt { enabled: true } => "Use SteamGridDB Artwork",
+            ContextMenuEntry::CreateShortcut => "Create Desktop Shortcut",
+            ContextMenuEntry::CopyLaunchCommand => "Copy Launch Command",
+            ContextMenuEntry::Actions => "Actions",
+            ContextMenuEntry::RemoveEntry => "Remove Entry",
+            ContextMenuEntry::QuitLauncher => "Quit Launcher",
+            ContextMenuEntry::Close => "Close",
+        }
+    }
+}
+
+/// Returns the context menu entries available for an item shown in the given category.
+/// The 16:9 preset is only offered when gamescope is installed.
+pub fn context_menu_entries(
+    category: Category,
+    item: &LauncherItem,
+    gamescope_available: bool,
+) -> Vec<ContextMenuEntry> {
+    let mut entries = vec![ContextMenuEntry::Launch];
+
+    if item.launch_key.is_some() {
+        entries.push(ContextMenuEntry::ToggleFavorite {
+            is_favorite: item.favorite,
+        });
+    }
+
+    if gamescope_available && item.launch_key.is_some() && is_direct_launch(item) {
+        entries.push(ContextMenuEntry::ToggleForce16x9 {
+            enabled: item.launch_prefix.as_deref() == Some(GAMESCOPE_16_9_PREFIX),
+        });
+    }
+
+    if item.launch_key.is_some() && accepts_launch_options(item) {
+        entries.push(ContextMenuEntry::LaunchOptions);
+    }
+
+    if category != Category::Apps && store_page(item).is_some() {
+        entries.push(ContextMenuEntry::StorePage);
+    }
+
+    if category != Category::Apps && steam_appid(item).is_some() {
+        entries.push(ContextMenuEntry::Details);
+    }
+
+    if category == Category::Games && item.launch_key.is_some() {
+        entries.push(ContextMenuEntry::FixArtwork);
+        if item.source_image_url.is_some() {
+            entries.push(ContextMenuEntry::ToggleSourceArt {
+                enabled: item.prefer_source_art,
+            });
+        }
+    }
+
+    if category == Category::Games
+        && item.launch_key.is_some()
+        && matches!(item.action, LauncherAction::Launch { .. })
+    {
+        entries.push(ContextMenuEntry::CreateShortcut);
+    }
+
+    if matches!(item.action, LauncherAction::Launch { .. }) {
+        entries.push(ContextMenuEntry::CopyLaunchCommand);
+    }
+
+    if !item.actions.is_empty() {
+        entries.push(ContextMenuEntry::Actions);
+    }
+
+    if category == Category::Apps {
+        entries.push(ContextMenuEntry::RemoveEntry);
+    }
+
+    entries.push(ContextMenuEntry::QuitLauncher);
+    entries.push(ContextMenuEntry::Close);
+    entries
+}
+
+fn is_direct_launch(item: &LauncherItem) -> bool {
+    matches!(&item.action, LauncherAction::Launch { exec } if supports_launch_prefix(exec))
+}
+
+/// Terminal apps are left out: options would end up outside the terminal's `sh -c`
+fn accepts_launch_options(item: &LauncherItem) -> bool {
+    !item.run_in_terminal
+        && matches!(&item.action, LauncherAction::Launch { exec } if supports_launch_options(exec))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::AppAction;
+
+    fn keyed_item(favorite: bool) -> LauncherItem {
+        LauncherItem {
+            launch_key: Some("steam:570".to_string()),
+            favorite,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_apps_menu_offers_remove_entry() {
+        let entries = context_menu_entries(Category::Apps, &keyed_item(false), false);
+        assert_eq!(
+            entries,
+            vec![
+                ContextMenuEntry::Launch,
+                ContextMenuEntry::ToggleFavorite { is_favorite: false },
+                ContextMenuEntry::RemoveEntry,
+                ContextMenuEntry::QuitLauncher,
+                ContextMenuEntry::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_actions_submenu_offered_for_items_with_actions() {
+        let mut item = keyed_item(false);
+        assert!(!context_menu_entries(Category::Apps, &item, false)
+            .contains(&ContextMenuEntry::Actions));
+
+        item.actions = vec![AppAction {
+            name: "New Private Window".to_string(),
+            exec: "firefox --private-window".to_string(),
+        }];
+        let entries = context_menu_entries(Category::Apps, &item, false);
+        assert!(entries.contains(&ContextMenuEntry::Actions));
+    }
+
+    #[test]
+    fn test_favorite_label_reflects_current_state() {
+        let entries = context_menu_entries(Category::Games, &keyed_item(true), false);
+        assert_eq!(entries[1].label(), "Remove from Favorites");
+        assert!(!entries.contains(&ContextMenuEntry::RemoveEntry));
+    }
+
+    #[test]
+    fn test_store_page_offered_for_store_games_only() {
+        let entries = context_menu_entries(Category::Games, &keyed_item(false), false);
+        assert!(entries.contains(&ContextMenuEntry::StorePage));
+
+        let mut rom = keyed_item(false);
+        rom.launch_key = Some("snes9x:/roms/smw.sfc".to_string());
+        let entries = context_menu_entries(Category::Games, &rom, false);
+        assert!(!entries.contains(&ContextMenuEntry::StorePage));
+    }
+
+    #[test]
+    fn test_details_offered_for_steam_games_only() {
+        let entries = context_menu_entries(Category::Games, &keyed_item(false), false);
+        assert!(entries.contains(&ContextMenuEntry::Details));
+
+        let mut gog = keyed_item(false);
+        gog.launch_key = Some("heroic:gog:1207658924".to_string());
+        let entries = context_menu_entries(Category::Games, &gog, false);
+        assert!(entries.contains(&ContextMenuEntry::StorePage));
+        assert!(!entries.contains(&ContextMenuEntry::Details));
+    }
+
+    #[test]
+    fn test_fix_artwork_offered_for_games_only() {
+        let entries = context_menu_entries(Category::Games, &keyed_item(false), false);
+        assert!(entries.contains(&ContextMenuEntry::FixArtwork));
+
+        let entries = context_menu_entries(Category::Apps, &keyed_item(false), false);
+        assert!(!entries.contains(&ContextMenuEntry::FixArtwork));
+    }
+
+    #[test]
+    fn test_store_artwork_offered_for_games_with_store_image() {
+        let entries = context_menu_entries(Category::Games, &keyed_item(false), false);
+        assert!(!entries
+            .iter()
+            .any(|e| matches!(e, ContextMenuEntry::ToggleSourceArt { .. })));
+
+        let mut item = keyed_item(false);
+        item.source_image_url = Some("https://cdn.example.com/cover.jpg".to_string());
+        let entries = context_menu_entries(Category::Games, &item, false);
+        assert!(entries.contains(&ContextMenuEntry::ToggleSourceArt { enabled: false }));
+
+        item.prefer_source_art = true;
+        let entries = context_menu_entries(Category::Games, &item, false);
+        assert!(entries.contains(&ContextMenuEntry::ToggleSourceArt { enabled: true }));
+    }
+
+    #[test]
+    fn test_desktop_shortcut_offered_for_launchable_games() {
+        let mut item = keyed_item(false);
+        item.action = LauncherAction::Launch {
+            exec: "steam -applaunch 570".to_string(),
+        };
+        let entries = context_menu_entries(Category::Games, &item, false);
+        assert!(entries.contains(&ContextMenuEntry::CreateShortcut));
+
+        let entries = context_menu_entries(Category::Apps, &item, false);
+        assert!(!entries.contains(&ContextMenuEntry::CreateShortcut));
+    }
+
+    #[test]
+    fn test_copy_launch_command_offered_for_launchable_items() {
+        let mut item = keyed_item(false);
+        item.action = LauncherAction::Launch {
+            exec: "firefox".to_string(),
+        };
+        let entries = context_menu_entries(Category::Apps, &item, false);
+        assert!(entries.contains(&ContextMenuEntry::CopyLaunchCommand));
+
+        let entries = context_menu_entries(Category::System, &LauncherItem::default(), false);
+        assert!(!entries.contains(&ContextMenuEntry::CopyLaunchCommand));
+    }
+
+    #[test]
+    fn test_items_without_launch_key_cannot_be_favorited() {
+        let entries = context_menu_entries(Category::Games, &LauncherItem::default(), true);
+        assert_eq!(
+            entries,
+            vec![
+                ContextMenuEntry::Launch,
+                ContextMenuEntry::QuitLauncher,
+                ContextMenuEntry::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_force_16_9_offered_for_direct_launches_with_gamescope() {
+        let mut item = keyed_item(false);
+        item.action = LauncherAction::Launch {
+            exec: "snes9x /roms/game.sfc".to_string(),
+        };
+
+        let entries = context_menu_entries(Category::Games, &item, true);
+        assert!(entries.contains(&ContextMenuEntry::ToggleForce16x9 { enabled: false }));
+
+        item.launch_prefix = Some(GAMESCOPE_16_9_PREFIX.to_string());
+        let entries = context_menu_entries(Category::Games, &item, true);
+        assert!(entries.contains(&ContextMenuEntry::ToggleForce16x9 { enabled: true }));
+
+        let entries = context_menu_entries(Category::Games, &item, false);
+        assert!(!entries
+            .iter()
+            .any(|e| matches!(e, ContextMenuEntry::ToggleForce16x9 { .. })));
+    }
+
+    #[test]
+    fn test_launch_options_offered_for_steam_but_not_heroic() {
+        let mut item = keyed_item(false);
+        item.action = LauncherAction::Launch {
+            exec: "steam -applaunch 570".to_string(),
+        };
+        assert!(context_menu_entries(Category::Games, &item, false)
+            .contains(&ContextMenuEntry::LaunchOptions));
+
+        item.action = LauncherAction::Launch {
+            exec: "xdg-open heroic://launch/legendary/Fortnite".to_string(),
+        };
+        assert!(!context_menu_entries(Category::Games, &item, false)
+            .contains(&ContextMenuEntry::LaunchOptions));
+    }
+}
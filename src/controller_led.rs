@@ -0,0 +1,191 @@
+//! Light bar color of DualSense and DualShock 4 controllers through the kernel's
+//! multicolor LED class (`/sys/class/leds/*:rgb:*`). Writing usually needs a udev rule
+//! granting access; pads without such an LED or without permission are skipped silently.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::ui_theme::COLOR_ACCENT;
+
+const LEDS_DIR: &str = "/sys/class/leds";
+
+/// Light bar colors for players 1-4 in PlayStation's order, repeating after that
+const PLAYER_COLORS: [[u8; 3]; 4] = [[0, 0, 255], [255, 0, 0], [0, 255, 0], [255, 0, 255]];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ControllerLedMode {
+    /// Leave the light bar as the driver set it
+    Off,
+    /// The theme's accent color on every pad
+    #[default]
+    Accent,
+    /// One color per player number
+    PerPlayer,
+}
+
+impl ControllerLedMode {
+    pub fn next(self) -> Self {
+        match self {
+            ControllerLedMode::Off => ControllerLedMode::Accent,
+            ControllerLedMode::Accent => ControllerLedMode::PerPlayer,
+            ControllerLedMode::PerPlayer => ControllerLedMode::Off,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            ControllerLedMode::Off => ControllerLedMode::PerPlayer,
+            ControllerLedMode::Accent => ControllerLedMode::Off,
+            ControllerLedMode::PerPlayer => ControllerLedMode::Accent,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ControllerLedMode::Off => "Off",
+            ControllerLedMode::Accent => "Accent",
+            ControllerLedMode::PerPlayer => "Per Player",
+        }
+    }
+
+    /// Color for the given player (starting at 1); `None` leaves the light bar alone
+    pub fn color(self, player_number: usize) -> Option<[u8; 3]> {
+        match self {
+            ControllerLedMode::Off => None,
+            ControllerLedMode::Accent => {
+                let byte = |channel: f32| (channel * 255.0).round() as u8;
+                Some([
+                    byte(COLOR_ACCENT.r),
+                    byte(COLOR_ACCENT.g),
+                    byte(COLOR_ACCENT.b),
+                ])
+            }
+            ControllerLedMode::PerPlayer => {
+                Some(PLAYER_COLORS[player_number.saturating_sub(1) % PLAYER_COLORS.len()])
+            }
+        }
+    }
+}
+
+/// Sets the light bar of a pad; `index` picks among several connected pads of the
+/// same model, in device order.
+pub fn apply_controller_led(vendor: u16, product: u16, index: usize, color: [u8; 3]) {
+    let leds = find_rgb_leds(Path::new(LEDS_DIR), vendor, product);
+    let Some(led) = leds.get(index) else {
+        return;
+    };
+    if let Err(err) = set_led_color(led, color) {
+        debug!(led = %led.display(), "Could not set controller light bar: {}", err);
+    }
+}
+
+/// RGB LEDs belonging to HID devices with the given IDs, sorted by device so identical
+/// pads keep a stable order
+fn find_rgb_leds(leds_dir: &Path, vendor: u16, product: u16) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(leds_dir) else {
+        return Vec::new();
+    };
+
+    let mut leds: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().contains(":rgb:"))
+        .filter_map(|entry| {
+            let device = fs::read_link(entry.path().join("device")).ok()?;
+            let device_name = device.file_name()?.to_string_lossy().to_string();
+            (parse_hid_device_name(&device_name)? == (vendor, product))
+                .then(|| (device_name, entry.path()))
+        })
+        .collect();
+    leds.sort();
+    leds.into_iter().map(|(_, path)| path).collect()
+}
+
+/// Vendor and product ID from a HID device name such as `0005:054C:0CE6.0003`
+fn parse_hid_device_name(name: &str) -> Option<(u16, u16)> {
+    let mut parts = name.split(':');
+    let _bus = parts.next()?;
+    let vendor = u16::from_str_radix(parts.next()?, 16).ok()?;
+    let product = u16::from_str_radix(parts.next()?.split('.').next()?, 16).ok()?;
+    Some((vendor, product))
+}
+
+/// `multi_intensity` takes one value per channel in `multi_index` order, which is
+/// red, green, blue for the PlayStation drivers
+fn format_multi_intensity([r, g, b]: [u8; 3]) -> String {
+    format!("{} {} {}", r, g, b)
+}
+
+fn set_led_color(led: &Path, color: [u8; 3]) -> io::Result<()> {
+    fs::write(led.join("multi_intensity"), format_multi_intensity(color))?;
+    // The channel intensities are scaled by the overall brightness
+    let max_brightness = fs::read_to_string(led.join("max_brightness"))
+        .ok()
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .unwrap_or(255);
+    fs::write(led.join("brightness"), max_brightness.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn test_find_rgb_leds_by_device_ids() {
+        let root = std::env::temp_dir().join(format!("rhinco-tv-leds-{}", std::process::id()));
+        let leds = root.join("leds");
+        let hid = root.join("hid");
+        fs::create_dir_all(&leds).unwrap();
+
+        let add_led = |led: &str, device: &str| {
+            fs::create_dir_all(hid.join(device)).unwrap();
+            fs::create_dir_all(leds.join(led)).unwrap();
+            symlink(hid.join(device), leds.join(led).join("device")).unwrap();
+        };
+        // Two DualSense pads, listed out of order, and a DualShock 4
+        add_led("input21:rgb:indicator", "0005:054C:0CE6.0007");
+        add_led("input12:rgb:indicator", "0003:054C:0CE6.0002");
+        add_led("input30:rgb:indicator", "0005:054C:09CC.0009");
+        // Player LEDs of the same pad aren't RGB
+        add_led("input12:white:player-1", "0003:054C:0CE6.0002");
+
+        assert_eq!(
+            find_rgb_leds(&leds, 0x054c, 0x0ce6),
+            vec![
+                leds.join("input12:rgb:indicator"),
+                leds.join("input21:rgb:indicator"),
+            ]
+        );
+        assert_eq!(
+            find_rgb_leds(&leds, 0x054c, 0x09cc),
+            vec![leds.join("input30:rgb:indicator")]
+        );
+        assert!(find_rgb_leds(&leds, 0x045e, 0x0b13).is_empty());
+        assert!(find_rgb_leds(&root.join("missing"), 0x054c, 0x0ce6).is_empty());
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_parse_hid_device_name() {
+        assert_eq!(
+            parse_hid_device_name("0005:054C:0CE6.0003"),
+            Some((0x054c, 0x0ce6))
+        );
+        assert_eq!(parse_hid_device_name("input12"), None);
+    }
+
+    #[test]
+    fn test_led_color_bytes() {
+        assert_eq!(format_multi_intensity([76, 201, 240]), "76 201 240");
+        assert_eq!(ControllerLedMode::Off.color(1), None);
+        assert_eq!(ControllerLedMode::Accent.color(3), Some([77, 201, 240]));
+        assert_eq!(ControllerLedMode::PerPlayer.color(2), Some([255, 0, 0]));
+        assert_eq!(ControllerLedMode::PerPlayer.color(5), Some([0, 0, 255]));
+    }
+}
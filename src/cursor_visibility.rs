@@ -0,0 +1,71 @@
+//! Hiding the mouse cursor on a TV: it disappears a few seconds after the mouse
+//! stops moving, and right away when the launcher is driven by gamepad or keyboard.
+
+use std::time::{Duration, Instant};
+
+/// Time without mouse movement before the cursor is hidden
+pub const CURSOR_IDLE_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Default)]
+pub struct CursorVisibility {
+    /// Last mouse movement; unset once the cursor is hidden
+    last_moved: Option<Instant>,
+}
+
+impl CursorVisibility {
+    pub fn mouse_moved(&mut self, now: Instant) {
+        self.last_moved = Some(now);
+    }
+
+    /// Gamepad or keyboard navigation hides the cursor immediately
+    pub fn navigated(&mut self) {
+        self.last_moved = None;
+    }
+
+    /// Hides the cursor once the mouse has been idle long enough.
+    pub fn tick(&mut self, now: Instant) {
+        if self
+            .last_moved
+            .is_some_and(|moved| now.saturating_duration_since(moved) >= CURSOR_IDLE_TIMEOUT)
+        {
+            self.last_moved = None;
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.last_moved.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hides_after_idle_timeout() {
+        let start = Instant::now();
+        let mut cursor = CursorVisibility::default();
+        assert!(!cursor.is_visible());
+
+        cursor.mouse_moved(start);
+        assert!(cursor.is_visible());
+        cursor.tick(start + Duration::from_secs(2));
+        assert!(cursor.is_visible());
+
+        // Moving again restarts the timeout
+        cursor.mouse_moved(start + Duration::from_secs(2));
+        cursor.tick(start + Duration::from_secs(4));
+        assert!(cursor.is_visible());
+        cursor.tick(start + Duration::from_secs(2) + CURSOR_IDLE_TIMEOUT);
+        assert!(!cursor.is_visible());
+    }
+
+    #[test]
+    fn test_navigation_hides_immediately() {
+        let start = Instant::now();
+        let mut cursor = CursorVisibility::default();
+        cursor.mouse_moved(start);
+        cursor.navigated();
+        assert!(!cursor.is_visible());
+    }
+}
@@ -0,0 +1,102 @@
+//! User-defined System row entries that run a shell command, e.g. "Restart Audio".
+
+use std::process::{Command, ExitStatus};
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{LauncherAction, LauncherItem, SystemIcon};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomAction {
+    pub name: String,
+    /// Image shown on the tile; a terminal icon is used when unset
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Run with `sh -c`
+    pub command: String,
+}
+
+impl CustomAction {
+    pub fn item(&self) -> LauncherItem {
+        LauncherItem {
+            name: self.name.clone(),
+            icon: self.icon.clone(),
+            system_icon: self.icon.is_none().then_some(SystemIcon::Terminal),
+            action: LauncherAction::Custom {
+                command: self.command.clone(),
+            },
+            launch_key: Some(format!("custom:{}", self.name)),
+            ..Default::default()
+        }
+    }
+}
+
+fn shell_command(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+/// Runs the command to completion and describes how it ended.
+pub fn run_custom_action(command: &str) -> Result<String, String> {
+    let status = shell_command(command)
+        .status()
+        .map_err(|e| format!("could not start: {}", e))?;
+    describe_exit(status)
+}
+
+fn describe_exit(status: ExitStatus) -> Result<String, String> {
+    match status.code() {
+        Some(0) => Ok("done".to_string()),
+        Some(code) => Err(format!("exited with status {}", code)),
+        None => Err("was terminated by a signal".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_custom_action() {
+        let action: CustomAction = serde_json::from_str(
+            r#"{ "name": "Restart Audio", "command": "systemctl --user restart pipewire" }"#,
+        )
+        .unwrap();
+        assert_eq!(action.icon, None);
+
+        let item = action.item();
+        assert_eq!(item.name, "Restart Audio");
+        assert_eq!(item.system_icon, Some(SystemIcon::Terminal));
+        assert_eq!(item.launch_key.as_deref(), Some("custom:Restart Audio"));
+        assert_eq!(
+            item.action,
+            LauncherAction::Custom {
+                command: "systemctl --user restart pipewire".to_string()
+            }
+        );
+
+        let with_icon = CustomAction {
+            icon: Some("/usr/share/icons/audio.png".to_string()),
+            ..action
+        };
+        assert_eq!(with_icon.item().system_icon, None);
+    }
+
+    #[test]
+    fn test_shell_command_dry_run() {
+        let command = shell_command("gamemoded -r && echo ok");
+        assert_eq!(command.get_program(), "sh");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, ["-c", "gamemoded -r && echo ok"]);
+    }
+
+    #[test]
+    fn test_run_reports_exit_status() {
+        assert_eq!(run_custom_action("true"), Ok("done".to_string()));
+        assert_eq!(
+            run_custom_action("exit 3"),
+            Err("exited with status 3".to_string())
+        );
+    }
+}
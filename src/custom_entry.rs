@@ -0,0 +1,229 @@
+//! Manually entered app entries (arbitrary commands or scripts) that don't come
+//! from a desktop file.
+
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::launcher::verify_command_exists;
+use crate::model::AppEntry;
+use crate::virtual_keyboard::VirtualKeyboard;
+
+const MAX_FIELD_LENGTH: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CustomEntryError {
+    #[error("Enter a name")]
+    MissingName,
+    #[error("Enter a command")]
+    MissingCommand,
+    #[error("Command not found: {0}")]
+    CommandNotFound(String),
+    #[error("Icon file not found: {0}")]
+    IconNotFound(String),
+}
+
+/// The field currently edited with the on-screen keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomEntryField {
+    Name,
+    Command,
+    /// Optional path to an image file
+    Icon,
+}
+
+impl CustomEntryField {
+    pub const ALL: &'static [CustomEntryField] = &[
+        CustomEntryField::Name,
+        CustomEntryField::Command,
+        CustomEntryField::Icon,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CustomEntryField::Name => "Name",
+            CustomEntryField::Command => "Command",
+            CustomEntryField::Icon => "Icon (optional)",
+        }
+    }
+
+    fn next(self) -> Option<Self> {
+        match self {
+            CustomEntryField::Name => Some(CustomEntryField::Command),
+            CustomEntryField::Command => Some(CustomEntryField::Icon),
+            CustomEntryField::Icon => None,
+        }
+    }
+
+    fn prev(self) -> Option<Self> {
+        match self {
+            CustomEntryField::Name => None,
+            CustomEntryField::Command => Some(CustomEntryField::Name),
+            CustomEntryField::Icon => Some(CustomEntryField::Command),
+        }
+    }
+}
+
+pub struct CustomEntryState {
+    pub field: CustomEntryField,
+    pub name: String,
+    pub command: String,
+    pub icon: String,
+    pub keyboard: VirtualKeyboard,
+    pub error: Option<CustomEntryError>,
+}
+
+impl CustomEntryState {
+    pub fn new() -> Self {
+        Self {
+            field: CustomEntryField::Name,
+            name: String::new(),
+            command: String::new(),
+            icon: String::new(),
+            keyboard: VirtualKeyboard::new(String::new()).with_max_length(MAX_FIELD_LENGTH),
+            error: None,
+        }
+    }
+
+    pub fn value(&self, field: CustomEntryField) -> &str {
+        match field {
+            CustomEntryField::Name => &self.name,
+            CustomEntryField::Command => &self.command,
+            CustomEntryField::Icon => &self.icon,
+        }
+    }
+
+    /// Stores keyboard input in the focused field
+    pub fn set_value(&mut self, value: String) {
+        match self.field {
+            CustomEntryField::Name => self.name = value,
+            CustomEntryField::Command => self.command = value,
+            CustomEntryField::Icon => self.icon = value,
+        }
+        self.error = None;
+    }
+
+    /// Moves focus to the next field; returns `false` on the last one.
+    pub fn focus_next(&mut self) -> bool {
+        self.focus(self.field.next())
+    }
+
+    /// Moves focus to the previous field; returns `false` on the first one.
+    pub fn focus_prev(&mut self) -> bool {
+        self.focus(self.field.prev())
+    }
+
+    fn focus(&mut self, field: Option<CustomEntryField>) -> bool {
+        let Some(field) = field else {
+            return false;
+        };
+        self.field = field;
+        self.keyboard.set_value(self.value(field).to_string());
+        true
+    }
+
+    pub fn build_entry(&self) -> Result<AppEntry, CustomEntryError> {
+        build_custom_entry(&self.name, &self.command, &self.icon)
+    }
+}
+
+/// Validates the typed fields and turns them into an app entry.
+///
+/// The command must resolve to an executable (in `PATH` or as a path); commands
+/// using shell syntax are accepted as-is since they can't be checked up front.
+pub fn build_custom_entry(
+    name: &str,
+    command: &str,
+    icon: &str,
+) -> Result<AppEntry, CustomEntryError> {
+    let name = name.trim();
+    let command = command.trim();
+    let icon = icon.trim();
+
+    if name.is_empty() {
+        return Err(CustomEntryError::MissingName);
+    }
+    if command.is_empty() {
+        return Err(CustomEntryError::MissingCommand);
+    }
+    if !verify_command_exists(command) {
+        return Err(CustomEntryError::CommandNotFound(command.to_string()));
+    }
+    if !icon.is_empty() && !Path::new(icon).is_file() {
+        return Err(CustomEntryError::IconNotFound(icon.to_string()));
+    }
+
+    let icon = (!icon.is_empty()).then(|| icon.to_string());
+    let entry = AppEntry::new(name.to_string(), command.to_string(), icon);
+    let launch_key = format!("custom:{}", entry.id);
+    Ok(entry.with_launch_key(launch_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_custom_entry() {
+        let entry = build_custom_entry("  My Script ", " sh -c 'echo hi' ", "").unwrap();
+
+        assert_eq!(entry.name, "My Script");
+        assert_eq!(entry.exec, "sh -c 'echo hi'");
+        assert_eq!(entry.icon, None);
+        assert_eq!(entry.launch_key, Some(format!("custom:{}", entry.id)));
+        assert!(!entry.run_in_terminal);
+    }
+
+    #[test]
+    fn test_build_custom_entry_with_icon() {
+        let icon = std::env::temp_dir().join(format!("custom_icon_{}.png", uuid::Uuid::new_v4()));
+        std::fs::write(&icon, b"png").unwrap();
+        let icon_str = icon.to_string_lossy().to_string();
+
+        let entry = build_custom_entry("Game", "sh", &icon_str).unwrap();
+        assert_eq!(entry.icon, Some(icon_str));
+
+        let _ = std::fs::remove_file(icon);
+    }
+
+    #[test]
+    fn test_build_custom_entry_validation() {
+        assert_eq!(
+            build_custom_entry(" ", "sh", "").unwrap_err(),
+            CustomEntryError::MissingName
+        );
+        assert_eq!(
+            build_custom_entry("Game", "   ", "").unwrap_err(),
+            CustomEntryError::MissingCommand
+        );
+        assert_eq!(
+            build_custom_entry("Game", "definitely-not-a-real-command-xyz --flag", "").unwrap_err(),
+            CustomEntryError::CommandNotFound(
+                "definitely-not-a-real-command-xyz --flag".to_string()
+            )
+        );
+        assert_eq!(
+            build_custom_entry("Game", "sh", "/nonexistent/icon.png").unwrap_err(),
+            CustomEntryError::IconNotFound("/nonexistent/icon.png".to_string())
+        );
+    }
+
+    #[test]
+    fn test_field_focus_keeps_typed_values() {
+        let mut state = CustomEntryState::new();
+        state.set_value("Game".to_string());
+        assert!(state.focus_next());
+        assert_eq!(state.field, CustomEntryField::Command);
+        assert_eq!(state.keyboard.value(), "");
+
+        state.set_value("sh".to_string());
+        assert!(state.focus_next());
+        assert!(!state.focus_next());
+        assert_eq!(state.field, CustomEntryField::Icon);
+
+        assert!(state.focus_prev());
+        assert!(state.focus_prev());
+        assert_eq!(state.keyboard.value(), "Game");
+        assert!(!state.focus_prev());
+    }
+}
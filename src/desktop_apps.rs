@@ -2,12 +2,20 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::model::AppAction;
+
 /// Represents a parsed XDG .desktop application
 #[derive(Debug, Clone)]
 pub struct DesktopApp {
     pub name: String,
     pub exec: String,
     pub icon_path: Option<PathBuf>,
+    /// `Terminal=true`: the app is a CLI/TUI program
+    pub terminal: bool,
+    /// `StartupWMClass`: window class of the app, used to find it once running
+    pub wm_class: Option<String>,
+    /// Entries of the `Actions=` key that have a name and exec line
+    pub actions: Vec<AppAction>,
     pub _desktop_file: PathBuf,
 }
 
@@ -66,30 +74,38 @@ fn scan_directory(dir: &Path, apps: &mut Vec<DesktopApp>) {
 
 fn parse_desktop_file(path: &Path) -> Option<DesktopApp> {
     let content = fs::read_to_string(path).ok()?;
+    parse_desktop_entry(&content, path)
+}
 
-    // Parse INI-like format
-    let mut in_desktop_entry = false;
-    let mut fields: HashMap<&str, String> = HashMap::new();
+fn parse_desktop_entry(content: &str, path: &Path) -> Option<DesktopApp> {
+    // Parse INI-like format: fields of each section, keyed by section name
+    let mut sections: HashMap<&str, HashMap<&str, String>> = HashMap::new();
+    let mut current_section: Option<&str> = None;
 
     for line in content.lines() {
         let line = line.trim();
 
         // Section header
-        if line.starts_with('[') {
-            in_desktop_entry = line == "[Desktop Entry]";
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current_section = Some(section);
             continue;
         }
 
-        if !in_desktop_entry {
+        let Some(section) = current_section else {
             continue;
-        }
+        };
 
         // Key=Value pairs
         if let Some((key, value)) = line.split_once('=') {
-            fields.insert(key.trim(), value.trim().to_string());
+            sections
+                .entry(section)
+                .or_default()
+                .insert(key.trim(), value.trim().to_string());
         }
     }
 
+    let fields = sections.get("Desktop Entry")?;
+
     // Filter criteria
     // Skip if Type is not Application
     if fields.get("Type").is_some_and(|t| t != "Application") {
@@ -118,14 +134,45 @@ fn parse_desktop_file(path: &Path) -> Option<DesktopApp> {
         .get("Icon")
         .and_then(|icon_name| resolve_icon(icon_name));
 
+    let terminal = fields.get("Terminal").is_some_and(|v| v == "true");
+
+    let wm_class = fields
+        .get("StartupWMClass")
+        .map(|class| class.trim().to_string())
+        .filter(|class| !class.is_empty());
+
+    let actions = fields
+        .get("Actions")
+        .map(|ids| parse_actions(ids, &sections))
+        .unwrap_or_default();
+
     Some(DesktopApp {
         name,
         exec,
         icon_path,
+        terminal,
+        wm_class,
+        actions,
         _desktop_file: path.to_path_buf(),
     })
 }
 
+/// Resolves the `;`-separated action ids to their `[Desktop Action <id>]` sections,
+/// skipping actions without a name or exec line.
+fn parse_actions(ids: &str, sections: &HashMap<&str, HashMap<&str, String>>) -> Vec<AppAction> {
+    ids.split(';')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .filter_map(|id| {
+            let fields = sections.get(format!("Desktop Action {}", id).as_str())?;
+            Some(AppAction {
+                name: fields.get("Name")?.clone(),
+                exec: clean_exec_command(fields.get("Exec")?),
+            })
+        })
+        .collect()
+}
+
 /// Remove .desktop field codes from exec command
 fn clean_exec_command(exec: &str) -> String {
     let mut result = String::new();
@@ -246,6 +293,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_desktop_actions() {
+        let content = "\
+[Desktop Entry]
+Type=Application
+Name=Firefox
+Exec=firefox %u
+Actions=new-window;new-private-window;broken;
+
+[Desktop Action new-window]
+Name=Open a New Window
+Exec=firefox --new-window %u
+
+[Desktop Action new-private-window]
+Name=Open a New Private Window
+Exec=firefox --private-window %u
+
+[Desktop Action broken]
+Name=Missing Exec
+";
+        let app = parse_desktop_entry(content, Path::new("firefox.desktop")).unwrap();
+
+        assert_eq!(app.name, "Firefox");
+        assert_eq!(app.exec, "firefox");
+        assert_eq!(
+            app.actions,
+            vec![
+                AppAction {
+                    name: "Open a New Window".to_string(),
+                    exec: "firefox --new-window".to_string(),
+                },
+                AppAction {
+                    name: "Open a New Private Window".to_string(),
+                    exec: "firefox --private-window".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_action_sections_do_not_override_entry() {
+        let content = "\
+[Desktop Action other]
+Name=Other
+Exec=other
+
+[Desktop Entry]
+Name=App
+Exec=app
+";
+        let app = parse_desktop_entry(content, Path::new("app.desktop")).unwrap();
+        assert_eq!(app.name, "App");
+        assert!(app.actions.is_empty());
+        assert_eq!(app.wm_class, None);
+    }
+
+    #[test]
+    fn test_parse_startup_wm_class() {
+        let content = "\
+[Desktop Entry]
+Name=SuperTuxKart
+Exec=supertuxkart
+StartupWMClass=SuperTuxKart
+";
+        let app = parse_desktop_entry(content, Path::new("supertuxkart.desktop")).unwrap();
+        assert_eq!(app.wm_class.as_deref(), Some("SuperTuxKart"));
+    }
+
     #[test]
     fn test_scan_finds_apps() {
         let apps = scan_desktop_apps();
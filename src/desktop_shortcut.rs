@@ -0,0 +1,126 @@
+//! Desktop entries for launcher games, so they show up in the system's application menu.
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::model::LauncherItem;
+
+/// File name in the applications directory, e.g. `rhinco-tv-steam-570.desktop`
+pub fn shortcut_file_name(launch_key: &str) -> String {
+    let slug: String = launch_key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("rhinco-tv-{}.desktop", slug)
+}
+
+/// Builds the desktop entry that runs `command` through `sh -c`, the way the launcher
+/// itself starts it. The cached cover is used as icon when there is one.
+pub fn desktop_entry(item: &LauncherItem, command: &str) -> String {
+    let mut entry = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec=sh -c {}\nTerminal=false\nCategories=Game;\n",
+        escape_value(&item.name),
+        quote_exec_arg(command)
+    );
+    if let Some(icon) = &item.icon {
+        entry.push_str(&format!("Icon={}\n", escape_value(icon)));
+    }
+    entry
+}
+
+/// Writes the entry to `~/.local/share/applications` and returns its path.
+pub fn write_desktop_shortcut(item: &LauncherItem, command: &str) -> Result<PathBuf, String> {
+    let launch_key = item
+        .launch_key
+        .as_deref()
+        .ok_or("This item can't get a shortcut")?;
+    let dir = directories::BaseDirs::new()
+        .map(|dirs| dirs.data_local_dir().join("applications"))
+        .ok_or("Could not determine the applications directory")?;
+    fs::create_dir_all(&dir)
+        .map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+
+    let path = dir.join(shortcut_file_name(launch_key));
+    fs::write(&path, desktop_entry(item, command))
+        .map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+    Ok(path)
+}
+
+/// Escapes a string value: backslashes and line breaks
+fn escape_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+}
+
+/// Quotes a single `Exec` argument. Inside the quotes `"`, `` ` ``, `$` and `\` need
+/// a backslash, `%` is doubled so it isn't taken as a field code, and the result is
+/// escaped once more as a string value (so a literal backslash ends up as four).
+pub fn quote_exec_arg(arg: &str) -> String {
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    for c in arg.chars() {
+        match c {
+            '"' | '`' | '$' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '%' => quoted.push_str("%%"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    escape_value(&quoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_desktop_entry_from_item() {
+        let item = LauncherItem {
+            name: "Half-Life 2".to_string(),
+            icon: Some("/home/user/.cache/rhinco-tv/covers/220.png".to_string()),
+            launch_key: Some("steam:220".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            desktop_entry(&item, "steam -applaunch 220"),
+            "[Desktop Entry]
+Type=Application
+Name=Half-Life 2
+Exec=sh -c \"steam -applaunch 220\"
+Terminal=false
+Categories=Game;
+Icon=/home/user/.cache/rhinco-tv/covers/220.png
+"
+        );
+        assert_eq!(
+            shortcut_file_name("steam:220"),
+            "rhinco-tv-steam-220.desktop"
+        );
+    }
+
+    #[test]
+    fn test_exec_escaping() {
+        let item = LauncherItem {
+            name: "Line\nBreak".to_string(),
+            launch_key: Some("custom:1".to_string()),
+            ..Default::default()
+        };
+        let entry = desktop_entry(
+            &item,
+            r#"env DXVK_HUD=fps "/opt/My Game/run.sh" --save=$HOME\saves 100%"#,
+        );
+
+        assert!(entry.contains("Name=Line\\nBreak\n"));
+        assert!(entry.contains(
+            r#"Exec=sh -c "env DXVK_HUD=fps \\"/opt/My Game/run.sh\\" --save=\\$HOME\\\\saves 100%%""#
+        ));
+        assert!(!entry.contains("Icon="));
+    }
+}
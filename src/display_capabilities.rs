@@ -0,0 +1,391 @@
+//! VRR (FreeSync/G-Sync/HDMI VRR) and HDR support of connected displays.
+//!
+//! Capabilities come from the display's EDID, read from `xrandr --props` on X11 or
+//! from `/sys/class/drm` otherwise. Whether a feature is currently on is only known
+//! where the session reports it: adaptive sync via `wlr-randr`, the HDR colorspace via
+//! `xrandr --props`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::launcher::verify_command_exists;
+
+const DRM_ROOT: &str = "/sys/class/drm";
+const EDID_BLOCK_SIZE: usize = 128;
+/// Tag of a CTA-861 extension block
+const CTA_EXTENSION_TAG: u8 = 0x02;
+/// IEEE OUIs of vendor specific data blocks advertising a VRR range
+const AMD_FREESYNC_OUI: u32 = 0x00001A;
+const HDMI_FORUM_OUI: u32 = 0xC45DD8;
+/// Extended tag of the HDR static metadata data block
+const HDR_STATIC_METADATA_TAG: u8 = 0x06;
+/// EOTF bits for SMPTE ST 2084 (PQ) and HLG
+const HDR_EOTF_MASK: u8 = 0b1100;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DisplayCapabilities {
+    /// Connector name, e.g. `HDMI-A-1`
+    pub output: String,
+    pub vrr_capable: bool,
+    /// `None` when the session doesn't report it
+    pub vrr_enabled: Option<bool>,
+    pub hdr_capable: bool,
+    /// `None` when the session doesn't report it
+    pub hdr_enabled: Option<bool>,
+}
+
+impl DisplayCapabilities {
+    pub fn vrr_status(&self) -> &'static str {
+        status_label(self.vrr_capable, self.vrr_enabled)
+    }
+
+    pub fn hdr_status(&self) -> &'static str {
+        status_label(self.hdr_capable, self.hdr_enabled)
+    }
+}
+
+/// The On/Off state is only shown for capable displays; a session may report the
+/// feature enabled on a display that can't use it
+fn status_label(capable: bool, enabled: Option<bool>) -> &'static str {
+    if !capable {
+        return "Not Supported";
+    }
+    match enabled {
+        Some(true) => "Supported (On)",
+        Some(false) => "Supported (Off)",
+        None => "Supported",
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct EdidFeatures {
+    vrr: bool,
+    hdr: bool,
+}
+
+pub fn detect_display_capabilities() -> Vec<DisplayCapabilities> {
+    if env::var("XDG_SESSION_TYPE").as_deref() == Ok("x11") {
+        return run_tool("xrandr", &["--props"])
+            .map(|output| parse_xrandr_props(&output))
+            .unwrap_or_default();
+    }
+
+    let mut displays = read_drm_connectors(Path::new(DRM_ROOT));
+    if let Some(output) = run_tool("wlr-randr", &[]) {
+        for (name, enabled) in parse_wlr_randr_adaptive_sync(&output) {
+            if let Some(display) = displays.iter_mut().find(|d| d.output == name) {
+                display.vrr_enabled = Some(enabled);
+                display.vrr_capable |= enabled;
+            }
+        }
+    }
+    displays
+}
+
+fn run_tool(program: &str, args: &[&str]) -> Option<String> {
+    if !verify_command_exists(program) {
+        return None;
+    }
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Connected connectors below `root` (`card0-HDMI-A-1`, ...) with their EDID features
+fn read_drm_connectors(root: &Path) -> Vec<DisplayCapabilities> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut connectors: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    connectors.sort();
+
+    connectors
+        .iter()
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            let (_, output) = name.split_once('-')?;
+            let status = fs::read_to_string(path.join("status")).ok()?;
+            if status.trim() != "connected" {
+                return None;
+            }
+            let features = fs::read(path.join("edid"))
+                .map(|edid| parse_edid(&edid))
+                .unwrap_or_default();
+            Some(DisplayCapabilities {
+                output: output.to_string(),
+                vrr_capable: features.vrr,
+                hdr_capable: features.hdr,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Looks for VRR ranges and HDR transfer functions in the CTA-861 extension blocks.
+fn parse_edid(edid: &[u8]) -> EdidFeatures {
+    let mut features = EdidFeatures::default();
+
+    for block in edid.chunks_exact(EDID_BLOCK_SIZE).skip(1) {
+        if block[0] != CTA_EXTENSION_TAG {
+            continue;
+        }
+        // Data blocks run from byte 4 up to the detailed timings at offset `block[2]`
+        let end = usize::from(block[2]).clamp(4, EDID_BLOCK_SIZE - 1);
+        let mut offset = 4;
+        while offset < end {
+            let header = block[offset];
+            let tag = header >> 5;
+            let length = usize::from(header & 0x1F);
+            let Some(payload) = block.get(offset + 1..offset + 1 + length) else {
+                break;
+            };
+
+            match tag {
+                // Vendor specific data block
+                3 if payload.len() >= 3 => {
+                    let oui = u32::from(payload[0])
+                        | u32::from(payload[1]) << 8
+                        | u32::from(payload[2]) << 16;
+                    if oui == AMD_FREESYNC_OUI {
+                        features.vrr = true;
+                    } else if oui == HDMI_FORUM_OUI && payload.len() >= 10 {
+                        let vrr_max = u16::from(payload[8] >> 6) << 8 | u16::from(payload[9]);
+                        features.vrr |= vrr_max > 0;
+                    }
+                }
+                // Extended tag
+                7 if payload.len() >= 2 && payload[0] == HDR_STATIC_METADATA_TAG => {
+                    features.hdr |= payload[1] & HDR_EOTF_MASK != 0;
+                }
+                _ => {}
+            }
+            offset += 1 + length;
+        }
+    }
+
+    features
+}
+
+/// Parses `xrandr --props`: per connected output the hex `EDID` property and the
+/// `vrr_capable` and `Colorspace` properties of the amdgpu/i915 drivers.
+fn parse_xrandr_props(output: &str) -> Vec<DisplayCapabilities> {
+    let mut displays: Vec<DisplayCapabilities> = Vec::new();
+    let mut edid_hex: Option<String> = None;
+    // Disconnected outputs list properties too; those are skipped
+    let mut in_connected = false;
+
+    let finish = |displays: &mut Vec<DisplayCapabilities>, edid_hex: &mut Option<String>| {
+        if let (Some(display), Some(hex)) = (displays.last_mut(), edid_hex.take()) {
+            let features = parse_edid(&decode_hex(&hex));
+            display.vrr_capable |= features.vrr;
+            display.hdr_capable = features.hdr;
+        }
+    };
+
+    for line in output.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            finish(&mut displays, &mut edid_hex);
+            let mut parts = line.split_whitespace();
+            in_connected = false;
+            if let (Some(name), Some("connected")) = (parts.next(), parts.next()) {
+                displays.push(DisplayCapabilities {
+                    output: name.to_string(),
+                    ..Default::default()
+                });
+                in_connected = true;
+            }
+            continue;
+        }
+        if !in_connected {
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if let Some(hex) = edid_hex.as_mut() {
+            if !trimmed.is_empty() && trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
+                hex.push_str(trimmed);
+                continue;
+            }
+            finish(&mut displays, &mut edid_hex);
+        }
+
+        let Some(display) = displays.last_mut() else {
+            continue;
+        };
+        let Some((key, value)) = trimmed.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "EDID" => edid_hex = Some(String::new()),
+            "vrr_capable" => display.vrr_capable |= value == "1",
+            "Colorspace" => display.hdr_enabled = Some(value.starts_with("BT2020")),
+            _ => {}
+        }
+    }
+    finish(&mut displays, &mut edid_hex);
+
+    displays
+}
+
+/// `Adaptive Sync: enabled` lines below each unindented `wlr-randr` output header
+fn parse_wlr_randr_adaptive_sync(output: &str) -> Vec<(String, bool)> {
+    let mut states = Vec::new();
+    let mut current_output: Option<&str> = None;
+
+    for line in output.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            current_output = line.split_whitespace().next();
+            continue;
+        }
+        let (Some(name), Some(value)) =
+            (current_output, line.trim().strip_prefix("Adaptive Sync:"))
+        else {
+            continue;
+        };
+        states.push((name.to_string(), value.trim() == "enabled"));
+    }
+
+    states
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    hex.as_bytes()
+        .chunks_exact(2)
+        .filter_map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A base block followed by a CTA extension holding the given data blocks
+    fn edid_with_data_blocks(data_blocks: &[&[u8]]) -> Vec<u8> {
+        let mut edid = vec![0u8; EDID_BLOCK_SIZE * 2];
+        edid[..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        edid[126] = 1;
+
+        let cta = &mut edid[EDID_BLOCK_SIZE..];
+        cta[0] = CTA_EXTENSION_TAG;
+        cta[1] = 3;
+        let mut offset = 4;
+        for block in data_blocks {
+            cta[offset..offset + block.len()].copy_from_slice(block);
+            offset += block.len();
+        }
+        cta[2] = offset as u8;
+        edid
+    }
+
+    const HDR_STATIC_METADATA: &[u8] = &[0xE3, 0x06, 0x0D, 0x01];
+    const FREESYNC_VSDB: &[u8] = &[0x68, 0x1A, 0x00, 0x00, 0x01, 0x01, 0x30, 0x90, 0x00];
+
+    fn to_hex_lines(edid: &[u8]) -> String {
+        edid.chunks(16)
+            .map(|line| {
+                let hex: String = line.iter().map(|b| format!("{:02x}", b)).collect();
+                format!("\t\t{}\n", hex)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_edid_flags() {
+        let edid = edid_with_data_blocks(&[HDR_STATIC_METADATA, FREESYNC_VSDB]);
+        assert_eq!(
+            parse_edid(&edid),
+            EdidFeatures {
+                vrr: true,
+                hdr: true
+            }
+        );
+
+        // HDMI Forum VSDB: VRRmin 40 Hz, VRRmax 120 Hz
+        let hf_vsdb: &[u8] = &[
+            0x6A, 0xD8, 0x5D, 0xC4, 0x01, 0x78, 0x00, 0x00, 0x00, 0x28, 0x78,
+        ];
+        assert!(parse_edid(&edid_with_data_blocks(&[hf_vsdb])).vrr);
+
+        // SDR-only transfer functions and a base block alone
+        let sdr: &[u8] = &[0xE3, 0x06, 0x03, 0x01];
+        assert_eq!(
+            parse_edid(&edid_with_data_blocks(&[sdr])),
+            EdidFeatures::default()
+        );
+        assert_eq!(parse_edid(&[0u8; 128]), EdidFeatures::default());
+    }
+
+    #[test]
+    fn test_parse_xrandr_props() {
+        let output = format!(
+            "Screen 0: minimum 320 x 200, current 3840 x 2160, maximum 16384 x 16384
+DisplayPort-0 connected primary 3840x2160+0+0 (normal left inverted right x axis y axis) 600mm x 340mm
+\tEDID:
+{}\tvrr_capable: 1
+\t\trange: (0, 1)
+\tColorspace: BT2020_RGB
+\t\tsupported: Default, BT709_YCC, BT2020_RGB, BT2020_YCC
+   3840x2160     60.00*+
+HDMI-A-0 connected 1920x1080+3840+0 (normal left inverted right x axis y axis) 1600mm x 900mm
+\tEDID:
+{}\tvrr_capable: 0
+\t\trange: (0, 1)
+\tColorspace: Default
+   1920x1080     60.00*+
+DisplayPort-1 disconnected (normal left inverted right x axis y axis)
+\tvrr_capable: 0
+",
+            to_hex_lines(&edid_with_data_blocks(&[HDR_STATIC_METADATA])),
+            to_hex_lines(&edid_with_data_blocks(&[])),
+        );
+
+        let displays = parse_xrandr_props(&output);
+        assert_eq!(
+            displays,
+            vec![
+                DisplayCapabilities {
+                    output: "DisplayPort-0".to_string(),
+                    vrr_capable: true,
+                    vrr_enabled: None,
+                    hdr_capable: true,
+                    hdr_enabled: Some(true),
+                },
+                DisplayCapabilities {
+                    output: "HDMI-A-0".to_string(),
+                    vrr_capable: false,
+                    vrr_enabled: None,
+                    hdr_capable: false,
+                    hdr_enabled: Some(false),
+                },
+            ]
+        );
+        assert_eq!(displays[0].vrr_status(), "Supported");
+        assert_eq!(displays[0].hdr_status(), "Supported (On)");
+        assert_eq!(displays[1].hdr_status(), "Not Supported");
+        // Reported as enabled on a display that can't use it
+        assert_eq!(status_label(false, Some(true)), "Not Supported");
+    }
+
+    #[test]
+    fn test_parse_wlr_randr_adaptive_sync() {
+        let output = "\
+DP-1 \"Dell Inc. DELL S2721DGF (DP-1)\"
+  Enabled: yes
+  Modes:
+    2560x1440 px, 164.802002 Hz (preferred, current)
+  Adaptive Sync: enabled
+HDMI-A-1 \"LG Electronics LG TV (HDMI-A-1)\"
+  Enabled: yes
+  Adaptive Sync: disabled
+";
+        assert_eq!(
+            parse_wlr_randr_adaptive_sync(output),
+            vec![("DP-1".to_string(), true), ("HDMI-A-1".to_string(), false)]
+        );
+    }
+}
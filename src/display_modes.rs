@@ -0,0 +1,262 @@
+//! Listing and switching display resolution/refresh rate via `wlr-randr` (Wayland)
+//! or `xrandr` (X11).
+
+use std::env;
+use std::process::Command;
+
+use crate::launcher::verify_command_exists;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayBackend {
+    WlrRandr,
+    Xrandr,
+}
+
+impl DisplayBackend {
+    fn program(self) -> &'static str {
+        match self {
+            DisplayBackend::WlrRandr => "wlr-randr",
+            DisplayBackend::Xrandr => "xrandr",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayMode {
+    /// Connector name, e.g. `HDMI-A-1`
+    pub output: String,
+    pub width: u32,
+    pub height: u32,
+    pub refresh: f32,
+    pub current: bool,
+}
+
+impl DisplayMode {
+    pub fn label(&self) -> String {
+        format!(
+            "{}  {}x{} @ {:.2} Hz",
+            self.output, self.width, self.height, self.refresh
+        )
+    }
+}
+
+/// Picks the mode tool matching the session type; `None` when it isn't installed.
+pub fn detect_display_backend() -> Option<DisplayBackend> {
+    let backend = match env::var("XDG_SESSION_TYPE").ok()?.as_str() {
+        "wayland" => DisplayBackend::WlrRandr,
+        "x11" => DisplayBackend::Xrandr,
+        _ => return None,
+    };
+    verify_command_exists(backend.program()).then_some(backend)
+}
+
+pub fn list_display_modes(backend: DisplayBackend) -> Result<Vec<DisplayMode>, String> {
+    let output = Command::new(backend.program())
+        .output()
+        .map_err(|err| format!("Failed to run {}: {}", backend.program(), err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            backend.program(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(match backend {
+        DisplayBackend::WlrRandr => parse_wlr_randr(&stdout),
+        DisplayBackend::Xrandr => parse_xrandr(&stdout),
+    })
+}
+
+pub fn apply_display_mode(backend: DisplayBackend, mode: &DisplayMode) -> Result<(), String> {
+    let size = format!("{}x{}", mode.width, mode.height);
+    let mut command = Command::new(backend.program());
+    command.args(["--output", mode.output.as_str(), "--mode"]);
+    match backend {
+        DisplayBackend::WlrRandr => {
+            command.arg(format!("{}@{:.6}Hz", size, mode.refresh));
+        }
+        DisplayBackend::Xrandr => {
+            command.args([size, "--rate".to_string(), format!("{:.2}", mode.refresh)]);
+        }
+    }
+
+    let output = command
+        .output()
+        .map_err(|err| format!("Failed to run {}: {}", backend.program(), err))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Could not switch to {}: {}",
+            mode.label(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Parses `WxH` (xrandr may append `i` for interlaced modes)
+fn parse_size(text: &str) -> Option<(u32, u32)> {
+    let (width, height) = text.split_once('x')?;
+    let height = height.trim_end_matches('i');
+    Some((width.parse().ok()?, height.parse().ok()?))
+}
+
+/// Parses `xrandr` output: mode lines are indented below a `<output> connected` line,
+/// each listing the size followed by its refresh rates. `*` marks the active rate.
+fn parse_xrandr(output: &str) -> Vec<DisplayMode> {
+    let mut modes = Vec::new();
+    let mut current_output: Option<&str> = None;
+
+    for line in output.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            let mut parts = line.split_whitespace();
+            let name = parts.next();
+            current_output = name.filter(|_| parts.next() == Some("connected"));
+            continue;
+        }
+
+        let Some(output_name) = current_output else {
+            continue;
+        };
+        let mut tokens = line.split_whitespace();
+        let Some((width, height)) = tokens.next().and_then(parse_size) else {
+            continue;
+        };
+
+        for token in tokens {
+            let rate = token.trim_end_matches(['*', '+']);
+            let Ok(refresh) = rate.parse::<f32>() else {
+                continue;
+            };
+            modes.push(DisplayMode {
+                output: output_name.to_string(),
+                width,
+                height,
+                refresh,
+                current: token.contains('*'),
+            });
+        }
+    }
+
+    modes
+}
+
+/// Parses `wlr-randr` output: an unindented output header followed by an indented
+/// `Modes:` block with lines like `1920x1080 px, 60.000000 Hz (preferred, current)`.
+fn parse_wlr_randr(output: &str) -> Vec<DisplayMode> {
+    let mut modes = Vec::new();
+    let mut current_output: Option<&str> = None;
+
+    for line in output.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            current_output = line.split_whitespace().next();
+            continue;
+        }
+
+        let Some(output_name) = current_output else {
+            continue;
+        };
+        let Some((size, rest)) = line.trim().split_once(" px, ") else {
+            continue;
+        };
+        let Some((width, height)) = parse_size(size) else {
+            continue;
+        };
+        let Some((rate, flags)) = rest.split_once(" Hz") else {
+            continue;
+        };
+        let Ok(refresh) = rate.trim().parse::<f32>() else {
+            continue;
+        };
+
+        modes.push(DisplayMode {
+            output: output_name.to_string(),
+            width,
+            height,
+            refresh,
+            current: flags.contains("current"),
+        });
+    }
+
+    modes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XRANDR_OUTPUT: &str = "\
+Screen 0: minimum 320 x 200, current 3840 x 2160, maximum 16384 x 16384
+HDMI-1 connected primary 3840x2160+0+0 (normal left inverted right x axis y axis) 1600mm x 900mm
+   3840x2160     60.00*+  59.94    30.00
+   1920x1080    120.00    60.00 +  59.94
+   1920x1080i    60.00
+DP-1 disconnected (normal left inverted right x axis y axis)
+   1280x720      60.00
+";
+
+    const WLR_RANDR_OUTPUT: &str = "\
+HDMI-A-1 \"LG Electronics LG TV 0x01010101 (HDMI-A-1)\"
+  Make: LG Electronics
+  Model: LG TV
+  Physical size: 1600x900 mm
+  Enabled: yes
+  Modes:
+    3840x2160 px, 60.000000 Hz (preferred, current)
+    3840x2160 px, 30.000000 Hz
+    1920x1080 px, 119.879997 Hz
+  Position: 0,0
+  Transform: normal
+  Scale: 1.000000
+";
+
+    #[test]
+    fn test_parse_xrandr() {
+        let modes = parse_xrandr(XRANDR_OUTPUT);
+
+        assert_eq!(modes.len(), 7);
+        assert!(modes.iter().all(|m| m.output == "HDMI-1"));
+        assert_eq!(
+            modes[0],
+            DisplayMode {
+                output: "HDMI-1".to_string(),
+                width: 3840,
+                height: 2160,
+                refresh: 60.0,
+                current: true,
+            }
+        );
+        assert_eq!(modes.iter().filter(|m| m.current).count(), 1);
+        assert_eq!((modes[3].width, modes[3].refresh), (1920, 120.0));
+        // Preferred marker separated by a space is not a rate of its own
+        assert_eq!(modes[4].refresh, 60.0);
+        assert_eq!((modes[6].width, modes[6].height), (1920, 1080));
+    }
+
+    #[test]
+    fn test_parse_wlr_randr() {
+        let modes = parse_wlr_randr(WLR_RANDR_OUTPUT);
+
+        assert_eq!(modes.len(), 3);
+        assert_eq!(
+            modes[0],
+            DisplayMode {
+                output: "HDMI-A-1".to_string(),
+                width: 3840,
+                height: 2160,
+                refresh: 60.0,
+                current: true,
+            }
+        );
+        assert!(!modes[1].current);
+        assert_eq!(modes[2].label(), "HDMI-A-1  1920x1080 @ 119.88 Hz");
+    }
+
+    #[test]
+    fn test_parse_empty_output() {
+        assert!(parse_xrandr("").is_empty());
+        assert!(parse_wlr_randr("garbage\n  more garbage\n").is_empty());
+    }
+}
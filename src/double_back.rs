@@ -0,0 +1,43 @@
+//! Detecting two quick Back presses on the home screen, which asks to quit the launcher.
+
+use std::time::{Duration, Instant};
+
+/// Longest gap between the two presses
+pub const DOUBLE_BACK_WINDOW: Duration = Duration::from_millis(600);
+
+#[derive(Debug, Default)]
+pub struct DoubleBack {
+    last_press: Option<Instant>,
+}
+
+impl DoubleBack {
+    /// Records a Back press; true when it completes a double press.
+    /// A third quick press starts over instead of counting as another double press.
+    pub fn press(&mut self, now: Instant) -> bool {
+        let double = self
+            .last_press
+            .is_some_and(|last| now.saturating_duration_since(last) <= DOUBLE_BACK_WINDOW);
+        self.last_press = (!double).then_some(now);
+        double
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_press_window() {
+        let start = Instant::now();
+        let mut back = DoubleBack::default();
+        assert!(!back.press(start));
+        assert!(back.press(start + Duration::from_millis(300)));
+
+        // Consumed by the double press
+        assert!(!back.press(start + Duration::from_millis(500)));
+
+        // Too slow: the late press becomes the first of a new pair
+        assert!(!back.press(start + Duration::from_millis(1200)));
+        assert!(back.press(start + Duration::from_millis(1500)));
+    }
+}
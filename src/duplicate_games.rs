@@ -0,0 +1,135 @@
+//! Collapsing games found in several sources (e.g. owned on Steam and via Heroic)
+//! into a single tile that asks which copy to launch.
+
+use std::collections::HashMap;
+
+use crate::model::{source_badge, LauncherItem};
+
+/// Title used to detect duplicates: lowercase letters and digits only, so
+/// punctuation, spacing and trademark signs don't matter.
+pub fn normalize_title(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Merges games with the same normalized title into the first one found; the
+/// others are kept as its `alternates`. The merged tile shows the most recent
+/// launch of any copy.
+pub fn merge_duplicates(items: Vec<LauncherItem>) -> Vec<LauncherItem> {
+    let mut merged: Vec<LauncherItem> = Vec::with_capacity(items.len());
+    let mut index_by_title: HashMap<String, usize> = HashMap::new();
+
+    for item in items {
+        let title = normalize_title(&item.name);
+        if title.is_empty() {
+            merged.push(item);
+            continue;
+        }
+
+        match index_by_title.get(&title) {
+            Some(&index) => {
+                let primary = &mut merged[index];
+                primary.last_started = primary.last_started.max(item.last_started);
+                primary.alternates.push(item);
+            }
+            None => {
+                index_by_title.insert(title, merged.len());
+                merged.push(item);
+            }
+        }
+    }
+
+    merged
+}
+
+/// Undoes [`merge_duplicates`], e.g. after the setting was turned off.
+pub fn split_duplicates(items: Vec<LauncherItem>) -> Vec<LauncherItem> {
+    let mut split = Vec::with_capacity(items.len());
+    for mut item in items {
+        let alternates = std::mem::take(&mut item.alternates);
+        split.push(item);
+        split.extend(alternates);
+    }
+    split
+}
+
+/// Menu label for one copy of a merged game, e.g. "Steam" or "GOG"
+pub fn source_label(item: &LauncherItem) -> String {
+    item.launch_key
+        .as_deref()
+        .and_then(source_badge)
+        .map(|badge| badge.name().to_string())
+        .unwrap_or_else(|| item.name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(name: &str, launch_key: &str, last_started: Option<i64>) -> LauncherItem {
+        LauncherItem {
+            name: name.to_string(),
+            launch_key: Some(launch_key.to_string()),
+            last_started,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(
+            normalize_title("The Witcher® 3: Wild Hunt"),
+            "thewitcher3wildhunt"
+        );
+        assert_eq!(
+            normalize_title("the witcher 3 - wild hunt"),
+            "thewitcher3wildhunt"
+        );
+        assert_eq!(normalize_title("Portal 2"), "portal2");
+        assert_ne!(normalize_title("Portal 2"), normalize_title("Portal"));
+        assert_eq!(normalize_title("™"), "");
+    }
+
+    #[test]
+    fn test_merge_steam_and_heroic_copy() {
+        let items = vec![
+            game("Hades", "steam:1145360", Some(100)),
+            game("Celeste", "steam:504230", None),
+            game("HADES", "heroic:legendary:Min", Some(300)),
+        ];
+
+        let merged = merge_duplicates(items);
+        assert_eq!(merged.len(), 2);
+
+        let hades = &merged[0];
+        assert_eq!(hades.launch_key.as_deref(), Some("steam:1145360"));
+        assert_eq!(hades.last_started, Some(300));
+        assert_eq!(hades.alternates.len(), 1);
+        assert_eq!(
+            hades.alternates[0].launch_key.as_deref(),
+            Some("heroic:legendary:Min")
+        );
+        assert_eq!(source_label(hades), "Steam");
+        assert_eq!(source_label(&hades.alternates[0]), "Epic Games");
+
+        assert!(merged[1].alternates.is_empty());
+    }
+
+    #[test]
+    fn test_split_restores_all_copies() {
+        let items = vec![
+            game("Hades", "steam:1145360", None),
+            game("Hades", "heroic:legendary:Min", None),
+        ];
+
+        let split = split_duplicates(merge_duplicates(items));
+        let keys: Vec<_> = split
+            .iter()
+            .map(|item| item.launch_key.as_deref().unwrap())
+            .collect();
+        assert_eq!(keys, vec!["steam:1145360", "heroic:legendary:Min"]);
+        assert!(split.iter().all(|item| item.alternates.is_empty()));
+    }
+}
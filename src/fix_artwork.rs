@@ -0,0 +1,148 @@
+//! "Fix Artwork": entering a SteamGridDB game ID when the automatic name search
+//! picked the wrong game.
+
+use uuid::Uuid;
+
+use crate::model::LauncherItem;
+use crate::virtual_keyboard::VirtualKeyboard;
+
+/// SteamGridDB IDs are well below this many digits
+const MAX_ID_LENGTH: usize = 12;
+
+pub struct FixArtworkState {
+    pub item_id: Uuid,
+    pub game_name: String,
+    pub launch_key: String,
+    pub keyboard: VirtualKeyboard,
+    pub error: Option<String>,
+    /// Position in the "Games Without Artwork" list to go back to on cancel,
+    /// when opened from there
+    pub review_index: Option<usize>,
+}
+
+impl FixArtworkState {
+    /// Starts with the currently used ID, if the game already has one.
+    pub fn new(item_id: Uuid, game_name: String, launch_key: String, current: Option<u64>) -> Self {
+        let initial = current.map(|id| id.to_string()).unwrap_or_default();
+        Self {
+            item_id,
+            game_name,
+            launch_key,
+            keyboard: VirtualKeyboard::new(initial).with_max_length(MAX_ID_LENGTH),
+            error: None,
+            review_index: None,
+        }
+    }
+}
+
+/// A game still showing the fallback icon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingArtwork {
+    pub item_id: Uuid,
+    pub name: String,
+}
+
+/// Games without a cover (or only a generated placeholder) that aren't waiting for a
+/// download either, by name. Games without a launch key are left out since their
+/// artwork can't be fixed.
+pub fn games_missing_artwork(
+    items: &[LauncherItem],
+    is_queued: impl Fn(Uuid) -> bool,
+    is_placeholder: impl Fn(&str) -> bool,
+) -> Vec<MissingArtwork> {
+    let mut games: Vec<MissingArtwork> = items
+        .iter()
+        .filter(|item| {
+            item.icon.as_deref().is_none_or(&is_placeholder)
+                && item.launch_key.is_some()
+                && !is_queued(item.id)
+        })
+        .map(|item| MissingArtwork {
+            item_id: item.id,
+            name: item.name.clone(),
+        })
+        .collect();
+    games.sort_by_key(|game| game.name.to_lowercase());
+    games
+}
+
+/// Parses the typed SteamGridDB game ID; only positive numbers are valid.
+pub fn parse_sgdb_id(input: &str) -> Option<u64> {
+    let input = input.trim();
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    input.parse().ok().filter(|id| *id > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sgdb_id() {
+        assert_eq!(parse_sgdb_id("5247542"), Some(5247542));
+        assert_eq!(parse_sgdb_id(" 36 "), Some(36));
+        assert_eq!(parse_sgdb_id(""), None);
+        assert_eq!(parse_sgdb_id("0"), None);
+        assert_eq!(parse_sgdb_id("-12"), None);
+        assert_eq!(parse_sgdb_id("12a"), None);
+        assert_eq!(parse_sgdb_id("99999999999999999999999"), None);
+    }
+
+    #[test]
+    fn test_new_prefills_current_id() {
+        let state =
+            FixArtworkState::new(Uuid::nil(), "Portal".into(), "steam:400".into(), Some(42));
+        assert_eq!(state.keyboard.value(), "42");
+
+        let state = FixArtworkState::new(Uuid::nil(), "Portal".into(), "steam:400".into(), None);
+        assert_eq!(state.keyboard.value(), "");
+    }
+
+    #[test]
+    fn test_games_missing_artwork_lists_items_without_icon() {
+        let game = |name: &str, icon: Option<&str>| LauncherItem {
+            name: name.to_string(),
+            icon: icon.map(str::to_string),
+            launch_key: Some(format!("steam:{}", name)),
+            ..Default::default()
+        };
+        let items = vec![
+            game("Portal", Some("/covers/portal.png")),
+            game("celeste", None),
+            game("Doom", None),
+            game("Hades", None),
+            game("Tunic", Some("/covers/placeholders/Tunic.png")),
+            LauncherItem {
+                name: "No Key".to_string(),
+                ..Default::default()
+            },
+        ];
+        // Hades' cover is still downloading
+        let queued = items[3].id;
+
+        let missing = games_missing_artwork(
+            &items,
+            |id| id == queued,
+            |icon| icon.contains("/placeholders/"),
+        );
+        assert_eq!(
+            missing,
+            vec![
+                MissingArtwork {
+                    item_id: items[1].id,
+                    name: "celeste".to_string(),
+                },
+                MissingArtwork {
+                    item_id: items[2].id,
+                    name: "Doom".to_string(),
+                },
+                MissingArtwork {
+                    item_id: items[4].id,
+                    name: "Tunic".to_string(),
+                },
+            ]
+        );
+    }
+}
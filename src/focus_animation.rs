@@ -0,0 +1,162 @@
+//! Timer-driven fade of the selection highlight between tiles.
+
+use std::time::{Duration, Instant};
+
+use crate::model::Category;
+
+/// Length of the transition from one tile to the next
+pub const FOCUS_ANIMATION_DURATION: Duration = Duration::from_millis(120);
+/// Redraw interval while a transition runs (~60 fps)
+pub const FOCUS_ANIMATION_FRAME: Duration = Duration::from_millis(16);
+
+/// A tile position in one of the category rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusKey {
+    pub category: Category,
+    pub index: usize,
+}
+
+/// Tracks the previously and currently selected tile so the highlight can fade
+/// out of one while fading into the other.
+pub struct FocusAnimation {
+    previous: Option<FocusKey>,
+    current: Option<FocusKey>,
+    started_at: Option<Instant>,
+    /// Time of the last rendered frame
+    now: Instant,
+}
+
+impl FocusAnimation {
+    pub fn new() -> Self {
+        Self {
+            previous: None,
+            current: None,
+            started_at: None,
+            now: Instant::now(),
+        }
+    }
+
+    /// Starts a transition when the selection moved; does nothing otherwise.
+    /// With `reduce_motion` the highlight jumps immediately.
+    pub fn focus(&mut self, key: FocusKey, now: Instant, reduce_motion: bool) {
+        if self.current == Some(key) {
+            return;
+        }
+
+        self.previous = self.current.filter(|_| !reduce_motion);
+        self.current = Some(key);
+        self.now = now;
+        self.started_at = (!reduce_motion).then_some(now);
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Advances to `now`, finishing the transition once its duration has passed
+    pub fn tick(&mut self, now: Instant) {
+        self.now = now;
+        if self.progress() >= 1.0 {
+            self.started_at = None;
+            self.previous = None;
+        }
+    }
+
+    /// Eased transition progress from 0.0 (just started) to 1.0 (settled)
+    fn progress(&self) -> f32 {
+        let Some(started_at) = self.started_at else {
+            return 1.0;
+        };
+        let elapsed = self.now.saturating_duration_since(started_at);
+        ease_out_cubic(elapsed.as_secs_f32() / FOCUS_ANIMATION_DURATION.as_secs_f32())
+    }
+
+    /// Highlight strength of a tile, from 0.0 (plain) to 1.0 (fully selected)
+    pub fn highlight(&self, category: Category, index: usize) -> f32 {
+        let key = Some(FocusKey { category, index });
+        if key == self.current {
+            self.progress()
+        } else if key == self.previous {
+            1.0 - self.progress()
+        } else {
+            0.0
+        }
+    }
+}
+
+pub fn ease_out_cubic(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    1.0 - (1.0 - t).powi(3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(index: usize) -> FocusKey {
+        FocusKey {
+            category: Category::Games,
+            index,
+        }
+    }
+
+    #[test]
+    fn test_ease_out_cubic() {
+        assert_eq!(ease_out_cubic(0.0), 0.0);
+        assert_eq!(ease_out_cubic(1.0), 1.0);
+        assert!((ease_out_cubic(0.5) - 0.875).abs() < 1e-6);
+        assert_eq!(ease_out_cubic(-1.0), 0.0);
+        assert_eq!(ease_out_cubic(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_highlight_interpolates_between_tiles() {
+        let start = Instant::now();
+        let mut animation = FocusAnimation::new();
+        animation.focus(key(0), start, false);
+        animation.tick(start + FOCUS_ANIMATION_DURATION);
+        assert_eq!(animation.highlight(Category::Games, 0), 1.0);
+
+        let start = start + FOCUS_ANIMATION_DURATION;
+        animation.focus(key(1), start, false);
+        assert!(animation.is_animating());
+        assert_eq!(animation.highlight(Category::Games, 1), 0.0);
+        assert_eq!(animation.highlight(Category::Games, 0), 1.0);
+
+        animation.tick(start + FOCUS_ANIMATION_DURATION / 2);
+        let incoming = animation.highlight(Category::Games, 1);
+        let outgoing = animation.highlight(Category::Games, 0);
+        assert!((incoming - 0.875).abs() < 1e-3);
+        assert!((incoming + outgoing - 1.0).abs() < 1e-6);
+        assert_eq!(animation.highlight(Category::Games, 2), 0.0);
+        assert_eq!(animation.highlight(Category::Apps, 1), 0.0);
+
+        animation.tick(start + FOCUS_ANIMATION_DURATION);
+        assert!(!animation.is_animating());
+        assert_eq!(animation.highlight(Category::Games, 1), 1.0);
+        assert_eq!(animation.highlight(Category::Games, 0), 0.0);
+    }
+
+    #[test]
+    fn test_unchanged_selection_does_not_restart() {
+        let start = Instant::now();
+        let mut animation = FocusAnimation::new();
+        animation.focus(key(0), start, false);
+        animation.tick(start + FOCUS_ANIMATION_DURATION);
+
+        animation.focus(key(0), start + FOCUS_ANIMATION_DURATION * 2, false);
+        assert!(!animation.is_animating());
+    }
+
+    #[test]
+    fn test_reduce_motion_jumps_immediately() {
+        let start = Instant::now();
+        let mut animation = FocusAnimation::new();
+        animation.focus(key(0), start, true);
+        animation.focus(key(1), start, true);
+
+        assert!(!animation.is_animating());
+        assert_eq!(animation.highlight(Category::Games, 1), 1.0);
+        assert_eq!(animation.highlight(Category::Games, 0), 0.0);
+    }
+}
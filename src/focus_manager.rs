@@ -1,21 +1,27 @@
 use procfs::process::Process;
 use std::ffi::OsStr;
+use std::process::Command;
 use std::time::{Duration, Instant};
 use tracing::{info, warn};
 
 const POLL_INTERVAL_FAST: Duration = Duration::from_millis(250);
 const POLL_INTERVAL_SLOW: Duration = Duration::from_millis(1000);
+/// Check interval for a locked game pid, much cheaper than a full process scan
+const PID_WATCH_INTERVAL: Duration = Duration::from_millis(50);
 const STEAM_LAUNCH_TIMEOUT: Duration = Duration::from_secs(60);
 const GAME_EXIT_GRACE_PERIOD_LONG: Duration = Duration::from_secs(10);
 const GAME_EXIT_GRACE_PERIOD_SHORT: Duration = Duration::from_millis(500);
 const STABLE_RUN_THRESHOLD: Duration = Duration::from_secs(15);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MonitorTarget {
     Pid(u32),
     SteamAppId(String),
     EnvVarEq(String, String),
     CmdLineContains(String),
+    /// Owner of a window with this X11 `WM_CLASS` (desktop entry `StartupWMClass`);
+    /// found via `wmctrl`, so Wayland-native windows are not seen
+    WmClass(String),
     Any(Vec<MonitorTarget>),
 }
 
@@ -31,10 +37,12 @@ pub async fn monitor_app_process(target: MonitorTarget) {
 
     loop {
         let mut is_running = false;
+        let mut waited = false;
 
-        // 1. Fast Path: Check locked PID if we have one
+        // 1. Fast Path: Watch the locked PID closely, so its exit is noticed right away
         if let Some(pid) = current_game_pid {
-            if is_process_running(pid) {
+            waited = true;
+            if watch_pid(pid, POLL_INTERVAL_FAST, is_process_running).await {
                 is_running = true;
             } else {
                 // PID died, reset lock and fall through to full scan
@@ -96,7 +104,26 @@ pub async fn monitor_app_process(target: MonitorTarget) {
             POLL_INTERVAL_SLOW
         };
 
-        tokio::time::sleep(interval).await;
+        // The pid watch already took up the interval
+        if !waited {
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+/// Checks `pid` every `PID_WATCH_INTERVAL` for up to `budget`. Returns false as soon as
+/// the process is gone, true if it is still running at the end.
+async fn watch_pid(pid: u32, budget: Duration, is_alive: impl Fn(u32) -> bool) -> bool {
+    let deadline = Instant::now() + budget;
+    loop {
+        if !is_alive(pid) {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        tokio::time::sleep(PID_WATCH_INTERVAL.min(deadline - now)).await;
     }
 }
 
@@ -119,6 +146,7 @@ fn check_target_running(
         MonitorTarget::CmdLineContains(pattern) => {
             check_cmdline(pattern, get_processes(process_cache))
         }
+        MonitorTarget::WmClass(class) => check_wm_class(class),
         MonitorTarget::Any(targets) => targets
             .iter()
             .find_map(|t| check_target_running(t, process_cache)),
@@ -190,3 +218,70 @@ fn check_env_var(target_key_str: &str, target_val_str: &str, processes: &[Proces
 
     None
 }
+
+/// Pid of a window whose class matches, from `wmctrl -lxp`
+fn check_wm_class(class: &str) -> Option<u32> {
+    let output = Command::new("wmctrl").arg("-lxp").output().ok()?;
+    find_window_pid(&String::from_utf8_lossy(&output.stdout), class)
+}
+
+/// Parses `wmctrl -lxp` lines (`<id> <desktop> <pid> <instance.Class> <host> <title>`).
+/// `WM_CLASS` holds an instance and a class name; either may match, ignoring case.
+fn find_window_pid(wmctrl_output: &str, class: &str) -> Option<u32> {
+    let class = class.to_lowercase();
+    wmctrl_output.lines().find_map(|line| {
+        let mut fields = line.split_whitespace().skip(2);
+        let pid: u32 = fields.next()?.parse().ok()?;
+        let wm_class = fields.next()?.to_lowercase();
+        let matches = wm_class == class
+            || wm_class.starts_with(&format!("{}.", class))
+            || wm_class.ends_with(&format!(".{}", class));
+        // Windows without _NET_WM_PID report pid 0
+        (matches && pid != 0).then_some(pid)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_window_pid_by_class() {
+        let output = "\
+0x02400003  0 1234   Navigator.firefox     tv Mozilla Firefox
+0x03a00002 -1 0      Xfce4-panel.Xfce4-panel  tv xfce4-panel
+0x04c00007  0 5678   supertuxkart.SuperTuxKart  tv SuperTuxKart
+";
+        assert_eq!(find_window_pid(output, "SuperTuxKart"), Some(5678));
+        assert_eq!(find_window_pid(output, "firefox"), Some(1234));
+        assert_eq!(find_window_pid(output, "navigator"), Some(1234));
+        // No pid to follow
+        assert_eq!(find_window_pid(output, "xfce4-panel"), None);
+        assert_eq!(find_window_pid(output, "fire"), None);
+    }
+
+    #[tokio::test]
+    async fn test_watch_pid_notices_exit_early() {
+        let checks = std::sync::atomic::AtomicUsize::new(0);
+        let started = Instant::now();
+        // Alive for the first three checks
+        let alive = watch_pid(42, Duration::from_secs(5), |_| {
+            checks.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < 3
+        })
+        .await;
+
+        assert!(!alive);
+        assert_eq!(checks.load(std::sync::atomic::Ordering::SeqCst), 4);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_watch_pid_returns_after_budget() {
+        let started = Instant::now();
+        assert!(watch_pid(42, Duration::from_millis(120), |_| true).await);
+        assert!(started.elapsed() >= Duration::from_millis(120));
+
+        // Already gone: no waiting at all
+        assert!(!watch_pid(42, Duration::from_secs(5), |_| false).await);
+    }
+}
@@ -0,0 +1,95 @@
+//! What the launcher does once a game it started has exited.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnGameExit {
+    /// Bring the launcher back in front
+    #[default]
+    ReturnToLauncher,
+    /// Leave the window minimized until the user switches back to it
+    StayMinimized,
+    /// Exit the launcher, e.g. when it was started from Steam just to pick a game
+    Quit,
+}
+
+/// What `handle_game_exited` does with the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameExitStep {
+    /// Open a fresh window in front of everything
+    RecreateWindow,
+    /// Embedded in Steam, which restores its own window stack; just take focus back
+    FocusWindow,
+    StayInBackground,
+    Quit,
+}
+
+impl OnGameExit {
+    pub fn next(self) -> Self {
+        match self {
+            OnGameExit::ReturnToLauncher => OnGameExit::StayMinimized,
+            OnGameExit::StayMinimized => OnGameExit::Quit,
+            OnGameExit::Quit => OnGameExit::ReturnToLauncher,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            OnGameExit::ReturnToLauncher => OnGameExit::Quit,
+            OnGameExit::StayMinimized => OnGameExit::ReturnToLauncher,
+            OnGameExit::Quit => OnGameExit::StayMinimized,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            OnGameExit::ReturnToLauncher => "Return to Launcher",
+            OnGameExit::StayMinimized => "Stay Minimized",
+            OnGameExit::Quit => "Quit Launcher",
+        }
+    }
+
+    pub fn step(self, embedded: bool) -> GameExitStep {
+        match (self, embedded) {
+            (OnGameExit::ReturnToLauncher, false) => GameExitStep::RecreateWindow,
+            (OnGameExit::ReturnToLauncher, true) => GameExitStep::FocusWindow,
+            (OnGameExit::StayMinimized, _) => GameExitStep::StayInBackground,
+            (OnGameExit::Quit, _) => GameExitStep::Quit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_exit_step_per_setting() {
+        assert_eq!(
+            OnGameExit::ReturnToLauncher.step(false),
+            GameExitStep::RecreateWindow
+        );
+        assert_eq!(
+            OnGameExit::ReturnToLauncher.step(true),
+            GameExitStep::FocusWindow
+        );
+        for embedded in [false, true] {
+            assert_eq!(
+                OnGameExit::StayMinimized.step(embedded),
+                GameExitStep::StayInBackground
+            );
+            assert_eq!(OnGameExit::Quit.step(embedded), GameExitStep::Quit);
+        }
+    }
+
+    #[test]
+    fn test_on_game_exit_cycles() {
+        let mut mode = OnGameExit::default();
+        for _ in 0..3 {
+            assert_eq!(mode.next().prev(), mode);
+            mode = mode.next();
+        }
+        assert_eq!(mode, OnGameExit::default());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::image_cache::ImageCache;
 use crate::searxng::SearxngClient;
-use crate::steamgriddb::SteamGridDbClient;
+use crate::steamgriddb::{CoverResolution, SteamGridDbClient};
 use std::path::PathBuf;
 use uuid::Uuid;
 
@@ -9,61 +9,130 @@ pub struct GameImageFetcher {
     cache: ImageCache,
     sgdb_client: SteamGridDbClient,
     searxng_client: SearxngClient,
+    /// Fall back to a SearXNG web search when no other source has a cover
+    web_search: bool,
+    resolution: CoverResolution,
     width: u32,
     height: u32,
 }
 
 impl GameImageFetcher {
     pub fn new(
-        cache_dir: PathBuf,
+        cache: ImageCache,
         sgdb_client: SteamGridDbClient,
         searxng_client: SearxngClient,
         width: u32,
         height: u32,
     ) -> Self {
         Self {
-            cache: ImageCache { cache_dir },
+            cache,
             sgdb_client,
             searxng_client,
+            web_search: true,
+            resolution: CoverResolution::default(),
             width,
             height,
         }
     }
 
+    /// Requests SteamGridDB covers of this tier and stores no cover larger than it
+    pub fn with_resolution(mut self, resolution: CoverResolution) -> Self {
+        self.resolution = resolution;
+        (self.width, self.height) = resolution.target_size(self.width, self.height);
+        self
+    }
+
+    /// Disabling web search keeps lookups to the cache, the store and SteamGridDB
+    pub fn with_web_search(mut self, enabled: bool) -> Self {
+        self.web_search = enabled;
+        self
+    }
+
+    /// Looks up cover art for a game. With an explicit SteamGridDB ID (chosen via
+    /// "Fix Artwork") only that entry is used; otherwise the cache, the source's own
+    /// image, SteamGridDB (if an API key is set) and SearxNG (unless web search is
+    /// off) are tried in turn. `prefer_source` uses the
+    /// store's image even when a SteamGridDB cover is cached, falling back to the
+    /// regular order only when the game has no store image.
     pub fn fetch(
         &self,
         game_id: Uuid,
         game_name: &str,
         source_image_url: Option<&str>,
         steam_appid: Option<&str>,
+        sgdb_id: Option<u64>,
+        prefer_source: bool,
     ) -> anyhow::Result<Option<(Uuid, PathBuf)>> {
+        if let Some(sgdb_id) = sgdb_id {
+            return Ok(self
+                .fetch_by_sgdb_id(game_name, sgdb_id)
+                .map(|p| (game_id, p)));
+        }
+
+        if prefer_source {
+            if let Some(path) = self.fetch_preferred_source_image(game_name, source_image_url) {
+                return Ok(Some((game_id, path)));
+            }
+        }
+
         let path = self
             .cache
             .find_existing_image(game_name)
             .or_else(|| self.try_source_image(game_name, source_image_url))
+            .or_else(|| self.try_sgdb(game_name, steam_appid))
+            .or_else(|| self.try_searxng_image(game_name));
+
+        Ok(path.map(|p| (game_id, p)))
+    }
+
+    /// SteamGridDB by Steam AppID, then by name. Without an API key every request
+    /// would be rejected, so SteamGridDB isn't contacted at all.
+    fn try_sgdb(&self, game_name: &str, steam_appid: Option<&str>) -> Option<PathBuf> {
+        if !self.sgdb_client.has_api_key() {
+            return None;
+        }
+
+        self.try_sgdb_by_steam_id(game_name, steam_appid)
             .or_else(|| {
-                let res = self.try_sgdb_by_steam_id(game_name, steam_appid);
-                if res.is_none() && steam_appid.is_some() {
+                if steam_appid.is_some() {
                     tracing::warn!(
                         "SGDB AppID lookup failed for '{}', falling back to name search",
                         game_name
                     );
                 }
-                res
+                self.try_sgdb_image(game_name)
             })
             .or_else(|| {
-                let res = self.try_sgdb_image(game_name);
-                if res.is_none() {
-                    tracing::warn!(
-                        "SGDB Name lookup failed for '{}', falling back to SearxNG",
-                        game_name
-                    );
-                }
-                res
+                tracing::warn!(
+                    "SGDB Name lookup failed for '{}', falling back to SearxNG",
+                    game_name
+                );
+                None
             })
-            .or_else(|| self.try_searxng_image(game_name));
+    }
 
-        Ok(path.map(|p| (game_id, p)))
+    /// Uses the given SteamGridDB game, bypassing the name search. The image is cached
+    /// under its own name so a fixed cover never collides with the searched one.
+    fn fetch_by_sgdb_id(&self, game_name: &str, sgdb_id: u64) -> Option<PathBuf> {
+        let cache_name = override_cache_name(game_name, sgdb_id);
+        self.cache
+            .find_existing_image(&cache_name)
+            .or_else(|| self.download_sgdb_image(&cache_name, sgdb_id))
+    }
+
+    /// The store's image, cached under its own name so it doesn't collide with a
+    /// SteamGridDB cover fetched before the preference was set.
+    fn fetch_preferred_source_image(
+        &self,
+        game_name: &str,
+        source_image_url: Option<&str>,
+    ) -> Option<PathBuf> {
+        // Games without a store image keep the regular order
+        source_image_url?;
+        let cache_name = source_cache_name(game_name);
+        self.cache
+            .find_existing_image(&cache_name)
+            .or_else(|| self.try_source_image(&cache_name, source_image_url))
     }
 
     fn try_source_image(&self, game_name: &str, source_image_url: Option<&str>) -> Option<PathBuf> {
@@ -75,7 +144,7 @@ impl GameImageFetcher {
 
     fn try_sgdb_by_steam_id(&self, game_name: &str, steam_appid: Option<&str>) -> Option<PathBuf> {
         let appid = steam_appid.map(str::trim).filter(|id| !id.is_empty())?;
-        match self.sgdb_client.get_game_by_steam_appid(appid) {
+        match self.sgdb_client.game_by_steam_appid(appid) {
             Ok(Some(sgdb_id)) => self.download_sgdb_image(game_name, sgdb_id),
             _ => None,
         }
@@ -89,7 +158,10 @@ impl GameImageFetcher {
     }
 
     fn download_sgdb_image(&self, game_name: &str, sgdb_id: u64) -> Option<PathBuf> {
-        match self.sgdb_client.get_images_for_game(sgdb_id) {
+        match self
+            .sgdb_client
+            .get_images_for_game(sgdb_id, self.resolution)
+        {
             Ok(images) => images.first().and_then(|image| {
                 self.cache
                     .save_image(game_name, &image.url, self.width, self.height)
@@ -100,6 +172,9 @@ impl GameImageFetcher {
     }
 
     fn try_searxng_image(&self, game_name: &str) -> Option<PathBuf> {
+        if !self.web_search {
+            return None;
+        }
         let search_query = format!("{} game cover", game_name);
         let url = self
             .searxng_client
@@ -111,3 +186,168 @@ impl GameImageFetcher {
             .ok()
     }
 }
+
+/// Cache key for covers fetched from an explicit SteamGridDB ID
+fn override_cache_name(game_name: &str, sgdb_id: u64) -> String {
+    format!("{} sgdb-{}", game_name, sgdb_id)
+}
+
+/// Cache key for store images of games that prefer them
+fn source_cache_name(game_name: &str) -> String {
+    format!("{} source", game_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image_cache::{CoverFormat, DEFAULT_COVER_QUALITY};
+    use std::fs;
+
+    fn fetcher_with_temp_cache() -> GameImageFetcher {
+        let cache_dir =
+            std::env::temp_dir().join(format!("launcher_test_image_fetcher_{}", Uuid::new_v4()));
+        fs::create_dir_all(&cache_dir).unwrap();
+        let cache = ImageCache {
+            cache_dir,
+            format: CoverFormat::default(),
+            quality: DEFAULT_COVER_QUALITY,
+        };
+        GameImageFetcher::new(
+            cache,
+            SteamGridDbClient::new(String::new()),
+            SearxngClient::with_base_url("http://127.0.0.1:9".to_string()),
+            300,
+            450,
+        )
+    }
+
+    #[test]
+    fn test_override_cache_name_is_distinct_per_id() {
+        assert_eq!(override_cache_name("Portal", 1234), "Portal sgdb-1234");
+        assert_ne!(
+            override_cache_name("Portal", 1234),
+            override_cache_name("Portal", 5678)
+        );
+    }
+
+    #[test]
+    fn test_explicit_sgdb_id_bypasses_name_cache() {
+        let fetcher = fetcher_with_temp_cache();
+        let searched = fetcher.cache.get_image_path("Portal", "png");
+        let fixed = fetcher
+            .cache
+            .get_image_path(&override_cache_name("Portal", 1234), "png");
+        fs::write(&searched, b"searched").unwrap();
+        fs::write(&fixed, b"fixed").unwrap();
+
+        let game_id = Uuid::new_v4();
+        let result = fetcher
+            .fetch(game_id, "Portal", None, Some("400"), Some(1234), false)
+            .unwrap();
+        assert_eq!(result, Some((game_id, fixed)));
+
+        let result = fetcher
+            .fetch(game_id, "Portal", None, None, None, false)
+            .unwrap();
+        assert_eq!(result, Some((game_id, searched)));
+
+        fs::remove_dir_all(&fetcher.cache.cache_dir).ok();
+    }
+
+    #[test]
+    fn test_prefer_source_image_skips_cached_grid() {
+        let fetcher = fetcher_with_temp_cache();
+        let grid = fetcher.cache.get_image_path("Celeste", "png");
+        let store = fetcher
+            .cache
+            .get_image_path(&source_cache_name("Celeste"), "jpg");
+        fs::write(&grid, b"grid").unwrap();
+        fs::write(&store, b"store").unwrap();
+        let url = Some("https://cdn.example.com/celeste.jpg");
+
+        let game_id = Uuid::new_v4();
+        let result = fetcher
+            .fetch(game_id, "Celeste", url, None, None, true)
+            .unwrap();
+        assert_eq!(result, Some((game_id, store)));
+
+        // Without the preference the regular order finds the grid first
+        let result = fetcher
+            .fetch(game_id, "Celeste", url, None, None, false)
+            .unwrap();
+        assert_eq!(result, Some((game_id, grid.clone())));
+
+        // No store image to prefer: the regular order applies
+        let result = fetcher
+            .fetch(game_id, "Celeste", None, None, None, true)
+            .unwrap();
+        assert_eq!(result, Some((game_id, grid)));
+
+        fs::remove_dir_all(&fetcher.cache.cache_dir).ok();
+    }
+
+    #[test]
+    fn test_resolution_caps_cached_cover_size() {
+        let fetcher = fetcher_with_temp_cache().with_resolution(CoverResolution::Small);
+        assert_eq!((fetcher.width, fetcher.height), (300, 450));
+
+        let fetcher = GameImageFetcher::new(
+            fetcher.cache.clone(),
+            SteamGridDbClient::new(String::new()),
+            SearxngClient::new(),
+            900,
+            1350,
+        )
+        .with_resolution(CoverResolution::Small);
+        assert_eq!((fetcher.width, fetcher.height), (342, 482));
+        assert_eq!(fetcher.resolution, CoverResolution::Small);
+
+        fs::remove_dir_all(&fetcher.cache.cache_dir).ok();
+    }
+
+    #[test]
+    fn test_web_search_disabled_skips_searxng() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let mut fetcher = fetcher_with_temp_cache().with_web_search(false);
+        fetcher.searxng_client = SearxngClient::with_base_url(base_url);
+
+        assert_eq!(fetcher.try_searxng_image("Celeste"), None);
+        let connection = listener.accept();
+        assert!(
+            matches!(&connection, Err(err) if err.kind() == std::io::ErrorKind::WouldBlock),
+            "SearXNG was contacted"
+        );
+
+        fs::remove_dir_all(&fetcher.cache.cache_dir).ok();
+    }
+
+    #[test]
+    fn test_missing_api_key_skips_sgdb() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let mut fetcher = fetcher_with_temp_cache().with_web_search(false);
+        for key in ["", "  "] {
+            fetcher.sgdb_client =
+                SteamGridDbClient::with_base_url(key.to_string(), base_url.clone());
+            assert!(!fetcher.sgdb_client.has_api_key());
+
+            let result = fetcher
+                .fetch(Uuid::new_v4(), "Celeste", None, Some("504230"), None, false)
+                .unwrap();
+            assert_eq!(result, None);
+            let connection = listener.accept();
+            assert!(
+                matches!(&connection, Err(err) if err.kind() == std::io::ErrorKind::WouldBlock),
+                "SteamGridDB was contacted without an API key"
+            );
+        }
+        assert!(SteamGridDbClient::new("abc123".to_string()).has_api_key());
+
+        fs::remove_dir_all(&fetcher.cache.cache_dir).ok();
+    }
+}
@@ -0,0 +1,222 @@
+//! Multi-select "Manage Games" view for hiding and favoriting many games at once.
+
+use std::collections::HashSet;
+
+use crate::input::Action;
+use crate::model::LauncherItem;
+
+/// Splits games into visible and hidden ones by their launch key
+pub fn partition_hidden(
+    items: Vec<LauncherItem>,
+    hidden_games: &HashSet<String>,
+) -> (Vec<LauncherItem>, Vec<LauncherItem>) {
+    items.into_iter().partition(|item| {
+        !item
+            .launch_key
+            .as_ref()
+            .is_some_and(|key| hidden_games.contains(key))
+    })
+}
+
+/// Change applied to every checked game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchOperation {
+    Hide,
+    Unhide,
+    Favorite,
+    Unfavorite,
+}
+
+impl BatchOperation {
+    pub const ALL: &'static [BatchOperation] = &[
+        BatchOperation::Hide,
+        BatchOperation::Unhide,
+        BatchOperation::Favorite,
+        BatchOperation::Unfavorite,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BatchOperation::Hide => "Hide",
+            BatchOperation::Unhide => "Unhide",
+            BatchOperation::Favorite => "Favorite",
+            BatchOperation::Unfavorite => "Unfavorite",
+        }
+    }
+
+    fn step(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let index = Self::ALL.iter().position(|op| *op == self).unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+
+    /// Applies the operation to the given launch keys
+    pub fn apply<'a>(
+        self,
+        favorites: &mut HashSet<String>,
+        hidden_games: &mut HashSet<String>,
+        launch_keys: impl IntoIterator<Item = &'a String>,
+    ) {
+        for key in launch_keys {
+            match self {
+                BatchOperation::Hide => hidden_games.insert(key.clone()),
+                BatchOperation::Unhide => hidden_games.remove(key),
+                BatchOperation::Favorite => favorites.insert(key.clone()),
+                BatchOperation::Unfavorite => favorites.remove(key),
+            };
+        }
+    }
+}
+
+/// A game row in the manage view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagedGame {
+    pub name: String,
+    pub launch_key: String,
+    pub hidden: bool,
+    pub favorite: bool,
+}
+
+pub struct ManageGamesState {
+    pub games: Vec<ManagedGame>,
+    /// Launch keys of checked games
+    pub checked: HashSet<String>,
+    pub selected_index: usize,
+    pub operation: BatchOperation,
+    /// Waiting for the user to confirm the operation
+    pub confirming: bool,
+}
+
+impl ManageGamesState {
+    /// Lists visible and hidden games by name; games without a launch key can't be managed.
+    pub fn new(items: &[LauncherItem], hidden_games: &HashSet<String>) -> Self {
+        let mut games: Vec<ManagedGame> = items
+            .iter()
+            .filter_map(|item| {
+                let launch_key = item.launch_key.clone()?;
+                Some(ManagedGame {
+                    name: item.name.clone(),
+                    hidden: hidden_games.contains(&launch_key),
+                    favorite: item.favorite,
+                    launch_key,
+                })
+            })
+            .collect();
+        games.sort_by_key(|game| game.name.to_lowercase());
+
+        Self {
+            games,
+            checked: HashSet::new(),
+            selected_index: 0,
+            operation: BatchOperation::Hide,
+            confirming: false,
+        }
+    }
+
+    pub fn navigate(&mut self, action: Action) {
+        match action {
+            Action::Up => self.selected_index = self.selected_index.saturating_sub(1),
+            Action::Down if self.selected_index + 1 < self.games.len() => self.selected_index += 1,
+            Action::Left => self.operation = self.operation.step(false),
+            Action::Right => self.operation = self.operation.step(true),
+            _ => {}
+        }
+    }
+
+    pub fn toggle_selected(&mut self) {
+        let Some(game) = self.games.get(self.selected_index) else {
+            return;
+        };
+        if !self.checked.remove(&game.launch_key) {
+            self.checked.insert(game.launch_key.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_apply_batch_hide_and_favorite() {
+        let mut favorites = HashSet::from(["steam:10".to_string()]);
+        let mut hidden_games = HashSet::new();
+
+        BatchOperation::Hide.apply(
+            &mut favorites,
+            &mut hidden_games,
+            &keys(&["steam:570", "heroic:gog:1"]),
+        );
+        BatchOperation::Favorite.apply(
+            &mut favorites,
+            &mut hidden_games,
+            &keys(&["steam:400", "steam:10"]),
+        );
+
+        assert_eq!(
+            hidden_games,
+            HashSet::from(["steam:570".to_string(), "heroic:gog:1".to_string()])
+        );
+        assert_eq!(
+            favorites,
+            HashSet::from(["steam:10".to_string(), "steam:400".to_string()])
+        );
+
+        BatchOperation::Unhide.apply(
+            &mut favorites,
+            &mut hidden_games,
+            &keys(&["steam:570", "steam:unknown"]),
+        );
+        BatchOperation::Unfavorite.apply(&mut favorites, &mut hidden_games, &keys(&["steam:10"]));
+
+        assert_eq!(hidden_games, HashSet::from(["heroic:gog:1".to_string()]));
+        assert_eq!(favorites, HashSet::from(["steam:400".to_string()]));
+    }
+
+    #[test]
+    fn test_manage_state_toggle_and_operation_cycle() {
+        let items = vec![
+            LauncherItem {
+                name: "Portal".to_string(),
+                launch_key: Some("steam:400".to_string()),
+                ..Default::default()
+            },
+            LauncherItem {
+                name: "dota 2".to_string(),
+                launch_key: Some("steam:570".to_string()),
+                ..Default::default()
+            },
+            LauncherItem {
+                name: "No Key".to_string(),
+                ..Default::default()
+            },
+        ];
+        let hidden = HashSet::from(["steam:400".to_string()]);
+        let mut state = ManageGamesState::new(&items, &hidden);
+
+        assert_eq!(state.games.len(), 2);
+        assert_eq!(state.games[0].name, "dota 2");
+        assert!(state.games[1].hidden);
+
+        state.navigate(Action::Down);
+        state.toggle_selected();
+        assert!(state.checked.contains("steam:400"));
+        state.toggle_selected();
+        assert!(state.checked.is_empty());
+
+        state.navigate(Action::Left);
+        assert_eq!(state.operation, BatchOperation::Unfavorite);
+        state.navigate(Action::Right);
+        state.navigate(Action::Right);
+        assert_eq!(state.operation, BatchOperation::Unhide);
+    }
+}
@@ -1,50 +1,358 @@
+use crate::appimage::AppImageSource;
 use crate::model::AppEntry;
-use crate::mupen64plus::scan_mupen64plus_games;
-use crate::snes9x::scan_snes9x_games;
+use crate::moonlight::MoonlightSource;
+use crate::mupen64plus::Mupen64PlusSource;
+use crate::pegasus::PegasusSource;
+use crate::scummvm::ScummvmSource;
+use crate::snes9x::Snes9xSource;
 use directories::BaseDirs;
 use rayon::prelude::*;
 use serde_json::Value;
-use std::collections::HashSet;
-use std::fs;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{fmt, fs, io};
+use thiserror::Error;
+
+/// Why a game source could not be scanned, with the details shown in the error list
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ScanDiagnostic {
+    /// The launcher is installed, but none of its game libraries exist or can be read
+    #[error("Installed, but no game library could be read")]
+    NoLibraries,
+    #[error("{} can't be read: {kind}", .path.display())]
+    Unreadable { path: PathBuf, kind: io::ErrorKind },
+    #[error("{} is malformed: {detail}", .path.display())]
+    Malformed { path: PathBuf, detail: String },
+}
+
+impl ScanDiagnostic {
+    /// Ranks diagnostics by how likely they explain missing games; lower is more relevant
+    fn relevance(&self) -> u8 {
+        match self {
+            ScanDiagnostic::Unreadable { .. } => 0,
+            ScanDiagnostic::NoLibraries => 1,
+            ScanDiagnostic::Malformed { .. } => 2,
+        }
+    }
+}
+
+/// A game source that could not be scanned; its games are missing from the list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanError {
+    pub source_name: &'static str,
+    pub diagnostic: ScanDiagnostic,
+}
+
+impl fmt::Display for ScanError {
+    /// A short sentence without paths, e.g. "Heroic config unreadable: permission denied"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.diagnostic {
+            ScanDiagnostic::NoLibraries => {
+                write!(
+                    f,
+                    "{} found, but no libraries are readable",
+                    self.source_name
+                )
+            }
+            ScanDiagnostic::Unreadable { kind, .. } => {
+                write!(f, "{} config unreadable: {}", self.source_name, kind)
+            }
+            ScanDiagnostic::Malformed { .. } => {
+                write!(f, "{} config is malformed", self.source_name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+/// All games found and the sources that failed
+#[derive(Debug, Clone, Default)]
+pub struct ScanResult {
+    pub games: Vec<AppEntry>,
+    pub errors: Vec<ScanError>,
+}
+
+/// The failure most likely to explain an empty or incomplete library; ties keep
+/// the source order of the scan
+pub fn most_relevant_error(errors: &[ScanError]) -> Option<&ScanError> {
+    errors
+        .iter()
+        .min_by_key(|error| error.diagnostic.relevance())
+}
+
+/// Games found by a single source, with the files it had to skip
+#[derive(Debug, Clone, Default)]
+pub struct SourceScan {
+    pub games: Vec<AppEntry>,
+    /// Broken files of the source; the games of its other files are still found
+    pub skipped: Vec<ScanDiagnostic>,
+}
+
+impl From<Vec<AppEntry>> for SourceScan {
+    fn from(games: Vec<AppEntry>) -> Self {
+        Self {
+            games,
+            skipped: Vec::new(),
+        }
+    }
+}
+
+/// Games found by a single source, or why the source failed as a whole
+pub type SourceResult = Result<SourceScan, ScanDiagnostic>;
+
+/// A scanner of one kind of game library, run alongside the others by `scan_games`
+pub trait GameSource: Send + Sync {
+    /// Shown in the scan errors, e.g. "Steam"
+    fn name(&self) -> &'static str;
+    fn scan(&self) -> SourceResult;
+    /// Whether the launcher or emulator behind the source is installed; unavailable
+    /// sources are skipped without an error
+    fn is_available(&self) -> bool;
+}
+
+/// Steam games of all libraries in `libraryfolders.vdf`
+pub struct SteamSource;
+
+impl GameSource for SteamSource {
+    fn name(&self) -> &'static str {
+        "Steam"
+    }
+
+    fn scan(&self) -> SourceResult {
+        scan_steam_games()
+    }
+
+    fn is_available(&self) -> bool {
+        BaseDirs::new().is_some_and(|dirs| !get_steam_roots(dirs.home_dir()).is_empty())
+    }
+}
+
+/// Installed Epic, GOG and Amazon games of Heroic, native or Flatpak
+pub struct HeroicSource;
+
+impl GameSource for HeroicSource {
+    fn name(&self) -> &'static str {
+        "Heroic"
+    }
+
+    fn scan(&self) -> SourceResult {
+        scan_heroic_games()
+    }
+
+    fn is_available(&self) -> bool {
+        BaseDirs::new().is_some_and(|dirs| heroic_roots(&dirs).iter().any(|root| root.exists()))
+    }
+}
+
+/// Every supported source, in the order their failures are listed. AppImages are
+/// looked for in `appimage_dirs`.
+fn registered_sources(appimage_dirs: &[String]) -> Vec<Box<dyn GameSource>> {
+    vec![
+        Box::new(SteamSource),
+        Box::new(HeroicSource),
+        Box::new(Mupen64PlusSource),
+        Box::new(Snes9xSource::new()),
+        Box::new(PegasusSource),
+        Box::new(AppImageSource::new(appimage_dirs)),
+        Box::new(MoonlightSource::new()),
+        Box::new(ScummvmSource::new()),
+    ]
+}
+
+/// Scan all game sources (Steam, Heroic, Mupen64Plus, SNES9x, Pegasus, AppImages in
+/// `appimage_dirs`, Moonlight hosts, ScummVM) in parallel and return unique entries
+/// together with the sources that failed. With `max_per_source` set, each source keeps
+/// only that many games, preferring the most recently launched ones in `launch_history`.
+/// `scan_threads` caps the threads used, see `scan_sources`.
+pub fn scan_games(
+    max_per_source: Option<usize>,
+    launch_history: &HashMap<String, i64>,
+    appimage_dirs: &[String],
+    scan_threads: Option<usize>,
+) -> ScanResult {
+    scan_all(
+        &registered_sources(appimage_dirs),
+        max_per_source,
+        launch_history,
+        scan_threads,
+    )
+}
+
+fn scan_all(
+    sources: &[Box<dyn GameSource>],
+    max_per_source: Option<usize>,
+    launch_history: &HashMap<String, i64>,
+    scan_threads: Option<usize>,
+) -> ScanResult {
+    let results = scan_sources(sources, scan_threads);
+    let results = match max_per_source {
+        Some(max) => results
+            .into_iter()
+            .map(|(source_name, result)| {
+                let result = result.map(|scan| SourceScan {
+                    games: cap_source_games(scan.games, max, launch_history),
+                    ..scan
+                });
+                (source_name, result)
+            })
+            .collect(),
+        None => results,
+    };
+
+    merge_source_results(results)
+}
+
+/// Runs the available sources in parallel, results in source order. `scan_threads` limits the
+/// threads of the scan, including those a source uses itself (e.g. to read Steam
+/// manifests); `None` uses one per CPU core.
+fn scan_sources(
+    sources: &[Box<dyn GameSource>],
+    scan_threads: Option<usize>,
+) -> Vec<(&'static str, SourceResult)> {
+    let scan = || {
+        sources
+            .par_iter()
+            .filter(|source| {
+                let available = source.is_available();
+                if !available {
+                    tracing::debug!("{} is not installed; skipping its scan", source.name());
+                }
+                available
+            })
+            .map(|source| (source.name(), source.scan()))
+            .collect()
+    };
+
+    let Some(threads) = scan_threads.filter(|&threads| threads > 0) else {
+        return scan();
+    };
+    match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+        Ok(pool) => pool.install(scan),
+        Err(e) => {
+            tracing::warn!("Failed to create a scan pool of {} threads: {}", threads, e);
+            scan()
+        }
+    }
+}
+
+/// Keeps at most `max` games of one source: the most recently launched first, then
+/// never launched ones alphabetically.
+fn cap_source_games(
+    mut games: Vec<AppEntry>,
+    max: usize,
+    launch_history: &HashMap<String, i64>,
+) -> Vec<AppEntry> {
+    if games.len() <= max {
+        return games;
+    }
+    let last_launch = |game: &AppEntry| {
+        game.launch_key
+            .as_ref()
+            .and_then(|key| launch_history.get(key).copied())
+    };
+    games.sort_by(|a, b| {
+        last_launch(b)
+            .cmp(&last_launch(a))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    games.truncate(max);
+    games
+}
+
+/// Minimum time between two automatic rescans
+const RESCAN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Limits automatic rescans (e.g. after returning from a game) to one per minute.
+#[derive(Debug, Default)]
+pub struct RescanThrottle {
+    last_scan: Option<Instant>,
+}
 
-/// Scan all game sources (Steam, Heroic, Mupen64Plus, SNES9x) in parallel and return unique entries
-pub fn scan_games() -> Vec<AppEntry> {
-    // Scan Steam, Heroic, Mupen64Plus, and SNES9x games concurrently
-    let ((steam_games, heroic_games), (mupen64plus_games, snes9x_games)) = rayon::join(
-        || rayon::join(scan_steam_games, scan_heroic_games),
-        || rayon::join(scan_mupen64plus_games, scan_snes9x_games),
-    );
-
-    // Combine results
-    let mut games = Vec::with_capacity(
-        steam_games.len() + heroic_games.len() + mupen64plus_games.len() + snes9x_games.len(),
-    );
-    games.extend(steam_games);
-    games.extend(heroic_games);
-    games.extend(mupen64plus_games);
-    games.extend(snes9x_games);
+impl RescanThrottle {
+    /// Returns true and records the scan if the last one is at least a minute old.
+    pub fn try_begin(&mut self, now: Instant) -> bool {
+        let due = self
+            .last_scan
+            .is_none_or(|last| now.saturating_duration_since(last) >= RESCAN_INTERVAL);
+        if due {
+            self.last_scan = Some(now);
+        }
+        due
+    }
+}
+
+/// Combines per-source results; a failing source or file is recorded and the others
+/// are kept.
+fn merge_source_results(results: Vec<(&'static str, SourceResult)>) -> ScanResult {
+    let mut games = Vec::new();
+    let mut errors = Vec::new();
+
+    for (source_name, result) in results {
+        match result {
+            Ok(scan) => {
+                games.extend(scan.games);
+                for diagnostic in scan.skipped {
+                    tracing::warn!("Skipped part of {}: {}", source_name, diagnostic);
+                    errors.push(ScanError {
+                        source_name,
+                        diagnostic,
+                    });
+                }
+            }
+            Err(diagnostic) => {
+                tracing::warn!("Scanning {} failed: {}", source_name, diagnostic);
+                errors.push(ScanError {
+                    source_name,
+                    diagnostic,
+                });
+            }
+        }
+    }
 
     // Sort and deduplicate
     games.sort_by(|a, b| a.name.cmp(&b.name).then(a.exec.cmp(&b.exec)));
     games.dedup_by(|a, b| a.name == b.name && a.exec == b.exec);
 
-    games
+    ScanResult { games, errors }
 }
 
-fn scan_steam_games() -> Vec<AppEntry> {
+fn scan_steam_games() -> SourceResult {
     let Some(base_dirs) = BaseDirs::new() else {
-        return Vec::new();
+        return Ok(SourceScan::default());
     };
 
     let roots = get_steam_roots(base_dirs.home_dir());
-    let library_paths = get_steam_library_paths(&roots);
+    let mut skipped = Vec::new();
+    let library_paths = get_steam_library_paths(&roots, &mut skipped);
+    if !roots.is_empty() && library_paths.is_empty() {
+        // A broken library file explains the failure better than its consequence
+        return Err(skipped
+            .into_iter()
+            .next()
+            .unwrap_or(ScanDiagnostic::NoLibraries));
+    }
     let manifest_paths = get_steam_manifest_paths(&library_paths);
 
     // Process manifests in parallel for better performance
-    manifest_paths
+    let games = manifest_paths
         .par_iter()
         .filter_map(|path| parse_steam_manifest_file(path))
+        .collect();
+    Ok(SourceScan { games, skipped })
+}
+
+/// `steamapps` directories of all Steam libraries; Steam adds and removes
+/// `appmanifest_*.acf` files here when games are installed or uninstalled
+pub fn steam_library_dirs() -> Vec<PathBuf> {
+    let Some(base_dirs) = BaseDirs::new() else {
+        return Vec::new();
+    };
+    let roots = get_steam_roots(base_dirs.home_dir());
+    get_steam_library_paths(&roots, &mut Vec::new())
+        .into_iter()
+        .map(|library| library.join("steamapps"))
         .collect()
 }
 
@@ -59,20 +367,42 @@ fn get_steam_roots(home: &Path) -> Vec<PathBuf> {
     .collect()
 }
 
-fn get_steam_library_paths(roots: &[PathBuf]) -> Vec<PathBuf> {
+/// Library folders of all Steam roots; a `libraryfolders.vdf` that can't be used is
+/// added to `skipped` and the other roots are still read.
+fn get_steam_library_paths(roots: &[PathBuf], skipped: &mut Vec<ScanDiagnostic>) -> Vec<PathBuf> {
     let mut paths = HashSet::new();
+    let mut seen_roots = HashSet::new();
 
     for root in roots {
+        // ~/.steam/steam and ~/.steam/root usually link to the same install
+        let root = fs::canonicalize(root).unwrap_or_else(|_| root.clone());
+        if !seen_roots.insert(root.clone()) {
+            continue;
+        }
+
         if root.join("steamapps").exists() {
             paths.insert(root.clone());
         }
 
         let library_file = root.join("steamapps/libraryfolders.vdf");
-        if let Ok(contents) = fs::read_to_string(&library_file) {
-            for path in parse_library_folders(&contents) {
-                paths.insert(path);
+        let contents = match read_file_if_exists(&library_file) {
+            Ok(Some(contents)) => contents,
+            Ok(None) => continue,
+            Err(diagnostic) => {
+                skipped.push(diagnostic);
+                continue;
             }
+        };
+
+        let libraries = parse_library_folders(&contents);
+        // Every valid file lists at least the root library itself
+        if libraries.is_empty() && !contents.trim().is_empty() {
+            skipped.push(ScanDiagnostic::Malformed {
+                path: library_file,
+                detail: "no library folders found".to_string(),
+            });
         }
+        paths.extend(libraries);
     }
 
     paths.into_iter().collect()
@@ -111,13 +441,31 @@ fn parse_steam_manifest_file(path: &Path) -> Option<AppEntry> {
     }
 
     let exec = format!("steam -applaunch {}", manifest.appid);
+    let proton = path
+        .parent()
+        .is_some_and(|steamapps| runs_under_proton(steamapps, &manifest.appid));
+    // Steam sets this on every process of a Proton launch, including the
+    // pressure-vessel container (`pv-bwrap`) and `wineserver`
+    let monitor_env = if proton {
+        vec![("STEAM_COMPAT_APP_ID".to_string(), manifest.appid.clone())]
+    } else {
+        Vec::new()
+    };
     Some(
         AppEntry::new(manifest.name, exec, None)
             .with_launch_key(format!("steam:{}", manifest.appid))
-            .with_steam_appid(manifest.appid),
+            .with_steam_appid(manifest.appid)
+            .with_runs_under_proton(proton)
+            .with_monitor_env(monitor_env),
     )
 }
 
+/// Steam creates a Wine prefix in `compatdata/<appid>` of the game's library the
+/// first time it starts the game through Proton; native Linux games have none.
+fn runs_under_proton(steamapps: &Path, appid: &str) -> bool {
+    steamapps.join("compatdata").join(appid).is_dir()
+}
+
 fn is_ignored_app(name: &str, id: &str) -> bool {
     const IGNORED_IDS: &[&str] = &[
         "228980",  // Steamworks Common Redist
@@ -147,56 +495,52 @@ fn is_ignored_app(name: &str, id: &str) -> bool {
     matches!(name_lower.as_str(), "dxvk" | "vkd3d")
 }
 
-fn scan_heroic_games() -> Vec<AppEntry> {
+fn scan_heroic_games() -> SourceResult {
     let Some(base_dirs) = BaseDirs::new() else {
-        return Vec::new();
+        return Ok(SourceScan::default());
     };
 
-    let config_dir = base_dirs.config_dir();
-    let home = base_dirs.home_dir();
-
-    let heroic_roots = [
-        config_dir.join("heroic"),
-        home.join(".var/app/com.heroicgameslauncher.hgl/config/heroic"),
-    ];
-
-    let mut games = Vec::new();
+    let mut scan = SourceScan::default();
     let mut seen_app_names = HashSet::new();
 
-    for root in heroic_roots.iter().filter(|r| r.exists()) {
-        scan_heroic_root(root, &mut games, &mut seen_app_names);
+    for root in heroic_roots(&base_dirs).iter().filter(|r| r.exists()) {
+        scan_heroic_root(root, &mut scan, &mut seen_app_names);
     }
 
-    games
+    Ok(scan)
 }
 
-fn scan_heroic_root(root: &Path, games: &mut Vec<AppEntry>, seen: &mut HashSet<String>) {
+/// Heroic config directories of the native and the Flatpak install
+pub fn heroic_roots(base_dirs: &BaseDirs) -> [PathBuf; 2] {
+    [
+        base_dirs.config_dir().join("heroic"),
+        base_dirs
+            .home_dir()
+            .join(".var/app/com.heroicgameslauncher.hgl/config/heroic"),
+    ]
+}
+
+/// Adds the games of all library files of one Heroic root; a file that can't be
+/// used is skipped and the others are still read.
+fn scan_heroic_root(root: &Path, scan: &mut SourceScan, seen: &mut HashSet<String>) {
     let store_cache = root.join("store_cache");
 
-    // 1. Store Libraries
-    for (file, store) in [
-        ("legendary_library.json", "legendary"),
-        ("gog_library.json", "gog"),
-        ("nile_library.json", "nile"),
-    ] {
-        process_heroic_file(&store_cache.join(file), store, games, seen);
-    }
-
-    // 2. Sideloads
-    // Primary: sideload_apps/library.json
-    // Fallback: store_cache/sideload_cache.json (legacy format)
-    process_heroic_file(
-        &root.join("sideload_apps/library.json"),
-        "sideload",
-        games,
-        seen,
-    );
-    process_heroic_file(
-        &store_cache.join("sideload_cache.json"),
-        "sideload",
-        games,
-        seen,
-    );
+    let files = [
+        // 1. Store Libraries
+        (store_cache.join("legendary_library.json"), "legendary"),
+        (store_cache.join("gog_library.json"), "gog"),
+        (store_cache.join("nile_library.json"), "nile"),
+        // 2. Sideloads
+        // Primary: sideload_apps/library.json
+        // Fallback: store_cache/sideload_cache.json (legacy format)
+        (root.join("sideload_apps/library.json"), "sideload"),
+        (store_cache.join("sideload_cache.json"), "sideload"),
+    ];
+    for (path, store) in files {
+        if let Err(diagnostic) = process_heroic_file(&path, store, &mut scan.games, seen) {
+            scan.skipped.push(diagnostic);
+        }
+    }
 }
 
 fn process_heroic_file(
@@ -204,9 +548,15 @@ fn process_heroic_file(
     store_hint: &str,
     games: &mut Vec<AppEntry>,
     seen: &mut HashSet<String>,
-) {
-    if let Some(contents) = read_file_if_exists(path) {
-        for game in parse_heroic_library_json(&contents, store_hint) {
+) -> Result<(), ScanDiagnostic> {
+    if let Some(contents) = read_file_if_exists(path)? {
+        let library = parse_heroic_library_json(&contents, store_hint).map_err(|err| {
+            ScanDiagnostic::Malformed {
+                path: path.to_path_buf(),
+                detail: err.to_string(),
+            }
+        })?;
+        for game in library {
             if !is_ignored_app(&game.title, &game.app_name) && seen.insert(game.app_name.clone()) {
                 let exec = heroic_exec(&game.store, &game.app_name);
                 games.push(
@@ -217,13 +567,19 @@ fn process_heroic_file(
             }
         }
     }
+    Ok(())
 }
 
-fn read_file_if_exists(path: &Path) -> Option<String> {
-    if !path.exists() {
-        return None;
+/// Reads a file that may legitimately be missing; any other read failure is an error.
+fn read_file_if_exists(path: &Path) -> Result<Option<String>, ScanDiagnostic> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(ScanDiagnostic::Unreadable {
+            path: path.to_path_buf(),
+            kind: err.kind(),
+        }),
     }
-    fs::read_to_string(path).ok()
 }
 
 fn heroic_exec(store: &str, app_name: &str) -> String {
@@ -262,17 +618,15 @@ struct HeroicGame {
     launch_key: String,
 }
 
-fn parse_heroic_library_json(contents: &str, store_hint: &str) -> Vec<HeroicGame> {
-    let value: Value = match serde_json::from_str(contents) {
-        Ok(value) => value,
-        Err(_err) => {
-            return Vec::new();
-        }
-    };
+fn parse_heroic_library_json(
+    contents: &str,
+    store_hint: &str,
+) -> Result<Vec<HeroicGame>, serde_json::Error> {
+    let value: Value = serde_json::from_str(contents)?;
 
     let mut games = Vec::new();
     collect_heroic_games(&value, store_hint, true, &mut games);
-    games
+    Ok(games)
 }
 
 fn collect_heroic_games(
@@ -539,6 +893,67 @@ fn extract_quoted_strings(line: &str) -> Vec<String> {
 mod tests {
     use super::*;
 
+    fn source_game(name: &str, launch_key: &str) -> AppEntry {
+        AppEntry {
+            id: uuid::Uuid::new_v4(),
+            name: name.to_string(),
+            exec: format!("launch {}", launch_key),
+            icon: None,
+            launch_key: Some(launch_key.to_string()),
+            game_executable: None,
+            last_started: None,
+            steam_appid: None,
+            run_in_terminal: false,
+            wm_class: None,
+            actions: Vec::new(),
+            monitor_env: Vec::new(),
+            runs_under_proton: false,
+        }
+    }
+
+    #[test]
+    fn test_cap_source_games_prefers_recently_launched() {
+        let games = vec![
+            source_game("Celeste", "steam:1"),
+            source_game("Alan Wake", "steam:2"),
+            source_game("Hades", "steam:3"),
+            source_game("Braid", "steam:4"),
+        ];
+        let history = HashMap::from([("steam:3".to_string(), 200), ("steam:1".to_string(), 100)]);
+
+        let names = |games: Vec<AppEntry>| -> Vec<String> {
+            games.into_iter().map(|game| game.name).collect()
+        };
+        assert_eq!(
+            names(cap_source_games(games.clone(), 3, &history)),
+            vec!["Hades", "Celeste", "Alan Wake"]
+        );
+        assert_eq!(
+            names(cap_source_games(games.clone(), 1, &HashMap::new())),
+            vec!["Alan Wake"]
+        );
+        assert!(cap_source_games(games.clone(), 0, &history).is_empty());
+        // Sources within the cap are left untouched
+        assert_eq!(
+            names(cap_source_games(games, 4, &history)),
+            vec!["Celeste", "Alan Wake", "Hades", "Braid"]
+        );
+    }
+
+    #[test]
+    fn test_rescan_throttle_allows_one_per_minute() {
+        let mut throttle = RescanThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.try_begin(start));
+        assert!(!throttle.try_begin(start + Duration::from_secs(10)));
+        assert!(!throttle.try_begin(start + Duration::from_secs(59)));
+        assert!(throttle.try_begin(start + RESCAN_INTERVAL));
+        // The window restarts from the last accepted scan
+        assert!(!throttle.try_begin(start + Duration::from_secs(90)));
+        assert!(throttle.try_begin(start + Duration::from_secs(120)));
+    }
+
     #[test]
     fn test_parse_library_folders_extracts_paths() {
         let contents = r#"
@@ -583,7 +998,7 @@ mod tests {
         }
         "#;
 
-        let games = parse_heroic_library_json(contents, "gog");
+        let games = parse_heroic_library_json(contents, "gog").unwrap();
         assert_eq!(games.len(), 1);
         assert_eq!(games[0].app_name, "gog-1");
         assert_eq!(games[0].title, "GOG One");
@@ -610,7 +1025,7 @@ mod tests {
         ]
         "#;
 
-        let games = parse_heroic_library_json(contents, "sideload");
+        let games = parse_heroic_library_json(contents, "sideload").unwrap();
         assert_eq!(games.len(), 1);
         assert_eq!(games[0].app_name, "Sideload1");
         assert_eq!(games[0].title, "My Sideloaded Game");
@@ -651,7 +1066,7 @@ mod tests {
         }
         "#;
 
-        let games = parse_heroic_library_json(contents, "sideload");
+        let games = parse_heroic_library_json(contents, "sideload").unwrap();
         assert_eq!(games.len(), 1);
         assert_eq!(games[0].app_name, "testAppId");
         assert_eq!(games[0].title, "Robot Arena 2");
@@ -665,18 +1080,360 @@ mod tests {
 
     #[test]
     fn test_deduplication_logic() {
-        let mut games = vec![
-            AppEntry::new("Game".to_string(), "exec1".to_string(), None),
-            AppEntry::new("Game".to_string(), "exec2".to_string(), None),
-            AppEntry::new("Game".to_string(), "exec1".to_string(), None),
-        ];
-
-        // Sort and deduplicate logic used in scan_games
-        games.sort_by(|a, b| a.name.cmp(&b.name).then(a.exec.cmp(&b.exec)));
-        games.dedup_by(|a, b| a.name == b.name && a.exec == b.exec);
+        let ScanResult { games, errors } = merge_source_results(vec![(
+            "Test",
+            Ok(vec![
+                AppEntry::new("Game".to_string(), "exec1".to_string(), None),
+                AppEntry::new("Game".to_string(), "exec2".to_string(), None),
+                AppEntry::new("Game".to_string(), "exec1".to_string(), None),
+            ]
+            .into()),
+        )]);
 
+        assert!(errors.is_empty());
         assert_eq!(games.len(), 2);
         assert_eq!(games[0].exec, "exec1");
         assert_eq!(games[1].exec, "exec2");
     }
+
+    struct MockSource {
+        name: &'static str,
+        result: SourceResult,
+        available: bool,
+    }
+
+    impl GameSource for MockSource {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn scan(&self) -> SourceResult {
+            assert!(self.available, "{} scanned though unavailable", self.name);
+            self.result.clone()
+        }
+
+        fn is_available(&self) -> bool {
+            self.available
+        }
+    }
+
+    fn mock(name: &'static str, games: &[String]) -> Box<dyn GameSource> {
+        Box::new(MockSource {
+            name,
+            result: Ok(games
+                .iter()
+                .map(|game| AppEntry::new(game.to_string(), format!("run {}", game), None))
+                .collect::<Vec<_>>()
+                .into()),
+            available: true,
+        })
+    }
+
+    #[test]
+    fn test_scan_all_aggregates_many_sources() {
+        const NAMES: [&str; 12] = [
+            "S0", "S1", "S2", "S3", "S4", "S5", "S6", "S7", "S8", "S9", "S10", "S11",
+        ];
+        let unreadable = ScanDiagnostic::Unreadable {
+            path: PathBuf::from("/data/library.json"),
+            kind: io::ErrorKind::PermissionDenied,
+        };
+        let mut sources: Vec<Box<dyn GameSource>> = NAMES
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                // Neighbouring sources share a game, e.g. one owned on two stores
+                let games = [format!("Game {:02}", i), format!("Game {:02}", i + 1)];
+                mock(name, &games)
+            })
+            .collect();
+        sources.insert(
+            3,
+            Box::new(MockSource {
+                name: "Broken",
+                result: Err(unreadable.clone()),
+                available: true,
+            }),
+        );
+        sources.push(mock("Empty", &[]));
+
+        for scan_threads in [None, Some(1), Some(3), Some(64)] {
+            let ScanResult { games, errors } =
+                scan_all(&sources, None, &HashMap::new(), scan_threads);
+
+            let names: Vec<String> = games.iter().map(|game| game.name.clone()).collect();
+            let expected: Vec<String> = (0..=NAMES.len())
+                .map(|i| format!("Game {:02}", i))
+                .collect();
+            assert_eq!(names, expected, "scan_threads {:?}", scan_threads);
+            assert_eq!(
+                errors,
+                vec![ScanError {
+                    source_name: "Broken",
+                    diagnostic: unreadable.clone(),
+                }]
+            );
+        }
+
+        // The cap applies per source, before duplicates across sources are merged
+        let ScanResult { games, .. } = scan_all(&sources, Some(1), &HashMap::new(), Some(2));
+        assert_eq!(games.len(), NAMES.len());
+    }
+
+    #[test]
+    fn test_unavailable_sources_are_skipped() {
+        let sources: Vec<Box<dyn GameSource>> = vec![
+            mock("Steam", &["Hades".to_string()]),
+            Box::new(MockSource {
+                name: "SNES9x",
+                result: Ok(vec![AppEntry::new(
+                    "Chrono Trigger".to_string(),
+                    "snes9x-gtk chrono.sfc".to_string(),
+                    None,
+                )]
+                .into()),
+                available: false,
+            }),
+            Box::new(MockSource {
+                name: "Heroic",
+                result: Err(ScanDiagnostic::NoLibraries),
+                available: false,
+            }),
+        ];
+
+        let ScanResult { games, errors } = scan_all(&sources, None, &HashMap::new(), Some(2));
+        let names: Vec<&str> = games.iter().map(|game| game.name.as_str()).collect();
+        assert_eq!(names, vec!["Hades"]);
+        // Not being installed isn't a failure
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_failing_source_is_recorded_without_aborting_scan() {
+        let malformed = ScanDiagnostic::Malformed {
+            path: PathBuf::from("libraryfolders.vdf"),
+            detail: "no library folders found".to_string(),
+        };
+        let ScanResult { games, errors } = merge_source_results(vec![
+            ("Steam", Err(malformed.clone())),
+            (
+                "Heroic",
+                Ok(SourceScan {
+                    games: vec![AppEntry::new(
+                        "Hades".to_string(),
+                        "xdg-open heroic://launch/gog/1".to_string(),
+                        None,
+                    )],
+                    skipped: vec![malformed.clone()],
+                }),
+            ),
+        ]);
+
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].name, "Hades");
+        // A skipped file is listed like a failed source
+        assert_eq!(
+            errors,
+            vec![
+                ScanError {
+                    source_name: "Steam",
+                    diagnostic: malformed.clone(),
+                },
+                ScanError {
+                    source_name: "Heroic",
+                    diagnostic: malformed,
+                },
+            ]
+        );
+        assert_eq!(errors[0].to_string(), "Steam config is malformed");
+        assert_eq!(
+            errors[0].diagnostic.to_string(),
+            "libraryfolders.vdf is malformed: no library folders found"
+        );
+    }
+
+    #[test]
+    fn test_compatdata_marks_proton_games() {
+        let root =
+            std::env::temp_dir().join(format!("launcher_test_steam_{}", uuid::Uuid::new_v4()));
+        let steamapps = root.join("steamapps");
+        fs::create_dir_all(steamapps.join("compatdata/1145360/pfx")).unwrap();
+        // Prefix of a game that isn't installed in this library anymore
+        fs::create_dir_all(steamapps.join("compatdata/292030")).unwrap();
+        let manifest = |appid: &str, name: &str| {
+            let contents = format!(
+                "\"AppState\"\n{{\n    \"appid\" \"{}\"\n    \"name\" \"{}\"\n}}\n",
+                appid, name
+            );
+            fs::write(
+                steamapps.join(format!("appmanifest_{}.acf", appid)),
+                contents,
+            )
+            .unwrap();
+        };
+        manifest("1145360", "Hades");
+        manifest("367520", "Hollow Knight");
+
+        let mut games: Vec<AppEntry> = get_steam_manifest_paths(std::slice::from_ref(&root))
+            .iter()
+            .filter_map(|path| parse_steam_manifest_file(path))
+            .collect();
+        games.sort_by(|a, b| a.name.cmp(&b.name));
+
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].name, "Hades");
+        assert!(games[0].runs_under_proton);
+        assert_eq!(
+            games[0].monitor_env,
+            vec![("STEAM_COMPAT_APP_ID".to_string(), "1145360".to_string())]
+        );
+        assert_eq!(games[1].name, "Hollow Knight");
+        assert!(!games[1].runs_under_proton);
+        assert!(games[1].monitor_env.is_empty());
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_malformed_library_folders_skips_only_that_root() {
+        let temp =
+            std::env::temp_dir().join(format!("launcher_test_steam_{}", uuid::Uuid::new_v4()));
+        let broken = temp.join("broken");
+        let valid = temp.join("valid");
+        fs::create_dir_all(broken.join("steamapps")).unwrap();
+        fs::create_dir_all(valid.join("steamapps")).unwrap();
+        fs::write(broken.join("steamapps/libraryfolders.vdf"), "{ not vdf }").unwrap();
+        fs::write(
+            valid.join("steamapps/libraryfolders.vdf"),
+            "\"libraryfolders\"\n{\n    \"0\"\n    {\n        \"path\" \"/mnt/games\"\n    }\n}\n",
+        )
+        .unwrap();
+
+        // A symlinked root pointing at an install already listed is only read once
+        let link = temp.join("link");
+        std::os::unix::fs::symlink(&broken, &link).unwrap();
+
+        let mut skipped = Vec::new();
+        let mut paths =
+            get_steam_library_paths(&[broken.clone(), valid.clone(), link], &mut skipped);
+        paths.sort();
+
+        assert_eq!(paths, vec![PathBuf::from("/mnt/games"), broken, valid]);
+        assert!(matches!(
+            skipped.as_slice(),
+            [ScanDiagnostic::Malformed { path, .. }] if path.ends_with("broken/steamapps/libraryfolders.vdf")
+        ));
+
+        let _ = fs::remove_dir_all(temp);
+    }
+
+    #[test]
+    fn test_corrupt_heroic_library_keeps_other_files() {
+        let root =
+            std::env::temp_dir().join(format!("launcher_test_heroic_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(root.join("store_cache")).unwrap();
+        fs::write(
+            root.join("store_cache/legendary_library.json"),
+            "{ truncated",
+        )
+        .unwrap();
+        fs::write(
+            root.join("store_cache/gog_library.json"),
+            r#"{"games": [{"app_name": "1207658930", "title": "Celeste", "is_installed": true, "runner": "gog"}]}"#,
+        )
+        .unwrap();
+
+        let mut scan = SourceScan::default();
+        scan_heroic_root(&root, &mut scan, &mut HashSet::new());
+
+        let names: Vec<&str> = scan.games.iter().map(|game| game.name.as_str()).collect();
+        assert_eq!(names, vec!["Celeste"]);
+        assert!(matches!(
+            scan.skipped.as_slice(),
+            [ScanDiagnostic::Malformed { path, .. }] if path.ends_with("legendary_library.json")
+        ));
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_read_failures_map_to_diagnostics() {
+        let dir = std::env::temp_dir().join(format!("launcher_test_read_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Missing files are expected, e.g. a store Heroic isn't logged in to
+        assert_eq!(read_file_if_exists(&dir.join("missing.json")), Ok(None));
+        // A directory where a file is expected can't be read as one
+        assert!(matches!(
+            read_file_if_exists(&dir),
+            Err(ScanDiagnostic::Unreadable { path, .. }) if path == dir
+        ));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_scan_error_messages() {
+        let error = |source_name, diagnostic| ScanError {
+            source_name,
+            diagnostic,
+        };
+
+        assert_eq!(
+            error("Steam", ScanDiagnostic::NoLibraries).to_string(),
+            "Steam found, but no libraries are readable"
+        );
+        let denied = error(
+            "Heroic",
+            ScanDiagnostic::Unreadable {
+                path: PathBuf::from("/home/me/.config/heroic/gog_store/installed.json"),
+                kind: io::ErrorKind::PermissionDenied,
+            },
+        );
+        assert_eq!(
+            denied.to_string(),
+            "Heroic config unreadable: permission denied"
+        );
+        assert_eq!(
+            denied.diagnostic.to_string(),
+            "/home/me/.config/heroic/gog_store/installed.json can't be read: permission denied"
+        );
+    }
+
+    #[test]
+    fn test_most_relevant_error_prefers_unreadable_config() {
+        let malformed = ScanError {
+            source_name: "Steam",
+            diagnostic: ScanDiagnostic::Malformed {
+                path: PathBuf::from("libraryfolders.vdf"),
+                detail: "no library folders found".to_string(),
+            },
+        };
+        let no_libraries = ScanError {
+            source_name: "Steam",
+            diagnostic: ScanDiagnostic::NoLibraries,
+        };
+        let denied = ScanError {
+            source_name: "Heroic",
+            diagnostic: ScanDiagnostic::Unreadable {
+                path: PathBuf::from("installed.json"),
+                kind: io::ErrorKind::PermissionDenied,
+            },
+        };
+
+        assert_eq!(most_relevant_error(&[]), None);
+        assert_eq!(
+            most_relevant_error(&[malformed.clone(), no_libraries.clone()]),
+            Some(&no_libraries)
+        );
+        assert_eq!(
+            most_relevant_error(&[malformed, no_libraries, denied.clone()]),
+            Some(&denied)
+        );
+    }
+
+    #[test]
+    fn test_malformed_heroic_library_is_an_error() {
+        assert!(parse_heroic_library_json("{ broken", "gog").is_err());
+    }
 }
@@ -1,17 +1,78 @@
+use crate::controller_led::{apply_controller_led, ControllerLedMode};
 use crate::input::Action;
 use gilrs::ff::{BaseEffect, BaseEffectType, EffectBuilder, Envelope, Replay, Ticks};
 use gilrs::{Axis, Button, Event, EventType, Gamepad, GamepadId, Gilrs, MappingSource, PowerInfo};
 use iced::futures::sink::SinkExt;
+use iced::futures::Stream;
 use iced::Subscription;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::time::{Duration, Instant};
 use tracing::error;
 
 const POLL_INTERVAL: Duration = Duration::from_millis(10);
+/// Poll rates used with power saving once the pads have been idle for a while
+const DROWSY_POLL_INTERVAL: Duration = Duration::from_millis(30);
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Input this recent keeps the full poll rate
+const ACTIVE_WINDOW: Duration = Duration::from_secs(2);
+/// Idle time after which the slowest poll rate is used
+const IDLE_AFTER: Duration = Duration::from_secs(10);
 const BATTERY_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// Poll rate of the connection watcher that runs while a game is played
+const PRESENCE_POLL_INTERVAL: Duration = Duration::from_millis(250);
 const REPEAT_DELAY: Duration = Duration::from_millis(400);
 const REPEAT_INTERVAL: Duration = Duration::from_millis(100);
-const DEADZONE: f32 = 0.6;
+const DEFAULT_DEADZONE: f32 = 0.6;
+pub const MIN_DEADZONE: f32 = 0.1;
+pub const MAX_DEADZONE: f32 = 0.95;
+/// Headroom added on top of the observed resting drift when suggesting a deadzone
+const DRIFT_MARGIN: f32 = 0.1;
+
+/// Stick deflection required before a direction is registered, per axis.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StickDeadzone {
+    pub horizontal: f32,
+    pub vertical: f32,
+}
+
+impl Default for StickDeadzone {
+    fn default() -> Self {
+        Self {
+            horizontal: DEFAULT_DEADZONE,
+            vertical: DEFAULT_DEADZONE,
+        }
+    }
+}
+
+impl Hash for StickDeadzone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.horizontal.to_bits().hash(state);
+        self.vertical.to_bits().hash(state);
+    }
+}
+
+/// Options the gamepad subscription is started with; changing them restarts it.
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct GamepadConfig {
+    pub deadzone: StickDeadzone,
+    /// Forward raw left stick readings, used while calibrating the deadzone
+    pub report_stick: bool,
+    /// Nintendo layout: the right face button (East) confirms and the bottom one goes back
+    pub swap_ab: bool,
+    /// Light bar color set on DualSense/DualShock 4 pads when they connect
+    pub led_mode: ControllerLedMode,
+    /// Poll less often while no input arrives, to save battery on handhelds
+    pub power_saving: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StickAxis {
+    Horizontal,
+    Vertical,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum GamepadInput {
@@ -24,12 +85,96 @@ pub struct GamepadInfo {
     pub power_info: PowerInfo,
     pub name: String,
     pub is_keyboard: bool,
+    /// Player number as announced by the connection rumble; `None` for keyboards
+    pub player: Option<usize>,
+}
+
+/// Whether any connected device works as a gamepad rather than a keyboard
+pub fn has_functional_gamepad(devices: &[GamepadInfo]) -> bool {
+    devices.iter().any(|device| !device.is_keyboard)
 }
 
 #[derive(Debug, Clone)]
 pub enum GamepadEvent {
     Input(Action),
+    /// The confirm button went up; only reported for hold to launch
+    SelectReleased,
     Battery(Vec<GamepadInfo>),
+    Stick(StickAxis, f32),
+}
+
+/// Reported by the connection watcher that runs while a game is played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceChange {
+    /// The last gamepad went away; keyboards don't count
+    AllDisconnected,
+    /// A gamepad came back after all of them were gone
+    Reconnected,
+}
+
+/// Connected gamepads by ID, to notice when none remain
+#[derive(Debug, Default)]
+struct ControllerPresence {
+    gamepads: HashSet<usize>,
+    lost: bool,
+}
+
+impl ControllerPresence {
+    fn connected(&mut self, id: usize, is_keyboard: bool) -> Option<PresenceChange> {
+        if is_keyboard {
+            return None;
+        }
+        self.gamepads.insert(id);
+        std::mem::take(&mut self.lost).then_some(PresenceChange::Reconnected)
+    }
+
+    /// Only losing the last gamepad counts; playing with a keyboard alone never does
+    fn disconnected(&mut self, id: usize) -> Option<PresenceChange> {
+        if self.gamepads.remove(&id) && self.gamepads.is_empty() {
+            self.lost = true;
+            return Some(PresenceChange::AllDisconnected);
+        }
+        None
+    }
+}
+
+/// Watches only for gamepads connecting and disconnecting. Unlike the input
+/// subscription it stays active while a game runs, without reading any input.
+pub fn controller_presence_stream() -> impl Stream<Item = PresenceChange> {
+    iced::stream::channel(
+        10,
+        |mut output: iced::futures::channel::mpsc::Sender<PresenceChange>| async move {
+            let mut gilrs = match Gilrs::new() {
+                Ok(g) => g,
+                Err(e) => {
+                    error!("Failed to initialize Gilrs: {}", e);
+                    return;
+                }
+            };
+
+            let mut presence = ControllerPresence::default();
+            for (id, gp) in gilrs.gamepads() {
+                presence.connected(usize::from(id), is_likely_keyboard(&gp));
+            }
+
+            loop {
+                while let Some(Event { id, event, .. }) = gilrs.next_event() {
+                    let change = match event {
+                        EventType::Connected => {
+                            let is_keyboard = is_likely_keyboard(&gilrs.gamepad(id));
+                            presence.connected(usize::from(id), is_keyboard)
+                        }
+                        EventType::Disconnected => presence.disconnected(usize::from(id)),
+                        _ => None,
+                    };
+                    if let Some(change) = change {
+                        let _ = output.send(change).await;
+                    }
+                }
+                tokio::time::sleep(PRESENCE_POLL_INTERVAL).await;
+            }
+        },
+    )
 }
 
 /// Device capabilities extracted from Gilrs for pure logic classification
@@ -67,19 +212,26 @@ impl GamepadCapabilities {
 struct AxisState {
     dir_x: i8,
     dir_y: i8,
+    /// Guide button held down, turning South, North, and West into shortcut chords
+    guide_held: bool,
 }
 
 impl AxisState {
     fn new() -> Self {
-        Self { dir_x: 0, dir_y: 0 }
+        Self {
+            dir_x: 0,
+            dir_y: 0,
+            guide_held: false,
+        }
     }
 }
 
-pub fn gamepad_subscription() -> Subscription<GamepadEvent> {
-    Subscription::run(|| {
+pub fn gamepad_subscription(config: GamepadConfig) -> Subscription<GamepadEvent> {
+    Subscription::run_with(config, |config| {
+        let config = *config;
         iced::stream::channel(
             100,
-            |mut output: iced::futures::channel::mpsc::Sender<GamepadEvent>| async move {
+            move |mut output: iced::futures::channel::mpsc::Sender<GamepadEvent>| async move {
                 let mut gilrs = match Gilrs::new() {
                     Ok(g) => g,
                     Err(e) => {
@@ -96,6 +248,7 @@ pub fn gamepad_subscription() -> Subscription<GamepadEvent> {
                 // Store active vibration effects to keep them alive while playing
                 let mut active_effects: Vec<(gilrs::ff::Effect, Instant)> = Vec::new();
                 let mut current_repeater: Option<(Action, Instant, Instant)> = None;
+                let mut last_event = Instant::now();
 
                 loop {
                     // Clean up finished effects
@@ -103,19 +256,30 @@ pub fn gamepad_subscription() -> Subscription<GamepadEvent> {
 
                     // 1. Process all available events (non-blocking)
                     while let Some(Event { id, event, .. }) = gilrs.next_event() {
+                        last_event = Instant::now();
                         match event {
                             EventType::Connected => {
                                 trigger_connection_haptics(&mut gilrs, id, &mut active_effects);
+                                update_controller_led(&gilrs, id, config.led_mode);
+                                // Show the new pad and its player number right away
+                                current_battery_interval = Duration::ZERO;
                             }
                             EventType::Disconnected => {
                                 axis_states.remove(&id);
+                                current_battery_interval = Duration::ZERO;
                                 continue;
                             }
                             _ => {}
                         }
 
+                        if config.report_stick {
+                            if let Some((axis, value)) = stick_reading(event) {
+                                let _ = output.send(GamepadEvent::Stick(axis, value)).await;
+                            }
+                        }
+
                         let state = axis_states.entry(id).or_insert_with(AxisState::new);
-                        if let Some(input) = process_event(event, state) {
+                        if let Some(input) = process_event(event, state, &config) {
                             match input {
                                 GamepadInput::Press(action) => {
                                     let _ = output.send(GamepadEvent::Input(action)).await;
@@ -125,6 +289,9 @@ pub fn gamepad_subscription() -> Subscription<GamepadEvent> {
                                     }
                                 }
                                 GamepadInput::Release(action) => {
+                                    if action == Action::Select {
+                                        let _ = output.send(GamepadEvent::SelectReleased).await;
+                                    }
                                     if let Some((curr_action, _, _)) = current_repeater {
                                         if curr_action == action {
                                             current_repeater = None;
@@ -148,18 +315,21 @@ pub fn gamepad_subscription() -> Subscription<GamepadEvent> {
 
                     // 2. Periodic Battery Check
                     if last_battery_check.elapsed() >= current_battery_interval {
-                        let batteries = gilrs
+                        let mut batteries: Vec<GamepadInfo> = gilrs
                             .gamepads()
-                            .map(|(_, gp)| {
+                            .map(|(id, gp)| {
                                 let name = gp.name().to_string();
                                 let is_keyboard = is_likely_keyboard(&gp);
                                 GamepadInfo {
                                     power_info: gp.power_info(),
                                     name,
                                     is_keyboard,
+                                    player: player_number(&gilrs, id),
                                 }
                             })
                             .collect();
+                        // Players in order, keyboards after them
+                        batteries.sort_by_key(|info| info.player.unwrap_or(usize::MAX));
 
                         let _ = output.send(GamepadEvent::Battery(batteries)).await;
 
@@ -168,34 +338,37 @@ pub fn gamepad_subscription() -> Subscription<GamepadEvent> {
                     }
 
                     // 3. Yield to avoid busy loop
-                    tokio::time::sleep(POLL_INTERVAL).await;
+                    let interval = if config.power_saving {
+                        adaptive_poll_interval(last_event.elapsed(), current_repeater.is_some())
+                    } else {
+                        POLL_INTERVAL
+                    };
+                    tokio::time::sleep(interval).await;
                 }
             },
         )
     })
 }
 
+/// Sleep between polls with power saving: the full rate while input is recent or a
+/// held direction repeats, slower the longer the pads stay idle. The first event
+/// after a pause switches back to the full rate.
+fn adaptive_poll_interval(since_last_event: Duration, repeating: bool) -> Duration {
+    if repeating || since_last_event < ACTIVE_WINDOW {
+        POLL_INTERVAL
+    } else if since_last_event < IDLE_AFTER {
+        DROWSY_POLL_INTERVAL
+    } else {
+        IDLE_POLL_INTERVAL
+    }
+}
+
 fn trigger_connection_haptics(
     gilrs: &mut Gilrs,
     connected_id: GamepadId,
     active_effects: &mut Vec<(gilrs::ff::Effect, Instant)>,
 ) {
-    let gamepad = gilrs.gamepad(connected_id);
-    if is_likely_keyboard(&gamepad) {
-        return;
-    }
-
-    // Determine player number based on sorted IDs of valid gamepads
-    let mut gamepads: Vec<_> = gilrs
-        .gamepads()
-        .filter(|(_, gp)| !is_likely_keyboard(gp))
-        .map(|(id, _)| id)
-        .collect();
-    gamepads.sort_by_key(|id| usize::from(*id));
-
-    if let Some(idx) = gamepads.iter().position(|&x| x == connected_id) {
-        let player_number = idx + 1;
-
+    if let Some(player_number) = player_number(gilrs, connected_id) {
         // Vibrate 'player_number' times
         // Pulse 200ms, Interval 400ms
 
@@ -232,6 +405,52 @@ fn trigger_connection_haptics(
     }
 }
 
+/// Player number based on sorted IDs of valid gamepads; `None` for keyboards
+fn player_number(gilrs: &Gilrs, id: GamepadId) -> Option<usize> {
+    let devices: Vec<(usize, bool)> = gilrs
+        .gamepads()
+        .map(|(id, gp)| (usize::from(id), is_likely_keyboard(&gp)))
+        .collect();
+    player_number_of(&devices, usize::from(id))
+}
+
+/// Player numbers count the non-keyboard devices `(id, is_keyboard)` in ID order,
+/// starting at 1, so they don't depend on the order the devices are listed in.
+fn player_number_of(devices: &[(usize, bool)], id: usize) -> Option<usize> {
+    let mut gamepads: Vec<usize> = devices
+        .iter()
+        .filter(|(_, is_keyboard)| !is_keyboard)
+        .map(|(id, _)| *id)
+        .collect();
+    gamepads.sort_unstable();
+    gamepads.iter().position(|&x| x == id).map(|idx| idx + 1)
+}
+
+/// Colors the light bar of a newly connected pad. Pads of the same model are told
+/// apart by connection order, matching their LEDs in device order.
+fn update_controller_led(gilrs: &Gilrs, id: GamepadId, mode: ControllerLedMode) {
+    let Some(player_number) = player_number(gilrs, id) else {
+        return;
+    };
+    let Some(color) = mode.color(player_number) else {
+        return;
+    };
+    let gamepad = gilrs.gamepad(id);
+    let (Some(vendor), Some(product)) = (gamepad.vendor_id(), gamepad.product_id()) else {
+        return;
+    };
+
+    let mut same_model: Vec<_> = gilrs
+        .gamepads()
+        .filter(|(_, gp)| gp.vendor_id() == Some(vendor) && gp.product_id() == Some(product))
+        .map(|(id, _)| id)
+        .collect();
+    same_model.sort_by_key(|id| usize::from(*id));
+    if let Some(index) = same_model.iter().position(|&x| x == id) {
+        apply_controller_led(vendor, product, index, color);
+    }
+}
+
 fn is_likely_keyboard(gp: &Gamepad) -> bool {
     let caps = GamepadCapabilities::from_gamepad(gp);
     classify_as_keyboard(&caps)
@@ -267,20 +486,70 @@ fn classify_as_keyboard(caps: &GamepadCapabilities) -> bool {
     false
 }
 
-fn map_axis_value(value: f32) -> i8 {
-    if value <= -DEADZONE {
+fn map_axis_value(value: f32, deadzone: f32) -> i8 {
+    if value <= -deadzone {
         -1
-    } else if value >= DEADZONE {
+    } else if value >= deadzone {
         1
     } else {
         0
     }
 }
 
-fn process_event(event: EventType, state: &mut AxisState) -> Option<GamepadInput> {
+/// Suggests a deadzone that sits just above the largest drift seen while the stick was at rest.
+pub fn suggest_deadzone(max_resting_drift: f32) -> f32 {
+    let suggestion = (max_resting_drift.abs() + DRIFT_MARGIN).clamp(MIN_DEADZONE, MAX_DEADZONE);
+    // Round up to the 0.05 steps used by the settings modal
+    ((suggestion * 20.0).ceil() / 20.0).min(MAX_DEADZONE)
+}
+
+fn stick_reading(event: EventType) -> Option<(StickAxis, f32)> {
     match event {
-        EventType::ButtonPressed(Button::South, _) => Some(GamepadInput::Press(Action::Select)),
-        EventType::ButtonPressed(Button::East, _) => Some(GamepadInput::Press(Action::Back)),
+        EventType::AxisChanged(Axis::LeftStickX, value, _) => Some((StickAxis::Horizontal, value)),
+        EventType::AxisChanged(Axis::LeftStickY, value, _) => Some((StickAxis::Vertical, value)),
+        _ => None,
+    }
+}
+
+/// Action of the confirm/cancel face buttons, honoring the A/B swap
+fn confirm_button_action(button: Button, swap_ab: bool) -> Option<Action> {
+    match (button, swap_ab) {
+        (Button::South, false) | (Button::East, true) => Some(Action::Select),
+        (Button::East, false) | (Button::South, true) => Some(Action::Back),
+        _ => None,
+    }
+}
+
+fn process_event(
+    event: EventType,
+    state: &mut AxisState,
+    config: &GamepadConfig,
+) -> Option<GamepadInput> {
+    let deadzone = config.deadzone;
+    match event {
+        EventType::ButtonPressed(Button::Mode, _) => {
+            state.guide_held = true;
+            None
+        }
+        EventType::ButtonReleased(Button::Mode, _) => {
+            state.guide_held = false;
+            None
+        }
+        EventType::ButtonPressed(Button::South, _) if state.guide_held => {
+            Some(GamepadInput::Press(Action::Screenshot))
+        }
+        EventType::ButtonPressed(Button::North, _) if state.guide_held => {
+            Some(GamepadInput::Press(Action::ToggleFullscreen))
+        }
+        EventType::ButtonPressed(Button::West, _) if state.guide_held => {
+            Some(GamepadInput::Press(Action::RelaunchLast))
+        }
+        EventType::ButtonPressed(Button::East, _) if state.guide_held => {
+            Some(GamepadInput::Press(Action::CycleSort))
+        }
+        EventType::ButtonPressed(button @ (Button::South | Button::East), _) => {
+            confirm_button_action(button, config.swap_ab).map(GamepadInput::Press)
+        }
         EventType::ButtonPressed(Button::West, _) => Some(GamepadInput::Press(Action::ContextMenu)),
         EventType::ButtonPressed(Button::North, _) => Some(GamepadInput::Press(Action::AddApp)),
         EventType::ButtonPressed(Button::DPadUp, _) => Some(GamepadInput::Press(Action::Up)),
@@ -301,6 +570,10 @@ fn process_event(event: EventType, state: &mut AxisState) -> Option<GamepadInput
         }
         EventType::ButtonPressed(Button::Select, _) => Some(GamepadInput::Press(Action::ShowHelp)),
 
+        EventType::ButtonReleased(button @ (Button::South | Button::East), _) => {
+            confirm_button_action(button, config.swap_ab).map(GamepadInput::Release)
+        }
+
         // Released events for navigation buttons
         EventType::ButtonReleased(Button::DPadUp, _) => Some(GamepadInput::Release(Action::Up)),
         EventType::ButtonReleased(Button::DPadDown, _) => Some(GamepadInput::Release(Action::Down)),
@@ -322,7 +595,7 @@ fn process_event(event: EventType, state: &mut AxisState) -> Option<GamepadInput
         }
 
         EventType::AxisChanged(gilrs::Axis::LeftStickX, value, _) => {
-            let new_dir = map_axis_value(value);
+            let new_dir = map_axis_value(value, deadzone.horizontal);
             if new_dir != state.dir_x {
                 let old_dir = state.dir_x;
                 state.dir_x = new_dir;
@@ -341,7 +614,7 @@ fn process_event(event: EventType, state: &mut AxisState) -> Option<GamepadInput
             }
         }
         EventType::AxisChanged(gilrs::Axis::LeftStickY, value, _) => {
-            let new_dir = map_axis_value(value);
+            let new_dir = map_axis_value(value, deadzone.vertical);
             if new_dir != state.dir_y {
                 let old_dir = state.dir_y;
                 state.dir_y = new_dir;
@@ -379,6 +652,56 @@ fn is_nav_action(action: Action) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_presence_reports_last_gamepad_lost() {
+        let mut presence = ControllerPresence::default();
+        assert_eq!(presence.connected(0, false), None);
+        assert_eq!(presence.connected(1, false), None);
+        assert_eq!(presence.connected(2, true), None);
+
+        assert_eq!(presence.disconnected(0), None);
+        assert_eq!(
+            presence.disconnected(1),
+            Some(PresenceChange::AllDisconnected)
+        );
+        // The keyboard that remains doesn't count as a controller
+        assert_eq!(presence.disconnected(2), None);
+
+        assert_eq!(presence.connected(3, true), None);
+        assert_eq!(
+            presence.connected(1, false),
+            Some(PresenceChange::Reconnected)
+        );
+        assert_eq!(presence.connected(0, false), None);
+    }
+
+    #[test]
+    fn test_presence_ignores_keyboard_only_setups() {
+        let mut presence = ControllerPresence::default();
+        assert_eq!(presence.connected(0, true), None);
+        assert_eq!(presence.disconnected(0), None);
+        // An unknown ID disconnecting is no loss either
+        assert_eq!(presence.disconnected(7), None);
+    }
+
+    #[test]
+    fn test_adaptive_poll_interval_ramps_down_when_idle() {
+        let secs = Duration::from_secs;
+        assert_eq!(adaptive_poll_interval(Duration::ZERO, false), POLL_INTERVAL);
+        assert_eq!(adaptive_poll_interval(secs(1), false), POLL_INTERVAL);
+        assert_eq!(adaptive_poll_interval(secs(5), false), DROWSY_POLL_INTERVAL);
+        assert_eq!(adaptive_poll_interval(secs(60), false), IDLE_POLL_INTERVAL);
+    }
+
+    #[test]
+    fn test_adaptive_poll_interval_keeps_full_rate_while_repeating() {
+        // A held direction sends no new events but must keep repeating smoothly
+        assert_eq!(
+            adaptive_poll_interval(Duration::from_secs(60), true),
+            POLL_INTERVAL
+        );
+    }
+
     #[test]
     fn test_classify_as_keyboard_logic() {
         // Case 1: Xbox Controller (SDL Mapped) -> Gamepad (False)
@@ -444,4 +767,86 @@ mod tests {
             "Device without face buttons is not a usable gamepad"
         );
     }
+
+    #[test]
+    fn test_has_functional_gamepad() {
+        let device = |name: &str, is_keyboard: bool| GamepadInfo {
+            power_info: PowerInfo::Wired,
+            name: name.to_string(),
+            is_keyboard,
+            player: None,
+        };
+
+        assert!(!has_functional_gamepad(&[]));
+        assert!(!has_functional_gamepad(&[
+            device("Keychron Q3 Pro System Control", true),
+            device("Logitech K400", true),
+        ]));
+        assert!(has_functional_gamepad(&[
+            device("Keychron Q3 Pro System Control", true),
+            device("Xbox Wireless Controller", false),
+        ]));
+    }
+
+    #[test]
+    fn test_player_numbers_follow_sorted_ids() {
+        // Listed out of order, with a keyboard among the pads
+        let devices = [(7, false), (2, true), (4, false), (0, false)];
+
+        assert_eq!(player_number_of(&devices, 0), Some(1));
+        assert_eq!(player_number_of(&devices, 4), Some(2));
+        assert_eq!(player_number_of(&devices, 7), Some(3));
+        assert_eq!(player_number_of(&devices, 2), None);
+        assert_eq!(player_number_of(&devices, 9), None);
+
+        // Player 1 leaving moves the others up
+        assert_eq!(player_number_of(&devices[..3], 4), Some(1));
+    }
+
+    #[test]
+    fn test_confirm_buttons_swap() {
+        assert_eq!(
+            confirm_button_action(Button::South, false),
+            Some(Action::Select)
+        );
+        assert_eq!(
+            confirm_button_action(Button::East, false),
+            Some(Action::Back)
+        );
+
+        assert_eq!(
+            confirm_button_action(Button::South, true),
+            Some(Action::Back)
+        );
+        assert_eq!(
+            confirm_button_action(Button::East, true),
+            Some(Action::Select)
+        );
+
+        assert_eq!(confirm_button_action(Button::West, true), None);
+    }
+
+    #[test]
+    fn test_map_axis_value_respects_configured_deadzone() {
+        assert_eq!(map_axis_value(0.5, 0.6), 0);
+        assert_eq!(map_axis_value(0.6, 0.6), 1);
+        assert_eq!(map_axis_value(-0.7, 0.6), -1);
+
+        assert_eq!(map_axis_value(0.3, 0.25), 1);
+        assert_eq!(map_axis_value(-0.3, 0.25), -1);
+        assert_eq!(map_axis_value(0.2, 0.25), 0);
+
+        assert_eq!(map_axis_value(0.9, 0.95), 0);
+        assert_eq!(map_axis_value(-1.0, 0.95), -1);
+    }
+
+    #[test]
+    fn test_suggest_deadzone_adds_margin_and_clamps() {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+
+        assert!(close(suggest_deadzone(0.0), MIN_DEADZONE));
+        assert!(close(suggest_deadzone(0.12), 0.25));
+        assert!(close(suggest_deadzone(-0.3), 0.4));
+        assert!(close(suggest_deadzone(1.0), MAX_DEADZONE));
+    }
 }
@@ -0,0 +1,55 @@
+//! Holding the gamepad's Select button on a game before it launches, so a stray
+//! press doesn't start a game by accident.
+
+use std::time::{Duration, Instant};
+
+/// How long Select has to stay down before the game starts
+pub const HOLD_TO_LAUNCH_DURATION: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Clone, Copy)]
+pub struct HoldToLaunch {
+    pressed_at: Instant,
+}
+
+impl HoldToLaunch {
+    pub fn new(pressed_at: Instant) -> Self {
+        Self { pressed_at }
+    }
+
+    /// Fill of the hold bar, from 0.0 when pressed to 1.0 when the game launches
+    pub fn progress(&self, now: Instant) -> f32 {
+        let held = now.saturating_duration_since(self.pressed_at);
+        (held.as_secs_f32() / HOLD_TO_LAUNCH_DURATION.as_secs_f32()).min(1.0)
+    }
+
+    /// True once Select was held long enough. Checked on every frame while holding
+    /// and once more on release, in case the release arrives before the next frame.
+    pub fn is_complete(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.pressed_at) >= HOLD_TO_LAUNCH_DURATION
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_tap_does_not_launch() {
+        let start = Instant::now();
+        let hold = HoldToLaunch::new(start);
+
+        let released = start + Duration::from_millis(120);
+        assert!(!hold.is_complete(released));
+        assert!((hold.progress(released) - 0.3).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_full_hold_launches() {
+        let start = Instant::now();
+        let hold = HoldToLaunch::new(start);
+
+        assert!(!hold.is_complete(start + Duration::from_millis(399)));
+        assert!(hold.is_complete(start + HOLD_TO_LAUNCH_DURATION));
+        assert_eq!(hold.progress(start + Duration::from_secs(2)), 1.0);
+    }
+}
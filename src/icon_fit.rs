@@ -0,0 +1,79 @@
+use iced::ContentFit;
+use serde::{Deserialize, Serialize};
+
+use crate::model::Category;
+
+/// How artwork that doesn't match its tile's shape is fitted into it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IconFit {
+    /// Keep the whole image visible, letterboxed
+    #[default]
+    Contain,
+    /// Fill the tile, cropping what sticks out
+    Cover,
+}
+
+impl IconFit {
+    pub fn content_fit(self) -> ContentFit {
+        match self {
+            IconFit::Contain => ContentFit::Contain,
+            IconFit::Cover => ContentFit::Cover,
+        }
+    }
+}
+
+/// Fit per row, e.g. `{ "games": "cover", "apps": "contain" }`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IconFitSettings {
+    pub games: IconFit,
+    pub apps: IconFit,
+    pub system: IconFit,
+}
+
+impl IconFitSettings {
+    /// Rows showing game posters (Recent, Favorites) follow the games setting
+    pub fn for_category(self, category: Category) -> IconFit {
+        match category {
+            Category::Games | Category::Recent | Category::Favorites => self.games,
+            Category::Apps => self.apps,
+            Category::System => self.system,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::Size;
+
+    #[test]
+    fn test_fitted_dimensions() {
+        let tile = Size::new(200.0, 300.0);
+        // A wide 2:1 icon in a portrait tile
+        let wide = Size::new(400.0, 200.0);
+
+        assert_eq!(
+            IconFit::Contain.content_fit().fit(wide, tile),
+            Size::new(200.0, 100.0)
+        );
+        assert_eq!(
+            IconFit::Cover.content_fit().fit(wide, tile),
+            Size::new(600.0, 300.0)
+        );
+        // Matching aspect ratios fill the tile either way
+        let poster = Size::new(400.0, 600.0);
+        assert_eq!(IconFit::Contain.content_fit().fit(poster, tile), tile);
+        assert_eq!(IconFit::Cover.content_fit().fit(poster, tile), tile);
+    }
+
+    #[test]
+    fn test_fit_per_category() {
+        let settings: IconFitSettings = serde_json::from_str(r#"{ "games": "cover" }"#).unwrap();
+        assert_eq!(settings.for_category(Category::Games), IconFit::Cover);
+        assert_eq!(settings.for_category(Category::Recent), IconFit::Cover);
+        assert_eq!(settings.for_category(Category::Apps), IconFit::Contain);
+        assert_eq!(settings.for_category(Category::System), IconFit::Contain);
+    }
+}
@@ -1,6 +1,10 @@
+use iced::widget::{Container, Text};
 use iced::{Color, Element};
 use iced_fonts::fontawesome;
 
+use crate::model::SourceBadge;
+use crate::ui_theme::{COLOR_ABYSS_DARK, COLOR_ACCENT, COLOR_OVERLAY_STRONG, SANSATION};
+
 pub fn power_off_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
     fontawesome::power_off()
         .size(size)
@@ -30,6 +34,122 @@ pub fn info_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
     fontawesome::info().size(size).color(Color::WHITE).into()
 }
 
+pub fn gear_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
+    fontawesome::gear().size(size).color(Color::WHITE).into()
+}
+
+pub fn file_lines_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
+    fontawesome::file_lines()
+        .size(size)
+        .color(Color::WHITE)
+        .into()
+}
+
+pub fn tv_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
+    fontawesome::tv().size(size).color(Color::WHITE).into()
+}
+
+pub fn bug_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
+    fontawesome::bug().size(size).color(Color::WHITE).into()
+}
+
+pub fn terminal_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
+    fontawesome::terminal()
+        .size(size)
+        .color(Color::WHITE)
+        .into()
+}
+
+/// Small pill marking where a game comes from; emulators get a gamepad glyph
+pub fn source_badge_icon<'a, Message: 'a>(badge: SourceBadge, size: f32) -> Element<'a, Message> {
+    let content: Element<'a, Message> = match badge {
+        SourceBadge::Emulator => fontawesome::gamepad().size(size).color(Color::WHITE).into(),
+        _ => Text::new(badge.label())
+            .font(SANSATION)
+            .size(size)
+            .color(Color::WHITE)
+            .into(),
+    };
+    overlay_pill(content, size)
+}
+
+/// Marks a Steam game that runs through Proton, next to its source badge
+pub fn proton_badge_icon<'a, Message: 'a>(size: f32) -> Element<'a, Message> {
+    overlay_pill(
+        Text::new("PROTON")
+            .font(SANSATION)
+            .size(size)
+            .color(Color::WHITE)
+            .into(),
+        size,
+    )
+}
+
+/// Dark rounded pill laid over artwork
+fn overlay_pill<'a, Message: 'a>(content: Element<'a, Message>, size: f32) -> Element<'a, Message> {
+    Container::new(content)
+        .padding([size * 0.2, size * 0.5])
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_OVERLAY_STRONG.into()),
+            border: iced::Border {
+                radius: (size * 0.4).into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Pill in the color of a rating, e.g. a ProtonDB tier
+pub fn tier_badge<'a, Message: 'a>(
+    label: &'a str,
+    color: Color,
+    size: f32,
+) -> Element<'a, Message> {
+    Container::new(
+        Text::new(label)
+            .font(SANSATION)
+            .size(size)
+            .color(COLOR_ABYSS_DARK),
+    )
+    .padding([size * 0.2, size * 0.5])
+    .style(move |_| iced::widget::container::Style {
+        background: Some(color.into()),
+        border: iced::Border {
+            radius: (size * 0.4).into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+/// Rounded accent pill with a number, e.g. pending system updates
+pub fn count_badge<'a, Message: 'a>(count: usize, size: f32) -> Element<'a, Message> {
+    let label = if count > 99 {
+        "99+".to_string()
+    } else {
+        count.to_string()
+    };
+
+    Container::new(
+        Text::new(label)
+            .font(SANSATION)
+            .size(size)
+            .color(Color::WHITE),
+    )
+    .padding([size * 0.2, size * 0.5])
+    .style(move |_| iced::widget::container::Style {
+        background: Some(COLOR_ACCENT.into()),
+        border: iced::Border {
+            radius: size.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
 pub fn gamepad_icon<'a, Message: 'a>(size: f32, color: Color) -> Element<'a, Message> {
     fontawesome::gamepad().size(size).color(color).into()
 }
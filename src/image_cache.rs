@@ -1,11 +1,102 @@
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
-use std::fs;
-use std::path::PathBuf;
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageFormat, Rgb, RgbImage};
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::hash::Hash;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use ureq::Agent;
+
+/// Default JPEG quality for re-encoded covers
+pub const DEFAULT_COVER_QUALITY: u8 = 85;
+/// Default number of cover images kept in memory
+pub const DEFAULT_COVER_MEMORY_CACHE: usize = 200;
+
+/// Size of generated placeholder covers, in the 2:3 shape of a poster
+const PLACEHOLDER_WIDTH: u32 = 200;
+const PLACEHOLDER_HEIGHT: u32 = 300;
+/// Side of one pixel of the placeholder font
+const PLACEHOLDER_GLYPH_SCALE: u32 = 10;
+
+/// 5x7 bitmap glyphs for placeholder initials, one byte per row with the leftmost
+/// pixel in bit 4. Initials outside this set are skipped.
+const PLACEHOLDER_GLYPHS: [(char, [u8; 7]); 37] = [
+    ('A', [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11]),
+    ('B', [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E]),
+    ('C', [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E]),
+    ('D', [0x1E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1E]),
+    ('E', [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F]),
+    ('F', [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10]),
+    ('G', [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F]),
+    ('H', [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11]),
+    ('I', [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E]),
+    ('J', [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C]),
+    ('K', [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11]),
+    ('L', [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F]),
+    ('M', [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11]),
+    ('N', [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11]),
+    ('O', [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E]),
+    ('P', [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10]),
+    ('Q', [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D]),
+    ('R', [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11]),
+    ('S', [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E]),
+    ('T', [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04]),
+    ('U', [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E]),
+    ('V', [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04]),
+    ('W', [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A]),
+    ('X', [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11]),
+    ('Y', [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04]),
+    ('Z', [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F]),
+    ('0', [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E]),
+    ('1', [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E]),
+    ('2', [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F]),
+    ('3', [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E]),
+    ('4', [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02]),
+    ('5', [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E]),
+    ('6', [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E]),
+    ('7', [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08]),
+    ('8', [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E]),
+    ('9', [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C]),
+    ('?', [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04]),
+];
+
+/// File format covers are stored in after resizing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverFormat {
+    /// Keep the format of the downloaded image
+    #[default]
+    Original,
+    Png,
+    /// Lossy and smallest; images with transparency (logos) are kept as PNG
+    Jpeg,
+    /// Lossless WebP, keeps transparency
+    Webp,
+}
+
+impl CoverFormat {
+    fn extension(self, source_extension: &str, has_alpha: bool) -> &str {
+        match self {
+            CoverFormat::Original => source_extension,
+            CoverFormat::Png => "png",
+            CoverFormat::Jpeg if has_alpha => "png",
+            CoverFormat::Jpeg => "jpg",
+            CoverFormat::Webp => "webp",
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct ImageCache {
     pub cache_dir: PathBuf,
+    pub format: CoverFormat,
+    /// JPEG quality (1-100); the other formats are lossless
+    pub quality: u8,
 }
 
 impl ImageCache {
@@ -14,7 +105,11 @@ impl ImageCache {
             .context("Failed to determine project directories")?;
         let cache_dir = dirs.cache_dir().join("grids");
         fs::create_dir_all(&cache_dir).context("Failed to create cache directory")?;
-        Ok(Self { cache_dir })
+        Ok(Self {
+            cache_dir,
+            format: CoverFormat::default(),
+            quality: DEFAULT_COVER_QUALITY,
+        })
     }
 
     pub fn get_image_path(&self, game_name: &str, extension: &str) -> PathBuf {
@@ -34,6 +129,37 @@ impl ImageCache {
             .collect()
     }
 
+    /// Where the placeholder cover of `game_name` is stored. The name's hash keeps
+    /// titles that sanitize to the same file name apart.
+    pub fn placeholder_path(&self, game_name: &str) -> PathBuf {
+        self.cache_dir.join("placeholders").join(format!(
+            "{}-{:016x}.png",
+            self.sanitize_name(game_name),
+            name_hash(game_name)
+        ))
+    }
+
+    /// Whether `path` is a generated placeholder rather than real artwork
+    pub fn is_placeholder(&self, path: &Path) -> bool {
+        path.starts_with(self.cache_dir.join("placeholders"))
+    }
+
+    /// A cover for games without artwork: their initials on a color derived from the
+    /// name. Generated once and kept on disk next to the downloaded covers.
+    pub fn generate_placeholder_cover(&self, game_name: &str) -> Result<PathBuf> {
+        let path = self.placeholder_path(game_name);
+        if path.exists() {
+            return Ok(path);
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).context("Failed to create placeholder directory")?;
+        }
+        render_placeholder(game_name)
+            .save_with_format(&path, ImageFormat::Png)
+            .context("Failed to save placeholder cover")?;
+        Ok(path)
+    }
+
     pub fn find_existing_image(&self, game_name: &str) -> Option<PathBuf> {
         let safe_name = self.sanitize_name(game_name);
         let extensions = ["png", "jpg", "jpeg", "webp"];
@@ -53,10 +179,7 @@ impl ImageCache {
         width: u32,
         height: u32,
     ) -> Result<PathBuf> {
-        let extension = url.split('.').next_back().unwrap_or("png");
-
-        let path = self.get_image_path(game_name, extension);
-        if path.exists() {
+        if let Some(path) = self.find_existing_image(game_name) {
             return Ok(path);
         }
 
@@ -67,13 +190,493 @@ impl ImageCache {
             .context("Failed to read response body")?;
 
         let img = image::load_from_memory(&bytes).context("Failed to load image from memory")?;
-        // Resize to requested dimensions, maintaining aspect ratio.
+        let source_extension = url.split('.').next_back().unwrap_or("png");
+
+        self.store_resized(game_name, &img, source_extension, width, height)
+    }
+
+    /// Downloads a backdrop and stores it small and blurred: drawn stretched across
+    /// the screen it stays soft, and decoding it is cheap. Always a JPEG.
+    pub fn save_blurred_image(
+        &self,
+        name: &str,
+        url: &str,
+        width: u32,
+        sigma: f32,
+    ) -> Result<PathBuf> {
+        let path = self.get_image_path(name, "jpg");
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(10)))
+            .build()
+            .new_agent();
+        let mut resp = agent.get(url).call().context("Failed to download image")?;
+        let bytes = resp
+            .body_mut()
+            .read_to_vec()
+            .context("Failed to read response body")?;
+        let img = image::load_from_memory(&bytes).context("Failed to load image from memory")?;
+
+        let height = (width as u64 * img.height() as u64 / img.width().max(1) as u64) as u32;
+        let blurred = img
+            .resize_exact(width, height.max(1), image::imageops::FilterType::Triangle)
+            .blur(sigma);
+        let file = BufWriter::new(File::create(&path)?);
+        let encoder = JpegEncoder::new_with_quality(file, self.quality.clamp(1, 100));
+        DynamicImage::ImageRgb8(blurred.to_rgb8())
+            .write_with_encoder(encoder)
+            .context("Failed to save blurred image")?;
+
+        Ok(path)
+    }
+
+    /// Resizes to the requested dimensions (maintaining aspect ratio) and writes the
+    /// image in the configured format.
+    fn store_resized(
+        &self,
+        game_name: &str,
+        img: &DynamicImage,
+        source_extension: &str,
+        width: u32,
+        height: u32,
+    ) -> Result<PathBuf> {
         let resized = img.resize(width, height, image::imageops::FilterType::Triangle);
+        let has_alpha = resized.color().has_alpha();
 
-        resized
-            .save(&path)
-            .context("Failed to save resized image")?;
+        let path = self.get_image_path(
+            game_name,
+            self.format.extension(source_extension, has_alpha),
+        );
+
+        match self.format {
+            CoverFormat::Original | CoverFormat::Png => resized.save(&path),
+            CoverFormat::Jpeg if has_alpha => resized.save_with_format(&path, ImageFormat::Png),
+            CoverFormat::Jpeg => {
+                let file = BufWriter::new(File::create(&path)?);
+                let encoder = JpegEncoder::new_with_quality(file, self.quality.clamp(1, 100));
+                DynamicImage::ImageRgb8(resized.to_rgb8()).write_with_encoder(encoder)
+            }
+            // The WebP encoder only accepts 8-bit RGB(A)
+            CoverFormat::Webp if has_alpha => DynamicImage::ImageRgba8(resized.to_rgba8())
+                .save_with_format(&path, ImageFormat::WebP),
+            CoverFormat::Webp => DynamicImage::ImageRgb8(resized.to_rgb8())
+                .save_with_format(&path, ImageFormat::WebP),
+        }
+        .context("Failed to save resized image")?;
 
         Ok(path)
     }
 }
+
+/// FNV-1a of the lowercased name; unlike `DefaultHasher` it is stable across builds,
+/// so a game keeps its placeholder color.
+fn name_hash(name: &str) -> u64 {
+    name.to_lowercase()
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+/// Up to two initials: the first letter or digit of each of the first words
+pub fn placeholder_initials(name: &str) -> String {
+    let initials: String = name
+        .split(|c: char| c.is_whitespace() || matches!(c, '-' | ':' | '_' | '.'))
+        .filter_map(|word| word.chars().next())
+        .filter(|c| c.is_ascii_alphanumeric())
+        .take(2)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if initials.is_empty() {
+        "?".to_string()
+    } else {
+        initials
+    }
+}
+
+/// A muted background color with the hue picked by the name's hash
+pub fn placeholder_color(name: &str) -> [u8; 3] {
+    let hue = (name_hash(name) % 360) as f32;
+    hsl_to_rgb(hue, 0.45, 0.35)
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [u8; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let byte = |channel: f32| ((channel + m) * 255.0).round() as u8;
+    [byte(r), byte(g), byte(b)]
+}
+
+fn render_placeholder(name: &str) -> DynamicImage {
+    let mut img = RgbImage::from_pixel(
+        PLACEHOLDER_WIDTH,
+        PLACEHOLDER_HEIGHT,
+        Rgb(placeholder_color(name)),
+    );
+
+    let glyphs: Vec<&[u8; 7]> = placeholder_initials(name)
+        .chars()
+        .filter_map(|c| {
+            PLACEHOLDER_GLYPHS
+                .iter()
+                .find(|(glyph, _)| *glyph == c)
+                .map(|(_, rows)| rows)
+        })
+        .collect();
+
+    // Glyphs are 5 pixels wide with one pixel of spacing between them
+    let scale = PLACEHOLDER_GLYPH_SCALE;
+    let text_width = (glyphs.len() as u32 * 6).saturating_sub(1) * scale;
+    let left = PLACEHOLDER_WIDTH.saturating_sub(text_width) / 2;
+    let top = (PLACEHOLDER_HEIGHT - 7 * scale) / 2;
+    for (index, rows) in glyphs.iter().enumerate() {
+        let glyph_left = left + index as u32 * 6 * scale;
+        for (row, bits) in rows.iter().enumerate() {
+            for column in 0..5 {
+                if bits & (0x10 >> column) == 0 {
+                    continue;
+                }
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        img.put_pixel(
+                            glyph_left + column * scale + dx,
+                            top + row as u32 * scale + dy,
+                            Rgb([235, 235, 235]),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    DynamicImage::ImageRgb8(img)
+}
+
+/// Map that keeps at most `capacity` entries, dropping the least recently used.
+pub struct Lru<K, V> {
+    capacity: usize,
+    /// Values with the tick they were last used at
+    entries: HashMap<K, (V, u64)>,
+    /// Keys by the tick they were last used at, oldest first
+    order: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K: Clone + Eq + Hash, V> Lru<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    /// Looks up an entry and marks it as the most recently used
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let (value, used) = self.entries.get_mut(key)?;
+        self.tick += 1;
+        if let Some(key) = self.order.remove(&*used) {
+            self.order.insert(self.tick, key);
+        }
+        *used = self.tick;
+        Some(value)
+    }
+
+    /// Looks up an entry without counting it as a use
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.tick += 1;
+        if let Some((_, used)) = self.entries.insert(key.clone(), (value, self.tick)) {
+            self.order.remove(&used);
+        }
+        self.order.insert(self.tick, key);
+        self.evict();
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if let Some((_, used)) = self.entries.remove(key) {
+            self.order.remove(&used);
+        }
+    }
+
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+/// Cover images kept in memory by path, so tiles scrolled back into view aren't read
+/// from disk again. The files are read in the background by [`read_cover_handles`];
+/// rendering only looks the handles up. As the same handle is reused, iced also
+/// keeps its decoded image while the tile is shown.
+///
+/// `view` can't record uses, so the launcher reports the covers around the selection
+/// through [`CoverHandleCache::request`] whenever it moves; those stay in memory and
+/// the ones furthest away are dropped first.
+pub struct CoverHandleCache {
+    handles: Lru<PathBuf, iced::widget::image::Handle>,
+}
+
+impl CoverHandleCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            handles: Lru::new(capacity),
+        }
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.handles.set_capacity(capacity);
+    }
+
+    /// The in-memory handle of a cover. `None` while it wasn't read, or when caching
+    /// is disabled; the image is then loaded from the path.
+    pub fn get(&self, path: &Path) -> Option<iced::widget::image::Handle> {
+        self.handles.peek(path).cloned()
+    }
+
+    /// Marks the covers of `paths`, nearest to the selection first, as in use and
+    /// returns those not in memory yet. Only as many as fit are taken, so covers
+    /// further away neither get read nor push nearer ones out.
+    pub fn request(&mut self, paths: impl IntoIterator<Item = PathBuf>) -> Vec<PathBuf> {
+        let mut wanted: Vec<PathBuf> = Vec::new();
+        for path in paths {
+            if wanted.len() >= self.handles.capacity() {
+                break;
+            }
+            if !wanted.contains(&path) {
+                wanted.push(path);
+            }
+        }
+        // Farthest first, so the nearest covers end up the most recently used
+        for path in wanted.iter().rev() {
+            self.handles.get(path);
+        }
+        wanted.retain(|path| self.handles.peek(path).is_none());
+        wanted
+    }
+
+    pub fn insert(&mut self, path: PathBuf, handle: iced::widget::image::Handle) {
+        self.handles.insert(path, handle);
+    }
+
+    /// Drops a cover whose file was replaced
+    pub fn invalidate(&mut self, path: &Path) {
+        self.handles.remove(path);
+    }
+}
+
+/// Reads cover files into memory, skipping unreadable ones. Blocking, so it runs
+/// in a background task.
+pub fn read_cover_handles(paths: Vec<PathBuf>) -> Vec<(PathBuf, iced::widget::image::Handle)> {
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let bytes = fs::read(&path).ok()?;
+            Some((path, iced::widget::image::Handle::from_bytes(bytes)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    fn temp_cache(format: CoverFormat) -> ImageCache {
+        let cache_dir = std::env::temp_dir().join(format!(
+            "launcher_test_image_cache_{}",
+            uuid::Uuid::new_v4()
+        ));
+        fs::create_dir_all(&cache_dir).unwrap();
+        ImageCache {
+            cache_dir,
+            format,
+            quality: DEFAULT_COVER_QUALITY,
+        }
+    }
+
+    fn cover() -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(300, 450, Rgb([200, 40, 40])))
+    }
+
+    fn logo() -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(300, 450, Rgba([200, 40, 40, 0])))
+    }
+
+    fn assert_stored(format: CoverFormat, img: DynamicImage, expected_extension: &str) {
+        let cache = temp_cache(format);
+
+        let path = cache
+            .store_resized("Hollow Knight", &img, "png", 100, 150)
+            .unwrap();
+        assert_eq!(
+            path.extension().and_then(|e| e.to_str()),
+            Some(expected_extension)
+        );
+
+        let decoded = image::open(&path).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (100, 150));
+
+        let _ = fs::remove_dir_all(cache.cache_dir);
+    }
+
+    #[test]
+    fn test_store_resized_uses_configured_format() {
+        assert_stored(CoverFormat::Original, cover(), "png");
+        assert_stored(CoverFormat::Png, cover(), "png");
+        assert_stored(CoverFormat::Jpeg, cover(), "jpg");
+        assert_stored(CoverFormat::Webp, cover(), "webp");
+    }
+
+    #[test]
+    fn test_transparent_images_keep_alpha() {
+        assert_stored(CoverFormat::Jpeg, logo(), "png");
+        assert_stored(CoverFormat::Webp, logo(), "webp");
+
+        let cache = temp_cache(CoverFormat::Webp);
+        let path = cache
+            .store_resized("Logo", &logo(), "png", 100, 150)
+            .unwrap();
+        assert!(image::open(&path).unwrap().color().has_alpha());
+
+        let _ = fs::remove_dir_all(cache.cache_dir);
+    }
+
+    #[test]
+    fn test_lru_evicts_least_recently_used() {
+        let mut lru = Lru::new(2);
+        lru.insert("a", 1);
+        lru.insert("b", 2);
+        // Storing "a" again makes "b" the oldest entry
+        lru.insert("a", 10);
+        lru.insert("c", 3);
+
+        assert_eq!(lru.peek("b"), None);
+        assert_eq!(lru.peek("a"), Some(&10));
+        assert_eq!(lru.peek("c"), Some(&3));
+
+        // Shrinking drops the oldest first; "a" was stored before "c"
+        lru.set_capacity(1);
+        assert_eq!(lru.peek("a"), None);
+        assert_eq!(lru.peek("c"), Some(&3));
+
+        lru.remove("c");
+        assert_eq!(lru.peek("c"), None);
+    }
+
+    #[test]
+    fn test_lru_read_entry_survives_eviction() {
+        let mut lru = Lru::new(2);
+        lru.insert("a", 1);
+        lru.insert("b", 2);
+        // Only read, never stored again, yet "b" is now the least recently used
+        assert_eq!(lru.get("a"), Some(&1));
+        lru.insert("c", 3);
+
+        assert_eq!(lru.peek("a"), Some(&1));
+        assert_eq!(lru.peek("b"), None);
+        assert_eq!(lru.peek("c"), Some(&3));
+        assert_eq!(lru.get("b"), None);
+    }
+
+    #[test]
+    fn test_cover_handles_keep_covers_near_the_selection() {
+        let cover = |name: &str| PathBuf::from(format!("/covers/{}.png", name));
+        let handle = |name: &str| iced::widget::image::Handle::from_path(cover(name));
+        let mut covers = CoverHandleCache::new(3);
+        for name in ["Celeste", "Doom", "Hades"] {
+            covers.insert(cover(name), handle(name));
+        }
+
+        // Selection on Hades: only the three nearest covers are taken, duplicates once
+        let nearest = ["Hades", "Hades", "Celeste", "Portal", "Doom", "Tunic"].map(cover);
+        assert_eq!(covers.request(nearest), vec![cover("Portal")]);
+
+        // Doom, stored before the requested covers, is dropped for the new one
+        covers.insert(cover("Portal"), handle("Portal"));
+        assert!(covers.get(&cover("Doom")).is_none());
+        assert!(covers.get(&cover("Celeste")).is_some());
+        assert!(covers.get(&cover("Hades")).is_some());
+
+        // Nothing is read while caching is disabled
+        covers.set_capacity(0);
+        assert!(covers.get(&cover("Hades")).is_none());
+        assert!(covers.request([cover("Hades")]).is_empty());
+    }
+
+    #[test]
+    fn test_placeholder_initials_and_color() {
+        assert_eq!(placeholder_initials("Half-Life 2"), "HL");
+        assert_eq!(placeholder_initials("celeste"), "C");
+        assert_eq!(placeholder_initials("The Legend of Zelda"), "TL");
+        assert_eq!(placeholder_initials("  "), "?");
+
+        // Same name, same color, regardless of case
+        assert_eq!(placeholder_color("Celeste"), placeholder_color("celeste"));
+        assert_eq!(placeholder_color("Celeste"), placeholder_color("Celeste"));
+        assert_ne!(placeholder_color("Celeste"), placeholder_color("Hades"));
+        assert_eq!(hsl_to_rgb(0.0, 1.0, 0.5), [255, 0, 0]);
+        assert_eq!(hsl_to_rgb(240.0, 1.0, 0.5), [0, 0, 255]);
+    }
+
+    #[test]
+    fn test_placeholder_cover_cached_on_disk() {
+        let cache = temp_cache(CoverFormat::Original);
+
+        let path = cache.generate_placeholder_cover("Celeste").unwrap();
+        assert_eq!(path, cache.placeholder_path("Celeste"));
+        assert!(cache.is_placeholder(&path));
+        assert!(!cache.is_placeholder(&cache.get_image_path("Celeste", "png")));
+        let img = image::open(&path).unwrap().to_rgb8();
+        assert_eq!(img.dimensions(), (PLACEHOLDER_WIDTH, PLACEHOLDER_HEIGHT));
+        assert_eq!(img.get_pixel(0, 0).0, placeholder_color("Celeste"));
+
+        // Kept instead of being drawn again
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+        cache.generate_placeholder_cover("Celeste").unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+
+        let _ = fs::remove_dir_all(cache.cache_dir);
+    }
+}
@@ -0,0 +1,150 @@
+//! Cover art downloads, started a few at a time so the selected game and its
+//! neighbors are fetched before the rest of the row.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use uuid::Uuid;
+
+/// Covers downloaded at the same time
+pub const MAX_CONCURRENT_FETCHES: usize = 4;
+
+#[derive(Default)]
+pub struct ImageFetchQueue {
+    pending: VecDeque<Uuid>,
+    in_flight: HashSet<Uuid>,
+}
+
+impl ImageFetchQueue {
+    /// Queues the given games in order, replacing what was still pending (e.g.
+    /// after a rescan). Games that are already downloading are not queued again.
+    pub fn replace(&mut self, ids: impl IntoIterator<Item = Uuid>) {
+        self.pending = ids
+            .into_iter()
+            .filter(|id| !self.in_flight.contains(id))
+            .collect();
+    }
+
+    /// Reorders the pending games by their distance to `selected` in `row`;
+    /// games no longer in the row go last.
+    pub fn prioritize(&mut self, row: &[Uuid], selected: usize) {
+        let rank: HashMap<Uuid, usize> = priority_order(row.len(), selected)
+            .into_iter()
+            .enumerate()
+            .map(|(rank, index)| (row[index], rank))
+            .collect();
+        self.pending
+            .make_contiguous()
+            .sort_by_key(|id| rank.get(id).copied().unwrap_or(usize::MAX));
+    }
+
+    /// Takes as many pending games as may start now and marks them in flight.
+    pub fn start_next(&mut self) -> Vec<Uuid> {
+        let free = MAX_CONCURRENT_FETCHES.saturating_sub(self.in_flight.len());
+        let started: Vec<Uuid> = self.pending.drain(..free.min(self.pending.len())).collect();
+        self.in_flight.extend(&started);
+        started
+    }
+
+    /// Drops pending games `keep` rejects, e.g. ones hidden since they were queued.
+    pub fn retain_pending(&mut self, keep: impl Fn(Uuid) -> bool) {
+        self.pending.retain(|id| keep(*id));
+    }
+
+    /// Whether the game's cover is waiting for or in the middle of a download.
+    pub fn is_queued(&self, id: Uuid) -> bool {
+        self.in_flight.contains(&id) || self.pending.contains(&id)
+    }
+
+    /// Marks a download as done, successful or not.
+    pub fn finish(&mut self, id: Uuid) {
+        self.in_flight.remove(&id);
+    }
+}
+
+/// Row indices ordered by priority: the selection first, then alternating to
+/// the right and left of it, preferring the right since that's where scrolling
+/// usually goes.
+pub fn priority_order(len: usize, selected: usize) -> Vec<usize> {
+    if len == 0 {
+        return Vec::new();
+    }
+    let selected = selected.min(len - 1);
+    let mut order = Vec::with_capacity(len);
+    order.push(selected);
+    for distance in 1..len {
+        if let Some(right) = selected.checked_add(distance).filter(|i| *i < len) {
+            order.push(right);
+        }
+        if let Some(left) = selected.checked_sub(distance) {
+            order.push(left);
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_order_from_selection() {
+        assert_eq!(priority_order(5, 2), vec![2, 3, 1, 4, 0]);
+        assert_eq!(priority_order(4, 0), vec![0, 1, 2, 3]);
+        assert_eq!(priority_order(4, 3), vec![3, 2, 1, 0]);
+        // Out of range selections clamp to the last tile
+        assert_eq!(priority_order(3, 10), vec![2, 1, 0]);
+        assert!(priority_order(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_selected_game_starts_first() {
+        let row: Vec<Uuid> = (0..8).map(|_| Uuid::new_v4()).collect();
+        let mut queue = ImageFetchQueue::default();
+        queue.replace(row.iter().copied());
+        queue.prioritize(&row, 5);
+
+        let started = queue.start_next();
+        assert_eq!(started, vec![row[5], row[6], row[4], row[7]]);
+        // No free slot until one finishes
+        assert!(queue.start_next().is_empty());
+
+        // Scrolling to the start reorders what is still pending
+        queue.prioritize(&row, 0);
+        queue.finish(row[5]);
+        assert_eq!(queue.start_next(), vec![row[0]]);
+    }
+
+    #[test]
+    fn test_retain_pending_keeps_in_flight_games() {
+        let row: Vec<Uuid> = (0..6).map(|_| Uuid::new_v4()).collect();
+        let mut queue = ImageFetchQueue::default();
+        queue.replace(row.iter().copied());
+        let started = queue.start_next();
+
+        // Hiding the first and last game drops only the pending one
+        queue.retain_pending(|id| id != row[0] && id != row[5]);
+        assert!(queue.is_queued(row[0]));
+        assert!(!queue.is_queued(row[5]));
+
+        for id in started {
+            queue.finish(id);
+        }
+        assert_eq!(queue.start_next(), vec![row[4]]);
+    }
+
+    #[test]
+    fn test_replace_skips_in_flight_games() {
+        let row: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+        let mut queue = ImageFetchQueue::default();
+        queue.replace(row.iter().copied());
+        let started = queue.start_next();
+        assert_eq!(started.len(), 2);
+
+        queue.replace(row.iter().copied());
+        assert!(queue.start_next().is_empty());
+
+        queue.finish(row[0]);
+        queue.replace(row.iter().copied());
+        assert_eq!(queue.start_next(), vec![row[0]]);
+    }
+}
@@ -1,4 +1,9 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+use crate::model::Category;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Action {
     Up,
     Down,
@@ -12,4 +17,18 @@ pub enum Action {
     AddApp,
     Quit,
     ShowHelp,
+    /// Save a screenshot of the launcher window
+    Screenshot,
+    /// Switch the window between fullscreen and windowed
+    ToggleFullscreen,
+    /// Launch the most recently started game or app from anywhere
+    RelaunchLast,
+    /// Switch the current row between recently played and alphabetical order
+    CycleSort,
+    /// Open the directory of `config.json` in the file manager
+    OpenConfigDir,
+    /// Go straight to a category row instead of cycling; bound to the number keys
+    /// and not remappable
+    #[serde(skip)]
+    JumpCategory(Category),
 }
@@ -0,0 +1,164 @@
+//! Keyboard shortcuts, remappable per action in `config.json`.
+
+use std::collections::HashMap;
+
+use iced::keyboard::Key;
+use serde::{Deserialize, Serialize};
+
+use crate::input::Action;
+use crate::model::Category;
+
+/// Built-in shortcuts. Named keys use iced's key names, characters the typed text.
+const DEFAULT_BINDINGS: &[(&str, Action)] = &[
+    ("ArrowUp", Action::Up),
+    ("ArrowDown", Action::Down),
+    ("ArrowLeft", Action::Left),
+    ("ArrowRight", Action::Right),
+    ("Enter", Action::Select),
+    ("Escape", Action::Back),
+    ("Tab", Action::NextCategory),
+    ("F4", Action::Quit),
+    ("c", Action::ContextMenu),
+    ("+", Action::AddApp),
+    ("a", Action::AddApp),
+    ("-", Action::ShowHelp),
+    ("PrintScreen", Action::Screenshot),
+    ("F11", Action::ToggleFullscreen),
+    ("r", Action::RelaunchLast),
+    ("s", Action::CycleSort),
+    ("F9", Action::OpenConfigDir),
+    ("1", Action::JumpCategory(Category::Games)),
+    ("2", Action::JumpCategory(Category::Apps)),
+    ("3", Action::JumpCategory(Category::System)),
+];
+
+/// User overrides keyed by action, e.g. `{ "up": ["ArrowUp", "w"] }`.
+/// An overridden action only responds to the listed keys; all other actions keep
+/// their defaults.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeyboardBindings {
+    overrides: HashMap<Action, Vec<String>>,
+}
+
+impl KeyboardBindings {
+    pub fn action_for(&self, key: &Key) -> Option<Action> {
+        self.action_for_name(&key_name(key)?)
+    }
+
+    fn action_for_name(&self, name: &str) -> Option<Action> {
+        let custom = self
+            .overrides
+            .iter()
+            .find(|(_, keys)| keys.iter().any(|key| key == name))
+            .map(|(action, _)| *action);
+
+        custom.or_else(|| {
+            DEFAULT_BINDINGS
+                .iter()
+                .find(|(key, action)| *key == name && !self.overrides.contains_key(action))
+                .map(|(_, action)| *action)
+        })
+    }
+
+    /// Keys currently bound to the action, overrides replacing the defaults
+    pub fn keys_for(&self, action: Action) -> Vec<String> {
+        match self.overrides.get(&action) {
+            Some(keys) => keys.clone(),
+            None => DEFAULT_BINDINGS
+                .iter()
+                .filter(|(_, bound)| *bound == action)
+                .map(|(key, _)| key.to_string())
+                .collect(),
+        }
+    }
+
+    /// Keys of the everyday actions with a label each, for on-screen reference
+    pub fn reference(&self) -> Vec<(String, &'static str)> {
+        [
+            (
+                &[Action::Up, Action::Down, Action::Left, Action::Right][..],
+                "Navigate",
+            ),
+            (&[Action::Select], "Select"),
+            (&[Action::Back], "Back"),
+            (&[Action::NextCategory], "Next Category"),
+            (&[Action::ContextMenu], "Context Menu"),
+            (&[Action::ShowHelp], "Show Controls"),
+        ]
+        .into_iter()
+        .map(|(actions, label)| {
+            let keys: Vec<String> = actions
+                .iter()
+                .flat_map(|action| self.keys_for(*action))
+                .collect();
+            (keys.join(" / "), label)
+        })
+        .collect()
+    }
+}
+
+fn key_name(key: &Key) -> Option<String> {
+    match key.as_ref() {
+        Key::Named(named) => Some(format!("{:?}", named)),
+        Key::Character(text) => Some(text.to_string()),
+        Key::Unidentified => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::keyboard::key::Named;
+
+    fn character(text: &str) -> Key {
+        Key::Character(text.into())
+    }
+
+    #[test]
+    fn test_default_bindings() {
+        let bindings = KeyboardBindings::default();
+        assert_eq!(
+            bindings.action_for(&Key::Named(Named::ArrowUp)),
+            Some(Action::Up)
+        );
+        assert_eq!(
+            bindings.action_for(&Key::Named(Named::Enter)),
+            Some(Action::Select)
+        );
+        assert_eq!(bindings.action_for(&character("a")), Some(Action::AddApp));
+        assert_eq!(
+            bindings.action_for(&character("2")),
+            Some(Action::JumpCategory(Category::Apps))
+        );
+        assert_eq!(bindings.action_for(&character("w")), None);
+        assert_eq!(bindings.action_for(&Key::Unidentified), None);
+    }
+
+    #[test]
+    fn test_remapped_key() {
+        let bindings: KeyboardBindings = serde_json::from_str(
+            r#"{ "up": ["w"], "left": ["a", "ArrowLeft"], "select": ["Space"] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(bindings.action_for(&character("w")), Some(Action::Up));
+        // Remapped keys win over their default action
+        assert_eq!(bindings.action_for(&character("a")), Some(Action::Left));
+        assert_eq!(
+            bindings.action_for(&Key::Named(Named::Space)),
+            Some(Action::Select)
+        );
+        // An overridden action drops its default keys
+        assert_eq!(bindings.action_for(&Key::Named(Named::ArrowUp)), None);
+        // Untouched actions keep their defaults
+        assert_eq!(
+            bindings.action_for(&Key::Named(Named::ArrowDown)),
+            Some(Action::Down)
+        );
+        assert_eq!(bindings.action_for(&character("+")), Some(Action::AddApp));
+
+        assert_eq!(bindings.keys_for(Action::Up), vec!["w"]);
+        assert_eq!(bindings.keys_for(Action::AddApp), vec!["+", "a"]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Catching games that crash right after launch (e.g. a broken Proton prefix) and keeping
+//! the end of their stderr so the error can be shown instead of silently returning.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::process::Child;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Exits within this time after launch count as a failed launch
+pub const QUICK_FAILURE_WINDOW: Duration = Duration::from_secs(10);
+/// Lines of stderr kept for the error modal
+const STDERR_TAIL_LINES: usize = 20;
+/// Time for the reader thread to catch the last lines after the process exited
+const STDERR_DRAIN_GRACE: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchFailure {
+    /// `None` when the process was killed by a signal
+    pub exit_code: Option<i32>,
+    pub stderr_tail: String,
+}
+
+impl LaunchFailure {
+    pub fn summary(&self) -> String {
+        match self.exit_code {
+            Some(code) => format!("Exited with code {} right after launch", code),
+            None => "Was terminated right after launch".to_string(),
+        }
+    }
+}
+
+/// The last lines written to stderr
+#[derive(Debug)]
+pub struct StderrTail {
+    lines: VecDeque<String>,
+    max_lines: usize,
+}
+
+impl StderrTail {
+    pub fn new(max_lines: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(max_lines),
+            max_lines,
+        }
+    }
+
+    /// Adds a raw line; invalid UTF-8 is replaced and blank lines are skipped
+    pub fn push(&mut self, line: &[u8]) {
+        let line = String::from_utf8_lossy(line).trim_end().to_string();
+        if line.is_empty() {
+            return;
+        }
+        if self.lines.len() == self.max_lines {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.iter().cloned().collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// A launch failed when the command exited unsuccessfully (or by a signal) before
+/// `QUICK_FAILURE_WINDOW` passed. Later exits are regular quits or crashes mid-game.
+pub fn is_quick_failure(exit_code: Option<i32>, elapsed: Duration) -> bool {
+    exit_code != Some(0) && elapsed < QUICK_FAILURE_WINDOW
+}
+
+/// Waits for the launch command, draining its piped stderr meanwhile, and reports it
+/// when it failed right away. Blocks until the command exits.
+///
+/// Only the launch command itself is waited for: Steam and Heroic launches hand off
+/// to the client and return immediately, so only direct launches are diagnosed.
+pub fn watch_launch(mut child: Child) -> Option<LaunchFailure> {
+    let started = Instant::now();
+    let tail = Arc::new(Mutex::new(StderrTail::new(STDERR_TAIL_LINES)));
+
+    if let Some(stderr) = child.stderr.take() {
+        let tail = Arc::clone(&tail);
+        // Keeps draining after the command exits while child processes still hold the
+        // pipe, so they never block on a full buffer
+        thread::spawn(move || {
+            let mut reader = BufReader::new(stderr);
+            let mut line = Vec::new();
+            while reader
+                .read_until(b'\n', &mut line)
+                .is_ok_and(|read| read > 0)
+            {
+                if let Ok(mut tail) = tail.lock() {
+                    tail.push(&line);
+                }
+                line.clear();
+            }
+        });
+    }
+
+    let status = child.wait().ok()?;
+    if !is_quick_failure(status.code(), started.elapsed()) {
+        return None;
+    }
+
+    thread::sleep(STDERR_DRAIN_GRACE);
+    let stderr_tail = tail.lock().map(|tail| tail.text()).unwrap_or_default();
+    Some(LaunchFailure {
+        exit_code: status.code(),
+        stderr_tail,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_quick_failure() {
+        let soon = Duration::from_secs(2);
+        assert!(is_quick_failure(Some(1), soon));
+        // Killed by a signal, e.g. a segfault
+        assert!(is_quick_failure(None, soon));
+        assert!(!is_quick_failure(Some(0), soon));
+        // Quitting or crashing after playing a while isn't a launch failure
+        assert!(!is_quick_failure(Some(1), QUICK_FAILURE_WINDOW));
+    }
+
+    #[test]
+    fn test_stderr_tail_keeps_last_lines() {
+        let mut tail = StderrTail::new(2);
+        tail.push(b"wine: starting\n");
+        tail.push(b"\n");
+        tail.push(b"err:module:import_dll Library d3d11.dll not found\n");
+        tail.push(b"wine: Unhandled page fault \xff\r\n");
+        assert_eq!(
+            tail.text(),
+            "err:module:import_dll Library d3d11.dll not found\nwine: Unhandled page fault \u{fffd}"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! "Launch Options": a free-form, Steam-style options string per game, typed on
+//! the on-screen keyboard.
+
+use uuid::Uuid;
+
+use crate::virtual_keyboard::VirtualKeyboard;
+
+pub struct LaunchOptionsState {
+    pub item_id: Uuid,
+    pub item_name: String,
+    pub launch_key: String,
+    pub keyboard: VirtualKeyboard,
+}
+
+impl LaunchOptionsState {
+    /// Starts with the options the item already has.
+    pub fn new(
+        item_id: Uuid,
+        item_name: String,
+        launch_key: String,
+        current: Option<&str>,
+    ) -> Self {
+        Self {
+            item_id,
+            item_name,
+            launch_key,
+            keyboard: VirtualKeyboard::new(current.unwrap_or_default().to_string()),
+        }
+    }
+
+    /// The entered options; blank input clears them.
+    pub fn options(&self) -> Option<String> {
+        let value = self.keyboard.value().trim();
+        (!value.is_empty()).then(|| value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_options_clear() {
+        let state = LaunchOptionsState::new(
+            Uuid::nil(),
+            "Dota 2".into(),
+            "steam:570".into(),
+            Some(" -novid "),
+        );
+        assert_eq!(state.keyboard.value(), " -novid ");
+        assert_eq!(state.options().as_deref(), Some("-novid"));
+
+        let state = LaunchOptionsState::new(Uuid::nil(), "Dota 2".into(), "steam:570".into(), None);
+        assert_eq!(state.options(), None);
+    }
+}
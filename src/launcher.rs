@@ -1,6 +1,6 @@
 use std::env;
 use std::path::Path;
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, Stdio};
 
 use std::fs;
 use std::os::unix::fs::PermissionsExt;
@@ -9,6 +9,34 @@ use urlencoding::decode;
 
 use crate::focus_manager::MonitorTarget;
 
+/// Launch prefix that renders the game in a fullscreen 1920x1080 gamescope session,
+/// letterboxing it on ultrawide displays instead of stretching.
+pub const GAMESCOPE_16_9_PREFIX: &str = "gamescope -W 1920 -H 1080 -f --";
+
+/// Terminal emulators probed when no `terminal` is configured, in order of preference,
+/// with the arguments that make them run a command.
+const KNOWN_TERMINALS: &[(&str, &str)] = &[
+    ("kitty", "kitty --"),
+    ("alacritty", "alacritty -e"),
+    ("foot", "foot --"),
+    ("wezterm", "wezterm start --"),
+    ("konsole", "konsole -e"),
+    ("gnome-terminal", "gnome-terminal --wait --"),
+    ("xfce4-terminal", "xfce4-terminal -x"),
+    ("xterm", "xterm -e"),
+];
+
+/// Passed as `$0` of the shell a terminal app runs in, so the terminal window the
+/// launcher opened can be told apart from other windows of the same terminal emulator
+const TERMINAL_MARKER: &str = "rhinco-tv-terminal";
+
+/// Steam-style placeholder in launch options marking where the original command goes
+pub const COMMAND_PLACEHOLDER: &str = "%command%";
+
+/// Steam launches that accept extra game arguments after the app ID
+pub(crate) const STEAM_APPLAUNCH_PREFIX: &str = "steam -applaunch ";
+const HEROIC_LAUNCH_SCHEME: &str = "heroic://launch/";
+
 /// Desktop entry field codes that should be stripped from exec commands
 /// See: https://specifications.freedesktop.org/desktop-entry-spec/latest/exec-variables.html
 const DESKTOP_FIELD_CODES: &[&str] = &[
@@ -29,6 +57,16 @@ pub enum LaunchError {
 }
 
 pub fn launch_app(exec: &str) -> Result<u32, LaunchError> {
+    spawn_app(exec, Stdio::null()).map(|child| child.id())
+}
+
+/// Like [`launch_app`], but hands out the child with its stderr piped for
+/// [`crate::launch_diagnostics::watch_launch`].
+pub fn launch_app_capturing_stderr(exec: &str) -> Result<Child, LaunchError> {
+    spawn_app(exec, Stdio::piped())
+}
+
+fn spawn_app(exec: &str, stderr: Stdio) -> Result<Child, LaunchError> {
     if exec.trim().is_empty() {
         return Err(LaunchError::EmptyCommand);
     }
@@ -44,13 +82,10 @@ pub fn launch_app(exec: &str) -> Result<u32, LaunchError> {
         .arg(exec)
         .stdin(Stdio::null())
         .stdout(Stdio::null())
-        .stderr(Stdio::null())
+        .stderr(stderr)
         .spawn()
     {
-        Ok(child) => {
-            let pid = child.id();
-            Ok(pid)
-        }
+        Ok(child) => Ok(child),
         Err(e) => Err(LaunchError::LaunchFailed {
             command: exec.to_string(),
             source: e,
@@ -58,65 +93,257 @@ pub fn launch_app(exec: &str) -> Result<u32, LaunchError> {
     }
 }
 
+/// Prepends an optional wrapper command (such as gamescope) to the exec line.
+pub fn apply_launch_prefix(exec: &str, prefix: Option<&str>) -> String {
+    match prefix.map(str::trim).filter(|p| !p.is_empty()) {
+        Some(prefix) => format!("{} {}", prefix, exec),
+        None => exec.to_string(),
+    }
+}
+
+/// Combines the exec line with Steam-style launch options.
+///
+/// Options are shell syntax and inserted verbatim, so arguments containing spaces
+/// need their own quotes. Every `%command%` is replaced by the exec line as-is
+/// (e.g. `gamemoderun %command% --fullscreen`); without the placeholder the options
+/// are appended as extra arguments. Steam launches only hand off to the client, so
+/// only the arguments after the last `%command%` are passed on to the game.
+pub fn apply_launch_options(exec: &str, options: Option<&str>) -> String {
+    let Some(options) = options.map(str::trim).filter(|o| !o.is_empty()) else {
+        return exec.to_string();
+    };
+
+    if exec.starts_with(STEAM_APPLAUNCH_PREFIX) {
+        let args = options
+            .rsplit_once(COMMAND_PLACEHOLDER)
+            .map_or(options, |(_, args)| args)
+            .trim();
+        return if args.is_empty() {
+            exec.to_string()
+        } else {
+            format!("{} {}", exec, args)
+        };
+    }
+
+    if options.contains(COMMAND_PLACEHOLDER) {
+        options.replace(COMMAND_PLACEHOLDER, exec)
+    } else {
+        format!("{} {}", exec, options)
+    }
+}
+
+/// The command line a launch actually runs: the exec line wrapped in `terminal` (for
+/// apps that need one), combined with the launch options, behind the launch prefix.
+/// Options are left out for terminal apps since they would end up outside its `sh -c`.
+pub fn build_launch_command(
+    exec: &str,
+    terminal: Option<&str>,
+    options: Option<&str>,
+    prefix: Option<&str>,
+) -> String {
+    let exec = match terminal {
+        Some(terminal) => wrap_in_terminal(exec, terminal),
+        None => exec.to_string(),
+    };
+    let options = options.filter(|_| terminal.is_none());
+    apply_launch_prefix(&apply_launch_options(&exec, options), prefix)
+}
+
+/// Whether launch options can reach the game: direct launches and Steam, but not
+/// Heroic's URL handoff.
+pub fn supports_launch_options(exec: &str) -> bool {
+    supports_launch_prefix(exec) || exec.starts_with(STEAM_APPLAUNCH_PREFIX)
+}
+
+/// Returns the command prefix used to run something inside a terminal.
+///
+/// A configured terminal wins; a bare known terminal name gets its exec arguments appended,
+/// anything else is used verbatim. Without configuration the first installed known terminal
+/// is picked.
+pub fn resolve_terminal(configured: Option<&str>) -> Option<String> {
+    if let Some(terminal) = configured.map(str::trim).filter(|t| !t.is_empty()) {
+        let prefix = KNOWN_TERMINALS
+            .iter()
+            .find(|(name, _)| *name == terminal)
+            .map_or(terminal, |(_, prefix)| prefix);
+        return Some(prefix.to_string());
+    }
+
+    KNOWN_TERMINALS
+        .iter()
+        .find(|(name, _)| verify_command_exists(name))
+        .map(|(_, prefix)| prefix.to_string())
+}
+
+/// Wraps the exec line so it runs through `sh -c` inside the given terminal.
+pub fn wrap_in_terminal(exec: &str, terminal: &str) -> String {
+    format!(
+        "{} sh -c '{}' {}",
+        terminal,
+        exec.replace('\'', "'\\''"),
+        TERMINAL_MARKER
+    )
+}
+
+/// The pattern matching the command line of the terminal opened by `wrap_in_terminal`:
+/// the wrapped exec line followed by the marker, as the terminal's arguments show them.
+/// It stays visible while the app runs, even when the shell `exec`s into it.
+fn terminal_monitor_pattern(exec: &str) -> Option<String> {
+    let quoted = exec.strip_suffix(TERMINAL_MARKER)?.strip_suffix(' ')?;
+    let start = quoted.find(" sh -c '")? + " sh -c ".len();
+    let inner = quoted[start..].strip_prefix('\'')?.strip_suffix('\'')?;
+    Some(format!(
+        "{} {}",
+        inner.replace("'\\''", "'"),
+        TERMINAL_MARKER
+    ))
+}
+
+/// Whether the exec line starts the game process directly, so a wrapper command
+/// can be put in front of it. Steam and Heroic launches only hand off to their
+/// already running clients.
+pub fn supports_launch_prefix(exec: &str) -> bool {
+    !exec.starts_with("steam ") && !exec.starts_with("xdg-open ")
+}
+
 pub fn resolve_monitor_target(
     exec: &str,
     item_name: &str,
     game_executable: Option<&String>,
+    wm_class: Option<&str>,
 ) -> Option<MonitorTarget> {
-    // Check if it's a Steam game launch
-    let steam_launch_prefix = "steam -applaunch ";
-    let heroic_launch_prefix = "xdg-open heroic://launch/";
+    // The terminal's name alone would also match any other window of it
+    if let Some(pattern) = terminal_monitor_pattern(exec) {
+        return Some(MonitorTarget::CmdLineContains(pattern));
+    }
 
-    if exec.starts_with(steam_launch_prefix) {
-        let appid = exec
-            .trim_start_matches(steam_launch_prefix)
-            .trim()
-            .to_string();
+    // Check if it's a Steam game launch
+    if let Some(appid) = find_steam_appid(exec) {
         // We still launch the steam command, but we monitor the AppId
         return Some(MonitorTarget::SteamAppId(appid));
     }
 
-    if exec.starts_with(heroic_launch_prefix) {
-        let url_part = exec.trim_start_matches(heroic_launch_prefix).trim();
-        let parts: Vec<&str> = url_part.split('/').collect();
+    if let Some(url_part) = find_heroic_launch_url(exec) {
+        if let Some((store, app_name)) = parse_heroic_launch_url(url_part) {
+            return Some(MonitorTarget::Any(heroic_monitor_targets(
+                store.as_deref(),
+                &app_name,
+                item_name,
+                game_executable,
+            )));
+        }
+    }
+
+    // For regular applications, use command-line pattern matching
+    let target = create_app_monitor_target(exec, item_name);
+    Some(match wm_class {
+        // The window outlives launcher scripts and forked processes, so check it first
+        Some(class) => MonitorTarget::Any(vec![MonitorTarget::WmClass(class.to_string()), target]),
+        None => target,
+    })
+}
 
-        let mut app_name = None;
+/// Adds game-specific environment markers (Steam's `STEAM_COMPAT_APP_ID` for Proton)
+/// as further ways to recognize the running game. Only pass variables that identify
+/// this game; any single one of them counts as the game running.
+pub fn with_monitor_env(target: MonitorTarget, env: &[(String, String)]) -> MonitorTarget {
+    if env.is_empty() {
+        return target;
+    }
+    let mut targets = match target {
+        MonitorTarget::Any(targets) => targets,
+        target => vec![target],
+    };
+    targets.extend(
+        env.iter()
+            .map(|(key, value)| MonitorTarget::EnvVarEq(key.clone(), value.clone())),
+    );
+    MonitorTarget::Any(targets)
+}
 
-        if parts.len() >= 2 {
-            // store/app_name
-            if let Ok(decoded) = decode(parts[1]) {
-                app_name = Some(decoded.to_string());
-            }
-        } else if parts.len() == 1 {
-            // app_name
-            if let Ok(decoded) = decode(parts[0]) {
-                app_name = Some(decoded.to_string());
-            }
+/// Finds the app ID following `-applaunch` anywhere in the command, so wrappers in
+/// front (`gamescope -- steam -applaunch 570`) or arguments after it don't hide it.
+fn find_steam_appid(exec: &str) -> Option<String> {
+    let tokens: Vec<&str> = exec.split_whitespace().collect();
+    tokens.windows(2).find_map(|pair| match pair {
+        ["-applaunch", appid] if appid.chars().all(|c| c.is_ascii_digit()) => {
+            Some(appid.to_string())
         }
+        _ => None,
+    })
+}
 
-        if let Some(name) = app_name {
-            let mut targets = vec![
-                MonitorTarget::EnvVarEq("LEGENDARY_GAME_ID".to_string(), name.clone()),
-                MonitorTarget::EnvVarEq("HeroicAppName".to_string(), name.clone()),
-                MonitorTarget::CmdLineContains(item_name.to_string()),
-            ];
+/// Returns the part after `heroic://launch/` wherever the URL appears in the command,
+/// without any closing quote or following arguments.
+fn find_heroic_launch_url(exec: &str) -> Option<&str> {
+    let start = exec.find(HEROIC_LAUNCH_SCHEME)? + HEROIC_LAUNCH_SCHEME.len();
+    exec[start..]
+        .split(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+        .next()
+}
 
-            // Add exact executable match if available
-            if let Some(exe) = game_executable {
-                targets.push(MonitorTarget::CmdLineContains(exe.clone()));
-            }
+/// Splits the part after `heroic://launch/` into an optional store and the decoded app name.
+///
+/// Accepts both `store/app_name` and bare `app_name` forms.
+fn parse_heroic_launch_url(url_part: &str) -> Option<(Option<String>, String)> {
+    let parts: Vec<&str> = url_part.trim().split('/').collect();
+
+    let (store, encoded_name) = match parts.as_slice() {
+        [store, name, ..] => (Some(store.to_ascii_lowercase()), *name),
+        [name] => (None, *name),
+        [] => return None,
+    };
 
-            let sanitized_name = item_name.replace(":", "");
-            if sanitized_name != item_name {
-                targets.push(MonitorTarget::CmdLineContains(sanitized_name));
-            }
+    let app_name = decode(encoded_name).ok()?.to_string();
+    if app_name.is_empty() {
+        return None;
+    }
+
+    Some((store, app_name))
+}
 
-            return Some(MonitorTarget::Any(targets));
+/// Builds the process targets for a Heroic launch.
+///
+/// Every runner is started with `HeroicAppName`; Legendary (Epic) games additionally
+/// carry `LEGENDARY_GAME_ID`. Amazon games run under Nile's own wrapper, which passes
+/// the product id on its command line instead.
+fn heroic_monitor_targets(
+    store: Option<&str>,
+    app_name: &str,
+    item_name: &str,
+    game_executable: Option<&String>,
+) -> Vec<MonitorTarget> {
+    let mut targets = vec![MonitorTarget::EnvVarEq(
+        "HeroicAppName".to_string(),
+        app_name.to_string(),
+    )];
+
+    match store {
+        Some("nile") => {
+            targets.push(MonitorTarget::CmdLineContains(app_name.to_string()));
+        }
+        Some("gog") => {}
+        _ => {
+            targets.push(MonitorTarget::EnvVarEq(
+                "LEGENDARY_GAME_ID".to_string(),
+                app_name.to_string(),
+            ));
         }
     }
 
-    // For regular applications, use command-line pattern matching
-    Some(create_app_monitor_target(exec, item_name))
+    targets.push(MonitorTarget::CmdLineContains(item_name.to_string()));
+
+    // Add exact executable match if available
+    if let Some(exe) = game_executable {
+        targets.push(MonitorTarget::CmdLineContains(exe.clone()));
+    }
+
+    let sanitized_name = item_name.replace(":", "");
+    if sanitized_name != item_name {
+        targets.push(MonitorTarget::CmdLineContains(sanitized_name));
+    }
+
+    targets
 }
 
 /// Extracts tokens from a shell-like command line, respecting quotes.
@@ -222,7 +449,7 @@ fn should_skip_command_check(exec: &str) -> bool {
         .any(|ch| SHELL_META_CHARS.contains(&ch) || ch == '\n')
 }
 
-fn verify_command_exists(exec: &str) -> bool {
+pub fn verify_command_exists(exec: &str) -> bool {
     if should_skip_command_check(exec) {
         return true;
     }
@@ -456,4 +683,297 @@ mod tests {
 
         let _ = fs::remove_dir_all(temp_dir);
     }
+
+    #[test]
+    fn test_heroic_nile_monitor_targets() {
+        let target = resolve_monitor_target(
+            "xdg-open heroic://launch/nile/amzn1.adg.product.1234",
+            "Some Game",
+            None,
+            None,
+        );
+
+        assert_eq!(
+            target,
+            Some(MonitorTarget::Any(vec![
+                MonitorTarget::EnvVarEq(
+                    "HeroicAppName".to_string(),
+                    "amzn1.adg.product.1234".to_string()
+                ),
+                MonitorTarget::CmdLineContains("amzn1.adg.product.1234".to_string()),
+                MonitorTarget::CmdLineContains("Some Game".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_heroic_legendary_monitor_targets_keep_legendary_env() {
+        let exe = "Game.exe".to_string();
+        let target = resolve_monitor_target(
+            "xdg-open heroic://launch/legendary/Fortnite%20X",
+            "Game: Deluxe",
+            Some(&exe),
+            None,
+        );
+
+        assert_eq!(
+            target,
+            Some(MonitorTarget::Any(vec![
+                MonitorTarget::EnvVarEq("HeroicAppName".to_string(), "Fortnite X".to_string()),
+                MonitorTarget::EnvVarEq("LEGENDARY_GAME_ID".to_string(), "Fortnite X".to_string()),
+                MonitorTarget::CmdLineContains("Game: Deluxe".to_string()),
+                MonitorTarget::CmdLineContains("Game.exe".to_string()),
+                MonitorTarget::CmdLineContains("Game Deluxe".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_parse_heroic_launch_url() {
+        assert_eq!(
+            parse_heroic_launch_url("nile/amzn1.adg.product.1234"),
+            Some((
+                Some("nile".to_string()),
+                "amzn1.adg.product.1234".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_heroic_launch_url("My%20Game"),
+            Some((None, "My Game".to_string()))
+        );
+        assert_eq!(parse_heroic_launch_url(""), None);
+    }
+
+    #[test]
+    fn test_apply_gamescope_16_9_prefix() {
+        assert_eq!(
+            apply_launch_prefix("snes9x \"/roms/Game.sfc\"", Some(GAMESCOPE_16_9_PREFIX)),
+            "gamescope -W 1920 -H 1080 -f -- snes9x \"/roms/Game.sfc\""
+        );
+        assert_eq!(apply_launch_prefix("snes9x", None), "snes9x");
+        assert_eq!(apply_launch_prefix("snes9x", Some("  ")), "snes9x");
+    }
+
+    #[test]
+    fn test_supports_launch_prefix_only_for_direct_launches() {
+        assert!(supports_launch_prefix("mupen64plus /roms/game.z64"));
+        assert!(!supports_launch_prefix("steam -applaunch 570"));
+        assert!(!supports_launch_prefix(
+            "xdg-open heroic://launch/legendary/Fortnite"
+        ));
+    }
+
+    #[test]
+    fn test_launch_options_replace_command_placeholder() {
+        let exec = "snes9x \"/roms/Game.sfc\"";
+        assert_eq!(
+            apply_launch_options(exec, Some("SDL_VIDEODRIVER=x11 gamemoderun %command% -fs")),
+            "SDL_VIDEODRIVER=x11 gamemoderun snes9x \"/roms/Game.sfc\" -fs"
+        );
+        // Quotes in the options are kept for the shell
+        assert_eq!(
+            apply_launch_options("retroarch", Some("%command% --config \"/my cfg/a.cfg\"")),
+            "retroarch --config \"/my cfg/a.cfg\""
+        );
+    }
+
+    #[test]
+    fn test_launch_options_without_placeholder_are_appended() {
+        assert_eq!(
+            apply_launch_options("mupen64plus /roms/game.z64", Some(" --fullscreen ")),
+            "mupen64plus /roms/game.z64 --fullscreen"
+        );
+        assert_eq!(apply_launch_options("snes9x", Some("  ")), "snes9x");
+        assert_eq!(apply_launch_options("snes9x", None), "snes9x");
+    }
+
+    #[test]
+    fn test_launch_options_pass_game_arguments_to_steam() {
+        assert_eq!(
+            apply_launch_options("steam -applaunch 570", Some("-novid -high")),
+            "steam -applaunch 570 -novid -high"
+        );
+        // Wrappers in front of %command% can't reach a game started by the client
+        assert_eq!(
+            apply_launch_options("steam -applaunch 570", Some("gamemoderun %command% -novid")),
+            "steam -applaunch 570 -novid"
+        );
+        assert_eq!(
+            apply_launch_options("steam -applaunch 570", Some("gamemoderun %command%")),
+            "steam -applaunch 570"
+        );
+        assert!(supports_launch_options("steam -applaunch 570"));
+        assert!(!supports_launch_options(
+            "xdg-open heroic://launch/legendary/Fortnite"
+        ));
+    }
+
+    #[test]
+    fn test_build_launch_command_combinations() {
+        let exec = "/games/celeste/Celeste";
+        assert_eq!(build_launch_command(exec, None, None, None), exec);
+        assert_eq!(
+            build_launch_command(exec, None, Some("DXVK_HUD=1 %command% -w"), None),
+            "DXVK_HUD=1 /games/celeste/Celeste -w"
+        );
+        assert_eq!(
+            build_launch_command(exec, None, None, Some(GAMESCOPE_16_9_PREFIX)),
+            "gamescope -W 1920 -H 1080 -f -- /games/celeste/Celeste"
+        );
+        assert_eq!(
+            build_launch_command(
+                exec,
+                None,
+                Some("gamemoderun %command%"),
+                Some(GAMESCOPE_16_9_PREFIX)
+            ),
+            "gamescope -W 1920 -H 1080 -f -- gamemoderun /games/celeste/Celeste"
+        );
+        assert_eq!(
+            build_launch_command("steam -applaunch 570", None, Some("-novid"), Some("  ")),
+            "steam -applaunch 570 -novid"
+        );
+        // Terminal apps ignore options; the prefix still wraps the terminal
+        assert_eq!(
+            build_launch_command(
+                "htop",
+                Some("kitty --"),
+                Some("-d 5"),
+                Some("env TERM=xterm")
+            ),
+            "env TERM=xterm kitty -- sh -c 'htop' rhinco-tv-terminal"
+        );
+    }
+
+    #[test]
+    fn test_wrap_in_terminal_quotes_exec() {
+        assert_eq!(
+            wrap_in_terminal("htop", "kitty --"),
+            "kitty -- sh -c 'htop' rhinco-tv-terminal"
+        );
+        assert_eq!(
+            wrap_in_terminal("ncmpcpp -c '/home/me/my config'", "alacritty -e"),
+            "alacritty -e sh -c 'ncmpcpp -c '\\''/home/me/my config'\\''' rhinco-tv-terminal"
+        );
+    }
+
+    #[test]
+    fn test_resolve_terminal_uses_configured_value() {
+        assert_eq!(
+            resolve_terminal(Some("alacritty")),
+            Some("alacritty -e".to_string())
+        );
+        assert_eq!(
+            resolve_terminal(Some("  st -e ")),
+            Some("st -e".to_string())
+        );
+    }
+
+    #[test]
+    fn test_wm_class_monitor_target() {
+        assert_eq!(
+            resolve_monitor_target("supertuxkart", "SuperTuxKart", None, Some("SuperTuxKart")),
+            Some(MonitorTarget::Any(vec![
+                MonitorTarget::WmClass("SuperTuxKart".to_string()),
+                MonitorTarget::CmdLineContains("supertuxkart".to_string()),
+            ]))
+        );
+        // Steam launches keep following the appid
+        assert_eq!(
+            resolve_monitor_target("steam -applaunch 570", "Dota 2", None, Some("dota2")),
+            Some(MonitorTarget::SteamAppId("570".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_wrapped_launch_monitor_targets() {
+        assert_eq!(
+            resolve_monitor_target(
+                "gamescope -W 1920 -H 1080 -- steam -applaunch 570 -novid",
+                "Dota 2",
+                None,
+                None,
+            ),
+            Some(MonitorTarget::SteamAppId("570".to_string()))
+        );
+
+        let exec = apply_launch_options(
+            "xdg-open heroic://launch/legendary/Fortnite%20X",
+            Some("gamescope -f -- %command%"),
+        );
+        assert_eq!(
+            exec,
+            "gamescope -f -- xdg-open heroic://launch/legendary/Fortnite%20X"
+        );
+        assert_eq!(
+            find_heroic_launch_url("env X=1 \"xdg-open\" 'heroic://launch/nile/amzn1.adg' --x"),
+            Some("nile/amzn1.adg")
+        );
+        let Some(MonitorTarget::Any(targets)) =
+            resolve_monitor_target(&exec, "Fortnite", None, None)
+        else {
+            panic!("expected Heroic targets");
+        };
+        assert_eq!(
+            targets[0],
+            MonitorTarget::EnvVarEq("HeroicAppName".to_string(), "Fortnite X".to_string())
+        );
+    }
+
+    #[test]
+    fn test_monitor_env_extends_targets() {
+        let target = resolve_monitor_target(
+            "xdg-open heroic://launch/gog/1207658924",
+            "Celeste",
+            None,
+            None,
+        )
+        .unwrap();
+        let env = vec![("STEAM_COMPAT_APP_ID".to_string(), "1207658924".to_string())];
+        let MonitorTarget::Any(targets) = with_monitor_env(target.clone(), &env) else {
+            panic!("expected combined targets");
+        };
+        assert_eq!(
+            targets.last(),
+            Some(&MonitorTarget::EnvVarEq(
+                "STEAM_COMPAT_APP_ID".to_string(),
+                "1207658924".to_string()
+            ))
+        );
+
+        assert_eq!(with_monitor_env(target.clone(), &[]), target);
+        assert_eq!(
+            with_monitor_env(MonitorTarget::Pid(7), &env),
+            MonitorTarget::Any(vec![
+                MonitorTarget::Pid(7),
+                MonitorTarget::EnvVarEq(
+                    "STEAM_COMPAT_APP_ID".to_string(),
+                    "1207658924".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_terminal_launch_monitors_its_own_terminal() {
+        let exec = wrap_in_terminal("htop", "kitty --");
+        assert_eq!(
+            resolve_monitor_target(&exec, "System Monitor", None, None),
+            Some(MonitorTarget::CmdLineContains(
+                "htop rhinco-tv-terminal".to_string()
+            ))
+        );
+
+        // Matched against the arguments joined by spaces, as the process monitor does
+        let exec = wrap_in_terminal("ncmpcpp -c '/home/me/my config'", "alacritty -e");
+        let Some(MonitorTarget::CmdLineContains(pattern)) =
+            resolve_monitor_target(&exec, "ncmpcpp", None, None)
+        else {
+            panic!("expected a command line pattern");
+        };
+        let launched = "alacritty -e sh -c ncmpcpp -c '/home/me/my config' rhinco-tv-terminal";
+        assert!(launched.contains(&pattern));
+        assert!(!"alacritty -e sh -c ncmpcpp rhinco-tv-terminal".contains(&pattern));
+        assert!(!"alacritty".contains(&pattern));
+    }
 }
@@ -0,0 +1,155 @@
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::{Mutex, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// Number of recent log lines kept for the in-app log viewer
+const LOG_BUFFER_CAPACITY: usize = 500;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub timestamp: DateTime<Local>,
+    pub level: Level,
+    pub message: String,
+}
+
+/// Fixed-size buffer that keeps the most recent log lines, evicting the oldest first.
+#[derive(Debug)]
+pub struct LogRingBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+}
+
+impl LogRingBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn push(&mut self, line: LogLine) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.lines.len() >= self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+
+    pub fn snapshot(&self) -> Vec<LogLine> {
+        self.lines.iter().cloned().collect()
+    }
+}
+
+fn global_buffer() -> &'static Mutex<LogRingBuffer> {
+    static BUFFER: OnceLock<Mutex<LogRingBuffer>> = OnceLock::new();
+    BUFFER.get_or_init(|| Mutex::new(LogRingBuffer::new(LOG_BUFFER_CAPACITY)))
+}
+
+/// Returns a copy of the captured log lines, oldest first.
+pub fn recent_log_lines() -> Vec<LogLine> {
+    global_buffer()
+        .lock()
+        .map(|buffer| buffer.snapshot())
+        .unwrap_or_default()
+}
+
+/// `tracing_subscriber` layer that mirrors every event into the global ring buffer.
+pub struct LogBufferLayer;
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let line = LogLine {
+            timestamp: Local::now(),
+            level: *event.metadata().level(),
+            message: visitor.finish(),
+        };
+
+        if let Ok(mut buffer) = global_buffer().lock() {
+            buffer.push(line);
+        }
+    }
+}
+
+/// Collects the `message` field plus any structured fields as `key=value` pairs.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        format!("{}{}", self.message, self.fields)
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(message: &str) -> LogLine {
+        LogLine {
+            timestamp: Local::now(),
+            level: Level::INFO,
+            message: message.to_string(),
+        }
+    }
+
+    fn messages(buffer: &LogRingBuffer) -> Vec<String> {
+        buffer.snapshot().into_iter().map(|l| l.message).collect()
+    }
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest_when_full() {
+        let mut buffer = LogRingBuffer::new(3);
+        for message in ["one", "two", "three", "four", "five"] {
+            buffer.push(line(message));
+        }
+
+        assert_eq!(messages(&buffer), vec!["three", "four", "five"]);
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_everything_below_capacity() {
+        let mut buffer = LogRingBuffer::new(3);
+        buffer.push(line("one"));
+        buffer.push(line("two"));
+
+        assert_eq!(messages(&buffer), vec!["one", "two"]);
+    }
+
+    #[test]
+    fn test_ring_buffer_with_zero_capacity_stays_empty() {
+        let mut buffer = LogRingBuffer::new(0);
+        buffer.push(line("ignored"));
+
+        assert!(buffer.snapshot().is_empty());
+    }
+}
@@ -1,44 +1,100 @@
+mod appimage;
 mod assets;
 mod auth_dialog;
 mod auth_flow;
+mod auto_suspend;
+mod autostart;
+mod backdrop;
+mod bug_report;
 mod category_list;
+mod command_runner;
+mod context_menu;
+mod controller_led;
+mod cursor_visibility;
+mod custom_actions;
+mod custom_entry;
 mod desktop_apps;
+mod desktop_shortcut;
+mod display_capabilities;
+mod display_modes;
+mod double_back;
+mod duplicate_games;
+mod fix_artwork;
+mod focus_animation;
 mod focus_manager;
+mod game_exit;
 mod game_image_fetcher;
+mod game_manager;
 mod game_sources;
 mod gamepad;
+mod hold_to_launch;
+mod icon_fit;
 mod icons;
 mod image_cache;
+mod image_fetch_queue;
 mod input;
+mod keyboard_bindings;
+mod launch_diagnostics;
+mod launch_options;
 mod launcher;
+mod log_buffer;
 mod messages;
 mod model;
+mod moonlight;
 mod mupen64plus;
 mod osk;
+mod pegasus;
+mod power;
+mod protondb;
+mod rom_utils;
+mod screenshot;
+mod scummvm;
 mod searxng;
+mod settings;
+mod setup_wizard;
 mod sleep_inhibit;
 mod snes9x;
+mod sort_mode;
+mod startup_scroll;
+mod steam_metadata;
 mod steamgriddb;
 mod storage;
+mod store_page;
 mod sudo_askpass;
 mod sys_utils;
 mod system_battery;
 mod system_info;
 mod system_update;
 mod system_update_state;
+mod tile_style;
 mod ui;
 mod ui_app_picker;
 mod ui_app_update_modal;
 mod ui_background;
 mod ui_components;
+mod ui_custom_entry_modal;
+mod ui_density;
+mod ui_fix_artwork_modal;
+mod ui_launch_options_modal;
+mod ui_list_modal;
+mod ui_logs_modal;
 mod ui_main_view;
+mod ui_manage_games_modal;
 mod ui_modals;
+mod ui_settings_modal;
+mod ui_setup_wizard_modal;
+mod ui_sounds;
 mod ui_state;
 mod ui_system_info_modal;
 mod ui_system_update_modal;
 mod ui_theme;
 mod updater;
 mod virtual_keyboard;
+mod watcher;
+mod window_mode;
+
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 
 fn main() -> iced::Result {
     let filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
@@ -46,7 +102,16 @@ fn main() -> iced::Result {
             "info,wgpu=warn,winit=warn,naga=warn,iced_wgpu=warn,iced_winit=warn",
         )
     });
-    tracing_subscriber::fmt().with_env_filter(filter).init();
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(log_buffer::LogBufferLayer)
+        .init();
+    // Window settings are needed before the launcher loads its config
+    let app_settings = storage::load_config()
+        .map(|config| config.settings)
+        .unwrap_or_default();
+
     let mut settings = iced::Settings::default();
     if let Some(sansation) = assets::get_sansation_font() {
         settings.fonts.push(sansation.into());
@@ -59,10 +124,9 @@ fn main() -> iced::Result {
         .title(ui::Launcher::title)
         .subscription(ui::Launcher::subscription)
         .settings(settings)
-        .window(iced::window::Settings {
-            decorations: false,
-            fullscreen: true,
-            ..Default::default()
-        })
+        .window(window_mode::window_settings(
+            app_settings.window_mode,
+            app_settings.window_geometry,
+        ))
         .run()
 }
@@ -1,12 +1,20 @@
 use chrono::{DateTime, Local};
+use iced::keyboard::Key;
 use iced::window;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use uuid::Uuid;
 
 use crate::desktop_apps::DesktopApp;
-use crate::gamepad::GamepadInfo;
+use crate::display_modes::DisplayMode;
+use crate::game_exit::GameExitStep;
+use crate::game_sources::ScanResult;
+use crate::gamepad::{GamepadInfo, PresenceChange, StickAxis};
 use crate::input::Action;
-use crate::model::AppEntry;
+use crate::launch_diagnostics::LaunchFailure;
+use crate::power::PowerProfile;
+use crate::protondb::ProtonTier;
+use crate::steam_metadata::GameMetadata;
 use crate::storage::AppConfig;
 use crate::sudo_askpass::AskpassEvent;
 use crate::system_info::GamingSystemInfo;
@@ -16,24 +24,63 @@ use crate::virtual_keyboard::KeyboardMessage;
 
 #[derive(Debug, Clone)]
 pub enum Message {
-    AppsLoaded(Result<AppConfig, String>),
-    GamesLoaded(Vec<AppEntry>),
-    ImageFetched(Uuid, PathBuf),
+    AppsLoaded(Result<Box<AppConfig>, String>),
+    GamesLoaded(ScanResult),
+    /// A game was installed or removed in a watched Steam or Heroic directory
+    GamesChanged,
+    /// No path if the download failed or the game has no artwork
+    ImageFetched(Uuid, Option<PathBuf>),
+    /// Blurred hero art of a game for the backdrop; no path if it has none
+    HeroArtFetched(Uuid, Option<PathBuf>),
+    /// Placeholder covers generated for items without artwork, by item id
+    PlaceholderCoversGenerated(Vec<(Uuid, PathBuf)>),
+    /// Covers read into memory in the background, see `CoverHandleCache`
+    CoverHandlesLoaded(Vec<(PathBuf, iced::widget::image::Handle)>),
     Input(Action),
+    /// Gamepad confirm button pressed (`true`) or released, for hold to launch
+    GamepadSelect(bool),
+    KeyPressed(Key),
+    /// Ctrl+V on a physical keyboard
+    PasteRequested,
+    /// Clipboard contents for the focused text field; `None` if it held no text
+    ClipboardPasted(Option<String>),
     ScaleFactorChanged(f64),
     WindowResized(f32, f32),
+    WindowMoved(f32, f32),
+    WindowCloseRequested,
+    MouseMoved,
+    ScreenshotSaved(Result<PathBuf, String>),
+    BugReportSaved(Result<PathBuf, String>),
     // App picker messages
     OpenAppPicker,
     AvailableAppsLoaded(Vec<DesktopApp>),
     AddSelectedApp,
     CloseAppPicker,
     AppPickerScrolled(iced::widget::scrollable::Viewport),
+    CustomEntryKeyboard(KeyboardMessage),
+    // Fix Artwork messages
+    FixArtworkKeyboard(KeyboardMessage),
+    SubmitFixArtwork,
+    /// Back to the missing artwork list when opened from there, else closed
+    CloseFixArtwork,
+    LaunchOptionsKeyboard(KeyboardMessage),
+    /// Game id, launch key, and the SteamGridDB ID; no path if it had no artwork
+    ArtworkFixed(Uuid, String, u64, Option<PathBuf>),
+    /// Position in the missing artwork list and id of the game picked there
+    FixMissingArtwork(usize, Uuid),
+    // Setup wizard messages
+    SetupKeyboard(KeyboardMessage),
+    /// Closes the wizard for good; `true` keeps the choices made in it
+    CompleteSetup(bool),
+    /// Name of a custom System action and how its command ended
+    CustomActionFinished(String, Result<String, String>),
     // System Update messages
     StartSystemUpdate,
     SystemUpdateProgress(SystemUpdateProgress),
     CloseSystemUpdateModal,
     CancelSystemUpdate,
     RequestReboot,
+    UpdateCountChecked(Result<usize, String>),
     // App Update messages
     AppUpdateCheckCompleted(Result<Option<ReleaseInfo>, String>),
     StartAppUpdate,
@@ -43,15 +90,45 @@ pub enum Message {
     OpenSystemInfo,
     SystemInfoLoaded(Box<GamingSystemInfo>),
     CloseSystemInfoModal,
+    ProtonTiersLoaded(HashMap<String, ProtonTier>),
+    /// Store details for the appid, or why they couldn't be loaded
+    GameDetailsLoaded(String, Result<GameMetadata, String>),
+    /// B in the display mode, power profile or missing artwork list
+    CloseListModal,
+    // Display mode messages
+    DisplayModesLoaded(Result<Vec<DisplayMode>, String>),
+    ApplyDisplayMode(DisplayMode),
+    DisplayModeApplied(Result<(), String>),
+    // Power profile messages
+    PowerProfilesLoaded(Result<Vec<PowerProfile>, String>),
+    /// Name of the profile picked in the power profile list
+    ApplyPowerProfile(String),
+    PowerProfileApplied(Result<(), String>),
+    /// Profile to restore after the game, if the launch switched it
+    GamePowerProfileSwitched(Result<Option<String>, String>),
     // Game/App lifecycle
     GameExited,
+    /// `launch_delay_ms` passed after minimizing; starts the pending launch
+    LaunchDelayElapsed,
+    /// `exit_delay_ms` passed after the game exited; brings the launcher back
+    ExitDelayElapsed(GameExitStep),
+    /// Result of watching a launch with `diagnose_launch_failures`: item name and failure
+    LaunchDiagnosed(String, Option<LaunchFailure>),
     WindowOpened(window::Id),
     WindowFocused(window::Id),
     RestartApp,
     GamepadBatteryUpdate(Vec<GamepadInfo>),
+    /// All gamepads disconnected, or one came back, while a game runs
+    ControllerPresence(PresenceChange),
+    StickMoved(StickAxis, f32),
     SystemBatteryUpdated(Option<gilrs::PowerInfo>),
     Tick(DateTime<Local>),
     AppUpdateSpinnerTick,
+    ScanSpinnerTick,
+    FocusAnimationTick(std::time::Instant),
+    BackdropTick(std::time::Instant),
+    StartupScrollTick(std::time::Instant),
+    HoldToLaunchTick(std::time::Instant),
     AskpassEvent(AskpassEvent),
     AuthKeyboard(KeyboardMessage),
     AuthSubmit,
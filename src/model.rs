@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::protondb::ProtonTier;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SystemIcon {
     PowerOff,
@@ -8,6 +10,62 @@ pub enum SystemIcon {
     ArrowsRotate,
     ExitBracket,
     Info,
+    Gear,
+    FileLines,
+    Tv,
+    Terminal,
+    Bolt,
+    Bug,
+}
+
+/// Store or emulator a game comes from, shown as a badge on its tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceBadge {
+    Steam,
+    Epic,
+    Gog,
+    Amazon,
+    Emulator,
+}
+
+impl SourceBadge {
+    pub fn label(self) -> &'static str {
+        match self {
+            SourceBadge::Steam => "STEAM",
+            SourceBadge::Epic => "EPIC",
+            SourceBadge::Gog => "GOG",
+            SourceBadge::Amazon => "AMAZON",
+            SourceBadge::Emulator => "EMU",
+        }
+    }
+
+    /// Full name, used where there is room for it (e.g. the launch source menu)
+    pub fn name(self) -> &'static str {
+        match self {
+            SourceBadge::Steam => "Steam",
+            SourceBadge::Epic => "Epic Games",
+            SourceBadge::Gog => "GOG",
+            SourceBadge::Amazon => "Amazon",
+            SourceBadge::Emulator => "Emulator",
+        }
+    }
+}
+
+/// Derives the source badge from a launch key prefix (`steam:`, `heroic:<store>:`,
+/// `snes9x:`, `mupen64plus:`, `pegasus:`). Desktop apps and sideloaded games get none.
+pub fn source_badge(launch_key: &str) -> Option<SourceBadge> {
+    let (prefix, rest) = launch_key.split_once(':')?;
+    match prefix {
+        "steam" => Some(SourceBadge::Steam),
+        "heroic" => match rest.split_once(':')?.0 {
+            "legendary" => Some(SourceBadge::Epic),
+            "gog" => Some(SourceBadge::Gog),
+            "nile" => Some(SourceBadge::Amazon),
+            _ => None,
+        },
+        "snes9x" | "mupen64plus" | "pegasus" | "scummvm" => Some(SourceBadge::Emulator),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -15,6 +73,10 @@ pub enum Category {
     Games,
     Apps,
     System,
+    /// Quick launch screen combining favorites from all other categories
+    Favorites,
+    /// Most recently launched games and apps, shown above the games row
+    Recent,
 }
 
 impl Category {
@@ -23,34 +85,148 @@ impl Category {
             Category::Apps => "Apps",
             Category::Games => "Games",
             Category::System => "System",
+            Category::Favorites => "Favorites",
+            Category::Recent => "Recent",
         }
     }
 
     pub fn next(self) -> Self {
         match self {
+            Category::Recent => Category::Games,
             Category::Games => Category::Apps,
             Category::Apps => Category::System,
-            Category::System => Category::Games,
+            Category::System => Category::Recent,
+            Category::Favorites => Category::Favorites,
         }
     }
 
     pub fn prev(self) -> Self {
         match self {
-            Category::Games => Category::System,
+            Category::Recent => Category::System,
+            Category::Games => Category::Recent,
             Category::Apps => Category::Games,
             Category::System => Category::Apps,
+            Category::Favorites => Category::Favorites,
         }
     }
 }
 
+/// Entries of the System row, in the order configured by `system_order`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemAction {
+    Shutdown,
+    Suspend,
+    SystemUpdate,
+    SystemInfo,
+    DisplayModes,
+    PowerProfiles,
+    Settings,
+    Logs,
+    BugReport,
+    Exit,
+}
+
+impl SystemAction {
+    pub const DEFAULT_ORDER: &'static [SystemAction] = &[
+        SystemAction::Shutdown,
+        SystemAction::Suspend,
+        SystemAction::SystemUpdate,
+        SystemAction::SystemInfo,
+        SystemAction::DisplayModes,
+        SystemAction::PowerProfiles,
+        SystemAction::Settings,
+        SystemAction::Logs,
+        SystemAction::BugReport,
+        SystemAction::Exit,
+    ];
+
+    pub fn item(self) -> LauncherItem {
+        match self {
+            SystemAction::Shutdown => LauncherItem::shutdown(),
+            SystemAction::Suspend => LauncherItem::suspend(),
+            SystemAction::SystemUpdate => LauncherItem::system_update(),
+            SystemAction::SystemInfo => LauncherItem::system_info(),
+            SystemAction::DisplayModes => LauncherItem::display_modes(),
+            SystemAction::PowerProfiles => LauncherItem::power_profiles(),
+            SystemAction::Settings => LauncherItem::settings(),
+            SystemAction::Logs => LauncherItem::logs(),
+            SystemAction::BugReport => LauncherItem::bug_report(),
+            SystemAction::Exit => LauncherItem::exit(),
+        }
+    }
+
+    fn of(action: &LauncherAction) -> Option<Self> {
+        match action {
+            LauncherAction::Launch { .. } | LauncherAction::Custom { .. } => None,
+            LauncherAction::SystemUpdate => Some(SystemAction::SystemUpdate),
+            LauncherAction::SystemInfo => Some(SystemAction::SystemInfo),
+            LauncherAction::OpenSettings => Some(SystemAction::Settings),
+            LauncherAction::OpenLogs => Some(SystemAction::Logs),
+            LauncherAction::SaveBugReport => Some(SystemAction::BugReport),
+            LauncherAction::OpenDisplayModes => Some(SystemAction::DisplayModes),
+            LauncherAction::OpenPowerProfiles => Some(SystemAction::PowerProfiles),
+            LauncherAction::Shutdown => Some(SystemAction::Shutdown),
+            LauncherAction::Suspend => Some(SystemAction::Suspend),
+            LauncherAction::Exit => Some(SystemAction::Exit),
+        }
+    }
+}
+
+/// The configured order, followed by any actions it leaves out in their default
+/// order, so a partial `system_order` never hides an entry.
+fn resolve_system_order(configured: &[SystemAction]) -> Vec<SystemAction> {
+    let mut order: Vec<SystemAction> = Vec::with_capacity(SystemAction::DEFAULT_ORDER.len());
+    for &action in configured.iter().chain(SystemAction::DEFAULT_ORDER) {
+        if !order.contains(&action) {
+            order.push(action);
+        }
+    }
+    order
+}
+
+/// Builds the System row in the given order, skipping unavailable actions.
+pub fn build_system_items(
+    order: &[SystemAction],
+    available: impl Fn(SystemAction) -> bool,
+) -> Vec<LauncherItem> {
+    resolve_system_order(order)
+        .into_iter()
+        .filter(|action| available(*action))
+        .map(SystemAction::item)
+        .collect()
+}
+
+/// Reorders already built System items, e.g. once the config has been loaded.
+pub fn sort_system_items(items: &mut [LauncherItem], order: &[SystemAction]) {
+    let order = resolve_system_order(order);
+    items.sort_by_key(|item| {
+        SystemAction::of(&item.action)
+            .and_then(|action| order.iter().position(|a| *a == action))
+            .unwrap_or(order.len())
+    });
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LauncherAction {
-    Launch { exec: String },
+    Launch {
+        exec: String,
+    },
     SystemUpdate,
     SystemInfo,
+    OpenSettings,
+    OpenLogs,
+    /// Writes version, system info and recent logs to a file in the home directory
+    SaveBugReport,
+    OpenDisplayModes,
+    OpenPowerProfiles,
     Shutdown,
     Suspend,
     Exit,
+    /// A shell command from `custom_system_actions`
+    Custom {
+        command: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -67,6 +243,28 @@ pub struct LauncherItem {
     /// Unix timestamp of when this item was last started via the launcher
     pub last_started: Option<i64>,
     pub steam_appid: Option<String>,
+    /// Whether the item is shown on the quick launch screen
+    pub favorite: bool,
+    /// Wrapper command put in front of the exec line (e.g. gamescope)
+    pub launch_prefix: Option<String>,
+    /// Steam-style launch options, see `apply_launch_options`
+    pub launch_options: Option<String>,
+    /// Cover comes from the store's image rather than SteamGridDB
+    pub prefer_source_art: bool,
+    /// Start the exec line inside a terminal emulator (CLI/TUI apps)
+    pub run_in_terminal: bool,
+    /// Window class from the desktop entry, matched while the app runs
+    pub wm_class: Option<String>,
+    /// Extra desktop entry actions, offered in the context menu
+    pub actions: Vec<AppAction>,
+    /// Copies of the same game from other sources, merged into this tile
+    pub alternates: Vec<LauncherItem>,
+    /// ProtonDB rating of Steam games, when `protondb_badges` is on
+    pub proton_tier: Option<ProtonTier>,
+    /// Game-specific environment the game process is started with, used to recognize it
+    pub monitor_env: Vec<(String, String)>,
+    /// Steam game run through Proton, shown with a badge
+    pub runs_under_proton: bool,
 }
 
 impl LauncherItem {
@@ -92,10 +290,21 @@ impl LauncherItem {
             launch_key: entry.launch_key,
             last_started: entry.last_started,
             steam_appid: entry.steam_appid,
+            favorite: false,
+            launch_prefix: None,
+            launch_options: None,
+            prefer_source_art: false,
+            run_in_terminal: entry.run_in_terminal,
+            wm_class: entry.wm_class,
+            actions: entry.actions,
+            alternates: Vec::new(),
+            proton_tier: None,
+            monitor_env: entry.monitor_env,
+            runs_under_proton: entry.runs_under_proton,
         }
     }
 
-    fn new_system(name: &str, system_icon: SystemIcon, action: LauncherAction) -> Self {
+    fn new_system(key: &str, name: &str, system_icon: SystemIcon, action: LauncherAction) -> Self {
         Self {
             id: Uuid::new_v4(),
             name: name.to_string(),
@@ -104,14 +313,26 @@ impl LauncherItem {
             action,
             source_image_url: None,
             game_executable: None,
-            launch_key: None,
+            launch_key: Some(format!("system:{}", key)),
             last_started: None,
             steam_appid: None,
+            favorite: false,
+            launch_prefix: None,
+            launch_options: None,
+            prefer_source_art: false,
+            run_in_terminal: false,
+            wm_class: None,
+            actions: Vec::new(),
+            alternates: Vec::new(),
+            proton_tier: None,
+            monitor_env: Vec::new(),
+            runs_under_proton: false,
         }
     }
 
     pub fn system_update() -> Self {
         Self::new_system(
+            "update",
             "Update System",
             SystemIcon::ArrowsRotate,
             LauncherAction::SystemUpdate,
@@ -119,19 +340,80 @@ impl LauncherItem {
     }
 
     pub fn system_info() -> Self {
-        Self::new_system("System Info", SystemIcon::Info, LauncherAction::SystemInfo)
+        Self::new_system(
+            "info",
+            "System Info",
+            SystemIcon::Info,
+            LauncherAction::SystemInfo,
+        )
+    }
+
+    pub fn settings() -> Self {
+        Self::new_system(
+            "settings",
+            "Settings",
+            SystemIcon::Gear,
+            LauncherAction::OpenSettings,
+        )
+    }
+
+    pub fn display_modes() -> Self {
+        Self::new_system(
+            "display",
+            "Display Mode",
+            SystemIcon::Tv,
+            LauncherAction::OpenDisplayModes,
+        )
+    }
+
+    pub fn power_profiles() -> Self {
+        Self::new_system(
+            "power_profile",
+            "Power Profile",
+            SystemIcon::Bolt,
+            LauncherAction::OpenPowerProfiles,
+        )
+    }
+
+    pub fn logs() -> Self {
+        Self::new_system(
+            "logs",
+            "View Logs",
+            SystemIcon::FileLines,
+            LauncherAction::OpenLogs,
+        )
+    }
+
+    pub fn bug_report() -> Self {
+        Self::new_system(
+            "bug_report",
+            "Report a Bug",
+            SystemIcon::Bug,
+            LauncherAction::SaveBugReport,
+        )
     }
 
     pub fn shutdown() -> Self {
-        Self::new_system("Shutdown", SystemIcon::PowerOff, LauncherAction::Shutdown)
+        Self::new_system(
+            "shutdown",
+            "Shutdown",
+            SystemIcon::PowerOff,
+            LauncherAction::Shutdown,
+        )
     }
 
     pub fn suspend() -> Self {
-        Self::new_system("Suspend", SystemIcon::Pause, LauncherAction::Suspend)
+        Self::new_system(
+            "suspend",
+            "Suspend",
+            SystemIcon::Pause,
+            LauncherAction::Suspend,
+        )
     }
 
     pub fn exit() -> Self {
         Self::new_system(
+            "exit",
             "Exit Launcher",
             SystemIcon::ExitBracket,
             LauncherAction::Exit,
@@ -153,6 +435,11 @@ impl LauncherItem {
             game_executable: self.game_executable.clone(),
             last_started: self.last_started,
             steam_appid: self.steam_appid.clone(),
+            run_in_terminal: self.run_in_terminal,
+            wm_class: self.wm_class.clone(),
+            actions: self.actions.clone(),
+            monitor_env: self.monitor_env.clone(),
+            runs_under_proton: self.runs_under_proton,
         }
     }
 }
@@ -170,6 +457,17 @@ impl Default for LauncherItem {
             launch_key: None,
             last_started: None,
             steam_appid: None,
+            favorite: false,
+            launch_prefix: None,
+            launch_options: None,
+            prefer_source_art: false,
+            run_in_terminal: false,
+            wm_class: None,
+            actions: Vec::new(),
+            alternates: Vec::new(),
+            proton_tier: None,
+            monitor_env: Vec::new(),
+            runs_under_proton: false,
         }
     }
 }
@@ -191,6 +489,29 @@ pub struct AppEntry {
     /// Optional Steam App ID for better metadata lookup
     #[serde(default)]
     pub steam_appid: Option<String>,
+    /// Whether the exec line needs a terminal emulator (desktop entry `Terminal=true`)
+    #[serde(default)]
+    pub run_in_terminal: bool,
+    /// Desktop entry `StartupWMClass`, used to find the app's window once running
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wm_class: Option<String>,
+    /// Desktop entry actions (`[Desktop Action ...]`), e.g. "New Private Window"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<AppAction>,
+    /// Game-specific environment variables a launcher sets on the game, e.g. Steam's
+    /// `STEAM_COMPAT_APP_ID` for Proton launches
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub monitor_env: Vec<(String, String)>,
+    /// Windows game Steam starts through Proton
+    #[serde(default)]
+    pub runs_under_proton: bool,
+}
+
+/// An extra launch variant declared by a desktop entry
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AppAction {
+    pub name: String,
+    pub exec: String,
 }
 
 impl AppEntry {
@@ -204,6 +525,11 @@ impl AppEntry {
             game_executable: None,
             last_started: None,
             steam_appid: None,
+            run_in_terminal: false,
+            wm_class: None,
+            actions: Vec::new(),
+            monitor_env: Vec::new(),
+            runs_under_proton: false,
         }
     }
 
@@ -217,6 +543,31 @@ impl AppEntry {
         self
     }
 
+    pub fn with_run_in_terminal(mut self, run_in_terminal: bool) -> Self {
+        self.run_in_terminal = run_in_terminal;
+        self
+    }
+
+    pub fn with_wm_class(mut self, wm_class: Option<String>) -> Self {
+        self.wm_class = wm_class;
+        self
+    }
+
+    pub fn with_actions(mut self, actions: Vec<AppAction>) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    pub fn with_monitor_env(mut self, monitor_env: Vec<(String, String)>) -> Self {
+        self.monitor_env = monitor_env;
+        self
+    }
+
+    pub fn with_runs_under_proton(mut self, runs_under_proton: bool) -> Self {
+        self.runs_under_proton = runs_under_proton;
+        self
+    }
+
     pub fn with_steam_appid(mut self, appid: impl Into<String>) -> Self {
         self.steam_appid = Some(appid.into());
         self
@@ -227,6 +578,101 @@ impl AppEntry {
 mod tests {
     use super::*;
 
+    fn system_actions(items: &[LauncherItem]) -> Vec<SystemAction> {
+        items
+            .iter()
+            .filter_map(|item| SystemAction::of(&item.action))
+            .collect()
+    }
+
+    #[test]
+    fn test_build_system_items_in_custom_order() {
+        let order = [
+            SystemAction::SystemInfo,
+            SystemAction::Suspend,
+            SystemAction::Shutdown,
+        ];
+        let items = build_system_items(&order, |action| {
+            !matches!(
+                action,
+                SystemAction::DisplayModes | SystemAction::PowerProfiles
+            )
+        });
+
+        assert_eq!(
+            system_actions(&items),
+            vec![
+                SystemAction::SystemInfo,
+                SystemAction::Suspend,
+                SystemAction::Shutdown,
+                // Not configured: appended in default order
+                SystemAction::SystemUpdate,
+                SystemAction::Settings,
+                SystemAction::Logs,
+                SystemAction::BugReport,
+                SystemAction::Exit,
+            ]
+        );
+
+        let mut items = build_system_items(SystemAction::DEFAULT_ORDER, |_| true);
+        sort_system_items(&mut items, &[SystemAction::Exit, SystemAction::Logs]);
+        assert_eq!(
+            system_actions(&items)[..3],
+            [
+                SystemAction::Exit,
+                SystemAction::Logs,
+                SystemAction::Shutdown
+            ]
+        );
+    }
+
+    #[test]
+    fn test_system_order_serialization() {
+        let order: Vec<SystemAction> =
+            serde_json::from_str(r#"["system_info", "display_modes", "exit"]"#).unwrap();
+        assert_eq!(
+            order,
+            vec![
+                SystemAction::SystemInfo,
+                SystemAction::DisplayModes,
+                SystemAction::Exit
+            ]
+        );
+    }
+
+    #[test]
+    fn test_source_badge_from_launch_key() {
+        assert_eq!(source_badge("steam:570"), Some(SourceBadge::Steam));
+        assert_eq!(
+            source_badge("heroic:legendary:Fortnite"),
+            Some(SourceBadge::Epic)
+        );
+        assert_eq!(
+            source_badge("heroic:gog:1207664643"),
+            Some(SourceBadge::Gog)
+        );
+        assert_eq!(
+            source_badge("heroic:nile:amzn1.adg.product.1234"),
+            Some(SourceBadge::Amazon)
+        );
+        assert_eq!(source_badge("snes9x:smw.sfc"), Some(SourceBadge::Emulator));
+        assert_eq!(source_badge("scummvm:monkey1"), Some(SourceBadge::Emulator));
+        assert_eq!(
+            source_badge("pegasus:Chrono Trigger.sfc"),
+            Some(SourceBadge::Emulator)
+        );
+        assert_eq!(
+            source_badge("mupen64plus:mk64.z64"),
+            Some(SourceBadge::Emulator)
+        );
+
+        assert_eq!(source_badge("heroic:MyApp"), None);
+        assert_eq!(source_badge("heroic:sideload:MyApp"), None);
+        assert_eq!(source_badge("desktop:firefox"), None);
+        assert_eq!(source_badge("system:settings"), None);
+        assert_eq!(source_badge("steam"), None);
+    }
+
     #[test]
     fn test_app_entry_creation() {
         let entry = AppEntry::new(
@@ -0,0 +1,263 @@
+//! Apps of hosts paired in Moonlight, streamed from a Sunshine or GeForce
+//! Experience PC with `moonlight stream <host> <app>`.
+
+use crate::game_sources::{GameSource, SourceResult};
+use crate::launcher::verify_command_exists;
+use crate::model::AppEntry;
+use directories::BaseDirs;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE: &str = "Moonlight Game Streaming Project/Moonlight.conf";
+const FLATPAK_ID: &str = "com.moonlight_stream.Moonlight";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MoonlightHost {
+    hostname: String,
+    uuid: Option<String>,
+    apps: Vec<String>,
+}
+
+/// How Moonlight is started and where it keeps its config
+struct MoonlightInstall {
+    command: String,
+    config_path: PathBuf,
+}
+
+/// Apps of the hosts paired in Moonlight
+pub struct MoonlightSource {
+    /// Looked up once per scan; `None` when Moonlight isn't installed
+    install: Option<MoonlightInstall>,
+}
+
+impl MoonlightSource {
+    pub fn new() -> Self {
+        Self {
+            install: find_install(),
+        }
+    }
+}
+
+impl GameSource for MoonlightSource {
+    fn name(&self) -> &'static str {
+        "Moonlight"
+    }
+
+    fn scan(&self) -> SourceResult {
+        let games = self
+            .install
+            .as_ref()
+            .map(scan_moonlight_games)
+            .unwrap_or_default();
+        Ok(games.into())
+    }
+
+    fn is_available(&self) -> bool {
+        self.install.is_some()
+    }
+}
+
+/// List the apps of all paired hosts
+fn scan_moonlight_games(install: &MoonlightInstall) -> Vec<AppEntry> {
+    let Ok(content) = fs::read_to_string(&install.config_path) else {
+        return Vec::new();
+    };
+
+    let mut games = Vec::new();
+    for host in parse_hosts(&content) {
+        for app in &host.apps {
+            let game = stream_entry(&install.command, &host, app);
+            tracing::info!("Discovered Moonlight app: '{}'", game.name);
+            games.push(game);
+        }
+    }
+    games
+}
+
+/// The native package wins over the Flatpak, which keeps its config in its sandbox
+fn find_install() -> Option<MoonlightInstall> {
+    let base_dirs = BaseDirs::new()?;
+
+    if verify_command_exists("moonlight") {
+        return Some(MoonlightInstall {
+            command: "moonlight".to_string(),
+            config_path: base_dirs.config_dir().join(CONFIG_FILE),
+        });
+    }
+
+    let flatpak_installed = [
+        base_dirs.data_dir().join("flatpak/app"),
+        PathBuf::from("/var/lib/flatpak/app"),
+    ]
+    .iter()
+    .any(|dir| dir.join(FLATPAK_ID).is_dir());
+    flatpak_installed.then(|| MoonlightInstall {
+        command: format!("flatpak run {}", FLATPAK_ID),
+        config_path: base_dirs
+            .home_dir()
+            .join(Path::new(".var/app").join(FLATPAK_ID).join("config"))
+            .join(CONFIG_FILE),
+    })
+}
+
+fn stream_entry(command: &str, host: &MoonlightHost, app: &str) -> AppEntry {
+    let exec = format!(
+        "{} stream {} {}",
+        command,
+        shell_quote(&host.hostname),
+        shell_quote(app)
+    );
+    let host_key = host.uuid.as_deref().unwrap_or(&host.hostname);
+    let launch_key = format!("moonlight:{}:{}", host_key, app);
+
+    AppEntry::new(format!("{} ({})", app, host.hostname), exec, None).with_launch_key(launch_key)
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Reads the `[hosts]` array Qt writes as `1\hostname=PC`, `1\apps\2\name=Steam`.
+/// Apps hidden in Moonlight are skipped.
+fn parse_hosts(content: &str) -> Vec<MoonlightHost> {
+    let mut hosts: BTreeMap<usize, MoonlightHost> = BTreeMap::new();
+    // Name and hidden flag per (host index, app index)
+    let mut apps: BTreeMap<(usize, usize), (Option<String>, bool)> = BTreeMap::new();
+    let mut in_hosts = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') && line.ends_with(']') {
+            in_hosts = line == "[hosts]";
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=').filter(|_| in_hosts) else {
+            continue;
+        };
+        let value = unquote(value.trim());
+        let parts: Vec<&str> = key.trim().split('\\').collect();
+
+        match parts.as_slice() {
+            [index, field] => {
+                let Ok(index) = index.parse::<usize>() else {
+                    continue;
+                };
+                let host = hosts.entry(index).or_insert_with(|| MoonlightHost {
+                    hostname: String::new(),
+                    uuid: None,
+                    apps: Vec::new(),
+                });
+                match *field {
+                    "hostname" => host.hostname = value,
+                    "uuid" if !value.is_empty() => host.uuid = Some(value),
+                    _ => {}
+                }
+            }
+            [host_index, "apps", app_index, field] => {
+                let (Ok(host_index), Ok(app_index)) =
+                    (host_index.parse::<usize>(), app_index.parse::<usize>())
+                else {
+                    continue;
+                };
+                let app = apps.entry((host_index, app_index)).or_default();
+                match *field {
+                    "name" => app.0 = Some(value),
+                    "hidden" => app.1 = value == "true",
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for ((host_index, _), (name, hidden)) in apps {
+        if let (Some(name), false, Some(host)) = (name, hidden, hosts.get_mut(&host_index)) {
+            host.apps.push(name);
+        }
+    }
+
+    hosts
+        .into_values()
+        .filter(|host| !host.hostname.is_empty())
+        .collect()
+}
+
+/// Qt quotes values containing special characters
+fn unquote(value: &str) -> String {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_CONFIG: &str = r#"[General]
+width=1920
+height=1080
+
+[hosts]
+1\apps\1\hidden=false
+1\apps\1\id=881448767
+1\apps\1\name=Desktop
+1\apps\2\hidden=true
+1\apps\2\name=Steam
+1\apps\3\name="Steam Big Picture"
+1\apps\size=3
+1\hostname=GAMING-PC
+1\localaddress=192.168.1.20
+1\uuid=B2D6A3E1-5C1F-4C5A-9E0B-8F3C2A1D4E5F
+2\hostname=Office
+2\apps\size=0
+size=2
+"#;
+
+    #[test]
+    fn test_parse_hosts_from_config() {
+        let hosts = parse_hosts(SAMPLE_CONFIG);
+        assert_eq!(
+            hosts,
+            vec![
+                MoonlightHost {
+                    hostname: "GAMING-PC".to_string(),
+                    uuid: Some("B2D6A3E1-5C1F-4C5A-9E0B-8F3C2A1D4E5F".to_string()),
+                    apps: vec!["Desktop".to_string(), "Steam Big Picture".to_string()],
+                },
+                MoonlightHost {
+                    hostname: "Office".to_string(),
+                    uuid: None,
+                    apps: Vec::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_hosts_ignores_other_sections() {
+        assert!(parse_hosts("[General]\n1\\hostname=PC\n").is_empty());
+        assert!(parse_hosts("").is_empty());
+    }
+
+    #[test]
+    fn test_stream_entry_exec() {
+        let host = MoonlightHost {
+            hostname: "GAMING-PC".to_string(),
+            uuid: Some("B2D6".to_string()),
+            apps: Vec::new(),
+        };
+        let entry = stream_entry("moonlight", &host, "Baldur's Gate 3");
+        assert_eq!(entry.name, "Baldur's Gate 3 (GAMING-PC)");
+        assert_eq!(
+            entry.exec,
+            "moonlight stream 'GAMING-PC' 'Baldur'\\''s Gate 3'"
+        );
+        assert_eq!(
+            entry.launch_key.as_deref(),
+            Some("moonlight:B2D6:Baldur's Gate 3")
+        );
+    }
+}
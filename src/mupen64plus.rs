@@ -1,17 +1,31 @@
+use crate::game_sources::{GameSource, SourceResult};
 use crate::model::AppEntry;
+use crate::rom_utils::extract_title_from_filename;
 use directories::BaseDirs;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Scan for mupen64plus games based on configuration
-pub fn scan_mupen64plus_games() -> Vec<AppEntry> {
-    let mut games = Vec::new();
-    if !is_mupen64plus_available() {
-        tracing::warn!("mupen64plus is not installed; skipping ROM scan");
-        return games;
+/// N64 ROMs in the directories set up in mupen64plus-qt
+pub struct Mupen64PlusSource;
+
+impl GameSource for Mupen64PlusSource {
+    fn name(&self) -> &'static str {
+        "Mupen64Plus"
+    }
+
+    fn scan(&self) -> SourceResult {
+        Ok(scan_mupen64plus_games().into())
     }
 
+    fn is_available(&self) -> bool {
+        is_mupen64plus_available()
+    }
+}
+
+/// Scan for mupen64plus games based on configuration
+fn scan_mupen64plus_games() -> Vec<AppEntry> {
+    let mut games = Vec::new();
     let Some(config_path) =
         BaseDirs::new().map(|dirs| dirs.config_dir().join("mupen64plus/mupen64plus-qt.conf"))
     else {
@@ -165,29 +179,6 @@ fn find_cover(rom_path: &Path) -> Option<String> {
     })
 }
 
-/// Extract clean title from filename.
-/// Removes text in () and [] and extension.
-fn extract_title_from_filename(path: &Path) -> String {
-    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
-
-    let mut title = String::with_capacity(stem.len());
-    let mut depth_round = 0i32;
-    let mut depth_square = 0i32;
-
-    for c in stem.chars() {
-        match c {
-            '(' => depth_round += 1,
-            ')' => depth_round = depth_round.saturating_sub(1),
-            '[' => depth_square += 1,
-            ']' => depth_square = depth_square.saturating_sub(1),
-            c if depth_round == 0 && depth_square == 0 => title.push(c),
-            _ => {}
-        }
-    }
-
-    title.trim().to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,18 +192,6 @@ mod tests {
         dir
     }
 
-    #[test]
-    fn test_extract_title_from_filename() {
-        assert_eq!(
-            extract_title_from_filename(Path::new("Mario Kart 64 (E) (V1.1) [!].z64")),
-            "Mario Kart 64"
-        );
-        assert_eq!(
-            extract_title_from_filename(Path::new("Super Mario 64.z64")),
-            "Super Mario 64"
-        );
-    }
-
     #[test]
     fn test_parse_config_single_rom_dir() {
         let dir = temp_dir();
@@ -0,0 +1,326 @@
+//! Games curated for the Pegasus frontend (`metadata.pegasus.txt`), launched
+//! with their collection's command and shown with their own box art.
+
+use crate::game_sources::{GameSource, SourceResult};
+use crate::model::AppEntry;
+use directories::BaseDirs;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Metadata file names Pegasus looks for in each game directory
+const METADATA_FILES: &[&str] = &["metadata.pegasus.txt", "metadata.txt"];
+
+/// Games curated in the Pegasus metadata files
+pub struct PegasusSource;
+
+impl GameSource for PegasusSource {
+    fn name(&self) -> &'static str {
+        "Pegasus"
+    }
+
+    fn scan(&self) -> SourceResult {
+        Ok(scan_pegasus_games().into())
+    }
+
+    fn is_available(&self) -> bool {
+        game_dirs_files().iter().any(|path| path.is_file())
+    }
+}
+
+/// Where Pegasus may keep its `game_dirs.txt`
+fn game_dirs_files() -> Vec<PathBuf> {
+    let Some(base_dirs) = BaseDirs::new() else {
+        return Vec::new();
+    };
+    [base_dirs.config_dir(), base_dirs.data_dir()]
+        .iter()
+        .map(|dir| dir.join("pegasus-frontend/game_dirs.txt"))
+        .collect()
+}
+
+/// Scan the game directories listed in Pegasus' `game_dirs.txt`
+fn scan_pegasus_games() -> Vec<AppEntry> {
+    let game_dirs: Vec<PathBuf> = game_dirs_files()
+        .into_iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .flat_map(|content| parse_game_dirs(&content))
+        .collect();
+
+    let mut games = Vec::new();
+    for dir in game_dirs {
+        let Some(metadata_path) = METADATA_FILES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+        else {
+            continue;
+        };
+        let Ok(content) = fs::read_to_string(&metadata_path) else {
+            tracing::warn!("Could not read {}", metadata_path.display());
+            continue;
+        };
+
+        for mut game in parse_metadata(&content, &dir) {
+            // Keep curated covers only if they exist; otherwise artwork is fetched
+            if game
+                .icon
+                .as_deref()
+                .is_some_and(|icon| !Path::new(icon).is_file())
+            {
+                game.icon = None;
+            }
+            tracing::info!("Discovered Pegasus game: '{}'", game.name);
+            games.push(game);
+        }
+    }
+
+    games
+}
+
+/// One directory per line; blank lines are skipped
+fn parse_game_dirs(content: &str) -> Vec<PathBuf> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Keys are case-insensitive and may use `_` or `-` (`assets.box_front` = `assets.boxFront`)
+fn normalize_key(key: &str) -> String {
+    key.trim()
+        .chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Splits the file into `key: value` fields. Indented lines continue the
+/// previous field, one value per line.
+fn parse_fields(content: &str) -> Vec<(String, Vec<String>)> {
+    let mut fields: Vec<(String, Vec<String>)> = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with(char::is_whitespace) {
+            if let Some((_, values)) = fields.last_mut() {
+                values.push(trimmed.to_string());
+            }
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        let values = if value.is_empty() {
+            Vec::new()
+        } else {
+            vec![value.to_string()]
+        };
+        fields.push((normalize_key(key), values));
+    }
+
+    fields
+}
+
+#[derive(Default)]
+struct PegasusGame {
+    title: String,
+    file: Option<String>,
+    launch: Option<String>,
+    box_front: Option<String>,
+}
+
+/// Parses a Pegasus metadata file. Games use their own `launch` command or that
+/// of the collection above them; relative paths are resolved against `base_dir`.
+/// Games without a file or a command are skipped.
+fn parse_metadata(content: &str, base_dir: &Path) -> Vec<AppEntry> {
+    let mut entries = Vec::new();
+    let mut collection_launch: Option<String> = None;
+    let mut game: Option<PegasusGame> = None;
+
+    let mut finish = |game: Option<PegasusGame>, collection_launch: &Option<String>| {
+        if let Some(game) = game {
+            if let Some(entry) = build_entry(game, collection_launch.as_deref(), base_dir) {
+                entries.push(entry);
+            }
+        }
+    };
+
+    for (key, values) in parse_fields(content) {
+        let value = values.join(" ");
+        match key.as_str() {
+            "collection" => {
+                finish(game.take(), &collection_launch);
+                collection_launch = None;
+            }
+            "game" => {
+                finish(game.take(), &collection_launch);
+                game = Some(PegasusGame {
+                    title: value,
+                    ..Default::default()
+                });
+            }
+            "launch" | "command" => match game.as_mut() {
+                Some(game) => game.launch = Some(value),
+                None => collection_launch = Some(value),
+            },
+            "file" | "files" => {
+                if let Some(game) = game.as_mut() {
+                    game.file = game.file.take().or(values.into_iter().next());
+                }
+            }
+            "assets.boxfront" => {
+                if let Some(game) = game.as_mut() {
+                    game.box_front = Some(value);
+                }
+            }
+            _ => {}
+        }
+    }
+    finish(game.take(), &collection_launch);
+
+    entries
+}
+
+fn build_entry(
+    game: PegasusGame,
+    collection_launch: Option<&str>,
+    base_dir: &Path,
+) -> Option<AppEntry> {
+    let title = game.title.trim();
+    if title.is_empty() {
+        return None;
+    }
+    let file = base_dir.join(game.file?);
+    let launch = game.launch.as_deref().or(collection_launch)?;
+
+    let exec = expand_launch_command(launch, &file);
+    let icon = game
+        .box_front
+        .map(|path| base_dir.join(path).to_string_lossy().to_string());
+    let launch_key = format!(
+        "pegasus:{}",
+        file.file_name().unwrap_or_default().to_string_lossy()
+    );
+
+    Some(AppEntry::new(title.to_string(), exec, icon).with_launch_key(launch_key))
+}
+
+/// Fills in Pegasus' `{file.*}` placeholders
+fn expand_launch_command(launch: &str, file: &Path) -> String {
+    let path = file.to_string_lossy();
+    let name = file.file_name().unwrap_or_default().to_string_lossy();
+    let basename = file.file_stem().unwrap_or_default().to_string_lossy();
+    let dir = file
+        .parent()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    launch
+        .replace("{file.path}", &path)
+        .replace("{file.uri}", &format!("file://{}", path))
+        .replace("{file.name}", &name)
+        .replace("{file.basename}", &basename)
+        .replace("{file.dir}", &dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"# Curated by hand
+collection: Super Nintendo
+shortname: snes
+extensions: sfc, smc
+launch: snes9x-gtk --fullscreen "{file.path}"
+
+game: Super Mario World
+file: Super Mario World (USA).sfc
+developer: Nintendo
+assets.boxFront: media/smw/boxFront.png
+description:
+  A platformer.
+  .
+  With Yoshi.
+
+game: Chrono Trigger
+files:
+  Chrono Trigger (USA).sfc
+  Chrono Trigger (USA) [h1].sfc
+assets.box_front: /srv/art/chrono.jpg
+x-custom: ignored
+
+game: No File Yet
+
+collection: Tools
+game: Memory Test
+file: memtest.bin
+"#;
+
+    #[test]
+    fn test_parse_pegasus_metadata() {
+        let games = parse_metadata(SAMPLE, Path::new("/roms/snes"));
+        let names: Vec<&str> = games.iter().map(|game| game.name.as_str()).collect();
+        // Games without a file or without any launch command are skipped
+        assert_eq!(names, vec!["Super Mario World", "Chrono Trigger"]);
+
+        let smw = &games[0];
+        assert_eq!(
+            smw.exec,
+            r#"snes9x-gtk --fullscreen "/roms/snes/Super Mario World (USA).sfc""#
+        );
+        assert_eq!(
+            smw.icon.as_deref(),
+            Some("/roms/snes/media/smw/boxFront.png")
+        );
+        assert_eq!(
+            smw.launch_key.as_deref(),
+            Some("pegasus:Super Mario World (USA).sfc")
+        );
+
+        // The first of several files is launched; absolute asset paths stay as-is
+        let chrono = &games[1];
+        assert!(chrono
+            .exec
+            .ends_with(r#""/roms/snes/Chrono Trigger (USA).sfc""#));
+        assert_eq!(chrono.icon.as_deref(), Some("/srv/art/chrono.jpg"));
+    }
+
+    #[test]
+    fn test_game_launch_overrides_collection() {
+        let content = "collection: Arcade\nlaunch: mame {file.basename}\n\n\
+                       game: Pac-Man\nfile: pacman.zip\ncommand: retroarch -L fbneo.so \"{file.path}\"\n\n\
+                       game: Galaga\nfile: galaga.zip\n";
+        let games = parse_metadata(content, Path::new("/arcade"));
+        assert_eq!(
+            games[0].exec,
+            r#"retroarch -L fbneo.so "/arcade/pacman.zip""#
+        );
+        assert_eq!(games[1].exec, "mame galaga");
+        assert_eq!(games[1].icon, None);
+    }
+
+    #[test]
+    fn test_expand_launch_placeholders() {
+        let file = Path::new("/roms/gba/Metroid Fusion.gba");
+        assert_eq!(
+            expand_launch_command("emu --dir {file.dir} {file.name} {file.uri}", file),
+            "emu --dir /roms/gba Metroid Fusion.gba file:///roms/gba/Metroid Fusion.gba"
+        );
+    }
+
+    #[test]
+    fn test_parse_game_dirs() {
+        assert_eq!(
+            parse_game_dirs("/roms/snes\n\n  /roms/gba  \n"),
+            vec![PathBuf::from("/roms/snes"), PathBuf::from("/roms/gba")]
+        );
+    }
+}
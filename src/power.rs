@@ -0,0 +1,202 @@
+//! Listing and switching power profiles via `powerprofilesctl` (power-profiles-daemon)
+//! or `tuned-adm` (TuneD).
+
+use std::process::Command;
+
+use crate::launcher::verify_command_exists;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerBackend {
+    PowerProfilesDaemon,
+    Tuned,
+}
+
+impl PowerBackend {
+    fn program(self) -> &'static str {
+        match self {
+            PowerBackend::PowerProfilesDaemon => "powerprofilesctl",
+            PowerBackend::Tuned => "tuned-adm",
+        }
+    }
+
+    /// Profile switched to while a game runs
+    pub fn performance_profile(self) -> &'static str {
+        match self {
+            PowerBackend::PowerProfilesDaemon => "performance",
+            PowerBackend::Tuned => "throughput-performance",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerProfile {
+    pub name: String,
+    pub current: bool,
+}
+
+impl PowerProfile {
+    pub fn label(&self) -> String {
+        match self.name.as_str() {
+            "power-saver" => "Power Saver".to_string(),
+            "balanced" => "Balanced".to_string(),
+            "performance" => "Performance".to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Picks the first installed profile tool; `None` when neither is available.
+pub fn detect_power_backend() -> Option<PowerBackend> {
+    [PowerBackend::PowerProfilesDaemon, PowerBackend::Tuned]
+        .into_iter()
+        .find(|backend| verify_command_exists(backend.program()))
+}
+
+pub fn list_power_profiles(backend: PowerBackend) -> Result<Vec<PowerProfile>, String> {
+    let stdout = run(backend, &["list"])?;
+    Ok(match backend {
+        PowerBackend::PowerProfilesDaemon => parse_powerprofilesctl_list(&stdout),
+        PowerBackend::Tuned => parse_tuned_adm_list(&stdout),
+    })
+}
+
+pub fn set_power_profile(backend: PowerBackend, name: &str) -> Result<(), String> {
+    let verb = match backend {
+        PowerBackend::PowerProfilesDaemon => "set",
+        PowerBackend::Tuned => "profile",
+    };
+    run(backend, &[verb, name]).map(|_| ())
+}
+
+/// Switches to the performance profile if it exists and isn't active yet.
+/// Returns the profile to restore afterwards.
+pub fn switch_to_performance(backend: PowerBackend) -> Result<Option<String>, String> {
+    let profiles = list_power_profiles(backend)?;
+    let performance = backend.performance_profile();
+    if !profiles.iter().any(|profile| profile.name == performance) {
+        return Ok(None);
+    }
+    let Some(previous) = profiles.into_iter().find(|profile| profile.current) else {
+        return Ok(None);
+    };
+    if previous.name == performance {
+        return Ok(None);
+    }
+    set_power_profile(backend, performance)?;
+    Ok(Some(previous.name))
+}
+
+fn run(backend: PowerBackend, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(backend.program())
+        .args(args)
+        .output()
+        .map_err(|err| format!("Failed to run {}: {}", backend.program(), err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            backend.program(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Profiles are unindented `name:` lines, the active one marked with `*`;
+/// their driver details follow indented.
+fn parse_powerprofilesctl_list(output: &str) -> Vec<PowerProfile> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let current = line.starts_with('*');
+            let rest = line.trim_start_matches('*');
+            let indent = rest.len() - rest.trim_start().len();
+            if indent > 2 {
+                return None;
+            }
+            let name = rest.trim().strip_suffix(':')?;
+            (!name.is_empty() && !name.contains(char::is_whitespace)).then(|| PowerProfile {
+                name: name.to_string(),
+                current,
+            })
+        })
+        .collect()
+}
+
+/// `- name   - description` lines, followed by `Current active profile: name`
+fn parse_tuned_adm_list(output: &str) -> Vec<PowerProfile> {
+    let active = output
+        .lines()
+        .find_map(|line| line.strip_prefix("Current active profile:"))
+        .map(str::trim);
+
+    output
+        .lines()
+        .filter_map(|line| line.strip_prefix("- "))
+        .filter_map(|rest| rest.split_whitespace().next())
+        .map(|name| PowerProfile {
+            name: name.to_string(),
+            current: Some(name) == active,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(profiles: &[PowerProfile]) -> Vec<&str> {
+        profiles.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_powerprofilesctl_list() {
+        let output = "  performance:
+    CpuDriver:\tamd_pstate
+    PlatformDriver:\tplatform_profile
+    Degraded:   no
+
+* balanced:
+    CpuDriver:\tamd_pstate
+    PlatformDriver:\tplatform_profile
+
+  power-saver:
+    CpuDriver:\tamd_pstate
+    PlatformDriver:\tplatform_profile
+";
+        let profiles = parse_powerprofilesctl_list(output);
+        assert_eq!(
+            names(&profiles),
+            vec!["performance", "balanced", "power-saver"]
+        );
+        let current: Vec<bool> = profiles.iter().map(|p| p.current).collect();
+        assert_eq!(current, vec![false, true, false]);
+        assert_eq!(profiles[2].label(), "Power Saver");
+    }
+
+    #[test]
+    fn test_parse_powerprofilesctl_without_performance() {
+        // Machines without a platform profile driver only offer two profiles
+        let output = "* balanced:\n    PlatformDriver:\tplaceholder\n\n  power-saver:\n    PlatformDriver:\tplaceholder\n";
+        let profiles = parse_powerprofilesctl_list(output);
+        assert_eq!(names(&profiles), vec!["balanced", "power-saver"]);
+        assert!(profiles[0].current);
+    }
+
+    #[test]
+    fn test_parse_tuned_adm_list() {
+        let output = "Available profiles:
+- balanced                    - General non-specialized tuned profile
+- powersave                   - Optimize for low power consumption
+- throughput-performance      - Broadly applicable tuning for high throughput
+Current active profile: powersave
+";
+        let profiles = parse_tuned_adm_list(output);
+        assert_eq!(
+            names(&profiles),
+            vec!["balanced", "powersave", "throughput-performance"]
+        );
+        assert!(profiles[1].current);
+        assert!(!profiles[2].current);
+        assert_eq!(profiles[2].label(), "throughput-performance");
+    }
+}
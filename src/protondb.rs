@@ -0,0 +1,228 @@
+//! ProtonDB compatibility tiers of Steam games, shown as a badge on the tile.
+//!
+//! Tiers are cached per appid in the cache dir. Failures are only logged: the
+//! badge simply doesn't show when ProtonDB can't be reached.
+
+use crate::storage::project_dirs;
+use iced::Color;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use ureq::Agent;
+
+const SUMMARY_URL: &str = "https://www.protondb.com/api/v1/reports/summaries";
+const CACHE_FILE: &str = "protondb.json";
+
+/// Tiers shift as reports come in, so cached ones are refreshed after a week
+const CACHE_MAX_AGE_SECS: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProtonTier {
+    Native,
+    Platinum,
+    Gold,
+    Silver,
+    Bronze,
+    Borked,
+}
+
+impl ProtonTier {
+    /// Tier names used by the API; `pending` (too few reports) has no badge
+    pub fn from_api(tier: &str) -> Option<Self> {
+        match tier.trim().to_ascii_lowercase().as_str() {
+            "native" => Some(ProtonTier::Native),
+            "platinum" => Some(ProtonTier::Platinum),
+            "gold" => Some(ProtonTier::Gold),
+            "silver" => Some(ProtonTier::Silver),
+            "bronze" => Some(ProtonTier::Bronze),
+            "borked" => Some(ProtonTier::Borked),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProtonTier::Native => "Native",
+            ProtonTier::Platinum => "Platinum",
+            ProtonTier::Gold => "Gold",
+            ProtonTier::Silver => "Silver",
+            ProtonTier::Bronze => "Bronze",
+            ProtonTier::Borked => "Borked",
+        }
+    }
+
+    /// The colors ProtonDB itself uses for the tiers
+    pub fn badge_color(self) -> Color {
+        match self {
+            ProtonTier::Native => Color::from_rgb8(0x00, 0x80, 0x00),
+            ProtonTier::Platinum => Color::from_rgb8(0xb4, 0xc7, 0xdc),
+            ProtonTier::Gold => Color::from_rgb8(0xcf, 0xb5, 0x3b),
+            ProtonTier::Silver => Color::from_rgb8(0xa6, 0xa6, 0xa6),
+            ProtonTier::Bronze => Color::from_rgb8(0xcd, 0x7f, 0x32),
+            ProtonTier::Borked => Color::from_rgb8(0xff, 0x00, 0x00),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Summary {
+    tier: String,
+}
+
+/// `None` for pending and unknown tiers as well as unexpected responses
+fn parse_summary(body: &str) -> Option<ProtonTier> {
+    let summary: Summary = serde_json::from_str(body).ok()?;
+    ProtonTier::from_api(&summary.tier)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedTier {
+    /// `None` when ProtonDB has no rated reports yet
+    tier: Option<ProtonTier>,
+    fetched_at: i64,
+}
+
+impl CachedTier {
+    fn is_fresh(&self, now: i64) -> bool {
+        now - self.fetched_at < CACHE_MAX_AGE_SECS
+    }
+}
+
+/// Tiers of the given Steam appids; appids without a rated tier are left out.
+/// Stops asking ProtonDB after the first failed request, so an offline machine
+/// doesn't wait for a timeout per game.
+pub fn fetch_proton_tiers(appids: Vec<String>) -> HashMap<String, ProtonTier> {
+    let cache_path = project_dirs()
+        .ok()
+        .map(|dirs| dirs.cache_dir().join(CACHE_FILE));
+    let mut cache = cache_path.as_deref().map(load_cache).unwrap_or_default();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    let agent = Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(10)))
+        .build()
+        .new_agent();
+    let mut reachable = true;
+    let mut changed = false;
+
+    for appid in &appids {
+        if !reachable || cache.get(appid).is_some_and(|cached| cached.is_fresh(now)) {
+            continue;
+        }
+        let tier = match agent.get(format!("{}/{}.json", SUMMARY_URL, appid)).call() {
+            Ok(mut resp) => resp
+                .body_mut()
+                .read_to_string()
+                .ok()
+                .and_then(|body| parse_summary(&body)),
+            // No reports for this game yet
+            Err(ureq::Error::StatusCode(404)) => None,
+            Err(e) => {
+                tracing::debug!("ProtonDB lookup for {} failed: {}", appid, e);
+                reachable = false;
+                continue;
+            }
+        };
+        cache.insert(
+            appid.clone(),
+            CachedTier {
+                tier,
+                fetched_at: now,
+            },
+        );
+        changed = true;
+    }
+
+    if let (true, Some(path)) = (changed, cache_path.as_deref()) {
+        if let Err(e) = save_cache(path, &cache) {
+            tracing::warn!("Failed to cache ProtonDB tiers: {}", e);
+        }
+    }
+
+    appids
+        .into_iter()
+        .filter_map(|appid| {
+            let tier = cache.get(&appid)?.tier?;
+            Some((appid, tier))
+        })
+        .collect()
+}
+
+fn load_cache(path: &Path) -> HashMap<String, CachedTier> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_cache(path: &Path, cache: &HashMap<String, CachedTier>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string(cache).map_err(std::io::Error::other)?;
+    fs::write(path, json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tier_strings_map_to_badge_colors() {
+        assert_eq!(ProtonTier::from_api("platinum"), Some(ProtonTier::Platinum));
+        assert_eq!(ProtonTier::from_api("Gold"), Some(ProtonTier::Gold));
+        assert_eq!(ProtonTier::from_api("borked"), Some(ProtonTier::Borked));
+        assert_eq!(ProtonTier::from_api("pending"), None);
+        assert_eq!(ProtonTier::from_api(""), None);
+
+        assert_eq!(
+            ProtonTier::Gold.badge_color(),
+            Color::from_rgb8(0xcf, 0xb5, 0x3b)
+        );
+        assert_eq!(
+            ProtonTier::Borked.badge_color(),
+            Color::from_rgb8(0xff, 0x00, 0x00)
+        );
+        // Every tier is told apart by its color
+        let tiers = [
+            ProtonTier::Native,
+            ProtonTier::Platinum,
+            ProtonTier::Gold,
+            ProtonTier::Silver,
+            ProtonTier::Bronze,
+            ProtonTier::Borked,
+        ];
+        for (i, a) in tiers.iter().enumerate() {
+            for b in &tiers[i + 1..] {
+                assert_ne!(a.badge_color(), b.badge_color());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_summary() {
+        let body = r#"{"bestReportedTier":"platinum","confidence":"strong","score":0.78,
+            "tier":"gold","total":1234,"trendingTier":"platinum"}"#;
+        assert_eq!(parse_summary(body), Some(ProtonTier::Gold));
+
+        assert_eq!(parse_summary(r#"{"tier":"pending","total":1}"#), None);
+        assert_eq!(parse_summary("<html>Not Found</html>"), None);
+        assert_eq!(parse_summary("{}"), None);
+    }
+
+    #[test]
+    fn test_cached_tier_expires_after_a_week() {
+        let cached = CachedTier {
+            tier: Some(ProtonTier::Silver),
+            fetched_at: 1_000,
+        };
+        assert!(cached.is_fresh(1_000 + CACHE_MAX_AGE_SECS - 1));
+        assert!(!cached.is_fresh(1_000 + CACHE_MAX_AGE_SECS));
+    }
+}
@@ -0,0 +1,61 @@
+//! Title helpers shared by the ROM scanners. Titles come from the file name only:
+//! CHD disc images carry no title metadata entry, and their volume label sits in
+//! compressed hunks, so CHD titles are not read.
+
+use std::path::Path;
+
+/// Extract clean title from filename.
+/// Removes text in () and [] and extension.
+pub fn extract_title_from_filename(path: &Path) -> String {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+
+    let mut title = String::with_capacity(stem.len());
+    let mut depth_round = 0i32;
+    let mut depth_square = 0i32;
+
+    for c in stem.chars() {
+        match c {
+            '(' => depth_round += 1,
+            ')' => depth_round = depth_round.saturating_sub(1),
+            '[' => depth_square += 1,
+            ']' => depth_square = depth_square.saturating_sub(1),
+            c if depth_round == 0 && depth_square == 0 => title.push(c),
+            _ => {}
+        }
+    }
+
+    title.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_title_from_filename() {
+        assert_eq!(
+            extract_title_from_filename(Path::new("Super Mario World (USA).sfc")),
+            "Super Mario World"
+        );
+        assert_eq!(
+            extract_title_from_filename(Path::new("Chrono Trigger (USA) [!].smc")),
+            "Chrono Trigger"
+        );
+        assert_eq!(
+            extract_title_from_filename(Path::new("Donkey Kong Country.sfc")),
+            "Donkey Kong Country"
+        );
+        assert_eq!(
+            extract_title_from_filename(Path::new("Final Fantasy III (USA) (v1.1).smc")),
+            "Final Fantasy III"
+        );
+        assert_eq!(
+            extract_title_from_filename(Path::new("Mario Kart 64 (E) (V1.1) [!].z64")),
+            "Mario Kart 64"
+        );
+        assert_eq!(
+            extract_title_from_filename(Path::new("Super Mario 64.z64")),
+            "Super Mario 64"
+        );
+    }
+}
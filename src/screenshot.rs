@@ -0,0 +1,66 @@
+//! Saving screenshots of the launcher window for bug reports.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+
+/// Timestamped file name, e.g. `rhinco-tv-2024-05-01_20-15-03.png`
+pub fn screenshot_file_name(time: DateTime<Local>) -> String {
+    format!("rhinco-tv-{}.png", time.format("%Y-%m-%d_%H-%M-%S"))
+}
+
+/// The XDG Pictures directory, falling back to `~/Pictures` when it isn't configured
+pub fn screenshot_dir(pictures_dir: Option<&Path>, home_dir: Option<&Path>) -> Option<PathBuf> {
+    pictures_dir
+        .map(Path::to_path_buf)
+        .or_else(|| home_dir.map(|home| home.join("Pictures")))
+}
+
+/// Writes an RGBA frame as PNG into the Pictures directory and returns its path.
+pub fn save_screenshot(rgba: &[u8], width: u32, height: u32) -> Result<PathBuf, String> {
+    let user_dirs = directories::UserDirs::new();
+    let dir = screenshot_dir(
+        user_dirs.as_ref().and_then(|dirs| dirs.picture_dir()),
+        user_dirs.as_ref().map(|dirs| dirs.home_dir()),
+    )
+    .ok_or("Could not determine the Pictures directory")?;
+    fs::create_dir_all(&dir)
+        .map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+
+    let path = dir.join(screenshot_file_name(Local::now()));
+    image::save_buffer(&path, rgba, width, height, image::ExtendedColorType::Rgba8)
+        .map_err(|err| format!("Failed to save screenshot: {}", err))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_screenshot_file_name() {
+        let time = Local.with_ymd_and_hms(2024, 5, 1, 20, 15, 3).unwrap();
+        assert_eq!(
+            screenshot_file_name(time),
+            "rhinco-tv-2024-05-01_20-15-03.png"
+        );
+    }
+
+    #[test]
+    fn test_screenshot_dir_resolution() {
+        let pictures = Path::new("/home/user/Bilder");
+        let home = Path::new("/home/user");
+
+        assert_eq!(
+            screenshot_dir(Some(pictures), Some(home)),
+            Some(PathBuf::from("/home/user/Bilder"))
+        );
+        assert_eq!(
+            screenshot_dir(None, Some(home)),
+            Some(PathBuf::from("/home/user/Pictures"))
+        );
+        assert_eq!(screenshot_dir(None, None), None);
+    }
+}
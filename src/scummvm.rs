@@ -0,0 +1,262 @@
+//! Games added to ScummVM, read from the targets in `scummvm.ini` and started
+//! with `scummvm <target>`.
+
+use crate::game_sources::{GameSource, SourceResult};
+use crate::launcher::verify_command_exists;
+use crate::model::AppEntry;
+use directories::BaseDirs;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE: &str = "scummvm/scummvm.ini";
+const FLATPAK_ID: &str = "org.scummvm.ScummVM";
+
+/// File names, without extension, checked for artwork in a game's directory
+const ARTWORK_NAMES: &[&str] = &["cover", "folder", "boxart", "box"];
+const ARTWORK_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ScummvmTarget {
+    /// Section name, passed to ScummVM to start the game
+    target: String,
+    description: Option<String>,
+    path: Option<PathBuf>,
+}
+
+/// How ScummVM is started and where it keeps its config
+struct ScummvmInstall {
+    command: String,
+    config_path: PathBuf,
+}
+
+/// Games added to ScummVM
+pub struct ScummvmSource {
+    /// Looked up once per scan; `None` when ScummVM isn't installed
+    install: Option<ScummvmInstall>,
+}
+
+impl ScummvmSource {
+    pub fn new() -> Self {
+        Self {
+            install: find_install(),
+        }
+    }
+}
+
+impl GameSource for ScummvmSource {
+    fn name(&self) -> &'static str {
+        "ScummVM"
+    }
+
+    fn scan(&self) -> SourceResult {
+        let games = self
+            .install
+            .as_ref()
+            .map(scan_scummvm_games)
+            .unwrap_or_default();
+        Ok(games.into())
+    }
+
+    fn is_available(&self) -> bool {
+        self.install.is_some()
+    }
+}
+
+/// List the games added to ScummVM
+fn scan_scummvm_games(install: &ScummvmInstall) -> Vec<AppEntry> {
+    let Ok(content) = fs::read_to_string(&install.config_path) else {
+        return Vec::new();
+    };
+
+    parse_targets(&content)
+        .iter()
+        .map(|target| {
+            let game = target_entry(&install.command, target);
+            tracing::info!("Discovered ScummVM game: '{}'", game.name);
+            game
+        })
+        .collect()
+}
+
+/// The native package wins over the Flatpak, which keeps its config in its sandbox
+fn find_install() -> Option<ScummvmInstall> {
+    let base_dirs = BaseDirs::new()?;
+
+    if verify_command_exists("scummvm") {
+        return Some(ScummvmInstall {
+            command: "scummvm".to_string(),
+            config_path: base_dirs.config_dir().join(CONFIG_FILE),
+        });
+    }
+
+    let flatpak_installed = [
+        base_dirs.data_dir().join("flatpak/app"),
+        PathBuf::from("/var/lib/flatpak/app"),
+    ]
+    .iter()
+    .any(|dir| dir.join(FLATPAK_ID).is_dir());
+    flatpak_installed.then(|| ScummvmInstall {
+        command: format!("flatpak run {}", FLATPAK_ID),
+        config_path: base_dirs
+            .home_dir()
+            .join(Path::new(".var/app").join(FLATPAK_ID).join("config"))
+            .join(CONFIG_FILE),
+    })
+}
+
+fn target_entry(command: &str, target: &ScummvmTarget) -> AppEntry {
+    let exec = format!("{} {}", command, shell_quote(&target.target));
+    let name = target
+        .description
+        .clone()
+        .unwrap_or_else(|| target.target.clone());
+    let icon = target.path.as_deref().and_then(find_artwork);
+
+    AppEntry::new(name, exec, icon).with_launch_key(format!("scummvm:{}", target.target))
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Every section except `[scummvm]` (global options) and `[keymapper]` is a game
+/// target; sections without a `gameid` aren't games either.
+fn parse_targets(content: &str) -> Vec<ScummvmTarget> {
+    let mut targets = Vec::new();
+    let mut current: Option<(ScummvmTarget, bool)> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            targets.extend(current.take().filter(|(_, has_id)| *has_id).map(|(t, _)| t));
+            if section != "scummvm" && section != "keymapper" {
+                current = Some((
+                    ScummvmTarget {
+                        target: section.to_string(),
+                        description: None,
+                        path: None,
+                    },
+                    false,
+                ));
+            }
+            continue;
+        }
+        let (Some((target, has_id)), Some((key, value))) = (current.as_mut(), line.split_once('='))
+        else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "gameid" => *has_id = !value.is_empty(),
+            "description" if !value.is_empty() => target.description = Some(value.to_string()),
+            "path" if !value.is_empty() => target.path = Some(PathBuf::from(value)),
+            _ => {}
+        }
+    }
+    targets.extend(current.filter(|(_, has_id)| *has_id).map(|(t, _)| t));
+
+    targets
+}
+
+/// Artwork saved in the game's directory, e.g. `cover.jpg` or `Folder.png`
+fn find_artwork(game_dir: &Path) -> Option<String> {
+    let entries = fs::read_dir(game_dir).ok()?;
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| is_artwork(path))
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+fn is_artwork(path: &Path) -> bool {
+    let lower = |s: Option<&std::ffi::OsStr>| {
+        s.and_then(|s| s.to_str())
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default()
+    };
+    ARTWORK_NAMES.contains(&lower(path.file_stem()).as_str())
+        && ARTWORK_EXTENSIONS.contains(&lower(path.extension()).as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    const SAMPLE_CONFIG: &str = r#"[scummvm]
+gfx_mode=opengl
+fullscreen=true
+lastselectedgame=monkey1
+
+[monkey1]
+description=The Secret of Monkey Island (CD/DOS/English)
+path=/home/me/Games/ScummVM/Monkey Island
+engineid=scumm
+gameid=monkey
+
+[sky]
+gameid=sky
+path=/home/me/Games/ScummVM/Beneath a Steel Sky
+
+[keymapper]
+keymap_global_MENU=C+F5
+"#;
+
+    #[test]
+    fn test_parse_targets_from_config() {
+        assert_eq!(
+            parse_targets(SAMPLE_CONFIG),
+            vec![
+                ScummvmTarget {
+                    target: "monkey1".to_string(),
+                    description: Some("The Secret of Monkey Island (CD/DOS/English)".to_string()),
+                    path: Some(PathBuf::from("/home/me/Games/ScummVM/Monkey Island")),
+                },
+                ScummvmTarget {
+                    target: "sky".to_string(),
+                    description: None,
+                    path: Some(PathBuf::from("/home/me/Games/ScummVM/Beneath a Steel Sky")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_targets_skips_sections_without_gameid() {
+        assert!(parse_targets("[scummvm]\nversiontest=1\n[misc]\nfoo=bar\n").is_empty());
+        assert!(parse_targets("").is_empty());
+    }
+
+    #[test]
+    fn test_target_entry_uses_description_and_artwork() {
+        let dir = std::env::temp_dir().join(format!("launcher_test_scummvm_{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("MONKEY.000"), b"").unwrap();
+        fs::write(dir.join("Cover.JPG"), b"").unwrap();
+
+        let targets = parse_targets(SAMPLE_CONFIG);
+        let monkey = ScummvmTarget {
+            path: Some(dir.clone()),
+            ..targets[0].clone()
+        };
+        let entry = target_entry("scummvm", &monkey);
+        assert_eq!(entry.name, "The Secret of Monkey Island (CD/DOS/English)");
+        assert_eq!(entry.exec, "scummvm 'monkey1'");
+        assert_eq!(entry.launch_key.as_deref(), Some("scummvm:monkey1"));
+        assert_eq!(
+            entry.icon,
+            Some(dir.join("Cover.JPG").to_string_lossy().to_string())
+        );
+
+        // Without a description the target name is shown
+        let sky = target_entry("flatpak run org.scummvm.ScummVM", &targets[1]);
+        assert_eq!(sky.name, "sky");
+        assert_eq!(sky.exec, "flatpak run org.scummvm.ScummVM 'sky'");
+        assert_eq!(sky.icon, None);
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
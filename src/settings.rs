@@ -0,0 +1,622 @@
+use serde::{Deserialize, Serialize};
+
+use crate::appimage::default_appimage_dirs;
+use crate::controller_led::ControllerLedMode;
+use crate::custom_actions::CustomAction;
+use crate::game_exit::OnGameExit;
+use crate::gamepad::{StickDeadzone, MAX_DEADZONE, MIN_DEADZONE};
+use crate::icon_fit::IconFitSettings;
+use crate::image_cache::{CoverFormat, DEFAULT_COVER_MEMORY_CACHE, DEFAULT_COVER_QUALITY};
+use crate::keyboard_bindings::KeyboardBindings;
+use crate::model::SystemAction;
+use crate::sort_mode::SortModes;
+use crate::startup_scroll::StartCategory;
+use crate::steamgriddb::CoverResolution;
+use crate::tile_style::TileStyle;
+use crate::ui_density::Density;
+use crate::ui_theme::{MAX_UI_SCALE, MIN_UI_SCALE};
+use crate::window_mode::{WindowGeometry, WindowMode};
+
+/// Step used when adjusting a stick deadzone from the Settings modal
+const DEADZONE_STEP: f32 = 0.05;
+
+/// Step used when adjusting the UI scale override
+const UI_SCALE_STEP: f32 = 0.1;
+
+const DEFAULT_UI_SOUND_VOLUME: u8 = 50;
+const UI_SOUND_VOLUME_STEP: u8 = 10;
+
+const DEFAULT_RECENT_COUNT: usize = 8;
+const MAX_RECENT_COUNT: usize = 20;
+
+/// User preferences stored at the top level of `config.json`.
+/// Every field falls back to its default so older config files keep loading.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Open on the favorites-only quick launch screen instead of the full category view
+    pub start_in_quick_launch: bool,
+    /// Row selected in the full category view at startup
+    pub start_category: StartCategory,
+    /// Scroll gently from the top to the start row instead of jumping there
+    pub startup_scroll_animation: bool,
+    /// Draw the whale shark pattern background; a solid color is used when disabled
+    pub background_enabled: bool,
+    /// Show the blurred hero art of the selected game behind the rows
+    pub hero_backdrop: bool,
+    /// Spacing and padding of the tile grid
+    pub density: Density,
+    /// Fixed UI scale (1.0 = 100%) instead of the one derived from the window height
+    pub ui_scale_override: Option<f32>,
+    /// Left stick deadzone per axis; raise it for drifting sticks, lower it for stiff ones
+    pub stick_deadzone: StickDeadzone,
+    /// Terminal emulator for apps that run in a terminal; auto-detected when unset
+    pub terminal: Option<String>,
+    /// Format downloaded covers are re-encoded to; the original format is kept by default
+    pub cover_format: CoverFormat,
+    /// JPEG quality (1-100) used when `cover_format` is `jpeg`
+    pub cover_quality: u8,
+    /// SteamGridDB grid size downloaded; smaller tiers save bandwidth and memory
+    pub cover_resolution: CoverResolution,
+    /// Search the web via SearXNG for covers no other source provides
+    pub allow_web_image_search: bool,
+    /// Covers kept in memory so scrolling back doesn't reload them from disk; 0 disables
+    pub cover_memory_cache: usize,
+    /// Running inside Steam Big Picture: don't minimize on launch or recreate the window
+    /// afterwards. Auto-detected from Steam's env markers when unset.
+    pub embedded_mode: Option<bool>,
+    /// Switch the selection highlight instantly instead of fading between tiles
+    pub reduce_motion: bool,
+    /// Draw modal backdrops fully opaque so text stays readable over busy backgrounds
+    pub reduce_transparency: bool,
+    /// Overlay a small store/emulator badge on game tiles, plus one for Proton games
+    pub show_source_badges: bool,
+    /// Show the number of items next to each row title, e.g. "Games (142)"
+    pub show_category_counts: bool,
+    /// Show the ProtonDB rating on Steam game tiles, fetched from protondb.com
+    pub protondb_badges: bool,
+    /// Show a generated cover with the initials for items without artwork
+    pub placeholder_covers: bool,
+    /// Fullscreen (default), a regular window, or a borderless maximized window
+    pub window_mode: WindowMode,
+    /// Size and position restored in windowed mode; updated when the window closes
+    pub window_geometry: Option<WindowGeometry>,
+    /// Keyboard shortcut overrides per action; unlisted actions keep their default keys
+    pub keyboard_bindings: KeyboardBindings,
+    /// Swap the confirm and back face buttons (Nintendo layout)
+    pub swap_ab: bool,
+    /// Show the most recently launched games and apps as the first row
+    pub recent_row_enabled: bool,
+    /// Number of items in the recent row
+    pub recent_count: usize,
+    /// Rescan game sources in the background after returning from a game
+    pub rescan_on_return: bool,
+    /// Watch the Steam and Heroic directories and rescan when games are installed or removed
+    pub watch_game_dirs: bool,
+    /// Return to the launcher, stay minimized, or quit once a game exits
+    pub on_game_exit: OnGameExit,
+    /// Show games found in several sources as one tile with a source choice on launch
+    pub merge_duplicate_games: bool,
+    /// Order of the System row; actions left out follow in their default order
+    pub system_order: Vec<SystemAction>,
+    /// Show the clock as 24-hour time instead of 12-hour with AM/PM
+    pub clock_24h: bool,
+    /// Directories scanned for `*.AppImage` games; `~` expands to the home directory
+    pub appimage_dirs: Vec<String>,
+    /// Caps the games each source returns, e.g. for debugging huge libraries
+    pub max_games_per_source: Option<usize>,
+    /// Threads used to scan the game sources; unset uses one per CPU core
+    pub scan_threads: Option<usize>,
+    /// Shell commands appended to the System row
+    pub custom_system_actions: Vec<CustomAction>,
+    /// Letterbox (contain) or crop (cover) artwork per row
+    pub icon_fit: IconFitSettings,
+    /// Tile corner radius and selection glow
+    pub tile_style: TileStyle,
+    /// Suspend the system after this many seconds without input; unset disables it
+    pub auto_suspend_secs: Option<u64>,
+    /// Hide the mouse cursor when idle; unset hides it only in fullscreen
+    pub hide_cursor: Option<bool>,
+    /// Warn before launching a Steam game with less available memory (MiB); unset disables it
+    pub low_memory_warning_mb: Option<u64>,
+    /// Wait between minimizing the launcher and starting a game (ms)
+    pub launch_delay_ms: u64,
+    /// Wait between a game exiting and bringing the launcher back (ms)
+    pub exit_delay_ms: u64,
+    /// Ask to connect a controller while only keyboards are detected
+    pub controller_splash: bool,
+    /// Bring the launcher to the front while a game runs and the last controller disconnects
+    pub controller_loss_prompt: bool,
+    /// Switch to the performance power profile while a game runs
+    pub performance_while_gaming: bool,
+    /// Order of the Games and Apps rows, each cycled on its own
+    pub sort_modes: SortModes,
+    /// Pressing Back twice quickly on the home screen asks to quit the launcher
+    pub double_back_to_exit: bool,
+    /// Launch games from the gamepad only after holding Select; Enter stays instant
+    pub hold_to_launch: bool,
+    /// Moving past either end of a row continues at its other end
+    pub wrap_navigation: bool,
+    /// Play short sounds when navigating, confirming and going back
+    pub ui_sounds: bool,
+    /// Volume of the UI sounds in percent
+    pub ui_sound_volume: u8,
+    /// Capture the stderr of launched games and show its end when they fail right away
+    pub diagnose_launch_failures: bool,
+    /// Light bar color of DualSense/DualShock 4 pads: off, the accent color, or per player
+    pub controller_led: ControllerLedMode,
+    /// Poll gamepads less often after a few idle seconds to save battery
+    pub gamepad_power_saving: bool,
+    /// Whether the XDG autostart entry exists. Read from disk at startup rather than
+    /// stored, so removing the file by hand is picked up.
+    #[serde(skip)]
+    pub autostart: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            start_in_quick_launch: false,
+            start_category: StartCategory::default(),
+            startup_scroll_animation: false,
+            background_enabled: true,
+            hero_backdrop: false,
+            density: Density::default(),
+            ui_scale_override: None,
+            stick_deadzone: StickDeadzone::default(),
+            terminal: None,
+            cover_format: CoverFormat::default(),
+            cover_quality: DEFAULT_COVER_QUALITY,
+            cover_resolution: CoverResolution::default(),
+            allow_web_image_search: true,
+            cover_memory_cache: DEFAULT_COVER_MEMORY_CACHE,
+            embedded_mode: None,
+            reduce_motion: false,
+            reduce_transparency: false,
+            show_source_badges: true,
+            show_category_counts: false,
+            protondb_badges: false,
+            placeholder_covers: true,
+            window_mode: WindowMode::default(),
+            window_geometry: None,
+            keyboard_bindings: KeyboardBindings::default(),
+            swap_ab: false,
+            recent_row_enabled: true,
+            recent_count: DEFAULT_RECENT_COUNT,
+            rescan_on_return: false,
+            watch_game_dirs: false,
+            on_game_exit: OnGameExit::default(),
+            merge_duplicate_games: true,
+            system_order: SystemAction::DEFAULT_ORDER.to_vec(),
+            clock_24h: true,
+            appimage_dirs: default_appimage_dirs(),
+            max_games_per_source: None,
+            scan_threads: None,
+            custom_system_actions: Vec::new(),
+            icon_fit: IconFitSettings::default(),
+            tile_style: TileStyle::default(),
+            auto_suspend_secs: None,
+            hide_cursor: None,
+            low_memory_warning_mb: None,
+            launch_delay_ms: 0,
+            exit_delay_ms: 0,
+            controller_splash: false,
+            controller_loss_prompt: false,
+            performance_while_gaming: false,
+            sort_modes: SortModes::default(),
+            double_back_to_exit: false,
+            hold_to_launch: false,
+            wrap_navigation: false,
+            ui_sounds: false,
+            ui_sound_volume: DEFAULT_UI_SOUND_VOLUME,
+            diagnose_launch_failures: false,
+            controller_led: ControllerLedMode::default(),
+            gamepad_power_saving: false,
+            autostart: false,
+        }
+    }
+}
+
+/// A single row of the in-app Settings modal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsEntry {
+    StartInQuickLaunch,
+    StartCategory,
+    StartupScroll,
+    BackgroundEnabled,
+    HeroBackdrop,
+    Density,
+    UiScale,
+    ReduceMotion,
+    ReduceTransparency,
+    SourceBadges,
+    CategoryCounts,
+    ProtonDbBadges,
+    PlaceholderCovers,
+    SwapAB,
+    ControllerLed,
+    GamepadPowerSaving,
+    RecentRow,
+    RecentCount,
+    RescanOnReturn,
+    WatchGameDirs,
+    OnGameExit,
+    MergeDuplicates,
+    ClockFormat,
+    DoubleBackToExit,
+    HoldToLaunch,
+    WrapNavigation,
+    UiSounds,
+    UiSoundVolume,
+    Autostart,
+    DeadzoneHorizontal,
+    DeadzoneVertical,
+    /// Opens the stick calibration dialog instead of changing a value directly
+    CalibrateStick,
+    /// Opens the Manage Games view
+    ManageGames,
+    /// Opens the list of games still showing the fallback icon
+    MissingArtwork,
+    /// Asks to forget all launch timestamps
+    ClearHistory,
+    /// Opens the directory of `config.json` in the file manager
+    ConfigFolder,
+}
+
+impl SettingsEntry {
+    pub const ALL: &'static [SettingsEntry] = &[
+        SettingsEntry::StartInQuickLaunch,
+        SettingsEntry::StartCategory,
+        SettingsEntry::StartupScroll,
+        SettingsEntry::BackgroundEnabled,
+        SettingsEntry::HeroBackdrop,
+        SettingsEntry::Density,
+        SettingsEntry::UiScale,
+        SettingsEntry::ReduceMotion,
+        SettingsEntry::ReduceTransparency,
+        SettingsEntry::SourceBadges,
+        SettingsEntry::CategoryCounts,
+        SettingsEntry::ProtonDbBadges,
+        SettingsEntry::PlaceholderCovers,
+        SettingsEntry::SwapAB,
+        SettingsEntry::ControllerLed,
+        SettingsEntry::GamepadPowerSaving,
+        SettingsEntry::RecentRow,
+        SettingsEntry::RecentCount,
+        SettingsEntry::RescanOnReturn,
+        SettingsEntry::WatchGameDirs,
+        SettingsEntry::OnGameExit,
+        SettingsEntry::MergeDuplicates,
+        SettingsEntry::ClockFormat,
+        SettingsEntry::DoubleBackToExit,
+        SettingsEntry::HoldToLaunch,
+        SettingsEntry::WrapNavigation,
+        SettingsEntry::UiSounds,
+        SettingsEntry::UiSoundVolume,
+        SettingsEntry::Autostart,
+        SettingsEntry::DeadzoneHorizontal,
+        SettingsEntry::DeadzoneVertical,
+        SettingsEntry::CalibrateStick,
+        SettingsEntry::ManageGames,
+        SettingsEntry::MissingArtwork,
+        SettingsEntry::ClearHistory,
+        SettingsEntry::ConfigFolder,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingsEntry::StartInQuickLaunch => "Start in Quick Launch",
+            SettingsEntry::StartCategory => "Start Row",
+            SettingsEntry::StartupScroll => "Startup Scroll Animation",
+            SettingsEntry::BackgroundEnabled => "Pattern Background",
+            SettingsEntry::HeroBackdrop => "Game Art Background",
+            SettingsEntry::Density => "Tile Density",
+            SettingsEntry::UiScale => "Interface Scale",
+            SettingsEntry::ReduceMotion => "Reduce Motion",
+            SettingsEntry::ReduceTransparency => "Reduce Transparency",
+            SettingsEntry::SourceBadges => "Store Badges",
+            SettingsEntry::CategoryCounts => "Item Counts",
+            SettingsEntry::ProtonDbBadges => "ProtonDB Ratings",
+            SettingsEntry::PlaceholderCovers => "Generated Covers",
+            SettingsEntry::SwapAB => "Swap A/B (Nintendo Layout)",
+            SettingsEntry::ControllerLed => "Controller Light Bar",
+            SettingsEntry::GamepadPowerSaving => "Gamepad Power Saving",
+            SettingsEntry::RecentRow => "Recently Played Row",
+            SettingsEntry::RecentCount => "Recently Played Count",
+            SettingsEntry::RescanOnReturn => "Rescan After Playing",
+            SettingsEntry::WatchGameDirs => "Watch for New Games",
+            SettingsEntry::OnGameExit => "After a Game Exits",
+            SettingsEntry::MergeDuplicates => "Merge Duplicate Games",
+            SettingsEntry::ClockFormat => "24-Hour Clock",
+            SettingsEntry::DoubleBackToExit => "Double Back to Quit",
+            SettingsEntry::HoldToLaunch => "Hold to Launch Games",
+            SettingsEntry::WrapNavigation => "Wrap Around Rows",
+            SettingsEntry::UiSounds => "Interface Sounds",
+            SettingsEntry::UiSoundVolume => "Sound Volume",
+            SettingsEntry::Autostart => "Start on Login",
+            SettingsEntry::DeadzoneHorizontal => "Stick Deadzone (Horizontal)",
+            SettingsEntry::DeadzoneVertical => "Stick Deadzone (Vertical)",
+            SettingsEntry::CalibrateStick => "Calibrate Stick",
+            SettingsEntry::ManageGames => "Manage Games",
+            SettingsEntry::MissingArtwork => "Games Without Artwork",
+            SettingsEntry::ClearHistory => "Clear Launch History",
+            SettingsEntry::ConfigFolder => "Config Folder",
+        }
+    }
+
+    pub fn value_label(self, settings: &Settings) -> String {
+        match self {
+            SettingsEntry::StartInQuickLaunch => on_off(settings.start_in_quick_launch),
+            SettingsEntry::StartCategory => settings.start_category.label().to_string(),
+            SettingsEntry::StartupScroll => on_off(settings.startup_scroll_animation),
+            SettingsEntry::BackgroundEnabled => on_off(settings.background_enabled),
+            SettingsEntry::HeroBackdrop => on_off(settings.hero_backdrop),
+            SettingsEntry::Density => settings.density.label().to_string(),
+            SettingsEntry::UiScale => match settings.ui_scale_override {
+                Some(scale) => format!("{:.0}%", scale * 100.0),
+                None => "Auto".to_string(),
+            },
+            SettingsEntry::ReduceMotion => on_off(settings.reduce_motion),
+            SettingsEntry::ReduceTransparency => on_off(settings.reduce_transparency),
+            SettingsEntry::SourceBadges => on_off(settings.show_source_badges),
+            SettingsEntry::CategoryCounts => on_off(settings.show_category_counts),
+            SettingsEntry::ProtonDbBadges => on_off(settings.protondb_badges),
+            SettingsEntry::PlaceholderCovers => on_off(settings.placeholder_covers),
+            SettingsEntry::SwapAB => on_off(settings.swap_ab),
+            SettingsEntry::ControllerLed => settings.controller_led.label().to_string(),
+            SettingsEntry::GamepadPowerSaving => on_off(settings.gamepad_power_saving),
+            SettingsEntry::RecentRow => on_off(settings.recent_row_enabled),
+            SettingsEntry::RecentCount => settings.recent_count.to_string(),
+            SettingsEntry::RescanOnReturn => on_off(settings.rescan_on_return),
+            SettingsEntry::WatchGameDirs => on_off(settings.watch_game_dirs),
+            SettingsEntry::OnGameExit => settings.on_game_exit.label().to_string(),
+            SettingsEntry::MergeDuplicates => on_off(settings.merge_duplicate_games),
+            SettingsEntry::ClockFormat => on_off(settings.clock_24h),
+            SettingsEntry::DoubleBackToExit => on_off(settings.double_back_to_exit),
+            SettingsEntry::HoldToLaunch => on_off(settings.hold_to_launch),
+            SettingsEntry::WrapNavigation => on_off(settings.wrap_navigation),
+            SettingsEntry::UiSounds => on_off(settings.ui_sounds),
+            SettingsEntry::UiSoundVolume => format!("{}%", settings.ui_sound_volume),
+            SettingsEntry::Autostart => on_off(settings.autostart),
+            SettingsEntry::DeadzoneHorizontal => {
+                format!("{:.2}", settings.stick_deadzone.horizontal)
+            }
+            SettingsEntry::DeadzoneVertical => format!("{:.2}", settings.stick_deadzone.vertical),
+            SettingsEntry::CalibrateStick => "Start".to_string(),
+            SettingsEntry::ManageGames
+            | SettingsEntry::MissingArtwork
+            | SettingsEntry::ConfigFolder => "Open".to_string(),
+            SettingsEntry::ClearHistory => "Clear".to_string(),
+        }
+    }
+
+    /// Changes the setting by one step: toggles booleans and cycles choices
+    /// forward or backward.
+    pub fn adjust(self, settings: &mut Settings, forward: bool) {
+        match self {
+            SettingsEntry::StartInQuickLaunch => {
+                settings.start_in_quick_launch = !settings.start_in_quick_launch
+            }
+            SettingsEntry::StartCategory => {
+                settings.start_category = if forward {
+                    settings.start_category.next()
+                } else {
+                    settings.start_category.prev()
+                }
+            }
+            SettingsEntry::StartupScroll => {
+                settings.startup_scroll_animation = !settings.startup_scroll_animation
+            }
+            SettingsEntry::BackgroundEnabled => {
+                settings.background_enabled = !settings.background_enabled
+            }
+            SettingsEntry::HeroBackdrop => settings.hero_backdrop = !settings.hero_backdrop,
+            SettingsEntry::Density => {
+                settings.density = if forward {
+                    settings.density.next()
+                } else {
+                    settings.density.prev()
+                }
+            }
+            SettingsEntry::UiScale => {
+                settings.ui_scale_override = step_ui_scale(settings.ui_scale_override, forward)
+            }
+            SettingsEntry::ReduceMotion => settings.reduce_motion = !settings.reduce_motion,
+            SettingsEntry::ReduceTransparency => {
+                settings.reduce_transparency = !settings.reduce_transparency
+            }
+            SettingsEntry::SourceBadges => {
+                settings.show_source_badges = !settings.show_source_badges
+            }
+            SettingsEntry::CategoryCounts => {
+                settings.show_category_counts = !settings.show_category_counts
+            }
+            SettingsEntry::ProtonDbBadges => settings.protondb_badges = !settings.protondb_badges,
+            SettingsEntry::PlaceholderCovers => {
+                settings.placeholder_covers = !settings.placeholder_covers
+            }
+            SettingsEntry::SwapAB => settings.swap_ab = !settings.swap_ab,
+            SettingsEntry::ControllerLed => {
+                settings.controller_led = if forward {
+                    settings.controller_led.next()
+                } else {
+                    settings.controller_led.prev()
+                }
+            }
+            SettingsEntry::GamepadPowerSaving => {
+                settings.gamepad_power_saving = !settings.gamepad_power_saving
+            }
+            SettingsEntry::RecentRow => settings.recent_row_enabled = !settings.recent_row_enabled,
+            SettingsEntry::RecentCount => {
+                settings.recent_count = if forward {
+                    (settings.recent_count + 1).min(MAX_RECENT_COUNT)
+                } else {
+                    settings.recent_count.saturating_sub(1).max(1)
+                }
+            }
+            SettingsEntry::RescanOnReturn => settings.rescan_on_return = !settings.rescan_on_return,
+            SettingsEntry::WatchGameDirs => settings.watch_game_dirs = !settings.watch_game_dirs,
+            SettingsEntry::OnGameExit => {
+                settings.on_game_exit = if forward {
+                    settings.on_game_exit.next()
+                } else {
+                    settings.on_game_exit.prev()
+                }
+            }
+            SettingsEntry::MergeDuplicates => {
+                settings.merge_duplicate_games = !settings.merge_duplicate_games
+            }
+            SettingsEntry::ClockFormat => settings.clock_24h = !settings.clock_24h,
+            SettingsEntry::DoubleBackToExit => {
+                settings.double_back_to_exit = !settings.double_back_to_exit
+            }
+            SettingsEntry::HoldToLaunch => settings.hold_to_launch = !settings.hold_to_launch,
+            SettingsEntry::WrapNavigation => settings.wrap_navigation = !settings.wrap_navigation,
+            SettingsEntry::UiSounds => settings.ui_sounds = !settings.ui_sounds,
+            SettingsEntry::UiSoundVolume => {
+                settings.ui_sound_volume = if forward {
+                    settings
+                        .ui_sound_volume
+                        .saturating_add(UI_SOUND_VOLUME_STEP)
+                        .min(100)
+                } else {
+                    settings
+                        .ui_sound_volume
+                        .saturating_sub(UI_SOUND_VOLUME_STEP)
+                        .max(UI_SOUND_VOLUME_STEP)
+                }
+            }
+            SettingsEntry::Autostart => settings.autostart = !settings.autostart,
+            SettingsEntry::DeadzoneHorizontal => {
+                step_deadzone(&mut settings.stick_deadzone.horizontal, forward)
+            }
+            SettingsEntry::DeadzoneVertical => {
+                step_deadzone(&mut settings.stick_deadzone.vertical, forward)
+            }
+            SettingsEntry::CalibrateStick
+            | SettingsEntry::ManageGames
+            | SettingsEntry::MissingArtwork
+            | SettingsEntry::ClearHistory
+            | SettingsEntry::ConfigFolder => {}
+        }
+    }
+}
+
+fn step_deadzone(value: &mut f32, forward: bool) {
+    let step = if forward {
+        DEADZONE_STEP
+    } else {
+        -DEADZONE_STEP
+    };
+    // Snap to whole steps so repeated presses don't accumulate float error
+    let stepped = ((*value + step) / DEADZONE_STEP).round() * DEADZONE_STEP;
+    *value = stepped.clamp(MIN_DEADZONE, MAX_DEADZONE);
+}
+
+/// Auto sits before the smallest scale; leaving it starts at 100%
+fn step_ui_scale(value: Option<f32>, forward: bool) -> Option<f32> {
+    match (value, forward) {
+        (None, true) => Some(1.0),
+        (None, false) => None,
+        (Some(scale), false) if scale <= MIN_UI_SCALE => None,
+        (Some(scale), _) => {
+            let step = if forward {
+                UI_SCALE_STEP
+            } else {
+                -UI_SCALE_STEP
+            };
+            let stepped = ((scale + step) / UI_SCALE_STEP).round() * UI_SCALE_STEP;
+            Some(stepped.clamp(MIN_UI_SCALE, MAX_UI_SCALE))
+        }
+    }
+}
+
+fn on_off(value: bool) -> String {
+    if value { "On" } else { "Off" }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_background_enabled_by_default() {
+        assert!(Settings::default().background_enabled);
+
+        let loaded: Settings = serde_json::from_str("{}").unwrap();
+        assert!(loaded.background_enabled);
+    }
+
+    #[test]
+    fn test_adjust_toggles_background() {
+        let mut settings = Settings::default();
+
+        SettingsEntry::BackgroundEnabled.adjust(&mut settings, true);
+        assert!(!settings.background_enabled);
+        assert_eq!(
+            SettingsEntry::BackgroundEnabled.value_label(&settings),
+            "Off"
+        );
+
+        SettingsEntry::BackgroundEnabled.adjust(&mut settings, false);
+        assert!(settings.background_enabled);
+    }
+
+    #[test]
+    fn test_adjust_cycles_density_both_ways() {
+        let mut settings = Settings::default();
+
+        SettingsEntry::Density.adjust(&mut settings, true);
+        assert_eq!(settings.density, Density::Spacious);
+
+        SettingsEntry::Density.adjust(&mut settings, false);
+        SettingsEntry::Density.adjust(&mut settings, false);
+        assert_eq!(settings.density, Density::Compact);
+    }
+
+    #[test]
+    fn test_adjust_steps_deadzone_within_bounds() {
+        let mut settings = Settings::default();
+
+        SettingsEntry::DeadzoneHorizontal.adjust(&mut settings, false);
+        assert_eq!(
+            SettingsEntry::DeadzoneHorizontal.value_label(&settings),
+            "0.55"
+        );
+        assert_eq!(
+            SettingsEntry::DeadzoneVertical.value_label(&settings),
+            "0.60"
+        );
+
+        for _ in 0..20 {
+            SettingsEntry::DeadzoneVertical.adjust(&mut settings, true);
+        }
+        assert_eq!(settings.stick_deadzone.vertical, MAX_DEADZONE);
+
+        for _ in 0..20 {
+            SettingsEntry::DeadzoneHorizontal.adjust(&mut settings, false);
+        }
+        assert_eq!(settings.stick_deadzone.horizontal, MIN_DEADZONE);
+    }
+
+    #[test]
+    fn test_adjust_steps_ui_scale_through_auto() {
+        let mut settings = Settings::default();
+        assert_eq!(SettingsEntry::UiScale.value_label(&settings), "Auto");
+
+        SettingsEntry::UiScale.adjust(&mut settings, true);
+        SettingsEntry::UiScale.adjust(&mut settings, true);
+        assert_eq!(SettingsEntry::UiScale.value_label(&settings), "110%");
+
+        for _ in 0..6 {
+            SettingsEntry::UiScale.adjust(&mut settings, false);
+        }
+        assert_eq!(settings.ui_scale_override, Some(MIN_UI_SCALE));
+        SettingsEntry::UiScale.adjust(&mut settings, false);
+        assert_eq!(settings.ui_scale_override, None);
+
+        settings.ui_scale_override = Some(MAX_UI_SCALE);
+        SettingsEntry::UiScale.adjust(&mut settings, true);
+        assert_eq!(settings.ui_scale_override, Some(MAX_UI_SCALE));
+    }
+}
@@ -0,0 +1,158 @@
+//! One-time guided setup shown when no config file exists yet.
+
+use crate::settings::Settings;
+use crate::virtual_keyboard::VirtualKeyboard;
+
+/// SteamGridDB API keys are 32 hex characters
+const MAX_API_KEY_LENGTH: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupStep {
+    Welcome,
+    /// Shows which game sources were found by the first scan
+    GameSources,
+    /// Optional SteamGridDB API key for cover art, typed on the on-screen keyboard
+    ApiKey,
+    ClockFormat,
+    /// Confirm and back buttons, with the option to swap them
+    Controls,
+    Finish,
+}
+
+impl SetupStep {
+    pub const ALL: &'static [SetupStep] = &[
+        SetupStep::Welcome,
+        SetupStep::GameSources,
+        SetupStep::ApiKey,
+        SetupStep::ClockFormat,
+        SetupStep::Controls,
+        SetupStep::Finish,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            SetupStep::Welcome => "Welcome to RhincoTV",
+            SetupStep::GameSources => "Game Sources",
+            SetupStep::ApiKey => "Cover Art",
+            SetupStep::ClockFormat => "Clock",
+            SetupStep::Controls => "Controls",
+            SetupStep::Finish => "All Set",
+        }
+    }
+
+    /// Position of the step, starting at 1
+    pub fn number(self) -> usize {
+        Self::ALL.iter().position(|step| *step == self).unwrap_or(0) + 1
+    }
+
+    fn offset(self, delta: isize) -> Option<Self> {
+        let index = self.number() as isize - 1 + delta;
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| Self::ALL.get(index).copied())
+    }
+}
+
+pub struct SetupWizardState {
+    pub step: SetupStep,
+    pub api_key: VirtualKeyboard,
+    pub clock_24h: bool,
+    pub swap_ab: bool,
+}
+
+impl SetupWizardState {
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            step: SetupStep::Welcome,
+            api_key: VirtualKeyboard::new(String::new()).with_max_length(MAX_API_KEY_LENGTH),
+            clock_24h: settings.clock_24h,
+            swap_ab: settings.swap_ab,
+        }
+    }
+
+    /// Moves to the next step; returns `false` on the last one.
+    pub fn advance(&mut self) -> bool {
+        self.go(1)
+    }
+
+    /// Moves to the previous step; returns `false` on the first one.
+    pub fn back(&mut self) -> bool {
+        self.go(-1)
+    }
+
+    fn go(&mut self, delta: isize) -> bool {
+        match self.step.offset(delta) {
+            Some(step) => {
+                self.step = step;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Flips the choice of the current step, if it has one
+    pub fn toggle_choice(&mut self) {
+        match self.step {
+            SetupStep::ClockFormat => self.clock_24h = !self.clock_24h,
+            SetupStep::Controls => self.swap_ab = !self.swap_ab,
+            _ => {}
+        }
+    }
+
+    /// The entered API key, if any
+    pub fn api_key(&self) -> Option<String> {
+        let key = self.api_key.value().trim();
+        (!key.is_empty()).then(|| key.to_string())
+    }
+
+    pub fn apply(&self, settings: &mut Settings) {
+        settings.clock_24h = self.clock_24h;
+        settings.swap_ab = self.swap_ab;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_progression() {
+        let mut state = SetupWizardState::new(&Settings::default());
+        assert_eq!(state.step, SetupStep::Welcome);
+        assert!(!state.back());
+
+        let mut visited = vec![state.step];
+        while state.advance() {
+            visited.push(state.step);
+        }
+        assert_eq!(visited, SetupStep::ALL);
+        assert_eq!(state.step, SetupStep::Finish);
+        assert_eq!(state.step.number(), SetupStep::ALL.len());
+
+        assert!(state.back());
+        assert_eq!(state.step, SetupStep::Controls);
+    }
+
+    #[test]
+    fn test_choices_apply_to_settings() {
+        let mut settings = Settings::default();
+        let mut state = SetupWizardState::new(&settings);
+
+        // Toggling does nothing on steps without a choice
+        state.toggle_choice();
+        assert_eq!(state.clock_24h, settings.clock_24h);
+
+        state.step = SetupStep::ClockFormat;
+        state.toggle_choice();
+        state.step = SetupStep::Controls;
+        state.toggle_choice();
+        state.apply(&mut settings);
+
+        assert!(!settings.clock_24h);
+        assert!(settings.swap_ab);
+        assert_eq!(state.api_key(), None);
+
+        state.api_key.set_value("  abc123  ".to_string());
+        assert_eq!(state.api_key(), Some("abc123".to_string()));
+    }
+}
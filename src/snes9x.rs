@@ -1,17 +1,47 @@
+use crate::game_sources::{GameSource, SourceResult};
 use crate::model::AppEntry;
+use crate::rom_utils::extract_title_from_filename;
 use directories::BaseDirs;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// SNES ROMs in the directories of the snes9x config
+pub struct Snes9xSource {
+    /// Looked up once per scan; `None` when snes9x isn't installed
+    emulator_binary: Option<String>,
+}
+
+impl Snes9xSource {
+    pub fn new() -> Self {
+        Self {
+            emulator_binary: get_snes9x_binary(),
+        }
+    }
+}
+
+impl GameSource for Snes9xSource {
+    fn name(&self) -> &'static str {
+        "SNES9x"
+    }
+
+    fn scan(&self) -> SourceResult {
+        let games = self
+            .emulator_binary
+            .as_deref()
+            .map(scan_snes9x_games)
+            .unwrap_or_default();
+        Ok(games.into())
+    }
+
+    fn is_available(&self) -> bool {
+        self.emulator_binary.is_some()
+    }
+}
+
 /// Scan for SNES ROMs based on snes9x configuration
-pub fn scan_snes9x_games() -> Vec<AppEntry> {
+fn scan_snes9x_games(emulator_binary: &str) -> Vec<AppEntry> {
     let mut games = Vec::new();
-    let Some(emulator_binary) = get_snes9x_binary() else {
-        tracing::warn!("snes9x or snes9x-gtk is not installed; skipping ROM scan");
-        return games;
-    };
-
     if emulator_binary == "snes9x-gtk" {
         ensure_fullscreen_on_open();
     }
@@ -38,7 +68,7 @@ pub fn scan_snes9x_games() -> Vec<AppEntry> {
             for entry in entries.flatten() {
                 let path = entry.path();
                 if is_valid_extension(&path) {
-                    if let Some(game) = process_rom(&path, &emulator_binary) {
+                    if let Some(game) = process_rom(&path, emulator_binary) {
                         games.push(game);
                     }
                 }
@@ -224,29 +254,6 @@ fn find_cover(rom_path: &Path) -> Option<String> {
     })
 }
 
-/// Extract clean title from filename.
-/// Removes text in () and [] and extension.
-fn extract_title_from_filename(path: &Path) -> String {
-    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
-
-    let mut title = String::with_capacity(stem.len());
-    let mut depth_round = 0i32;
-    let mut depth_square = 0i32;
-
-    for c in stem.chars() {
-        match c {
-            '(' => depth_round += 1,
-            ')' => depth_round = depth_round.saturating_sub(1),
-            '[' => depth_square += 1,
-            ']' => depth_square = depth_square.saturating_sub(1),
-            c if depth_round == 0 && depth_square == 0 => title.push(c),
-            _ => {}
-        }
-    }
-
-    title.trim().to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,26 +267,6 @@ mod tests {
         dir
     }
 
-    #[test]
-    fn test_extract_title_from_filename() {
-        assert_eq!(
-            extract_title_from_filename(Path::new("Super Mario World (USA).sfc")),
-            "Super Mario World"
-        );
-        assert_eq!(
-            extract_title_from_filename(Path::new("Chrono Trigger (USA) [!].smc")),
-            "Chrono Trigger"
-        );
-        assert_eq!(
-            extract_title_from_filename(Path::new("Donkey Kong Country.sfc")),
-            "Donkey Kong Country"
-        );
-        assert_eq!(
-            extract_title_from_filename(Path::new("Final Fantasy III (USA) (v1.1).smc")),
-            "Final Fantasy III"
-        );
-    }
-
     #[test]
     fn test_valid_snes_extensions() {
         assert!(is_valid_extension(Path::new("game.sfc")));
@@ -544,11 +531,11 @@ mod tests {
 
     #[test]
     fn test_scan_returns_empty_when_emulator_missing() {
-        // This test verifies the function doesn't panic
-        // Result depends on whether snes9x is actually installed
-        let _games = scan_snes9x_games();
-        // If snes9x is not installed, returns empty vec
-        // If snes9x is installed, may return games depending on config
+        let source = Snes9xSource {
+            emulator_binary: None,
+        };
+        assert!(!source.is_available());
+        assert!(source.scan().unwrap().games.is_empty());
     }
 
     #[test]
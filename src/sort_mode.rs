@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::Category;
+
+/// Order of the items in a row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortMode {
+    /// Most recently started first, never started ones alphabetically after them
+    #[default]
+    Recent,
+    Alphabetical,
+}
+
+impl SortMode {
+    pub fn next(self) -> Self {
+        match self {
+            SortMode::Recent => SortMode::Alphabetical,
+            SortMode::Alphabetical => SortMode::Recent,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Recent => "Recently played",
+            SortMode::Alphabetical => "Alphabetical",
+        }
+    }
+}
+
+/// Sort mode per row, e.g. `{ "games": "recent", "apps": "alphabetical" }`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SortModes {
+    pub games: SortMode,
+    pub apps: SortMode,
+}
+
+impl SortModes {
+    /// Switches the category's row to its next mode and returns it; only the Games
+    /// and Apps rows are sortable, the others have a fixed order
+    pub fn cycle(&mut self, category: Category) -> Option<SortMode> {
+        let mode = match category {
+            Category::Games => &mut self.games,
+            Category::Apps => &mut self.apps,
+            Category::Recent | Category::Favorites | Category::System => return None,
+        };
+        *mode = mode.next();
+        Some(*mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycling_games_keeps_apps_sort() {
+        let mut modes: SortModes = serde_json::from_str(r#"{ "apps": "alphabetical" }"#).unwrap();
+        assert_eq!(modes.games, SortMode::Recent);
+
+        assert_eq!(modes.cycle(Category::Games), Some(SortMode::Alphabetical));
+        assert_eq!(modes.cycle(Category::Games), Some(SortMode::Recent));
+        assert_eq!(modes.apps, SortMode::Alphabetical);
+
+        assert_eq!(modes.cycle(Category::System), None);
+        assert_eq!(
+            modes,
+            SortModes {
+                games: SortMode::Recent,
+                apps: SortMode::Alphabetical,
+            }
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Row the main view opens on and the optional scroll towards it at startup.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::focus_animation::ease_out_cubic;
+use crate::model::Category;
+
+/// Length of the startup scroll from the top to the start row
+pub const STARTUP_SCROLL_DURATION: Duration = Duration::from_millis(700);
+
+/// Row selected when the launcher opens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartCategory {
+    Recent,
+    #[default]
+    Games,
+    Apps,
+    System,
+}
+
+impl StartCategory {
+    const ALL: [StartCategory; 4] = [
+        StartCategory::Recent,
+        StartCategory::Games,
+        StartCategory::Apps,
+        StartCategory::System,
+    ];
+
+    pub fn category(self) -> Category {
+        match self {
+            StartCategory::Recent => Category::Recent,
+            StartCategory::Games => Category::Games,
+            StartCategory::Apps => Category::Apps,
+            StartCategory::System => Category::System,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        self.category().title()
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let index = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// The row to open on: the configured one, or Games when it isn't shown
+/// (an empty or disabled recent row).
+pub fn initial_category(start: StartCategory, rows: &[Category]) -> Category {
+    let category = start.category();
+    if rows.contains(&category) {
+        category
+    } else {
+        Category::Games
+    }
+}
+
+/// Vertical scroll offset of `target`: the summed height of the rows above it.
+/// Rows not in the main view scroll to the top.
+pub fn row_offset(
+    rows: &[Category],
+    target: Category,
+    row_height: impl Fn(Category) -> f32,
+) -> f32 {
+    let Some(index) = rows.iter().position(|category| *category == target) else {
+        return 0.0;
+    };
+    rows[..index]
+        .iter()
+        .map(|category| row_height(*category))
+        .sum()
+}
+
+/// Eased scroll of the main view from the top to the start row.
+pub struct StartupScroll {
+    target_y: f32,
+    started_at: Instant,
+}
+
+impl StartupScroll {
+    pub fn new(target_y: f32, now: Instant) -> Self {
+        Self {
+            target_y,
+            started_at: now,
+        }
+    }
+
+    /// Offset at `now`, and whether the scroll has arrived
+    pub fn offset_at(&self, now: Instant) -> (f32, bool) {
+        let elapsed = now.saturating_duration_since(self.started_at);
+        let t = elapsed.as_secs_f32() / STARTUP_SCROLL_DURATION.as_secs_f32();
+        (self.target_y * ease_out_cubic(t), t >= 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROWS: [Category; 4] = [
+        Category::Recent,
+        Category::Games,
+        Category::Apps,
+        Category::System,
+    ];
+
+    #[test]
+    fn test_initial_scroll_target_matches_start_category() {
+        let height = |category: Category| match category {
+            Category::Recent => 100.0,
+            Category::Games => 300.0,
+            _ => 150.0,
+        };
+
+        let category = initial_category(StartCategory::Apps, &ROWS);
+        assert_eq!(category, Category::Apps);
+        assert_eq!(row_offset(&ROWS, category, height), 400.0);
+
+        let category = initial_category(StartCategory::Recent, &ROWS);
+        assert_eq!(row_offset(&ROWS, category, height), 0.0);
+
+        // Without a recent row the games row is at the top
+        let rows = &ROWS[1..];
+        let category = initial_category(StartCategory::Recent, rows);
+        assert_eq!(category, Category::Games);
+        assert_eq!(row_offset(rows, category, height), 0.0);
+    }
+
+    #[test]
+    fn test_startup_scroll_arrives_at_target() {
+        let now = Instant::now();
+        let scroll = StartupScroll::new(400.0, now);
+        assert_eq!(scroll.offset_at(now), (0.0, false));
+        assert_eq!(
+            scroll.offset_at(now + STARTUP_SCROLL_DURATION),
+            (400.0, true)
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Store details of Steam games (developers, genres, release date, description),
+//! fetched from the public appdetails endpoint and cached on disk per appid.
+
+use crate::storage::project_dirs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+use ureq::Agent;
+
+const APPDETAILS_URL: &str = "https://store.steampowered.com/api/appdetails";
+
+/// What the details panel shows for a Steam game
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameMetadata {
+    pub developers: Vec<String>,
+    pub genres: Vec<String>,
+    pub release_date: Option<String>,
+    pub short_description: Option<String>,
+}
+
+impl GameMetadata {
+    /// Delisted games and tools have no store page, so nothing is known about them
+    pub fn is_empty(&self) -> bool {
+        self.developers.is_empty()
+            && self.genres.is_empty()
+            && self.release_date.is_none()
+            && self.short_description.is_none()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum MetadataError {
+    #[error("Steam is limiting requests, try again in a few minutes")]
+    RateLimited,
+    #[error("Steam store unreachable: {0}")]
+    Network(String),
+    #[error("Unexpected Steam store response: {0}")]
+    Malformed(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct AppDetailsEntry {
+    success: bool,
+    #[serde(default)]
+    data: Option<AppDetailsData>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct AppDetailsData {
+    short_description: String,
+    developers: Vec<String>,
+    genres: Vec<Genre>,
+    release_date: Option<ReleaseDate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Genre {
+    description: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ReleaseDate {
+    coming_soon: bool,
+    date: String,
+}
+
+/// Metadata of a Steam game, from the disk cache or the store. Unknown appids are
+/// cached as empty metadata, so they aren't requested again.
+pub fn fetch_game_metadata(appid: &str) -> Result<GameMetadata, MetadataError> {
+    let cache = MetadataCache::new();
+    if let Some(metadata) = cache.as_ref().and_then(|cache| cache.get(appid)) {
+        return Ok(metadata);
+    }
+
+    let body = request_app_details(appid)?;
+    let metadata = parse_app_details(appid, &body)?;
+    if let Some(cache) = cache {
+        if let Err(e) = cache.store(appid, &metadata) {
+            tracing::warn!("Failed to cache store details of {}: {}", appid, e);
+        }
+    }
+    Ok(metadata)
+}
+
+fn request_app_details(appid: &str) -> Result<String, MetadataError> {
+    let agent = Agent::config_builder()
+        .timeout_global(Some(Duration::from_secs(10)))
+        .build()
+        .new_agent();
+
+    let response = agent
+        .get(APPDETAILS_URL)
+        .query("appids", appid)
+        .query("l", "english")
+        .call();
+    match response {
+        Ok(mut resp) => resp
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| MetadataError::Network(e.to_string())),
+        // The store answers 429, or 403 for a while after, once an IP asks too often
+        Err(ureq::Error::StatusCode(429 | 403)) => Err(MetadataError::RateLimited),
+        Err(e) => Err(MetadataError::Network(e.to_string())),
+    }
+}
+
+/// The response maps the appid to `{"success": bool, "data": {...}}`. A rate
+/// limited request gets a `null` body instead of an HTTP error.
+fn parse_app_details(appid: &str, body: &str) -> Result<GameMetadata, MetadataError> {
+    let entries: Option<HashMap<String, AppDetailsEntry>> =
+        serde_json::from_str(body).map_err(|e| MetadataError::Malformed(e.to_string()))?;
+    let mut entries = entries.ok_or(MetadataError::RateLimited)?;
+    let entry = entries
+        .remove(appid)
+        .ok_or_else(|| MetadataError::Malformed(format!("no entry for appid {}", appid)))?;
+
+    let Some(data) = entry.data.filter(|_| entry.success) else {
+        return Ok(GameMetadata::default());
+    };
+
+    let release_date = data.release_date.and_then(|release| {
+        let date = release.date.trim().to_string();
+        match (date.is_empty(), release.coming_soon) {
+            (true, true) => Some("Coming soon".to_string()),
+            (true, false) => None,
+            (false, true) => Some(format!("{} (coming soon)", date)),
+            (false, false) => Some(date),
+        }
+    });
+    let short_description = Some(plain_text(&data.short_description)).filter(|d| !d.is_empty());
+
+    Ok(GameMetadata {
+        developers: data.developers,
+        genres: data.genres.into_iter().map(|g| g.description).collect(),
+        release_date,
+        short_description,
+    })
+}
+
+/// Store texts are HTML: tags are dropped and the common entities decoded
+fn plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+/// One JSON file per appid below the cache dir
+struct MetadataCache {
+    dir: PathBuf,
+}
+
+impl MetadataCache {
+    fn new() -> Option<Self> {
+        let dirs = project_dirs().ok()?;
+        Some(Self::in_dir(&dirs.cache_dir().join("steam_metadata")))
+    }
+
+    fn in_dir(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    fn path(&self, appid: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", appid))
+    }
+
+    fn get(&self, appid: &str) -> Option<GameMetadata> {
+        let content = fs::read_to_string(self.path(appid)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn store(&self, appid: &str, metadata: &GameMetadata) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let json = serde_json::to_string(metadata).map_err(std::io::Error::other)?;
+        fs::write(self.path(appid), json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    const SAMPLE_RESPONSE: &str = r#"{"620":{"success":true,"data":{
+        "type":"game","name":"Portal 2","steam_appid":620,
+        "short_description":"The &quot;Perpetual Testing Initiative&quot; has been expanded to allow you to design co-op puzzles for you and your friends!<br>",
+        "developers":["Valve"],"publishers":["Valve"],
+        "genres":[{"id":"1","description":"Action"},{"id":"25","description":"Adventure"}],
+        "release_date":{"coming_soon":false,"date":"18 Apr, 2011"}}}}"#;
+
+    #[test]
+    fn test_parse_app_details() {
+        let metadata = parse_app_details("620", SAMPLE_RESPONSE).unwrap();
+        assert_eq!(
+            metadata,
+            GameMetadata {
+                developers: vec!["Valve".to_string()],
+                genres: vec!["Action".to_string(), "Adventure".to_string()],
+                release_date: Some("18 Apr, 2011".to_string()),
+                short_description: Some(
+                    "The \"Perpetual Testing Initiative\" has been expanded to allow you to design co-op puzzles for you and your friends!"
+                        .to_string()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_app_details_missing_data() {
+        // Delisted or unknown appid
+        let unknown = parse_app_details("1", r#"{"1":{"success":false}}"#).unwrap();
+        assert!(unknown.is_empty());
+
+        // Only some fields present
+        let sparse = parse_app_details(
+            "2",
+            r#"{"2":{"success":true,"data":{"release_date":{"coming_soon":true,"date":""}}}}"#,
+        )
+        .unwrap();
+        assert_eq!(sparse.release_date.as_deref(), Some("Coming soon"));
+        assert!(sparse.developers.is_empty());
+        assert_eq!(sparse.short_description, None);
+
+        assert!(matches!(
+            parse_app_details("3", "null"),
+            Err(MetadataError::RateLimited)
+        ));
+        assert!(matches!(
+            parse_app_details("4", r#"{"5":{"success":false}}"#),
+            Err(MetadataError::Malformed(_))
+        ));
+        assert!(matches!(
+            parse_app_details("6", "<html>"),
+            Err(MetadataError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_metadata_cache_round_trip() {
+        let dir = std::env::temp_dir().join(format!("launcher_test_metadata_{}", Uuid::new_v4()));
+        let cache = MetadataCache::in_dir(&dir);
+        assert_eq!(cache.get("620"), None);
+
+        let metadata = parse_app_details("620", SAMPLE_RESPONSE).unwrap();
+        cache.store("620", &metadata).unwrap();
+        assert_eq!(cache.get("620"), Some(metadata));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
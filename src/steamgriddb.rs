@@ -1,15 +1,56 @@
 use anyhow::{Context, Result};
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use ureq::Agent;
 
 const API_BASE_URL: &str = "https://www.steamgriddb.com/api/v2";
 
+/// Size of the vertical grids requested from SteamGridDB. Smaller tiers download
+/// faster and take less memory to decode and draw.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverResolution {
+    /// 342x482
+    Small,
+    /// 600x900
+    #[default]
+    Standard,
+    /// 660x930
+    Large,
+}
+
+impl CoverResolution {
+    pub fn dimensions(self) -> (u32, u32) {
+        match self {
+            CoverResolution::Small => (342, 482),
+            CoverResolution::Standard => (600, 900),
+            CoverResolution::Large => (660, 930),
+        }
+    }
+
+    /// Value of the `dimensions` filter of the grids endpoint
+    fn query_value(self) -> &'static str {
+        match self {
+            CoverResolution::Small => "342x482",
+            CoverResolution::Standard => "600x900",
+            CoverResolution::Large => "660x930",
+        }
+    }
+
+    /// Covers are shrunk to the tile size on screen, but never stored larger than
+    /// the tier, so a small tier also keeps the cache small on 4K screens
+    pub fn target_size(self, display_width: u32, display_height: u32) -> (u32, u32) {
+        let (width, height) = self.dimensions();
+        (display_width.min(width), display_height.min(height))
+    }
+}
+
 #[derive(Clone)]
 pub struct SteamGridDbClient {
     agent: Agent,
     api_key: String,
+    base_url: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -21,7 +62,15 @@ struct SearchResponse {
 #[derive(Debug, Deserialize)]
 struct GameResponse {
     success: bool,
-    data: GameData,
+    /// Missing when the lookup failed, e.g. for an unknown appid
+    #[serde(default)]
+    data: Option<GameData>,
+}
+
+impl GameResponse {
+    fn game_id(self) -> Option<u64> {
+        self.data.filter(|_| self.success).map(|game| game.id)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,15 +92,28 @@ pub struct GridData {
 
 impl SteamGridDbClient {
     pub fn new(api_key: String) -> Self {
+        Self::with_base_url(api_key, API_BASE_URL.to_string())
+    }
+
+    pub fn with_base_url(api_key: String, base_url: String) -> Self {
         let agent = Agent::config_builder()
             .timeout_global(Some(Duration::from_secs(10)))
             .build()
             .new_agent();
-        Self { agent, api_key }
+        Self {
+            agent,
+            api_key,
+            base_url,
+        }
+    }
+
+    /// Every SteamGridDB endpoint needs a key; without one all requests fail
+    pub fn has_api_key(&self) -> bool {
+        !self.api_key.trim().is_empty()
     }
 
     fn get<T: DeserializeOwned>(&self, path: &str, params: &[(&str, &str)]) -> Result<T> {
-        let url = format!("{}{}", API_BASE_URL, path);
+        let url = format!("{}{}", self.base_url, path);
         let mut req = self
             .agent
             .get(&url)
@@ -97,14 +159,14 @@ impl SteamGridDbClient {
         Ok(Some(search_resp.data[0].id))
     }
 
-    pub fn get_game_by_steam_appid(&self, appid: &str) -> Result<Option<u64>> {
-        let appid = appid.trim();
-        if appid.is_empty() {
+    /// Looks up the SteamGridDB game for a Steam appid, which is exact unlike the
+    /// name search.
+    pub fn game_by_steam_appid(&self, appid: &str) -> Result<Option<u64>> {
+        let Some(path) = steam_appid_path(appid) else {
             return Ok(None);
-        }
+        };
 
-        let path = format!("/games/steam/{}", appid);
-        let search_resp: GameResponse = match self.get(&path, &[]) {
+        let game_resp: GameResponse = match self.get(&path, &[]) {
             Ok(r) => r,
             Err(e) => {
                 tracing::warn!("SGDB AppID lookup failed for '{}': {}", appid, e);
@@ -112,17 +174,16 @@ impl SteamGridDbClient {
             }
         };
 
-        if search_resp.success {
-            Ok(Some(search_resp.data.id))
-        } else {
-            Ok(None)
-        }
+        Ok(game_resp.game_id())
     }
 
-    pub fn get_images_for_game(&self, game_id: u64) -> Result<Vec<GridData>> {
+    pub fn get_images_for_game(
+        &self,
+        game_id: u64,
+        resolution: CoverResolution,
+    ) -> Result<Vec<GridData>> {
         let path = format!("/grids/game/{}", game_id);
-        // We prefer 600x900 vertical grids
-        let grid_resp: GridResponse = match self.get(&path, &[("dimensions", "600x900")]) {
+        let grid_resp: GridResponse = match self.get(&path, &grid_params(resolution)) {
             Ok(r) => r,
             Err(e) => {
                 tracing::warn!("SGDB Grid fetch failed for game_id {}: {}", game_id, e);
@@ -136,6 +197,38 @@ impl SteamGridDbClient {
             Ok(Vec::new())
         }
     }
+
+    /// Wide banner artwork (heroes) of a game, used as the backdrop
+    pub fn get_heroes_for_game(&self, game_id: u64) -> Result<Vec<GridData>> {
+        let path = format!("/heroes/game/{}", game_id);
+        let hero_resp: GridResponse = match self.get(&path, &[]) {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::warn!("SGDB Hero fetch failed for game_id {}: {}", game_id, e);
+                return Ok(Vec::new());
+            }
+        };
+
+        if hero_resp.success {
+            Ok(hero_resp.data)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+}
+
+/// Query of the grids request: only vertical grids of the chosen tier
+fn grid_params(resolution: CoverResolution) -> [(&'static str, &'static str); 1] {
+    [("dimensions", resolution.query_value())]
+}
+
+/// API path of the game with the given Steam appid; only numeric appids are valid.
+fn steam_appid_path(appid: &str) -> Option<String> {
+    let appid = appid.trim();
+    if appid.is_empty() || !appid.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("/games/steam/{}", appid))
 }
 
 #[cfg(test)]
@@ -143,6 +236,59 @@ mod tests {
     use super::*;
     use std::env;
 
+    #[test]
+    fn test_steam_appid_path() {
+        assert_eq!(
+            steam_appid_path("504230").as_deref(),
+            Some("/games/steam/504230")
+        );
+        assert_eq!(
+            steam_appid_path(" 48000 ").as_deref(),
+            Some("/games/steam/48000")
+        );
+        assert_eq!(steam_appid_path(""), None);
+        assert_eq!(steam_appid_path("48000/../1"), None);
+    }
+
+    #[test]
+    fn test_grid_params_per_resolution() {
+        assert_eq!(
+            grid_params(CoverResolution::Small),
+            [("dimensions", "342x482")]
+        );
+        assert_eq!(
+            grid_params(CoverResolution::default()),
+            [("dimensions", "600x900")]
+        );
+        assert_eq!(
+            grid_params(CoverResolution::Large),
+            [("dimensions", "660x930")]
+        );
+    }
+
+    #[test]
+    fn test_cover_target_size_capped_by_tier() {
+        // 4K tile: the download's own size is the limit
+        assert_eq!(CoverResolution::Small.target_size(800, 1200), (342, 482));
+        assert_eq!(CoverResolution::Standard.target_size(800, 1200), (600, 900));
+        // 1080p tile: already smaller than every tier
+        assert_eq!(CoverResolution::Small.target_size(200, 300), (200, 300));
+        assert_eq!(CoverResolution::Large.target_size(200, 300), (200, 300));
+    }
+
+    #[test]
+    fn test_parse_game_response() {
+        let found: GameResponse = serde_json::from_str(
+            r#"{ "success": true, "data": { "id": 2590, "name": "Celeste", "verified": true } }"#,
+        )
+        .unwrap();
+        assert_eq!(found.game_id(), Some(2590));
+
+        let missing: GameResponse =
+            serde_json::from_str(r#"{ "success": false, "errors": ["Game not found"] }"#).unwrap();
+        assert_eq!(missing.game_id(), None);
+    }
+
     fn get_api_key() -> Option<String> {
         env::var("STEAMGRIDDB_API_KEY").ok()
     }
@@ -167,18 +313,20 @@ mod tests {
     }
 
     #[test]
-    fn test_get_game_by_steam_appid_integration() {
+    fn test_game_by_steam_appid_integration() {
         let api_key = match get_api_key() {
             Some(key) => key,
             None => {
-                println!("Skipping test_get_game_by_steam_appid_integration: STEAMGRIDDB_API_KEY not set");
+                println!(
+                    "Skipping test_game_by_steam_appid_integration: STEAMGRIDDB_API_KEY not set"
+                );
                 return;
             }
         };
 
         let client = SteamGridDbClient::new(api_key);
         // Celeste Steam AppID is 504230
-        let result = client.get_game_by_steam_appid("504230");
+        let result = client.game_by_steam_appid("504230");
         assert!(result.is_ok());
         let game_id = result.unwrap();
         assert!(game_id.is_some());
@@ -199,14 +347,14 @@ mod tests {
 
         // 1. Search by AppID (Limbo = 48000)
         println!("Testing Limbo Lookup by AppID (48000)...");
-        let result_id = client.get_game_by_steam_appid("48000");
+        let result_id = client.game_by_steam_appid("48000");
         assert!(result_id.is_ok());
         let game_id = result_id.unwrap().expect("Should find Limbo by AppID");
         println!("Found Limbo ID: {}", game_id);
 
         // 2. Fetch Images
         println!("Fetching images for Limbo (ID: {})...", game_id);
-        let images = client.get_images_for_game(game_id);
+        let images = client.get_images_for_game(game_id, CoverResolution::default());
         assert!(images.is_ok());
         let grids = images.unwrap();
         assert!(!grids.is_empty(), "Should find at least one grid for Limbo");
@@ -1,10 +1,17 @@
 use crate::model::AppEntry;
+use crate::settings::Settings;
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Minimum time between two config writes; changes in between are saved together
+pub const SAVE_DEBOUNCE: Duration = Duration::from_secs(3);
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppConfig {
@@ -14,6 +21,37 @@ pub struct AppConfig {
     /// Games are scanned fresh each startup, so we persist their launch history separately
     #[serde(default)]
     pub game_launch_history: HashMap<String, i64>,
+    /// Launch keys of items the user marked as favorite
+    #[serde(default)]
+    pub favorites: HashSet<String>,
+    /// Per-item wrapper commands such as the gamescope 16:9 preset, keyed by launch key
+    #[serde(default)]
+    pub launch_prefixes: HashMap<String, String>,
+    /// Per-item launch options (with optional `%command%`), keyed by launch key
+    #[serde(default)]
+    pub launch_options: HashMap<String, String>,
+    /// Launch keys of games hidden from the games row via Manage Games
+    #[serde(default)]
+    pub hidden_games: HashSet<String>,
+    /// SteamGridDB game IDs picked via "Fix Artwork", keyed by launch key
+    #[serde(default)]
+    pub artwork_overrides: HashMap<String, u64>,
+    /// Launch keys of games that use their store's image instead of SteamGridDB art
+    #[serde(default)]
+    pub prefer_source_art: HashSet<String>,
+    /// Whether the first run setup wizard was finished or skipped. Only a fresh
+    /// config (no file yet) starts with `false`; older files count as set up.
+    #[serde(default = "existing_config_is_set_up")]
+    pub setup_completed: bool,
+    /// Whether the hint about the missing SteamGridDB API key was shown already
+    #[serde(default)]
+    pub sgdb_key_hint_shown: bool,
+    #[serde(flatten)]
+    pub settings: Settings,
+}
+
+fn existing_config_is_set_up() -> bool {
+    true
 }
 
 /// Returns the project directories for this application.
@@ -32,25 +70,101 @@ pub fn config_path() -> Result<PathBuf> {
     Ok(config_dir.join("config.json"))
 }
 
+/// Directory holding `config.json`, for opening it in a file manager
+pub fn config_dir() -> Result<PathBuf> {
+    let path = config_path()?;
+    path.parent()
+        .map(Path::to_path_buf)
+        .context("Config file has no parent directory")
+}
+
 /// Load application configuration from disk
 pub fn load_config() -> Result<AppConfig> {
-    let path = config_path()?;
+    load_config_from(&config_path()?)
+}
+
+/// Loads the configuration stored at `path`, or the defaults when there is no file yet
+pub fn load_config_from(path: &Path) -> Result<AppConfig> {
     if !path.exists() {
         return Ok(AppConfig::default());
     }
 
-    let content = fs::read_to_string(&path).context("Failed to read config file")?;
+    let content = fs::read_to_string(path).context("Failed to read config file")?;
     let config = serde_json::from_str::<AppConfig>(&content).context("Failed to parse config")?;
     Ok(config)
 }
 
-pub fn save_config(config: &AppConfig) -> Result<()> {
-    let path = config_path()?;
-    let content = serde_json::to_string_pretty(config).context("Failed to serialize config")?;
-    fs::write(&path, content).context("Failed to write config file")?;
+/// Writes the configuration to `path`, see [`write_json_atomic`]
+pub fn save_config_to(path: &Path, config: &AppConfig) -> Result<()> {
+    write_json_atomic::<AppConfig>(path, config)
+}
+
+/// Serializes `value` and replaces `path` with it atomically: the JSON is checked to
+/// parse back, written to a temp file next to `path`, synced, and renamed into place.
+/// On any error the existing file is left untouched.
+fn write_json_atomic<T: Serialize + DeserializeOwned>(path: &Path, value: &T) -> Result<()> {
+    let content = serde_json::to_string_pretty(value).context("Failed to serialize config")?;
+    serde_json::from_str::<T>(&content).context("Serialized config does not parse back")?;
+
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let write_tmp = || -> Result<()> {
+        let mut file = fs::File::create(&tmp_path).context("Failed to create temp config file")?;
+        file.write_all(content.as_bytes())
+            .context("Failed to write temp config file")?;
+        file.sync_all().context("Failed to sync temp config file")?;
+        Ok(())
+    };
+    if let Err(err) = write_tmp() {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err);
+    }
+
+    fs::rename(&tmp_path, path).context("Failed to replace config file")?;
     Ok(())
 }
 
+/// Limits config writes to one per [`SAVE_DEBOUNCE`]; requests in between are
+/// remembered and flushed once the interval has passed.
+#[derive(Debug, Default)]
+pub struct SaveDebouncer {
+    last_write: Option<Instant>,
+    pending: bool,
+}
+
+impl SaveDebouncer {
+    /// Returns `true` when the write may happen now, otherwise marks it as pending.
+    pub fn request(&mut self, now: Instant) -> bool {
+        if self.is_throttled(now) {
+            self.pending = true;
+            return false;
+        }
+        self.last_write = Some(now);
+        self.pending = false;
+        true
+    }
+
+    /// Returns `true` when a deferred write is due and should be performed now.
+    pub fn take_due(&mut self, now: Instant) -> bool {
+        if !self.pending || self.is_throttled(now) {
+            return false;
+        }
+        self.request(now)
+    }
+
+    /// Returns `true` when a write is pending, regardless of the interval (e.g. on exit).
+    pub fn take_pending(&mut self) -> bool {
+        std::mem::take(&mut self.pending)
+    }
+
+    fn is_throttled(&self, now: Instant) -> bool {
+        self.last_write
+            .is_some_and(|last| now.saturating_duration_since(last) < SAVE_DEBOUNCE)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,6 +182,24 @@ mod tests {
             ],
             steamgriddb_api_key: Some("test-key".into()),
             game_launch_history: game_history,
+            favorites: HashSet::from(["steam:570".to_string()]),
+            launch_prefixes: HashMap::from([(
+                "snes9x:/roms/a.sfc".to_string(),
+                "gamescope -W 1920 -H 1080 -f --".to_string(),
+            )]),
+            launch_options: HashMap::from([(
+                "steam:570".to_string(),
+                "-novid %command%".to_string(),
+            )]),
+            hidden_games: HashSet::from(["steam:400".to_string()]),
+            artwork_overrides: HashMap::from([("steam:400".to_string(), 5247542)]),
+            prefer_source_art: HashSet::from(["heroic:gog:1207658930".to_string()]),
+            setup_completed: true,
+            sgdb_key_hint_shown: true,
+            settings: Settings {
+                start_in_quick_launch: true,
+                ..Default::default()
+            },
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -76,5 +208,102 @@ mod tests {
         assert_eq!(config.apps, loaded.apps);
         assert_eq!(config.steamgriddb_api_key, loaded.steamgriddb_api_key);
         assert_eq!(config.game_launch_history, loaded.game_launch_history);
+        assert_eq!(config.favorites, loaded.favorites);
+        assert_eq!(config.launch_prefixes, loaded.launch_prefixes);
+        assert_eq!(config.launch_options, loaded.launch_options);
+        assert_eq!(config.hidden_games, loaded.hidden_games);
+        assert_eq!(config.artwork_overrides, loaded.artwork_overrides);
+        assert_eq!(config.prefer_source_art, loaded.prefer_source_art);
+        assert_eq!(config.setup_completed, loaded.setup_completed);
+        assert_eq!(config.sgdb_key_hint_shown, loaded.sgdb_key_hint_shown);
+        assert_eq!(config.settings, loaded.settings);
+    }
+
+    fn temp_config_path() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rhinco-config-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("config.json")
+    }
+
+    #[test]
+    fn test_write_json_atomic_replaces_file() {
+        let path = temp_config_path();
+        fs::write(&path, "old").unwrap();
+
+        let config = AppConfig {
+            favorites: HashSet::from(["steam:570".to_string()]),
+            ..Default::default()
+        };
+        write_json_atomic(&path, &config).unwrap();
+
+        let loaded: AppConfig = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(loaded.favorites, config.favorites);
+        // No temp file left behind
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_failed_serialization_keeps_existing_file() {
+        let path = temp_config_path();
+        fs::write(&path, r#"{"apps": []}"#).unwrap();
+
+        // JSON object keys must be strings, so this map cannot be serialized
+        let unserializable: HashMap<(u8, u8), u8> = HashMap::from([((1, 2), 3)]);
+        assert!(write_json_atomic(&path, &unserializable).is_err());
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"{"apps": []}"#);
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_save_debouncer() {
+        let start = Instant::now();
+        let mut debouncer = SaveDebouncer::default();
+
+        assert!(debouncer.request(start));
+        assert!(!debouncer.request(start + Duration::from_secs(1)));
+        assert!(!debouncer.take_due(start + Duration::from_secs(2)));
+        assert!(debouncer.take_due(start + SAVE_DEBOUNCE));
+        // Nothing left to flush
+        assert!(!debouncer.take_due(start + SAVE_DEBOUNCE * 3));
+        assert!(!debouncer.take_pending());
+
+        assert!(!debouncer.request(start + SAVE_DEBOUNCE + Duration::from_secs(1)));
+        assert!(debouncer.take_pending());
+        assert!(!debouncer.take_pending());
+    }
+
+    #[test]
+    fn test_missing_settings_fall_back_to_defaults() {
+        let json = r#"{"apps": [], "steamgriddb_api_key": null}"#;
+        let loaded: AppConfig = serde_json::from_str(json).unwrap();
+
+        assert!(loaded.favorites.is_empty());
+        assert_eq!(loaded.settings, Settings::default());
+    }
+
+    #[test]
+    fn test_setup_wizard_only_for_fresh_configs() {
+        // No config file yet: load_config returns the default
+        assert!(!AppConfig::default().setup_completed);
+
+        // Config files written before the wizard existed skip it
+        let loaded: AppConfig = serde_json::from_str(r#"{"apps": []}"#).unwrap();
+        assert!(loaded.setup_completed);
+
+        let loaded: AppConfig =
+            serde_json::from_str(r#"{"apps": [], "setup_completed": false}"#).unwrap();
+        assert!(!loaded.setup_completed);
+    }
+
+    #[test]
+    fn test_config_dir_contains_config_file() {
+        let dir = config_dir().unwrap();
+        assert_eq!(config_path().unwrap(), dir.join("config.json"));
+        assert!(dir.is_dir());
     }
 }
@@ -0,0 +1,154 @@
+//! Store page links for games, so users can check DLC and updates.
+
+use crate::launcher::STEAM_APPLAUNCH_PREFIX;
+use crate::model::{LauncherAction, LauncherItem};
+
+/// Where a game's store page is opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorePage {
+    /// Opened in the Steam client
+    Steam { appid: String },
+    /// Opened in the default browser
+    Web { url: String },
+}
+
+impl StorePage {
+    /// Program and argument that open the page
+    pub fn command(&self) -> (&'static str, String) {
+        match self {
+            StorePage::Steam { appid } => ("steam", format!("steam://store/{}", appid)),
+            StorePage::Web { url } => ("xdg-open", url.clone()),
+        }
+    }
+}
+
+/// Derives the store page from the launch key (`steam:<appid>`, `heroic:<store>:<app>`)
+/// or, for Steam, the `-applaunch` exec line. Returns `None` for items without a store,
+/// such as emulated ROMs, sideloaded Heroic games, and Amazon games.
+pub fn store_page(item: &LauncherItem) -> Option<StorePage> {
+    if let Some(appid) = steam_appid(item) {
+        return Some(StorePage::Steam { appid });
+    }
+
+    let launch_key = item.launch_key.as_deref()?;
+    let (store, _app_name) = launch_key.strip_prefix("heroic:")?.split_once(':')?;
+
+    // Heroic stores product ids rather than store slugs, so search by title
+    let title = urlencoding::encode(item.name.trim());
+    let url = match store {
+        "gog" => format!("https://www.gog.com/en/games?query={}", title),
+        "legendary" => format!("https://store.epicgames.com/browse?q={}", title),
+        _ => return None,
+    };
+    Some(StorePage::Web { url })
+}
+
+/// Numeric appid of a Steam game, from its launch key or `-applaunch` exec line
+pub fn steam_appid(item: &LauncherItem) -> Option<String> {
+    let from_key = item
+        .launch_key
+        .as_deref()
+        .and_then(|key| key.strip_prefix("steam:"));
+    let from_exec = match &item.action {
+        LauncherAction::Launch { exec } => exec.strip_prefix(STEAM_APPLAUNCH_PREFIX),
+        _ => None,
+    };
+
+    from_key
+        .or(from_exec)
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(name: &str, launch_key: Option<&str>, exec: &str) -> LauncherItem {
+        LauncherItem {
+            name: name.to_string(),
+            launch_key: launch_key.map(String::from),
+            action: LauncherAction::Launch {
+                exec: exec.to_string(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_steam_store_page() {
+        let item = game("Dota 2", Some("steam:570"), "steam -applaunch 570");
+        let page = store_page(&item).unwrap();
+        assert_eq!(
+            page,
+            StorePage::Steam {
+                appid: "570".to_string()
+            }
+        );
+        assert_eq!(page.command(), ("steam", "steam://store/570".to_string()));
+
+        // Exec line alone is enough
+        let item = game("Portal", None, "steam -applaunch 400");
+        assert_eq!(
+            store_page(&item),
+            Some(StorePage::Steam {
+                appid: "400".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_heroic_store_pages() {
+        let item = game(
+            "The Witcher 3",
+            Some("heroic:gog:1207664643"),
+            "xdg-open heroic://launch/gog/1207664643",
+        );
+        assert_eq!(
+            store_page(&item).unwrap().command(),
+            (
+                "xdg-open",
+                "https://www.gog.com/en/games?query=The%20Witcher%203".to_string()
+            )
+        );
+
+        let item = game(
+            "Fortnite",
+            Some("heroic:legendary:Fortnite"),
+            "xdg-open heroic://launch/legendary/Fortnite",
+        );
+        assert_eq!(
+            store_page(&item),
+            Some(StorePage::Web {
+                url: "https://store.epicgames.com/browse?q=Fortnite".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_games_without_store_have_no_page() {
+        let rom = game(
+            "Super Mario World",
+            Some("snes9x:/roms/smw.sfc"),
+            "snes9x /roms/smw.sfc",
+        );
+        assert_eq!(store_page(&rom), None);
+
+        let sideloaded = game(
+            "Tool",
+            Some("heroic:MyApp"),
+            "xdg-open heroic://launch/MyApp",
+        );
+        assert_eq!(store_page(&sideloaded), None);
+
+        let amazon = game(
+            "Amazon Game",
+            Some("heroic:nile:amzn1.adg.product.1234"),
+            "xdg-open heroic://launch/nile/amzn1.adg.product.1234",
+        );
+        assert_eq!(store_page(&amazon), None);
+
+        assert_eq!(store_page(&LauncherItem::default()), None);
+    }
+}
@@ -38,3 +38,50 @@ fn exec(command: &mut process::Command) -> io::Error {
         Err(err) => err,
     }
 }
+
+/// Env markers set by Steam for processes started from Big Picture / the gamepad UI
+const STEAM_GAMEPAD_UI_MARKERS: &[&str] = &["SteamTenfoot", "SteamGamepadUI"];
+/// Set for anything launched through the Steam runtime
+const STEAM_RUNTIME_MARKER: &str = "STEAM_RUNTIME";
+
+/// Whether the launcher runs embedded in Steam Big Picture, which already owns
+/// window focus and fullscreen handling.
+pub fn detect_embedded_mode() -> bool {
+    embedded_mode_from_env(|key| env::var(key).ok())
+}
+
+fn embedded_mode_from_env(lookup: impl Fn(&str) -> Option<String>) -> bool {
+    let gamepad_ui = STEAM_GAMEPAD_UI_MARKERS
+        .iter()
+        .any(|key| lookup(key).is_some_and(|value| value == "1"));
+
+    gamepad_ui || lookup(STEAM_RUNTIME_MARKER).is_some_and(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn detect(vars: &[(&str, &str)]) -> bool {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        embedded_mode_from_env(|key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn test_embedded_mode_from_env() {
+        assert!(!detect(&[]));
+        assert!(!detect(&[("XDG_SESSION_TYPE", "wayland")]));
+        assert!(detect(&[("SteamTenfoot", "1")]));
+        assert!(detect(&[("SteamGamepadUI", "1")]));
+        assert!(!detect(&[("SteamTenfoot", "0")]));
+        assert!(detect(&[(
+            "STEAM_RUNTIME",
+            "/home/user/.steam/root/ubuntu12_32/steam-runtime"
+        )]));
+        assert!(!detect(&[("STEAM_RUNTIME", "")]));
+    }
+}
@@ -1,8 +1,11 @@
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::display_capabilities::{detect_display_capabilities, DisplayCapabilities};
+use crate::updater::{load_update_history, UpdateRecord};
+
 #[derive(Debug, Clone, Default)]
 pub struct DiskInfo {
     pub mount_point: String,
@@ -42,6 +45,35 @@ pub struct GameModeInfo {
     pub active: bool,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct FanInfo {
+    pub label: String,
+    pub rpm: u32,
+}
+
+/// Temperatures in °C, read from hwmon
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThermalInfo {
+    pub cpu_temp: Option<f32>,
+    pub gpu_temp: Option<f32>,
+    pub fans: Vec<FanInfo>,
+}
+
+impl ThermalInfo {
+    pub fn is_empty(&self) -> bool {
+        self.cpu_temp.is_none() && self.gpu_temp.is_none() && self.fans.is_empty()
+    }
+}
+
+const HWMON_ROOT: &str = "/sys/class/hwmon";
+/// hwmon driver names of CPU sensors (Intel, AMD, ARM SoCs)
+const CPU_HWMON_NAMES: &[&str] = &["coretemp", "k10temp", "zenpower", "cpu_thermal"];
+/// hwmon driver names of GPU sensors (NVIDIA's proprietary driver has none)
+const GPU_HWMON_NAMES: &[&str] = &["amdgpu", "radeon", "nouveau"];
+/// Preferred sensor labels, best first: die/package temps for CPUs, edge for GPUs
+const CPU_TEMP_LABELS: &[&str] = &["Package id 0", "Tdie", "Tctl"];
+const GPU_TEMP_LABELS: &[&str] = &["edge"];
+
 #[derive(Debug, Clone, Default)]
 pub struct GamingSystemInfo {
     pub os_name: String,
@@ -61,6 +93,10 @@ pub struct GamingSystemInfo {
     pub controllers: Vec<ControllerInfo>,
     pub kernel_tweaks: KernelTweaks,
     pub gamemode: GameModeInfo,
+    pub thermals: ThermalInfo,
+    pub displays: Vec<DisplayCapabilities>,
+    /// Self-updates of the launcher, oldest first
+    pub update_history: Vec<UpdateRecord>,
 }
 
 pub fn fetch_system_info() -> GamingSystemInfo {
@@ -79,6 +115,9 @@ pub fn fetch_system_info() -> GamingSystemInfo {
     let controllers = get_controllers();
     let kernel_tweaks = get_kernel_tweaks();
     let gamemode = get_gamemode_info();
+    let thermals = read_thermals(Path::new(HWMON_ROOT));
+    let displays = detect_display_capabilities();
+    let update_history = load_update_history();
 
     GamingSystemInfo {
         os_name,
@@ -98,6 +137,9 @@ pub fn fetch_system_info() -> GamingSystemInfo {
         controllers,
         kernel_tweaks,
         gamemode,
+        thermals,
+        displays,
+        update_history,
     }
 }
 
@@ -139,18 +181,52 @@ fn get_cpu_model() -> String {
 }
 
 fn get_memory_info() -> (String, String) {
-    if let Ok(output) = Command::new("free").arg("-h").output() {
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        for line in output_str.lines() {
-            if line.starts_with("Mem:") {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() >= 3 {
-                    return (parts[1].to_string(), parts[2].to_string());
-                }
-            }
-        }
+    match free_memory_columns().as_deref() {
+        Some([total, used, ..]) => (total.clone(), used.clone()),
+        _ => ("Unknown".to_string(), "Unknown".to_string()),
     }
-    ("Unknown".to_string(), "Unknown".to_string())
+}
+
+/// Available memory as reported by `free -h`, e.g. `5.2Gi`
+pub fn get_available_memory() -> Option<String> {
+    free_memory_columns()?.get(5).cloned()
+}
+
+/// Values of the `Mem:` line of `free -h`: total, used, free, shared, buff/cache, available
+fn free_memory_columns() -> Option<Vec<String>> {
+    let output = Command::new("free").arg("-h").output().ok()?;
+    parse_free_memory_line(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn parse_free_memory_line(output: &str) -> Option<Vec<String>> {
+    let line = output.lines().find(|line| line.starts_with("Mem:"))?;
+    let columns: Vec<String> = line.split_whitespace().skip(1).map(String::from).collect();
+    (columns.len() >= 2).then_some(columns)
+}
+
+/// Parses a `free -h` size such as `7.5Gi`, `512Mi` or `0B` into MiB
+fn parse_human_size_mib(value: &str) -> Option<f64> {
+    let unit_start = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(unit_start);
+    // Some locales print a decimal comma
+    let number: f64 = number.replace(',', ".").parse().ok()?;
+    let mib_per_unit = match unit.trim_end_matches('B').trim_end_matches('i') {
+        "" => 1.0 / (1024.0 * 1024.0),
+        "K" => 1.0 / 1024.0,
+        "M" => 1.0,
+        "G" => 1024.0,
+        "T" => 1024.0 * 1024.0,
+        "P" => 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some(number * mib_per_unit)
+}
+
+/// Whether a `free -h` size is below `threshold_mb` MiB; unparsable sizes never are
+pub fn is_memory_below(available: &str, threshold_mb: u64) -> bool {
+    parse_human_size_mib(available).is_some_and(|mib| mib < threshold_mb as f64)
 }
 
 fn get_gpu_info() -> (String, String) {
@@ -545,3 +621,225 @@ fn get_gamemode_info() -> GameModeInfo {
 
     GameModeInfo { available, active }
 }
+
+/// Reads CPU/GPU temperatures and fan speeds from every `hwmonN` directory below `root`.
+/// Machines without hwmon (or without readable sensors) yield an empty result.
+fn read_thermals(root: &Path) -> ThermalInfo {
+    let mut thermals = ThermalInfo::default();
+
+    let Ok(entries) = fs::read_dir(root) else {
+        return thermals;
+    };
+    let mut chips: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    chips.sort();
+
+    for chip in chips {
+        let name = read_trimmed(&chip.join("name")).unwrap_or_default();
+
+        if CPU_HWMON_NAMES.contains(&name.as_str()) && thermals.cpu_temp.is_none() {
+            thermals.cpu_temp = pick_temperature(&chip, CPU_TEMP_LABELS);
+        } else if GPU_HWMON_NAMES.contains(&name.as_str()) && thermals.gpu_temp.is_none() {
+            thermals.gpu_temp = pick_temperature(&chip, GPU_TEMP_LABELS);
+        }
+
+        thermals.fans.extend(read_fans(&chip, &name));
+    }
+
+    thermals
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+/// Indices `N` of the `<prefix>N_input` files in a hwmon directory, in ascending order
+fn sensor_indices(chip: &Path, prefix: &str) -> Vec<u32> {
+    let Ok(entries) = fs::read_dir(chip) else {
+        return Vec::new();
+    };
+
+    let mut indices: Vec<u32> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_str()?;
+            file_name
+                .strip_prefix(prefix)?
+                .strip_suffix("_input")?
+                .parse()
+                .ok()
+        })
+        .collect();
+    indices.sort_unstable();
+    indices
+}
+
+/// Temperature of the first sensor matching a preferred label, else the first sensor
+fn pick_temperature(chip: &Path, preferred_labels: &[&str]) -> Option<f32> {
+    let sensors: Vec<(Option<String>, f32)> = sensor_indices(chip, "temp")
+        .into_iter()
+        .filter_map(|i| {
+            let millidegrees: i64 = read_trimmed(&chip.join(format!("temp{}_input", i)))?
+                .parse()
+                .ok()?;
+            let label = read_trimmed(&chip.join(format!("temp{}_label", i)));
+            Some((label, millidegrees as f32 / 1000.0))
+        })
+        .collect();
+
+    preferred_labels
+        .iter()
+        .find_map(|wanted| {
+            sensors
+                .iter()
+                .find(|(label, _)| label.as_deref() == Some(*wanted))
+        })
+        .or(sensors.first())
+        .map(|(_, temp)| *temp)
+}
+
+fn read_fans(chip: &Path, chip_name: &str) -> Vec<FanInfo> {
+    sensor_indices(chip, "fan")
+        .into_iter()
+        .filter_map(|i| {
+            let rpm = read_trimmed(&chip.join(format!("fan{}_input", i)))?
+                .parse()
+                .ok()?;
+            let label = read_trimmed(&chip.join(format!("fan{}_label", i)))
+                .filter(|label| !label.is_empty())
+                .unwrap_or_else(|| format!("{} fan {}", chip_name, i));
+            Some(FanInfo { label, rpm })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeHwmon {
+        root: PathBuf,
+    }
+
+    impl FakeHwmon {
+        fn new() -> Self {
+            let root =
+                std::env::temp_dir().join(format!("launcher_test_hwmon_{}", uuid::Uuid::new_v4()));
+            fs::create_dir_all(&root).unwrap();
+            Self { root }
+        }
+
+        fn chip(&self, dir: &str, name: &str, files: &[(&str, &str)]) {
+            let chip = self.root.join(dir);
+            fs::create_dir_all(&chip).unwrap();
+            fs::write(chip.join("name"), format!("{}\n", name)).unwrap();
+            for (file, content) in files {
+                fs::write(chip.join(file), format!("{}\n", content)).unwrap();
+            }
+        }
+    }
+
+    impl Drop for FakeHwmon {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    #[test]
+    fn test_read_thermals_picks_package_and_edge_temps() {
+        let hwmon = FakeHwmon::new();
+        hwmon.chip(
+            "hwmon0",
+            "nvme",
+            &[("temp1_input", "38850"), ("temp1_label", "Composite")],
+        );
+        hwmon.chip(
+            "hwmon1",
+            "k10temp",
+            &[
+                ("temp1_input", "61250"),
+                ("temp1_label", "Tctl"),
+                ("temp3_input", "55000"),
+                ("temp3_label", "Tccd1"),
+            ],
+        );
+        hwmon.chip(
+            "hwmon2",
+            "amdgpu",
+            &[
+                ("temp2_input", "71000"),
+                ("temp2_label", "junction"),
+                ("temp1_input", "58000"),
+                ("temp1_label", "edge"),
+                ("fan1_input", "1450"),
+            ],
+        );
+        hwmon.chip(
+            "hwmon3",
+            "nct6775",
+            &[("fan2_input", "900"), ("fan2_label", "CPU Fan")],
+        );
+
+        let thermals = read_thermals(&hwmon.root);
+        assert_eq!(thermals.cpu_temp, Some(61.25));
+        assert_eq!(thermals.gpu_temp, Some(58.0));
+        assert_eq!(
+            thermals.fans,
+            vec![
+                FanInfo {
+                    label: "amdgpu fan 1".to_string(),
+                    rpm: 1450
+                },
+                FanInfo {
+                    label: "CPU Fan".to_string(),
+                    rpm: 900
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_thermals_falls_back_to_first_sensor() {
+        let hwmon = FakeHwmon::new();
+        hwmon.chip(
+            "hwmon0",
+            "coretemp",
+            &[("temp2_input", "48000"), ("temp1_input", "not a number")],
+        );
+
+        let thermals = read_thermals(&hwmon.root);
+        assert_eq!(thermals.cpu_temp, Some(48.0));
+        assert_eq!(thermals.gpu_temp, None);
+    }
+
+    #[test]
+    fn test_read_thermals_without_hwmon() {
+        let thermals = read_thermals(Path::new("/nonexistent/hwmon"));
+        assert!(thermals.is_empty());
+
+        let hwmon = FakeHwmon::new();
+        assert!(read_thermals(&hwmon.root).is_empty());
+    }
+
+    #[test]
+    fn test_memory_threshold_from_free_output() {
+        let output =
+            "               total        used        free      shared  buff/cache   available
+Mem:            15Gi       9.8Gi       1.1Gi       512Mi       4.6Gi       5.2Gi
+Swap:          8.0Gi       1.0Gi       7.0Gi
+";
+        let columns = parse_free_memory_line(output).unwrap();
+        assert_eq!(columns[0], "15Gi");
+        let available = &columns[5];
+        assert_eq!(available, "5.2Gi");
+
+        assert!(is_memory_below(available, 6144));
+        assert!(!is_memory_below(available, 4096));
+        assert!(is_memory_below("900Mi", 1024));
+        assert!(!is_memory_below("1,5Gi", 1024));
+        assert!(is_memory_below("0B", 1));
+        // Unknown formats never trigger the warning
+        assert!(!is_memory_below("lots", 4096));
+        assert_eq!(parse_free_memory_line("Swap: 0B 0B 0B"), None);
+    }
+}
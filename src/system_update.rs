@@ -444,6 +444,49 @@ pub fn is_update_supported() -> bool {
     get_update_command().is_ok()
 }
 
+/// Counts pending package updates without installing anything.
+///
+/// Prefers `checkupdates` (pacman-contrib), which syncs a temporary copy of the
+/// databases and needs no root; falls back to `pacman -Qu` against the last sync.
+pub fn check_updates_available() -> Result<usize, String> {
+    let (program, args, no_updates_code): (&str, &[&str], i32) = if command_exists("checkupdates") {
+        ("checkupdates", &[], 2)
+    } else if command_exists("pacman") {
+        ("pacman", &["-Qu"], 1)
+    } else {
+        return Err("No supported package manager found".to_string());
+    };
+
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|err| format!("Failed to run {}: {}", program, err))?;
+
+    // Both tools exit with a non-zero code when nothing is pending
+    if !output.status.success() {
+        if output.status.code() == Some(no_updates_code) {
+            return Ok(0);
+        }
+        return Err(format!(
+            "{} failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(count_pending_updates(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Counts `name old -> new` lines, skipping packages pacman is told to ignore.
+fn count_pending_updates(output: &str) -> usize {
+    output
+        .lines()
+        .filter(|line| line.contains(" -> ") && !line.trim_end().ends_with("[ignored]"))
+        .count()
+}
+
 fn command_exists(command: &str) -> bool {
     if let Some(path_var) = env::var_os("PATH") {
         for path in env::split_paths(&path_var) {
@@ -477,6 +520,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_count_pending_updates() {
+        let output = "linux 6.9.1.arch1-1 -> 6.9.2.arch1-1\n\
+                      mesa 1:24.1.0-1 -> 1:24.1.1-1\n\
+                      nvidia 550.78-1 -> 550.90-1 [ignored]\n";
+        assert_eq!(count_pending_updates(output), 2);
+        assert_eq!(count_pending_updates(""), 0);
+        assert_eq!(count_pending_updates("\n"), 0);
+    }
+
     #[test]
     fn test_parse_building_package() {
         let line = "==> Making package: topgrade-bin 16.8.0-1 (Sa 10 Jan 2026 13:23:20 CET)";
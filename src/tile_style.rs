@@ -0,0 +1,197 @@
+use iced::widget::container;
+use iced::{Border, Color, Shadow, Vector};
+use serde::{Deserialize, Serialize};
+
+use crate::ui_theme::COLOR_ACCENT;
+
+/// Opacity of unselected covers and labels with `focus_dim`
+const FOCUS_DIM_OPACITY: f32 = 0.45;
+
+/// Average glyph width of the UI font relative to its size, for fitting titles
+const AVERAGE_CHAR_WIDTH: f32 = 0.55;
+
+/// Shape of tiles and how the selected one stands out,
+/// e.g. `{ "corner_radius": 12.0, "selection_glow": false, "title_overflow": "ellipsize" }`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TileStyle {
+    /// Corner radius before UI scaling
+    pub corner_radius: f32,
+    /// Glow around the selected tile; without it only the border is highlighted
+    pub selection_glow: bool,
+    /// Dim covers and labels of all tiles but the selected one
+    pub focus_dim: bool,
+    /// How names too long for one line are shown
+    pub title_overflow: TitleOverflow,
+}
+
+/// Long tile names either wrap onto more lines or are cut to one line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleOverflow {
+    #[default]
+    Wrap,
+    /// Keeps all tiles the same height
+    Ellipsize,
+}
+
+impl Default for TileStyle {
+    fn default() -> Self {
+        Self {
+            corner_radius: 4.0,
+            selection_glow: true,
+            focus_dim: false,
+            title_overflow: TitleOverflow::default(),
+        }
+    }
+}
+
+impl TileStyle {
+    /// Opacity of a tile's cover and label at `highlight`; follows the focus
+    /// animation, so tiles brighten as the selection moves onto them
+    pub fn content_opacity(self, highlight: f32) -> f32 {
+        if self.focus_dim {
+            FOCUS_DIM_OPACITY + (1.0 - FOCUS_DIM_OPACITY) * highlight.clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Label of a tile `width` wide; with `Ellipsize` cut to roughly what fits on
+    /// one line at `font_size`
+    pub fn title(self, name: &str, width: f32, font_size: f32) -> String {
+        match self.title_overflow {
+            TitleOverflow::Wrap => name.to_string(),
+            TitleOverflow::Ellipsize => ellipsize(name, title_char_budget(width, font_size)),
+        }
+    }
+
+    /// Container style of a tile at `highlight` (0.0 unselected to 1.0 selected)
+    pub fn container_style(self, highlight: f32, scale: f32) -> container::Style {
+        let accent = |alpha: f32| Color {
+            a: alpha,
+            ..COLOR_ACCENT
+        };
+        let shadow = if self.selection_glow {
+            Shadow {
+                color: accent(highlight * 0.5),
+                offset: Vector::ZERO,
+                blur_radius: highlight * 10.0 * scale,
+            }
+        } else {
+            Shadow::default()
+        };
+
+        container::Style {
+            border: Border {
+                color: accent(highlight),
+                width: 1.0 * scale.max(1.0),
+                radius: (self.corner_radius.max(0.0) * scale).into(),
+            },
+            shadow,
+            ..Default::default()
+        }
+    }
+}
+
+/// Characters that fit into `width` at `font_size`, by the average glyph width
+fn title_char_budget(width: f32, font_size: f32) -> usize {
+    if font_size <= 0.0 {
+        return 0;
+    }
+    (width.max(0.0) / (font_size * AVERAGE_CHAR_WIDTH)).floor() as usize
+}
+
+/// Cuts `text` to `max_chars` including a trailing "…", preferring the end of a word
+fn ellipsize(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    let kept: String = text.chars().take(max_chars - 1).collect();
+    // Break at a word boundary unless that drops more than half of the line
+    let cut = match kept.rfind(' ') {
+        Some(space) if kept[..space].chars().count() * 2 >= max_chars => &kept[..space],
+        _ => kept.as_str(),
+    };
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellipsize_at_various_widths() {
+        let name = "The Legend of Heroes: Trails in the Sky";
+
+        // 14px font: 7.7px per char
+        assert_eq!(title_char_budget(160.0, 14.0), 20);
+        assert_eq!(title_char_budget(80.0, 14.0), 10);
+        assert_eq!(title_char_budget(0.0, 14.0), 0);
+        assert_eq!(title_char_budget(100.0, 0.0), 0);
+
+        assert_eq!(ellipsize(name, 100), name);
+        assert_eq!(ellipsize(name, name.chars().count()), name);
+        assert_eq!(ellipsize(name, 20), "The Legend of…");
+        assert_eq!(ellipsize(name, 12), "The Legend…");
+        // A long word is cut instead of leaving a mostly empty line
+        assert_eq!(ellipsize("Supercalifragilistic Game", 10), "Supercali…");
+        assert_eq!(ellipsize(name, 1), "…");
+        assert_eq!(ellipsize(name, 0), "");
+        // Counts characters, not bytes
+        assert_eq!(ellipsize("Ōkami HD Remaster", 8), "Ōkami…");
+
+        let style = TileStyle {
+            title_overflow: TitleOverflow::Ellipsize,
+            ..TileStyle::default()
+        };
+        assert_eq!(style.title(name, 160.0, 14.0), "The Legend of…");
+        assert_eq!(TileStyle::default().title(name, 160.0, 14.0), name);
+    }
+
+    #[test]
+    fn test_default_style_keeps_glow() {
+        let style = TileStyle::default().container_style(1.0, 2.0);
+        assert_eq!(style.border.radius.top_left, 8.0);
+        assert_eq!(style.border.color, COLOR_ACCENT);
+        assert_eq!(style.shadow.blur_radius, 20.0);
+        assert_eq!(style.shadow.color.a, 0.5);
+    }
+
+    #[test]
+    fn test_configured_radius_and_border_only() {
+        let tile_style: TileStyle =
+            serde_json::from_str(r#"{ "corner_radius": 12.0, "selection_glow": false }"#).unwrap();
+
+        let selected = tile_style.container_style(1.0, 1.5);
+        assert_eq!(selected.border.radius.top_left, 18.0);
+        assert_eq!(selected.border.color.a, 1.0);
+        assert_eq!(selected.shadow, Shadow::default());
+
+        // Unselected tiles keep the radius with an invisible border
+        let unselected = tile_style.container_style(0.0, 1.5);
+        assert_eq!(unselected.border.radius.bottom_right, 18.0);
+        assert_eq!(unselected.border.color.a, 0.0);
+    }
+
+    #[test]
+    fn test_focus_dim_fades_unselected_tiles() {
+        let plain = TileStyle::default();
+        assert_eq!(plain.content_opacity(0.0), 1.0);
+        assert_eq!(plain.content_opacity(1.0), 1.0);
+
+        let dimmed = TileStyle {
+            focus_dim: true,
+            ..TileStyle::default()
+        };
+        assert_eq!(dimmed.content_opacity(0.0), FOCUS_DIM_OPACITY);
+        assert_eq!(dimmed.content_opacity(1.0), 1.0);
+        let halfway = dimmed.content_opacity(0.5);
+        assert!(halfway > FOCUS_DIM_OPACITY && halfway < 1.0);
+    }
+}
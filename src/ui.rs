@@ -1,78 +1,167 @@
-use iced::keyboard::{self, key::Named, Key};
+use iced::keyboard;
 use iced::widget::operation;
 
 use crate::ui_app_update_modal::{handle_app_update_navigation, render_app_update_modal};
-use crate::ui_modals::{render_app_not_found_modal, render_context_menu, render_help_modal};
+use crate::ui_modals::{
+    render_app_actions_menu, render_app_not_found_modal, render_auto_suspend_countdown,
+    render_confirm_clear_history_modal, render_confirm_exit_modal, render_context_menu,
+    render_controller_lost, render_controller_splash, render_game_details_modal, render_help_modal,
+    render_launch_failed_modal, render_launch_source_menu, render_low_memory_modal,
+    render_scan_errors_modal,
+};
 use crate::ui_system_update_modal::render_system_update_modal;
 use crate::ui_theme::{
-    BASE_FONT_TITLE, BASE_PADDING_SMALL, BATTERY_CHECK_INTERVAL_SECS, CATEGORY_ROW_SPACING,
-    GAME_POSTER_HEIGHT, GAME_POSTER_WIDTH, ITEM_SPACING, MAIN_CONTENT_VERTICAL_PADDING,
-    MAX_UI_SCALE, MIN_UI_SCALE, REFERENCE_WINDOW_HEIGHT, RESTART_DELAY_SECS,
+    ui_scale_for, BASE_FONT_TITLE, BASE_PADDING_SMALL, BATTERY_CHECK_INTERVAL_SECS,
+    GAME_POSTER_HEIGHT, GAME_POSTER_WIDTH, MAIN_CONTENT_VERTICAL_PADDING, RESTART_DELAY_SECS,
+    SPINNER_CHARS, UPDATE_COUNT_CHECK_INTERVAL_SECS,
 };
 use crate::updater::{apply_update, check_update_available, ReleaseInfo};
 use iced::window;
 use iced::{
-    widget::{Column, Container, Scrollable, Stack},
-    Color, Element, Event, Length, Subscription, Task,
+    widget::{Column, Container, Image, Scrollable, Stack},
+    Color, ContentFit, Element, Event, Length, Subscription, Task,
 };
-use tracing::{error, info};
+use tracing::{debug, error, info, warn};
 
 use chrono::{DateTime, Local};
-use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 use std::env;
-use std::path::PathBuf;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 use crate::assets::get_default_icon;
 use crate::auth_dialog::render_auth_dialog;
 use crate::auth_flow::{AuthFlow, AuthFlowState};
-use crate::category_list::CategoryList;
+use crate::auto_suspend::{IdleState, IdleSuspend};
+use crate::autostart::{is_autostart_enabled, set_autostart};
+use crate::backdrop::{backdrop_source, fetch_hero_art, Backdrop, BackdropSource};
+use crate::bug_report::{build_bug_report, save_bug_report};
+use crate::category_list::{collect_favorites, collect_recent, most_recent, CategoryList};
+use crate::command_runner::{CommandRunner, SystemRunner};
+use crate::context_menu::{context_menu_entries, ContextMenuEntry};
+use crate::cursor_visibility::CursorVisibility;
+use crate::custom_actions::{run_custom_action, CustomAction};
+use crate::custom_entry::CustomEntryState;
 use crate::desktop_apps::{scan_desktop_apps, DesktopApp};
+use crate::desktop_shortcut::write_desktop_shortcut;
+use crate::display_modes::{
+    apply_display_mode, detect_display_backend, list_display_modes, DisplayBackend, DisplayMode,
+};
+use crate::double_back::DoubleBack;
+use crate::duplicate_games::{merge_duplicates, source_label, split_duplicates};
+use crate::fix_artwork::{games_missing_artwork, parse_sgdb_id, FixArtworkState};
+use crate::focus_animation::{FocusAnimation, FocusKey, FOCUS_ANIMATION_FRAME};
 use crate::focus_manager::{monitor_app_process, MonitorTarget};
+use crate::game_exit::GameExitStep;
 use crate::game_image_fetcher::GameImageFetcher;
-use crate::game_sources::scan_games;
-use crate::gamepad::{gamepad_subscription, GamepadEvent, GamepadInfo};
-use crate::image_cache::ImageCache;
+use crate::game_manager::{partition_hidden, BatchOperation, ManageGamesState};
+use crate::game_sources::{most_relevant_error, scan_games, RescanThrottle, ScanError};
+use crate::gamepad::{
+    controller_presence_stream, gamepad_subscription, has_functional_gamepad, suggest_deadzone,
+    GamepadConfig, GamepadEvent, GamepadInfo, PresenceChange, StickAxis,
+};
+use crate::hold_to_launch::HoldToLaunch;
+use crate::image_cache::{
+    read_cover_handles, CoverHandleCache, ImageCache, DEFAULT_COVER_MEMORY_CACHE,
+};
+use crate::image_fetch_queue::{priority_order, ImageFetchQueue};
 use crate::input::Action;
-use crate::launcher::{launch_app, resolve_monitor_target, LaunchError};
+use crate::launch_diagnostics::{watch_launch, LaunchFailure};
+use crate::launch_options::LaunchOptionsState;
+use crate::launcher::{
+    build_launch_command, resolve_monitor_target, resolve_terminal, verify_command_exists,
+    with_monitor_env, LaunchError, GAMESCOPE_16_9_PREFIX,
+};
+use crate::log_buffer::recent_log_lines;
 use crate::messages::Message;
-use crate::model::{AppEntry, Category, LauncherAction, LauncherItem};
+use crate::model::{
+    build_system_items, sort_system_items, source_badge, AppEntry, Category, LauncherAction,
+    LauncherItem, SystemAction,
+};
 use crate::osk::OskManager;
+use crate::power::{
+    detect_power_backend, list_power_profiles, set_power_profile, switch_to_performance,
+    PowerBackend, PowerProfile,
+};
+use crate::protondb::{fetch_proton_tiers, ProtonTier};
+use crate::screenshot::save_screenshot;
 use crate::searxng::SearxngClient;
+use crate::settings::{Settings, SettingsEntry};
+use crate::setup_wizard::{SetupStep, SetupWizardState};
 use crate::sleep_inhibit::SleepInhibitor;
+use crate::startup_scroll::{initial_category, row_offset, StartupScroll};
+use crate::steam_metadata::{fetch_game_metadata, GameMetadata};
 use crate::steamgriddb::SteamGridDbClient;
-use crate::storage::{load_config, save_config, AppConfig};
+use crate::storage::{
+    config_dir, config_path, load_config, load_config_from, project_dirs, save_config_to,
+    AppConfig, SaveDebouncer,
+};
+use crate::store_page::{steam_appid, store_page};
 use crate::sudo_askpass::{askpass_subscription, AskpassEvent};
-use crate::sys_utils::restart_process;
+use crate::sys_utils::{detect_embedded_mode, restart_process};
 use crate::system_battery::read_system_battery;
-use crate::system_info::{fetch_system_info, GamingSystemInfo};
-use crate::system_update::{is_update_supported, system_update_stream};
+use crate::system_info::{
+    fetch_system_info, get_available_memory, is_memory_below, GamingSystemInfo,
+};
+use crate::system_update::{check_updates_available, is_update_supported, system_update_stream};
 use crate::system_update_state::{SystemUpdateProgress, SystemUpdateState, UpdateStatus};
 use crate::ui_app_picker::{render_app_picker, AppPickerState};
-use crate::ui_background::WhaleSharkBackground;
-use crate::ui_components::{get_battery_visuals, render_clock, render_gamepad_infos};
+use crate::ui_background::{solid_background, BackgroundKind, WhaleSharkBackground};
+use crate::ui_components::{
+    get_battery_visuals, is_svg, optional_layer, render_clock, render_gamepad_infos,
+};
+use crate::ui_custom_entry_modal::render_custom_entry_modal;
+use crate::ui_fix_artwork_modal::{
+    handle_fix_artwork_keyboard_message, handle_fix_artwork_navigation, render_fix_artwork_modal,
+};
+use crate::ui_launch_options_modal::render_launch_options_modal;
+use crate::ui_list_modal::{
+    handle_list_navigation, render_list_modal, ListModalState, ListModalText,
+};
+use crate::ui_logs_modal::{render_logs_modal, LogViewerState};
 use crate::ui_main_view::{
-    get_category_dimensions, render_controls_hint, render_section_row, render_status,
+    get_category_dimensions, render_controls_hint, render_scan_error_banner, render_section_row,
+    render_status, TileBadges,
 };
-use crate::ui_state::{AppUpdatePhase, AppUpdateState, AuthState, ModalState};
+use crate::ui_manage_games_modal::render_manage_games_modal;
+use crate::ui_settings_modal::{render_settings_modal, render_stick_calibration_modal};
+use crate::ui_setup_wizard_modal::{
+    handle_setup_keyboard_message, handle_setup_wizard_navigation, render_setup_wizard,
+};
+use crate::ui_sounds::{UiSound, UiSounds};
+use crate::ui_state::{AppUpdatePhase, AppUpdateState, AuthState, ModalState, PendingLaunch};
 use crate::ui_system_info_modal::render_system_info_modal;
 use crate::virtual_keyboard::{KeyboardMessage, KeyboardOutput, VirtualKeyboard};
+use crate::watcher::games_changed_stream;
+use crate::window_mode::{window_settings, windowed_size, WindowGeometry, WindowMode};
 
 pub struct Launcher {
     apps: CategoryList,
     games: CategoryList,
     system_items: CategoryList,
+    /// Favorites from all categories, shown on the quick launch screen
+    quick_launch: CategoryList,
+    /// Most recently launched games and apps, shown as the first row
+    recent: CategoryList,
 
     category: Category,
     default_icon_handle: Option<iced::widget::svg::Handle>,
     status_message: Option<String>,
+    /// Game sources that failed during the last scan
+    scan_errors: Vec<ScanError>,
+    /// Hides the "sources failed" banner until the next scan
+    scan_errors_dismissed: bool,
 
     apps_loaded: bool,
     games_loaded: bool,
+    /// Frame of the spinner shown while the first game scan runs
+    scan_spinner_tick: usize,
     sgdb_client: SteamGridDbClient,
     searxng_client: SearxngClient,
     image_cache: Option<ImageCache>,
+    /// Recently shown covers, read from disk once
+    cover_handles: CoverHandleCache,
     scale_factor: f64,
     window_width: f32,
     window_height: f32, // Track window height for scaling
@@ -88,20 +177,97 @@ pub struct Launcher {
     game_running: bool,
     osk_manager: OskManager,
     sleep_inhibitor: SleepInhibitor,
+    /// Starts games and system commands; replaced by a fake in tests
+    command_runner: Box<dyn CommandRunner>,
     current_exe: Option<PathBuf>,
     api_key: Option<String>,
     current_time: DateTime<Local>,
     gamepad_infos: Vec<GamepadInfo>,
+    /// Whether the gamepad list has been received; the controller splash waits for it
+    gamepads_reported: bool,
+    /// The controller splash was dismissed with a key press for this session
+    controller_splash_dismissed: bool,
+    /// The last controller disconnected while a game runs; the launcher is in front
+    controller_lost: bool,
+    /// Launch started by minimizing, spawned once `launch_delay_ms` passed
+    pending_launch: Option<PendingLaunch>,
+    /// Loaded sample set while `ui_sounds` is on
+    ui_sounds: Option<UiSounds>,
+    /// ProtonDB tiers by Steam appid, fetched while `protondb_badges` is on
+    proton_tiers: HashMap<String, ProtonTier>,
     /// Stores launch timestamps for games (keyed by game identifier)
     game_launch_history: std::collections::HashMap<String, i64>,
+    /// Launch keys of favorite items (persisted in config)
+    favorites: HashSet<String>,
+    /// Per-item wrapper commands keyed by launch key (persisted in config)
+    launch_prefixes: std::collections::HashMap<String, String>,
+    /// Per-item launch options keyed by launch key (persisted in config)
+    launch_options: std::collections::HashMap<String, String>,
+    /// Launch keys of games hidden via Manage Games (persisted in config)
+    hidden_games: HashSet<String>,
+    /// Scanned games that are currently hidden, kept so they can be unhidden
+    hidden_game_items: Vec<LauncherItem>,
+    /// SteamGridDB game IDs chosen via "Fix Artwork", keyed by launch key (persisted in config)
+    artwork_overrides: std::collections::HashMap<String, u64>,
+    /// Launch keys of games using their store's image instead of SteamGridDB art
+    prefer_source_art: HashSet<String>,
+    /// Whether the first run setup wizard was finished or skipped (persisted in config)
+    setup_completed: bool,
+    /// SteamGridDB API key entered in the setup wizard, written to the config
+    setup_api_key: Option<String>,
+    /// Whether the user was told covers need a SteamGridDB API key (persisted in config)
+    sgdb_key_hint_shown: bool,
+    /// Pending cover downloads, ordered by distance to the selected game
+    image_fetch_queue: ImageFetchQueue,
+    /// Throttles config writes triggered by rapid changes
+    config_saver: SaveDebouncer,
+    /// File config saves go to; pointed at a temp file in tests
+    config_file: Option<PathBuf>,
+    /// Limits background rescans after returning from a game
+    rescan_throttle: RescanThrottle,
+    /// Game directories changed while a game ran; rescanned once it exits
+    games_changed_while_playing: bool,
+    /// Whether gamescope is installed, enabling the "Force 16:9" preset
+    gamescope_available: bool,
+    /// Mode switching tool for the current session, if installed
+    display_backend: Option<DisplayBackend>,
+    /// Power profile tool, if installed
+    power_backend: Option<PowerBackend>,
+    /// Profile active before `performance_while_gaming` switched it, restored on exit
+    power_profile_before_game: Option<String>,
+    /// Whether Steam's env markers show we were started from Big Picture
+    steam_gamepad_ui: bool,
+    settings: Settings,
     background: WhaleSharkBackground,
+    /// Hero art of the selected game behind the rows, with `hero_backdrop`
+    backdrop: Backdrop,
+    /// Looked up hero art per game; `None` while loading or when there is none
+    hero_art: HashMap<Uuid, Option<PathBuf>>,
     system_battery: Option<gilrs::PowerInfo>,
     last_battery_check: std::time::Instant,
+    /// Time since the last input, for the optional auto-suspend
+    idle_suspend: IdleSuspend,
+    /// Seconds until auto-suspend while its countdown is shown
+    suspend_countdown: Option<u64>,
+    /// Shows the mouse cursor only while the mouse is in use
+    cursor_visibility: CursorVisibility,
+    /// Pending package updates shown on the System Update tile
+    pending_updates: usize,
+    /// Unset until the first check, and again after updating, so the count refreshes soon
+    last_update_count_check: Option<std::time::Instant>,
     pending_update: Option<ReleaseInfo>,
     /// Main vertical scrollable Id for programmatic scroll control
     main_scroll_id: iced::widget::Id,
     /// Animated overlay alpha for modal fade-in (0.0 = invisible, 0.7/0.85 = visible)
     overlay_alpha: iced_anim::Animated<f32>,
+    /// Fade of the selection highlight between the previous and current tile
+    focus_animation: FocusAnimation,
+    /// Running scroll from the top to the start row, see `startup_scroll_animation`
+    startup_scroll: Option<StartupScroll>,
+    /// Back presses on the home screen, see `double_back_to_exit`
+    double_back: DoubleBack,
+    /// Select held on a game tile, see `hold_to_launch`
+    launch_hold: Option<HoldToLaunch>,
 }
 
 impl Launcher {
@@ -119,33 +285,45 @@ impl Launcher {
         let searxng_client = SearxngClient::new();
         let image_cache = ImageCache::new().ok();
         let current_exe = env::current_exe().ok();
-
-        let mut system_items_vec = vec![LauncherItem::shutdown(), LauncherItem::suspend()];
-
-        if is_update_supported() {
-            system_items_vec.push(LauncherItem::system_update());
+        let steam_gamepad_ui = detect_embedded_mode();
+        if steam_gamepad_ui {
+            info!("Started from Steam Big Picture, running in embedded mode");
         }
 
-        system_items_vec.push(LauncherItem::system_info());
-        system_items_vec.push(LauncherItem::exit());
+        let display_backend = detect_display_backend();
+        let power_backend = detect_power_backend();
+        // Built in default order; the configured order is applied once the config is loaded
+        let system_items_vec =
+            build_system_items(SystemAction::DEFAULT_ORDER, |action| match action {
+                SystemAction::SystemUpdate => is_update_supported(),
+                SystemAction::DisplayModes => display_backend.is_some(),
+                SystemAction::PowerProfiles => power_backend.is_some(),
+                _ => true,
+            });
 
         // Default 1080p assumption until resize event
         let default_height = 720.0; // Assume 720p minimum safe start
-        let initial_scale = default_height / REFERENCE_WINDOW_HEIGHT;
+        let initial_scale = ui_scale_for(default_height, None);
 
         let launcher = Self {
             apps: CategoryList::new(Vec::new()),
             games: CategoryList::new(Vec::new()),
             system_items: CategoryList::new(system_items_vec),
+            quick_launch: CategoryList::new(Vec::new()),
+            recent: CategoryList::new(Vec::new()),
             category: Category::Games,
             default_icon_handle: default_icon,
             status_message: None,
+            scan_errors: Vec::new(),
+            scan_errors_dismissed: false,
 
             apps_loaded: false,
             games_loaded: false,
+            scan_spinner_tick: 0,
             sgdb_client,
             searxng_client,
             image_cache,
+            cover_handles: CoverHandleCache::new(DEFAULT_COVER_MEMORY_CACHE),
             scale_factor: 1.0,
             window_width: 1280.0,
             window_height: default_height,
@@ -157,24 +335,63 @@ impl Launcher {
             game_running: false,
             osk_manager: OskManager::new(),
             sleep_inhibitor: SleepInhibitor::new(),
+            command_runner: Box::new(SystemRunner),
             current_exe,
             api_key: env_key,
             current_time: Local::now(),
             gamepad_infos: Vec::new(),
+            gamepads_reported: false,
+            controller_splash_dismissed: false,
+            controller_lost: false,
+            pending_launch: None,
+            ui_sounds: None,
+            proton_tiers: HashMap::new(),
             game_launch_history: std::collections::HashMap::new(),
+            favorites: HashSet::new(),
+            launch_prefixes: std::collections::HashMap::new(),
+            launch_options: std::collections::HashMap::new(),
+            hidden_games: HashSet::new(),
+            hidden_game_items: Vec::new(),
+            artwork_overrides: std::collections::HashMap::new(),
+            prefer_source_art: HashSet::new(),
+            setup_completed: true,
+            setup_api_key: None,
+            sgdb_key_hint_shown: false,
+            image_fetch_queue: ImageFetchQueue::default(),
+            config_saver: SaveDebouncer::default(),
+            config_file: config_path().ok(),
+            rescan_throttle: RescanThrottle::default(),
+            games_changed_while_playing: false,
+            gamescope_available: verify_command_exists("gamescope"),
+            display_backend,
+            power_backend,
+            power_profile_before_game: None,
+            steam_gamepad_ui,
+            settings: Settings::default(),
             background: WhaleSharkBackground::new(),
+            backdrop: Backdrop::new(),
+            hero_art: HashMap::new(),
             system_battery: None,
             last_battery_check: std::time::Instant::now(),
+            idle_suspend: IdleSuspend::new(Instant::now()),
+            suspend_countdown: None,
+            cursor_visibility: CursorVisibility::default(),
+            pending_updates: 0,
+            last_update_count_check: None,
             pending_update: None,
             main_scroll_id: iced::widget::Id::unique(),
             overlay_alpha: iced_anim::Animated::spring(0.0, iced_anim::spring::Motion::SNAPPY),
+            focus_animation: FocusAnimation::new(),
+            startup_scroll: None,
+            double_back: DoubleBack::default(),
+            launch_hold: None,
         };
 
         // Chain startup: Load config first to potentially get API key, then scan games
         // Also perform initial battery check
         let tasks = Task::batch(vec![
             Task::perform(
-                async { load_config().map_err(|err| err.to_string()) },
+                async { load_config().map(Box::new).map_err(|err| err.to_string()) },
                 Message::AppsLoaded,
             ),
             Task::perform(
@@ -200,6 +417,8 @@ impl Launcher {
             Category::Apps => &self.apps,
             Category::Games => &self.games,
             Category::System => &self.system_items,
+            Category::Favorites => &self.quick_launch,
+            Category::Recent => &self.recent,
         }
     }
 
@@ -208,27 +427,281 @@ impl Launcher {
             Category::Apps => &mut self.apps,
             Category::Games => &mut self.games,
             Category::System => &mut self.system_items,
+            Category::Favorites => &mut self.quick_launch,
+            Category::Recent => &mut self.recent,
+        }
+    }
+
+    /// Returns the real category an item belongs to, looked up by id.
+    fn category_of_item(&self, id: Uuid) -> Option<Category> {
+        [
+            (Category::Games, &self.games),
+            (Category::Apps, &self.apps),
+            (Category::System, &self.system_items),
+        ]
+        .into_iter()
+        .find(|(_, list)| list.items.iter().any(|item| item.id == id))
+        .map(|(category, _)| category)
+    }
+
+    fn is_favorite(favorites: &HashSet<String>, item: &LauncherItem) -> bool {
+        item.launch_key
+            .as_ref()
+            .is_some_and(|key| favorites.contains(key))
+    }
+
+    fn saved_launch_prefix(&self, item: &LauncherItem) -> Option<String> {
+        item.launch_key
+            .as_ref()
+            .and_then(|key| self.launch_prefixes.get(key).cloned())
+    }
+
+    fn saved_launch_options(&self, item: &LauncherItem) -> Option<String> {
+        item.launch_key
+            .as_ref()
+            .and_then(|key| self.launch_options.get(key).cloned())
+    }
+
+    /// Rebuilds the quick launch list from the favorite flags of all categories,
+    /// and the recent row from the launch timestamps of games and apps.
+    fn refresh_quick_launch(&mut self) {
+        let favorites = collect_favorites(&[&self.games, &self.apps, &self.system_items]);
+        self.quick_launch.set_items(favorites);
+
+        let recent = collect_recent(&[&self.games, &self.apps], self.settings.recent_count);
+        self.recent.set_items(recent);
+        if self.category == Category::Recent && !self.recent_row_visible() {
+            self.category = Category::Games;
+        }
+    }
+
+    fn recent_row_visible(&self) -> bool {
+        self.settings.recent_row_enabled && !self.recent.is_empty()
+    }
+
+    /// Next row in the main view, skipping the recent row while it is hidden
+    fn step_category(&self, forward: bool) -> Category {
+        let step = |category: Category| {
+            if forward {
+                category.next()
+            } else {
+                category.prev()
+            }
+        };
+        let next = step(self.category);
+        if next == Category::Recent && !self.recent_row_visible() {
+            step(next)
+        } else {
+            next
         }
     }
 
     pub fn update(&mut self, message: Message) -> Task<Message> {
+        let task = self.handle_message(message);
+        self.sync_focus_animation();
+        let backdrop_fetch = self.sync_backdrop();
+        Task::batch([task, backdrop_fetch])
+    }
+
+    /// Starts the highlight transition when the selected tile changed
+    fn sync_focus_animation(&mut self) {
+        let key = FocusKey {
+            category: self.category,
+            index: self.current_category_list().selected_index,
+        };
+        self.focus_animation
+            .focus(key, Instant::now(), self.settings.reduce_motion);
+    }
+
+    /// Fades the backdrop to the hero art of the selected game, looking it up
+    /// first if it wasn't yet
+    fn sync_backdrop(&mut self) -> Task<Message> {
+        let now = Instant::now();
+        let reduce_motion = self.settings.reduce_motion;
+        if !self.settings.hero_backdrop {
+            self.backdrop.show(None, now, true);
+            return Task::none();
+        }
+
+        let selected = self.current_category_list().get_selected();
+        let is_game =
+            selected.is_some_and(|item| self.category_of_item(item.id) == Some(Category::Games));
+        match backdrop_source(selected, is_game, &self.hero_art) {
+            BackdropSource::Hero(path) => {
+                self.backdrop.show(Some(path), now, reduce_motion);
+                Task::none()
+            }
+            BackdropSource::Pattern => {
+                self.backdrop.show(None, now, reduce_motion);
+                Task::none()
+            }
+            BackdropSource::Fetch => {
+                let item = selected.cloned();
+                self.backdrop.show(None, now, reduce_motion);
+                let (Some(cache), Some(item)) = (self.image_cache.clone(), item) else {
+                    return Task::none();
+                };
+                let id = item.id;
+                self.hero_art.insert(id, None);
+                let sgdb_client = self.sgdb_client.clone();
+                Task::perform(
+                    async move {
+                        tokio::task::spawn_blocking(move || {
+                            fetch_hero_art(&cache, &sgdb_client, &item)
+                        })
+                        .await
+                        .ok()
+                        .flatten()
+                    },
+                    move |path| Message::HeroArtFetched(id, path),
+                )
+            }
+        }
+    }
+
+    fn handle_message(&mut self, message: Message) -> Task<Message> {
         match message {
             // Initialization & Data Loading
             Message::AppsLoaded(res) => self.handle_apps_loaded(res),
-            Message::GamesLoaded(games) => self.handle_games_loaded(games),
+            Message::GamesLoaded(result) => self.handle_games_loaded(result.games, result.errors),
+            Message::GamesChanged => {
+                // The first scan is still running and will see the change
+                if !self.games_loaded {
+                    return Task::none();
+                }
+                // A scan would compete with the game for the CPU
+                if self.game_running {
+                    self.games_changed_while_playing = true;
+                    return Task::none();
+                }
+                info!("Rescanning game sources after the game directories changed");
+                self.scan_games_task()
+            }
             Message::ImageFetched(id, path) => self.handle_image_fetched(id, path),
+            Message::HeroArtFetched(id, path) => {
+                let load = self.load_cover_handles(path.clone());
+                self.hero_art.insert(id, path);
+                load
+            }
+            Message::PlaceholderCoversGenerated(covers) => {
+                self.handle_placeholder_covers_generated(covers)
+            }
+            Message::CoverHandlesLoaded(handles) => {
+                for (path, handle) in handles {
+                    self.cover_handles.insert(path, handle);
+                }
+                Task::none()
+            }
+            Message::FixArtworkKeyboard(message) => {
+                self.handle_fix_artwork_keyboard_message(message)
+            }
+            Message::SubmitFixArtwork => self.submit_fix_artwork(),
+            Message::CloseFixArtwork => self.close_fix_artwork(),
+            Message::LaunchOptionsKeyboard(message) => {
+                self.handle_launch_options_keyboard_message(message)
+            }
+            Message::ArtworkFixed(id, launch_key, sgdb_id, path) => {
+                self.handle_artwork_fixed(id, launch_key, sgdb_id, path)
+            }
+            Message::FixMissingArtwork(index, id) => self.fix_missing_artwork(index, id),
+            Message::SetupKeyboard(message) => self.handle_setup_keyboard_message(message),
+            Message::CompleteSetup(apply) => self.complete_setup(apply),
+            Message::CustomActionFinished(name, result) => {
+                self.handle_custom_action_finished(name, result)
+            }
 
             // Input & Navigation
-            Message::Input(action) => self.handle_navigation(action),
+            Message::Input(action) => {
+                self.cursor_visibility.navigated();
+                if self.register_activity() {
+                    return Task::none();
+                }
+                self.handle_navigation(action)
+            }
+            Message::GamepadSelect(pressed) => self.handle_gamepad_select(pressed),
+            Message::KeyPressed(key) => {
+                self.cursor_visibility.navigated();
+                if self.register_activity() {
+                    return Task::none();
+                }
+                if self.controller_splash_visible() {
+                    self.controller_splash_dismissed = true;
+                    return Task::none();
+                }
+                match self.settings.keyboard_bindings.action_for(&key) {
+                    Some(action) => self.handle_navigation(action),
+                    None => Task::none(),
+                }
+            }
+            Message::PasteRequested => {
+                self.cursor_visibility.navigated();
+                if self.register_activity() || !self.text_entry_active() {
+                    return Task::none();
+                }
+                iced::clipboard::read().map(Message::ClipboardPasted)
+            }
+            Message::ClipboardPasted(text) => match text {
+                Some(text) => self.handle_paste(text),
+                None => Task::none(),
+            },
 
             // Window & System Events
             Message::ScaleFactorChanged(s) => {
                 self.scale_factor = s;
                 Task::none()
             }
+            Message::ScreenshotSaved(result) => {
+                self.status_message = Some(match result {
+                    Ok(path) => {
+                        info!("Saved screenshot to {}", path.display());
+                        format!("Screenshot saved to {}", path.display())
+                    }
+                    Err(err) => {
+                        error!("{}", err);
+                        err
+                    }
+                });
+                Task::none()
+            }
+            Message::BugReportSaved(result) => {
+                self.status_message = Some(match result {
+                    Ok(path) => {
+                        info!("Saved bug report to {}", path.display());
+                        format!("Bug report saved to {}", path.display())
+                    }
+                    Err(err) => {
+                        error!("{}", err);
+                        err
+                    }
+                });
+                Task::none()
+            }
             Message::Tick(t) => {
                 self.current_time = t;
-                self.maybe_refresh_battery()
+                self.flush_config(false);
+                self.cursor_visibility.tick(Instant::now());
+                Task::batch(vec![
+                    self.maybe_refresh_battery(),
+                    self.maybe_refresh_update_count(),
+                    self.maybe_auto_suspend(),
+                ])
+            }
+            Message::FocusAnimationTick(now) => {
+                self.focus_animation.tick(now);
+                Task::none()
+            }
+            Message::BackdropTick(now) => {
+                self.backdrop.tick(now);
+                Task::none()
+            }
+            Message::StartupScrollTick(now) => self.advance_startup_scroll(now),
+            Message::HoldToLaunchTick(now) => match self.launch_hold {
+                Some(hold) if hold.is_complete(now) => self.finish_launch_hold(),
+                _ => Task::none(),
+            },
+            Message::ScanSpinnerTick => {
+                self.scan_spinner_tick = self.scan_spinner_tick.wrapping_add(1);
+                Task::none()
             }
             Message::AppUpdateSpinnerTick => {
                 if let ModalState::AppUpdate(state) = &mut self.modal {
@@ -244,9 +717,33 @@ impl Launcher {
             Message::WindowResized(w, h) => {
                 self.window_width = w;
                 self.window_height = h;
-                // Calculate UI scale based on reference height
-                // Clamp to reasonable limits to avoid UI disappearing or becoming massive
-                self.ui_scale = (h / REFERENCE_WINDOW_HEIGHT).clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+                self.sync_ui_scale();
+                if self.settings.window_mode == WindowMode::Windowed {
+                    let geometry = self
+                        .settings
+                        .window_geometry
+                        .get_or_insert(WindowGeometry::new(w, h));
+                    geometry.width = w;
+                    geometry.height = h;
+                }
+                Task::none()
+            }
+            Message::WindowMoved(x, y) => {
+                if self.settings.window_mode == WindowMode::Windowed {
+                    let (w, h) = (self.window_width, self.window_height);
+                    let geometry = self
+                        .settings
+                        .window_geometry
+                        .get_or_insert(WindowGeometry::new(w, h));
+                    geometry.x = Some(x);
+                    geometry.y = Some(y);
+                }
+                Task::none()
+            }
+            Message::WindowCloseRequested => self.exit_app(),
+            Message::MouseMoved => {
+                self.cursor_visibility.mouse_moved(Instant::now());
+                self.register_activity();
                 Task::none()
             }
             Message::WindowFocused(id) => {
@@ -263,6 +760,9 @@ impl Launcher {
             Message::AddSelectedApp => self.add_selected_app(),
             Message::CloseAppPicker => self.close_modal_none(),
             Message::AppPickerScrolled(vp) => self.handle_app_picker_scrolled(vp),
+            Message::CustomEntryKeyboard(message) => {
+                self.handle_custom_entry_keyboard_message(message)
+            }
 
             // System Update Modal
             Message::StartSystemUpdate => self.start_system_update(),
@@ -275,6 +775,20 @@ impl Launcher {
             Message::OpenSystemInfo => self.open_system_info(),
             Message::SystemInfoLoaded(info) => self.handle_system_info_loaded(info),
             Message::CloseSystemInfoModal => self.close_modal_none(),
+            Message::ProtonTiersLoaded(tiers) => self.handle_proton_tiers_loaded(tiers),
+            Message::GameDetailsLoaded(appid, result) => {
+                self.handle_game_details_loaded(appid, result)
+            }
+            Message::DisplayModesLoaded(result) => self.handle_display_modes_loaded(result),
+            Message::CloseListModal => self.close_modal_none(),
+            Message::ApplyDisplayMode(mode) => self.apply_display_mode(mode),
+            Message::DisplayModeApplied(result) => self.handle_display_mode_applied(result),
+            Message::PowerProfilesLoaded(result) => self.handle_power_profiles_loaded(result),
+            Message::ApplyPowerProfile(name) => self.switch_power_profile(name),
+            Message::PowerProfileApplied(result) => self.handle_power_profile_applied(result),
+            Message::GamePowerProfileSwitched(result) => {
+                self.handle_game_power_profile_switched(result)
+            }
 
             Message::AskpassEvent(event) => self.handle_askpass_event(event),
             Message::AuthKeyboard(message) => self.handle_auth_keyboard_message(message),
@@ -283,8 +797,36 @@ impl Launcher {
 
             // Game Execution Monitoring
             Message::GameExited => self.handle_game_exited(),
+            Message::LaunchDelayElapsed => self.start_pending_launch(),
+            Message::ExitDelayElapsed(step) => self.window_after_game(step),
+            Message::LaunchDiagnosed(item_name, failure) => {
+                self.handle_launch_diagnosed(item_name, failure)
+            }
             Message::GamepadBatteryUpdate(infos) => {
                 self.gamepad_infos = infos;
+                self.gamepads_reported = true;
+                Task::none()
+            }
+            Message::ControllerPresence(change) => self.handle_controller_presence(change),
+            Message::StickMoved(axis, value) => {
+                if let ModalState::StickCalibration {
+                    horizontal_drift,
+                    vertical_drift,
+                } = &mut self.modal
+                {
+                    let drift = match axis {
+                        StickAxis::Horizontal => horizontal_drift,
+                        StickAxis::Vertical => vertical_drift,
+                    };
+                    *drift = drift.max(value.abs());
+                }
+                Task::none()
+            }
+            Message::UpdateCountChecked(result) => {
+                match result {
+                    Ok(count) => self.pending_updates = count,
+                    Err(err) => debug!("Checking for pending updates failed: {}", err),
+                }
                 Task::none()
             }
             Message::SystemBatteryUpdated(info) => {
@@ -321,10 +863,82 @@ impl Launcher {
         )
     }
 
-    fn handle_apps_loaded(&mut self, result: Result<AppConfig, String>) -> Task<Message> {
+    /// Restarts the idle timer; returns true if the input only cancelled the
+    /// auto-suspend countdown and should not act on the UI.
+    fn register_activity(&mut self) -> bool {
+        let cancelled = self.idle_suspend.activity(Instant::now());
+        if cancelled {
+            info!("Auto-suspend cancelled by input");
+            self.suspend_countdown = None;
+        }
+        cancelled
+    }
+
+    /// Suspends after `auto_suspend_secs` without input and a countdown; never
+    /// while a game is running or an update is in progress.
+    fn maybe_auto_suspend(&mut self) -> Task<Message> {
+        let timeout = self
+            .settings
+            .auto_suspend_secs
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs);
+        let updating = self
+            .system_update_state()
+            .is_some_and(|state| state.status.is_running())
+            || matches!(
+                &self.modal,
+                ModalState::AppUpdate(state) if state.phase == AppUpdatePhase::Updating
+            );
+        let blocked = self.game_running || updating;
+
+        match self.idle_suspend.tick(Instant::now(), timeout, blocked) {
+            IdleState::Active => {
+                self.suspend_countdown = None;
+                Task::none()
+            }
+            IdleState::CountingDown { seconds_left } => {
+                self.suspend_countdown = Some(seconds_left);
+                Task::none()
+            }
+            IdleState::Suspend => {
+                info!("Suspending after being idle");
+                self.suspend_countdown = None;
+                self.system_command("systemctl", &["suspend"], "suspend")
+            }
+        }
+    }
+
+    /// Counts pending system updates every few hours; only while the System Update
+    /// tile is shown and no game is running.
+    fn maybe_refresh_update_count(&mut self) -> Task<Message> {
+        let has_update_tile = self
+            .system_items
+            .items
+            .iter()
+            .any(|item| matches!(item.action, LauncherAction::SystemUpdate));
+        let due = self
+            .last_update_count_check
+            .is_none_or(|last| last.elapsed().as_secs() >= UPDATE_COUNT_CHECK_INTERVAL_SECS);
+        if !has_update_tile || self.game_running || !due {
+            return Task::none();
+        }
+
+        self.last_update_count_check = Some(std::time::Instant::now());
+        Task::perform(
+            async {
+                tokio::task::spawn_blocking(check_updates_available)
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result)
+            },
+            Message::UpdateCountChecked,
+        )
+    }
+
+    fn handle_apps_loaded(&mut self, result: Result<Box<AppConfig>, String>) -> Task<Message> {
         self.apps_loaded = true;
         match result {
-            Ok(config) => self.process_loaded_apps(config),
+            Ok(config) => self.process_loaded_apps(*config),
             Err(err) => {
                 self.apps.clear();
                 self.status_message = Some(err);
@@ -332,17 +946,108 @@ impl Launcher {
         }
 
         // Continue startup chain: Scan games now that we have config (and potential API key)
+        self.rescan_throttle.try_begin(Instant::now());
+        Task::batch([
+            self.scan_games_task(),
+            self.load_nearby_covers(),
+            self.sync_placeholder_covers(),
+        ])
+    }
+
+    /// Cover files of the shown rows, each row from its selection outwards. The rows
+    /// take turns, the current one first, so every row keeps the covers around its
+    /// selection in memory.
+    fn cover_paths_by_distance(&self) -> Vec<PathBuf> {
+        let current = self.current_category_list();
+        let mut rows = vec![current];
+        if self.category != Category::Favorites {
+            let recent = self.recent_row_visible().then_some(&self.recent);
+            rows.extend(
+                [Some(&self.games), Some(&self.apps), recent]
+                    .into_iter()
+                    .flatten()
+                    .filter(|row| !std::ptr::eq(*row, current)),
+            );
+        }
+        let orders: Vec<Vec<usize>> = rows
+            .iter()
+            .map(|row| priority_order(row.items.len(), row.selected_index))
+            .collect();
+        let longest = orders.iter().map(Vec::len).max().unwrap_or(0);
+        (0..longest)
+            .flat_map(|rank| {
+                rows.iter()
+                    .zip(&orders)
+                    .filter_map(move |(row, order)| order.get(rank).map(|&i| &row.items[i]))
+            })
+            .filter_map(|item| item.icon.as_deref().map(PathBuf::from))
+            .collect()
+    }
+
+    fn load_nearby_covers(&mut self) -> Task<Message> {
+        let paths = self.cover_paths_by_distance();
+        self.load_cover_handles(paths)
+    }
+
+    /// Marks the covers of `paths`, nearest first, as in use and reads those that
+    /// aren't in memory yet in the background, so rendering never touches the disk
+    fn load_cover_handles(&mut self, paths: impl IntoIterator<Item = PathBuf>) -> Task<Message> {
+        let paths = self
+            .cover_handles
+            .request(paths.into_iter().filter(|path| !is_svg(path)));
+        if paths.is_empty() {
+            return Task::none();
+        }
         Task::perform(
-            async {
-                tokio::task::spawn_blocking(scan_games)
+            async move {
+                tokio::task::spawn_blocking(move || read_cover_handles(paths))
                     .await
-                    .unwrap_or_else(|_| Vec::new())
+                    .unwrap_or_default()
+            },
+            Message::CoverHandlesLoaded,
+        )
+    }
+
+    fn scan_games_task(&self) -> Task<Message> {
+        let max_per_source = self.settings.max_games_per_source;
+        let launch_history = self.game_launch_history.clone();
+        let appimage_dirs = self.settings.appimage_dirs.clone();
+        let scan_threads = self.settings.scan_threads;
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    scan_games(
+                        max_per_source,
+                        &launch_history,
+                        &appimage_dirs,
+                        scan_threads,
+                    )
+                })
+                .await
+                .unwrap_or_default()
             },
             Message::GamesLoaded,
         )
     }
 
     fn process_loaded_apps(&mut self, config: AppConfig) {
+        self.favorites = config.favorites;
+        self.launch_prefixes = config.launch_prefixes;
+        self.launch_options = config.launch_options;
+        self.hidden_games = config.hidden_games;
+        self.artwork_overrides = config.artwork_overrides;
+        self.prefer_source_art = config.prefer_source_art;
+        self.setup_completed = config.setup_completed;
+        self.sgdb_key_hint_shown = config.sgdb_key_hint_shown;
+        self.settings = config.settings;
+        self.settings.autostart = is_autostart_enabled();
+        self.sync_ui_scale();
+        self.sync_ui_sounds();
+        self.cover_handles
+            .set_capacity(self.settings.cover_memory_cache);
+        self.apps.sort_mode = self.settings.sort_modes.apps;
+        self.games.set_sort_mode(self.settings.sort_modes.games);
+
         let items: Vec<LauncherItem> = config
             .apps
             .into_iter()
@@ -353,6 +1058,9 @@ impl Launcher {
                         item.launch_key = Some(format!("desktop:{}", exec));
                     }
                 }
+                item.favorite = Self::is_favorite(&self.favorites, &item);
+                item.launch_prefix = self.saved_launch_prefix(&item);
+                item.launch_options = self.saved_launch_options(&item);
                 item
             })
             .collect();
@@ -360,6 +1068,28 @@ impl Launcher {
         self.apps.sort_inplace();
         self.status_message = None;
 
+        self.system_items
+            .items
+            .retain(|item| !matches!(item.action, LauncherAction::Custom { .. }));
+        self.system_items.items.extend(
+            self.settings
+                .custom_system_actions
+                .iter()
+                .map(CustomAction::item),
+        );
+        // Custom actions have no place in system_order and stay at the end
+        sort_system_items(&mut self.system_items.items, &self.settings.system_order);
+        for item in &mut self.system_items.items {
+            item.favorite = Self::is_favorite(&self.favorites, item);
+        }
+        self.refresh_quick_launch();
+
+        self.category = if self.settings.start_in_quick_launch {
+            Category::Favorites
+        } else {
+            self.settings.start_category.category()
+        };
+
         // Store game launch history for later use when games are loaded
         self.game_launch_history = config.game_launch_history;
 
@@ -370,101 +1100,352 @@ impl Launcher {
                 self.sgdb_client = SteamGridDbClient::new(key);
             }
         }
+
+        if !self.setup_completed {
+            self.modal = ModalState::SetupWizard(SetupWizardState::new(&self.settings));
+            self.sync_overlay_alpha();
+        } else {
+            self.show_sgdb_key_hint();
+        }
+    }
+
+    /// Without an API key covers only come from the stores and web search. Says so
+    /// once, then remembers it in the config so it doesn't nag on every start.
+    fn show_sgdb_key_hint(&mut self) {
+        if self.api_key.is_some() || self.sgdb_key_hint_shown {
+            return;
+        }
+        self.sgdb_key_hint_shown = true;
+        self.status_message = Some(
+            "No SteamGridDB API key set, so covers come from stores and web search. \
+             Add it as steamgriddb_api_key in the config (System → Settings → Config Folder)."
+                .to_string(),
+        );
+        if let Err(e) = self.write_config() {
+            error!("Error saving config after the SteamGridDB key hint: {}", e);
+        }
     }
 
-    fn handle_games_loaded(&mut self, games: Vec<AppEntry>) -> Task<Message> {
-        let items: Vec<LauncherItem> = games
+    fn handle_games_loaded(
+        &mut self,
+        games: Vec<AppEntry>,
+        scan_errors: Vec<ScanError>,
+    ) -> Task<Message> {
+        let first_scan = !self.games_loaded;
+        // On a rescan, keep ids and fetched covers of known games so tiles don't
+        // flicker, and restore the selection afterwards
+        let known: std::collections::HashMap<String, (uuid::Uuid, Option<String>)> = self
+            .games
+            .items
+            .iter()
+            .chain(&self.hidden_game_items)
+            .filter_map(|item| {
+                let key = item.launch_key.clone()?;
+                Some((key, (item.id, item.icon.clone())))
+            })
+            .collect();
+        let selected_key = self
+            .games
+            .get_selected()
+            .and_then(|item| item.launch_key.clone());
+
+        let mut items: Vec<LauncherItem> = games
             .into_iter()
             .map(|entry| {
                 let mut item = LauncherItem::from_app_entry(entry);
+                if let Some((id, icon)) = item.launch_key.as_ref().and_then(|key| known.get(key)) {
+                    item.id = *id;
+                    if item.icon.is_none() {
+                        item.icon = icon.clone();
+                    }
+                }
                 // Lookup launch history using game identifier
                 if let Some(launch_key) = item.launch_key.as_ref() {
                     if let Some(&timestamp) = self.game_launch_history.get(launch_key) {
                         item.last_started = Some(timestamp);
                     }
                 }
+                item.favorite = Self::is_favorite(&self.favorites, &item);
+                item.launch_prefix = self.saved_launch_prefix(&item);
+                item.launch_options = self.saved_launch_options(&item);
+                item.prefer_source_art = item
+                    .launch_key
+                    .as_ref()
+                    .is_some_and(|key| self.prefer_source_art.contains(key));
                 item
             })
             .collect();
-        self.games.set_items(items);
-        self.games.sort_inplace();
+        if self.settings.merge_duplicate_games {
+            items = merge_duplicates(items);
+        }
+        self.hidden_game_items.clear();
+        self.set_game_items(items);
+        if let Some(index) = selected_key.and_then(|key| {
+            self.games
+                .items
+                .iter()
+                .position(|item| item.launch_key.as_ref() == Some(&key))
+        }) {
+            self.games.selected_index = index;
+        }
         self.games_loaded = true;
         self.status_message = None;
-
-        self.create_image_fetch_tasks()
+        // A rescan only brings back the error banner if something new failed
+        if self.scan_errors != scan_errors {
+            self.scan_errors_dismissed = false;
+        }
+        self.scan_errors = scan_errors;
+        self.refresh_quick_launch();
+
+        let fetch_tasks = Task::batch([
+            self.create_image_fetch_tasks(),
+            self.fetch_proton_tiers(),
+            self.load_nearby_covers(),
+            self.sync_placeholder_covers(),
+        ]);
+        if first_scan {
+            Task::batch([fetch_tasks, self.scroll_to_start_category()])
+        } else {
+            fetch_tasks
+        }
     }
 
-    fn create_image_fetch_tasks(&self) -> Task<Message> {
-        let Some(cache) = &self.image_cache else {
+    /// Looks up the ProtonDB tiers of all Steam games in the background
+    fn fetch_proton_tiers(&self) -> Task<Message> {
+        if !self.settings.protondb_badges {
             return Task::none();
-        };
-
-        let target_width = (GAME_POSTER_WIDTH as f64 * self.scale_factor) as u32;
-        let target_height = (GAME_POSTER_HEIGHT as f64 * self.scale_factor) as u32;
-        let pipeline_template = GameImageFetcher::new(
-            cache.cache_dir.clone(),
-            self.sgdb_client.clone(),
-            self.searxng_client.clone(),
-            target_width,
-            target_height,
-        );
-
-        let tasks: Vec<_> = self
+        }
+        let appids: Vec<String> = self
             .games
             .items
-            .par_iter()
-            .map(|game| {
-                let game_id = game.id;
-                let game_name = game.name.clone();
-                let source_image_url = game.source_image_url.clone();
-                let steam_appid = game.steam_appid.clone();
-                let pipeline = pipeline_template.clone();
-
-                Task::perform(
-                    async move {
-                        tokio::task::spawn_blocking(move || {
-                            pipeline.fetch(
-                                game_id,
-                                &game_name,
-                                source_image_url.as_deref(),
-                                steam_appid.as_deref(),
-                            )
-                        })
-                        .await
-                        .map_err(|e| anyhow::anyhow!("Task join error: {}", e))?
-                    },
-                    |res| match res {
-                        Ok(Some((id, path))) => Message::ImageFetched(id, path),
-                        _ => Message::None,
-                    },
-                )
-            })
+            .iter()
+            .filter_map(|item| item.steam_appid.clone())
             .collect();
-
-        Task::batch(tasks)
+        if appids.is_empty() {
+            return Task::none();
+        }
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || fetch_proton_tiers(appids))
+                    .await
+                    .unwrap_or_default()
+            },
+            Message::ProtonTiersLoaded,
+        )
     }
 
-    fn handle_image_fetched(&mut self, id: uuid::Uuid, path: PathBuf) -> Task<Message> {
-        self.games.update_item_by_id(id, |item| {
-            item.icon = Some(path.to_string_lossy().to_string());
-        });
+    fn handle_proton_tiers_loaded(&mut self, tiers: HashMap<String, ProtonTier>) -> Task<Message> {
+        self.proton_tiers = tiers;
+        for item in &mut self.games.items {
+            item.proton_tier = Self::proton_tier_of(&self.proton_tiers, item);
+        }
+        self.refresh_quick_launch();
         Task::none()
     }
 
-    fn handle_window_opened(&mut self, id: window::Id) -> Task<Message> {
-        self.window_id = Some(id);
+    fn proton_tier_of(
+        tiers: &HashMap<String, ProtonTier>,
+        item: &LauncherItem,
+    ) -> Option<ProtonTier> {
+        tiers.get(item.steam_appid.as_ref()?).copied()
+    }
 
-        // If we are recreating the window (returning from game), skip the update check
-        if self.recreating_window {
-            self.recreating_window = false;
+    /// Brings the start row into view once all rows are filled; scrolling any
+    /// earlier is cut short by the still empty rows.
+    fn scroll_to_start_category(&mut self) -> Task<Message> {
+        if self.category == Category::Favorites {
             return Task::none();
         }
+        // Unless the user already moved on during the scan
+        let start = self.settings.start_category;
+        if self.category == start.category() {
+            self.category = initial_category(start, &self.main_rows());
+        }
 
-        // Acquire sleep inhibition now that window is open
-        self.sleep_inhibitor.acquire();
-
-        if cfg!(debug_assertions) {
-            info!("Debug mode detected: Skipping app update check");
+        let target_y = self.main_scroll_offset(self.category);
+        if self.settings.startup_scroll_animation && !self.settings.reduce_motion && target_y > 0.0
+        {
+            self.startup_scroll = Some(StartupScroll::new(target_y, Instant::now()));
+            return Task::none();
+        }
+        self.snap_to_main_selection()
+    }
+
+    fn advance_startup_scroll(&mut self, now: Instant) -> Task<Message> {
+        let Some(scroll) = &self.startup_scroll else {
+            return Task::none();
+        };
+        let (offset, arrived) = scroll.offset_at(now);
+        if arrived {
+            self.startup_scroll = None;
+            return self.snap_to_main_selection();
+        }
+        operation::scroll_to(
+            self.main_scroll_id.clone(),
+            iced::widget::scrollable::AbsoluteOffset { x: 0.0, y: offset },
+        )
+    }
+
+    /// Re-applies the duplicate merge setting to the loaded games.
+    fn regroup_duplicate_games(&mut self) {
+        let mut items = split_duplicates(std::mem::take(&mut self.games.items));
+        items.extend(split_duplicates(std::mem::take(
+            &mut self.hidden_game_items,
+        )));
+        // Merged tiles carried the newest launch of all copies
+        for item in &mut items {
+            item.last_started = item
+                .launch_key
+                .as_ref()
+                .and_then(|key| self.game_launch_history.get(key).copied());
+        }
+        if self.settings.merge_duplicate_games {
+            items = merge_duplicates(items);
+        }
+        self.set_game_items(items);
+        self.refresh_quick_launch();
+    }
+
+    /// Splits games into the visible row and the hidden list
+    fn set_game_items(&mut self, items: Vec<LauncherItem>) {
+        let (mut visible, hidden) = partition_hidden(items, &self.hidden_games);
+        for item in &mut visible {
+            item.proton_tier = Self::proton_tier_of(&self.proton_tiers, item);
+        }
+        self.hidden_game_items = hidden;
+        self.games.set_items(visible);
+        self.games.sort_inplace();
+    }
+
+    /// Queues cover downloads for all games, nearest to the selection first.
+    fn create_image_fetch_tasks(&mut self) -> Task<Message> {
+        if self.image_cache.is_none() {
+            return Task::none();
+        }
+        self.image_fetch_queue
+            .replace(self.games.items.iter().map(|game| game.id));
+        self.prioritize_image_fetches();
+        self.start_image_fetches()
+    }
+
+    /// Moves the selected game's neighbors to the front of the download queue.
+    fn prioritize_image_fetches(&mut self) {
+        let row: Vec<Uuid> = self.games.items.iter().map(|game| game.id).collect();
+        self.image_fetch_queue
+            .prioritize(&row, self.games.selected_index);
+    }
+
+    /// Starts queued downloads while fewer than the concurrency limit are running.
+    fn start_image_fetches(&mut self) -> Task<Message> {
+        let Some(pipeline_template) = self.image_fetcher() else {
+            return Task::none();
+        };
+
+        let mut tasks = Vec::new();
+        // Games gone since they were queued free their slot right away, so keep taking
+        // until the slots are full or nothing is pending; otherwise no finished
+        // download would restart the queue
+        loop {
+            let started = self.image_fetch_queue.start_next();
+            if started.is_empty() {
+                break;
+            }
+            for game_id in started {
+                // Gone since it was queued, e.g. hidden or removed by a rescan
+                let Some(game) = self.games.items.iter().find(|game| game.id == game_id) else {
+                    self.image_fetch_queue.finish(game_id);
+                    continue;
+                };
+                let game_name = game.name.clone();
+                let source_image_url = game.source_image_url.clone();
+                let steam_appid = game.steam_appid.clone();
+                let sgdb_id = game
+                    .launch_key
+                    .as_ref()
+                    .and_then(|key| self.artwork_overrides.get(key).copied());
+                let prefer_source = game.prefer_source_art;
+                let pipeline = pipeline_template.clone();
+
+                tasks.push(Task::perform(
+                    async move {
+                        tokio::task::spawn_blocking(move || {
+                            pipeline.fetch(
+                                game_id,
+                                &game_name,
+                                source_image_url.as_deref(),
+                                steam_appid.as_deref(),
+                                sgdb_id,
+                                prefer_source,
+                            )
+                        })
+                        .await
+                        .map_err(|e| anyhow::anyhow!("Task join error: {}", e))?
+                    },
+                    move |res| match res {
+                        Ok(Some((id, path))) => Message::ImageFetched(id, Some(path)),
+                        _ => Message::ImageFetched(game_id, None),
+                    },
+                ));
+            }
+        }
+
+        Task::batch(tasks)
+    }
+
+    fn image_fetcher(&self) -> Option<GameImageFetcher> {
+        let cache = self.image_cache.as_ref()?;
+
+        let target_width = (GAME_POSTER_WIDTH as f64 * self.scale_factor) as u32;
+        let target_height = (GAME_POSTER_HEIGHT as f64 * self.scale_factor) as u32;
+        let cache = ImageCache {
+            format: self.settings.cover_format,
+            quality: self.settings.cover_quality,
+            ..cache.clone()
+        };
+        Some(
+            GameImageFetcher::new(
+                cache,
+                self.sgdb_client.clone(),
+                self.searxng_client.clone(),
+                target_width,
+                target_height,
+            )
+            .with_web_search(self.settings.allow_web_image_search)
+            .with_resolution(self.settings.cover_resolution),
+        )
+    }
+
+    fn handle_image_fetched(&mut self, id: uuid::Uuid, path: Option<PathBuf>) -> Task<Message> {
+        self.image_fetch_queue.finish(id);
+        let mut load = Task::none();
+        if let Some(path) = path {
+            // Fix Artwork can replace a cover under the same file name
+            self.cover_handles.invalidate(&path);
+            let icon = path.to_string_lossy().to_string();
+            self.games
+                .update_item_by_id(id, |item| item.icon = Some(icon.clone()));
+            self.recent
+                .update_item_by_id(id, |item| item.icon = Some(icon));
+            // Only read if it is near a selection, so it doesn't push nearer covers out
+            load = self.load_nearby_covers();
+        }
+        Task::batch([load, self.start_image_fetches()])
+    }
+
+    fn handle_window_opened(&mut self, id: window::Id) -> Task<Message> {
+        self.window_id = Some(id);
+
+        // If we are recreating the window (returning from game), skip the update check
+        if self.recreating_window {
+            self.recreating_window = false;
+            return Task::none();
+        }
+
+        // Acquire sleep inhibition now that window is open
+        self.sleep_inhibitor.acquire();
+
+        if cfg!(debug_assertions) {
+            info!("Debug mode detected: Skipping app update check");
             return Task::none();
         }
 
@@ -531,7 +1512,10 @@ impl Launcher {
                 selected_app.exec.clone(),
                 icon_path,
             )
-            .with_launch_key(format!("desktop:{}", selected_app.exec));
+            .with_launch_key(format!("desktop:{}", selected_app.exec))
+            .with_run_in_terminal(selected_app.terminal)
+            .with_wm_class(selected_app.wm_class.clone())
+            .with_actions(selected_app.actions.clone());
 
             let new_item = LauncherItem::from_app_entry(new_entry);
 
@@ -557,6 +1541,87 @@ impl Launcher {
         Task::none()
     }
 
+    fn open_custom_entry(&mut self) -> Task<Message> {
+        self.modal = ModalState::CustomEntry(CustomEntryState::new());
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    /// Whether the open modal has a text field that takes pasted text
+    fn text_entry_active(&self) -> bool {
+        match &self.modal {
+            ModalState::SetupWizard(state) => state.step == SetupStep::ApiKey,
+            ModalState::CustomEntry(_)
+            | ModalState::FixArtwork(_)
+            | ModalState::LaunchOptions(_)
+            | ModalState::Auth(_)
+            | ModalState::SystemUpdateAuth { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Appends clipboard text to the field of the open modal, like typing it on
+    /// the on-screen keyboard
+    fn handle_paste(&mut self, text: String) -> Task<Message> {
+        if !self.text_entry_active() {
+            return Task::none();
+        }
+        let message = KeyboardMessage::Paste(text);
+        match &self.modal {
+            ModalState::CustomEntry(_) => self.handle_custom_entry_keyboard_message(message),
+            ModalState::FixArtwork(_) => self.handle_fix_artwork_keyboard_message(message),
+            ModalState::LaunchOptions(_) => self.handle_launch_options_keyboard_message(message),
+            ModalState::SetupWizard(_) => self.handle_setup_keyboard_message(message),
+            ModalState::Auth(_) | ModalState::SystemUpdateAuth { .. } => {
+                self.handle_auth_keyboard_message(message)
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn handle_custom_entry_keyboard_message(&mut self, message: KeyboardMessage) -> Task<Message> {
+        let output = match self.custom_entry_state_mut() {
+            Some(state) => state.keyboard.handle_message(message),
+            None => return Task::none(),
+        };
+
+        self.handle_custom_entry_keyboard_output(output)
+    }
+
+    fn handle_custom_entry_keyboard_output(&mut self, output: KeyboardOutput) -> Task<Message> {
+        let Some(state) = self.custom_entry_state_mut() else {
+            return Task::none();
+        };
+
+        match output {
+            KeyboardOutput::Input(value) => state.set_value(value),
+            // Enter moves through the fields and saves on the last one
+            KeyboardOutput::Submit => {
+                if !state.focus_next() {
+                    self.save_custom_entry();
+                }
+            }
+            KeyboardOutput::None => {}
+        }
+        Task::none()
+    }
+
+    fn save_custom_entry(&mut self) {
+        let Some(state) = self.custom_entry_state_mut() else {
+            return;
+        };
+
+        match state.build_entry() {
+            Ok(entry) => {
+                let name = entry.name.clone();
+                self.apps.add_item(LauncherItem::from_app_entry(entry));
+                self.save_apps_config("Added", "adding", &name);
+                self.close_modal();
+            }
+            Err(err) => state.error = Some(err),
+        }
+    }
+
     fn start_system_update(&mut self) -> Task<Message> {
         self.osk_manager.show();
         self.modal = ModalState::SystemUpdate(SystemUpdateState::new());
@@ -565,12 +1630,14 @@ impl Launcher {
     }
 
     fn handle_system_update_progress(&mut self, progress: SystemUpdateProgress) -> Task<Message> {
+        let mut completed = false;
         if let Some(state) = self.system_update_state_mut() {
             // Prevent updates if the process is already finished (e.g. cancelled/failed)
             // This avoids race conditions where pending stream messages overwrite the cancellation state
             if !state.status.is_finished() {
                 match progress {
                     SystemUpdateProgress::StatusChange(new_status) => {
+                        completed = matches!(new_status, UpdateStatus::Completed { .. });
                         state.status = new_status;
                     }
                     SystemUpdateProgress::LogLine(line) => {
@@ -582,6 +1649,11 @@ impl Launcher {
                 }
             }
         }
+        if completed {
+            // Everything was just installed; recount on the next tick
+            self.pending_updates = 0;
+            self.last_update_count_check = None;
+        }
         Task::none()
     }
 
@@ -596,13 +1668,8 @@ impl Launcher {
     }
 
     fn request_reboot(&mut self) -> Task<Message> {
-        if let Err(e) = std::process::Command::new("systemctl")
-            .arg("reboot")
-            .spawn()
-        {
-            self.status_message = Some(format!("Failed to reboot: {}", e));
-        }
-        Task::none()
+        self.flush_config(true);
+        self.system_command("systemctl", &["reboot"], "reboot")
     }
 
     fn open_system_info(&mut self) -> Task<Message> {
@@ -620,6 +1687,52 @@ impl Launcher {
         )
     }
 
+    fn open_game_details(&mut self) -> Task<Message> {
+        let Some(item) = self.current_category_list().get_selected() else {
+            return self.close_modal_none();
+        };
+        let Some(appid) = steam_appid(item) else {
+            return self.close_modal_none();
+        };
+        self.modal = ModalState::GameDetails {
+            appid: appid.clone(),
+            item_name: item.name.clone(),
+            details: None,
+        };
+        self.sync_overlay_alpha();
+
+        Task::perform(
+            async move {
+                let fetch_appid = appid.clone();
+                let result =
+                    tokio::task::spawn_blocking(move || fetch_game_metadata(&fetch_appid)).await;
+                let result = match result {
+                    Ok(metadata) => metadata.map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                (appid, result)
+            },
+            |(appid, result)| Message::GameDetailsLoaded(appid, result),
+        )
+    }
+
+    fn handle_game_details_loaded(
+        &mut self,
+        loaded_appid: String,
+        result: Result<GameMetadata, String>,
+    ) -> Task<Message> {
+        if let Err(e) = &result {
+            warn!("Failed to load store details of {}: {}", loaded_appid, e);
+        }
+        // The modal may have been closed or opened for another game meanwhile
+        if let ModalState::GameDetails { appid, details, .. } = &mut self.modal {
+            if *appid == loaded_appid {
+                *details = Some(result);
+            }
+        }
+        Task::none()
+    }
+
     fn handle_system_info_loaded(&mut self, info_box: Box<GamingSystemInfo>) -> Task<Message> {
         if let ModalState::SystemInfo(state) = &mut self.modal {
             **state = Some(*info_box);
@@ -752,15 +1865,105 @@ impl Launcher {
     }
 
     fn handle_game_exited(&mut self) -> Task<Message> {
+        let step = self.settings.on_game_exit.step(self.embedded_mode());
+        if step == GameExitStep::Quit {
+            info!("Game exited, quitting as configured");
+            // Tasks don't run anymore once the process exits
+            if let (Some(backend), Some(previous)) =
+                (self.power_backend, self.power_profile_before_game.take())
+            {
+                if let Err(err) = set_power_profile(backend, &previous) {
+                    error!("Failed to restore power profile: {}", err);
+                }
+            }
+            self.exit_app();
+        }
+
         self.game_running = false;
+        self.controller_lost = false;
+        // No ticks arrive while playing, so the idle time starts over now
+        self.idle_suspend.activity(Instant::now());
         self.try_show_pending_update();
+        let rescan = if std::mem::take(&mut self.games_changed_while_playing) {
+            info!("Rescanning game sources after the game directories changed during the game");
+            self.rescan_throttle.try_begin(Instant::now());
+            self.scan_games_task()
+        } else if self.settings.rescan_on_return && self.rescan_throttle.try_begin(Instant::now()) {
+            info!("Rescanning game sources after returning from a game");
+            self.scan_games_task()
+        } else {
+            Task::none()
+        };
+        let delay = Duration::from_millis(self.settings.exit_delay_ms);
+        let window = if delay.is_zero() {
+            self.window_after_game(step)
+        } else {
+            Task::perform(async move { tokio::time::sleep(delay).await }, move |_| {
+                Message::ExitDelayElapsed(step)
+            })
+        };
+        Task::batch(vec![window, self.restore_power_profile(), rescan])
+    }
+
+    fn window_after_game(&mut self, step: GameExitStep) -> Task<Message> {
+        match step {
+            GameExitStep::RecreateWindow => self.recreate_window_after_game(),
+            GameExitStep::FocusWindow => self.window_id.map_or_else(Task::none, window::gain_focus),
+            GameExitStep::StayInBackground | GameExitStep::Quit => Task::none(),
+        }
+    }
+
+    fn handle_launch_diagnosed(
+        &mut self,
+        item_name: String,
+        failure: Option<LaunchFailure>,
+    ) -> Task<Message> {
+        let Some(failure) = failure else {
+            return Task::none();
+        };
+        warn!(
+            "{} failed to start: {}\n{}",
+            item_name,
+            failure.summary(),
+            failure.stderr_tail
+        );
+        self.modal = ModalState::LaunchFailed { item_name, failure };
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    /// Raises the launcher over the game while no controller is left, which makes most
+    /// games pause, and hands the screen back once one reconnects
+    fn handle_controller_presence(&mut self, change: PresenceChange) -> Task<Message> {
+        let Some(id) = self.window_id.filter(|_| self.game_running) else {
+            return Task::none();
+        };
+        match change {
+            PresenceChange::AllDisconnected => {
+                warn!("All controllers disconnected while a game is running");
+                self.controller_lost = true;
+                Task::batch(vec![window::minimize(id, false), window::gain_focus(id)])
+            }
+            PresenceChange::Reconnected => {
+                info!("Controller reconnected, returning to the game");
+                self.controller_lost = false;
+                if self.embedded_mode() {
+                    Task::none()
+                } else {
+                    window::minimize(id, true)
+                }
+            }
+        }
+    }
+
+    /// Brings the launcher back in front once a game has exited
+    fn recreate_window_after_game(&mut self) -> Task<Message> {
         if let Some(old_id) = self.window_id {
-            let settings = window::Settings {
-                decorations: false,
-                fullscreen: true,
-                level: window::Level::AlwaysOnTop,
-                ..Default::default()
-            };
+            let mut settings =
+                window_settings(self.settings.window_mode, self.settings.window_geometry);
+            if self.settings.window_mode != WindowMode::Windowed {
+                settings.level = window::Level::AlwaysOnTop;
+            }
             let (new_id, open_task) = window::open(settings);
             self.window_id = Some(new_id);
             self.recreating_window = true;
@@ -825,33 +2028,33 @@ impl Launcher {
     /// Sync overlay alpha animation target with current modal state.
     /// Call after EVERY `self.modal = ...` assignment.
     fn sync_overlay_alpha(&mut self) {
-        use crate::ui_theme::{COLOR_OVERLAY, COLOR_OVERLAY_STRONG};
+        use crate::ui_theme::modal_overlay_color;
 
         match &self.modal {
             ModalState::None => {
                 // Instant dismiss — no fade-out animation
                 self.overlay_alpha.update(iced_anim::Event::SettleAt(0.0));
             }
-            ModalState::ContextMenu { .. } => {
-                // Context menu uses lighter overlay (COLOR_OVERLAY alpha = 0.7)
-                self.overlay_alpha.set_target(COLOR_OVERLAY.a);
-            }
-            _ => {
-                // All other modals use stronger overlay (COLOR_OVERLAY_STRONG alpha = 0.85)
-                self.overlay_alpha.set_target(COLOR_OVERLAY_STRONG.a);
+            modal => {
+                // Context menu uses a lighter overlay than the other modals
+                let context_menu = matches!(modal, ModalState::ContextMenu { .. });
+                let color = modal_overlay_color(context_menu, self.settings.reduce_transparency);
+                self.overlay_alpha.set_target(color.a);
             }
         }
     }
 
     fn start_app_update(&mut self) -> Task<Message> {
-        if let ModalState::AppUpdate(state) = &mut self.modal {
-            state.phase = AppUpdatePhase::Updating;
-            state.status_message = None;
-        }
+        let ModalState::AppUpdate(state) = &mut self.modal else {
+            return Task::none();
+        };
+        state.phase = AppUpdatePhase::Updating;
+        state.status_message = None;
+        let version = state.release.version.clone();
 
         Task::perform(
-            async {
-                tokio::task::spawn_blocking(apply_update)
+            async move {
+                tokio::task::spawn_blocking(move || apply_update(version))
                     .await
                     .map_err(|e| format!("Task join error: {}", e))
                     .and_then(|r| r)
@@ -896,8 +2099,10 @@ impl Launcher {
     }
 
     fn restart_app(&mut self) -> Task<Message> {
-        if let Some(exe) = &self.current_exe {
-            restart_process(exe.clone());
+        if let Some(exe) = self.current_exe.clone() {
+            // The restart replaces this process without returning
+            self.flush_config(true);
+            restart_process(exe);
         }
         Task::none()
     }
@@ -905,8 +2110,9 @@ impl Launcher {
     fn update_app_picker_cols(&mut self) {
         let width = self.window_width;
         let scale = self.ui_scale;
+        let density = self.settings.density;
         if let Some(state) = self.app_picker_state_mut() {
-            state.update_cols(width, scale);
+            state.update_cols(width, scale, density);
         }
     }
 
@@ -917,6 +2123,13 @@ impl Launcher {
         }
     }
 
+    fn custom_entry_state_mut(&mut self) -> Option<&mut CustomEntryState> {
+        match &mut self.modal {
+            ModalState::CustomEntry(state) => Some(state),
+            _ => None,
+        }
+    }
+
     fn app_picker_state_mut(&mut self) -> Option<&mut AppPickerState> {
         match &mut self.modal {
             ModalState::AppPicker(state) => Some(state),
@@ -976,6 +2189,18 @@ impl Launcher {
         if let Some(status) = render_status(&self.status_message, self.ui_scale) {
             column = column.push(status);
         }
+        if self.scan_error_banner_visible() {
+            // One source can have several broken files
+            let failed_sources: HashSet<&str> = self
+                .scan_errors
+                .iter()
+                .map(|error| error.source_name)
+                .collect();
+            column = column.push(render_scan_error_banner(
+                failed_sources.len(),
+                self.ui_scale,
+            ));
+        }
 
         let scrollable_content = Scrollable::new(column)
             .width(Length::Fill)
@@ -1048,16 +2273,43 @@ impl Launcher {
             }
         }
 
-        let status_bar_row = status_bar_row.push(render_clock(&self.current_time, self.ui_scale));
+        let status_bar_row = status_bar_row.push(render_clock(
+            &self.current_time,
+            self.settings.clock_24h,
+            self.ui_scale,
+        ));
 
         let status_bar = Container::new(status_bar_row)
             .padding([10.0 * self.ui_scale, 20.0 * self.ui_scale])
             .width(Length::Fill);
 
-        let background = self.background.view();
+        let background: Element<'_, Message> = match BackgroundKind::from_settings(&self.settings) {
+            BackgroundKind::Pattern => self.background.view(),
+            BackgroundKind::Solid => solid_background(),
+        };
+
+        // The backdrop always takes one layer, so the rows above keep their position
+        // in the tree and the scroll state survives crossfades
+        let backdrop_layers = self.backdrop.layers().into_iter().map(|(path, opacity)| {
+            let handle = self
+                .cover_handles
+                .get(path)
+                .unwrap_or_else(|| iced::widget::image::Handle::from_path(path));
+            Element::from(
+                Image::new(handle)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .content_fit(ContentFit::Cover)
+                    .opacity(opacity),
+            )
+        });
+        let backdrop = Stack::with_children(backdrop_layers)
+            .width(Length::Fill)
+            .height(Length::Fill);
 
         let mut base_stack = Stack::new()
             .push(background)
+            .push(backdrop)
             .push(main_content)
             .push(status_bar);
 
@@ -1071,7 +2323,58 @@ impl Launcher {
 
         let base_view = base_stack.into();
 
-        self.render_with_modal(base_view)
+        let view = self.render_with_modal(base_view);
+        let splash = self.controller_splash_visible().then(|| {
+            render_controller_splash(
+                self.settings.keyboard_bindings.reference(),
+                self.settings.reduce_transparency,
+                self.ui_scale,
+            )
+        });
+        let view = Stack::new().push(view).push(optional_layer(splash));
+        let controller_lost = self
+            .controller_lost
+            .then(|| render_controller_lost(self.settings.reduce_transparency, self.ui_scale));
+        let view = Stack::new()
+            .push(view)
+            .push(optional_layer(controller_lost));
+        let view = Stack::new()
+            .push(view)
+            .push(optional_layer(self.suspend_countdown.map(|seconds_left| {
+                render_auto_suspend_countdown(
+                    seconds_left,
+                    self.settings.reduce_transparency,
+                    self.ui_scale,
+                )
+            })));
+
+        // Always wrapped, so toggling the cursor doesn't reset the state of the widgets
+        // below; `Interaction::None` leaves the cursor to them
+        let interaction = if self.cursor_hidden() {
+            iced::mouse::Interaction::Hidden
+        } else {
+            iced::mouse::Interaction::None
+        };
+        iced::widget::mouse_area(view)
+            .interaction(interaction)
+            .into()
+    }
+
+    /// Opt-in notice while only keyboards are connected; hides again as soon as a
+    /// gamepad shows up
+    fn controller_splash_visible(&self) -> bool {
+        self.settings.controller_splash
+            && self.gamepads_reported
+            && !self.controller_splash_dismissed
+            && !has_functional_gamepad(&self.gamepad_infos)
+    }
+
+    fn cursor_hidden(&self) -> bool {
+        let enabled = self
+            .settings
+            .hide_cursor
+            .unwrap_or_else(|| self.settings.window_mode.is_fullscreen());
+        enabled && !self.cursor_visibility.is_visible()
     }
 
     fn render_with_modal<'a>(&'a self, main_content: Element<'a, Message>) -> Element<'a, Message> {
@@ -1110,15 +2413,39 @@ impl Launcher {
     fn render_modal_layer(&self) -> Option<Element<'_, Message>> {
         let scale = self.ui_scale;
         match &self.modal {
-            ModalState::ContextMenu { index } => {
-                Some(render_context_menu(*index, self.category, scale))
-            }
-            ModalState::AppPicker(state) => {
-                Some(render_app_picker(state, &self.available_apps, scale))
-            }
+            ModalState::ContextMenu { index } => Some(render_context_menu(
+                &self.selected_context_menu_entries(),
+                *index,
+                scale,
+            )),
+            ModalState::AppActions { index } => Some(render_app_actions_menu(
+                self.current_category_list()
+                    .get_selected()
+                    .map_or(&[], |item| item.actions.as_slice()),
+                *index,
+                scale,
+            )),
+            ModalState::LaunchSource { index } => Some(render_launch_source_menu(
+                self.current_category_list().get_selected(),
+                *index,
+                scale,
+            )),
+            ModalState::AppPicker(state) => Some(render_app_picker(
+                state,
+                &self.available_apps,
+                scale,
+                self.settings.density,
+            )),
+            ModalState::CustomEntry(state) => Some(render_custom_entry_modal(state, scale)),
+            ModalState::FixArtwork(state) => Some(render_fix_artwork_modal(state, scale)),
+            ModalState::MissingArtwork(state) => Some(render_list_modal(state, scale)),
+            ModalState::LaunchOptions(state) => Some(render_launch_options_modal(state, scale)),
             ModalState::SystemUpdate(state) => Some(render_system_update_modal(state, scale)),
             ModalState::AppUpdate(state) => Some(render_app_update_modal(state, scale)),
             ModalState::SystemInfo(info) => Some(render_system_info_modal(info, scale)),
+            ModalState::Display(state) => Some(render_list_modal(state, scale)),
+            ModalState::PowerProfiles(state) => Some(render_list_modal(state, scale)),
+            ModalState::ManageGames(state) => Some(render_manage_games_modal(state, scale)),
             ModalState::SystemUpdateAuth { auth, .. } => {
                 Some(render_auth_dialog(&auth.flow, &auth.keyboard, scale))
             }
@@ -1134,20 +2461,89 @@ impl Launcher {
                 *selected_index,
                 scale,
             )),
-            ModalState::Help => Some(render_help_modal(scale)),
+            ModalState::LowMemory {
+                item,
+                available,
+                selected_index,
+                ..
+            } => Some(render_low_memory_modal(
+                &item.name,
+                available,
+                *selected_index,
+                scale,
+            )),
+            ModalState::ConfirmExit { selected_index } => {
+                Some(render_confirm_exit_modal(*selected_index, scale))
+            }
+            ModalState::ConfirmClearHistory { selected_index } => {
+                Some(render_confirm_clear_history_modal(*selected_index, scale))
+            }
+            ModalState::Help => Some(render_help_modal(self.settings.swap_ab, scale)),
+            ModalState::Settings { index } => {
+                Some(render_settings_modal(&self.settings, *index, scale))
+            }
+            ModalState::Logs(state) => Some(render_logs_modal(state, scale)),
+            ModalState::ScanErrors(errors) => Some(render_scan_errors_modal(errors, scale)),
+            ModalState::LaunchFailed { item_name, failure } => {
+                Some(render_launch_failed_modal(item_name, failure, scale))
+            }
+            ModalState::GameDetails {
+                item_name, details, ..
+            } => Some(render_game_details_modal(
+                item_name,
+                details.as_ref(),
+                scale,
+            )),
+            ModalState::StickCalibration {
+                horizontal_drift,
+                vertical_drift,
+            } => Some(render_stick_calibration_modal(
+                *horizontal_drift,
+                *vertical_drift,
+                scale,
+            )),
+            ModalState::SetupWizard(state) => Some(render_setup_wizard(
+                state,
+                self.games_loaded.then(|| self.setup_source_summary()),
+                &self.scan_errors,
+                scale,
+            )),
             ModalState::None => None,
         }
     }
 
     pub fn subscription(&self) -> Subscription<Message> {
-        // Disable all input subscriptions while a game is running
+        let games_changed = if self.settings.watch_game_dirs {
+            Subscription::run(games_changed_stream).map(|_| Message::GamesChanged)
+        } else {
+            Subscription::none()
+        };
+
+        // Disable all input subscriptions while a game is running. The directory
+        // watcher reads no input and keeps running, so games installed during the
+        // session (e.g. from Steam started by the launcher) are noticed.
         if self.game_running {
-            return Subscription::none();
+            let controller_presence = if self.settings.controller_loss_prompt {
+                Subscription::run(controller_presence_stream).map(Message::ControllerPresence)
+            } else {
+                Subscription::none()
+            };
+            return Subscription::batch([controller_presence, games_changed]);
         }
 
-        let gamepad = gamepad_subscription().map(|event| match event {
+        let gamepad = gamepad_subscription(GamepadConfig {
+            deadzone: self.settings.stick_deadzone,
+            report_stick: matches!(self.modal, ModalState::StickCalibration { .. }),
+            swap_ab: self.settings.swap_ab,
+            led_mode: self.settings.controller_led,
+            power_saving: self.settings.gamepad_power_saving,
+        })
+        .map(|event| match event {
+            GamepadEvent::Input(Action::Select) => Message::GamepadSelect(true),
+            GamepadEvent::SelectReleased => Message::GamepadSelect(false),
             GamepadEvent::Input(action) => Message::Input(action),
             GamepadEvent::Battery(batteries) => Message::GamepadBatteryUpdate(batteries),
+            GamepadEvent::Stick(axis, value) => Message::StickMoved(axis, value),
         });
 
         let window_events = iced::event::listen_with(|event, _status, window_id| match event {
@@ -1160,7 +2556,14 @@ impl Launcher {
             Event::Window(iced::window::Event::Resized(size)) => {
                 Some(Message::WindowResized(size.width, size.height))
             }
+            Event::Window(iced::window::Event::Moved(position)) => {
+                Some(Message::WindowMoved(position.x, position.y))
+            }
+            Event::Window(iced::window::Event::CloseRequested) => {
+                Some(Message::WindowCloseRequested)
+            }
             Event::Window(iced::window::Event::Focused) => Some(Message::WindowFocused(window_id)),
+            Event::Mouse(iced::mouse::Event::CursorMoved { .. }) => Some(Message::MouseMoved),
             _ => None,
         });
 
@@ -1169,6 +2572,30 @@ impl Launcher {
 
         let mut subscriptions = vec![gamepad, keyboard, window_events, askpass];
 
+        if self.focus_animation.is_animating() {
+            subscriptions
+                .push(iced::time::every(FOCUS_ANIMATION_FRAME).map(Message::FocusAnimationTick));
+        }
+        if self.backdrop.is_animating() {
+            subscriptions.push(iced::time::every(FOCUS_ANIMATION_FRAME).map(Message::BackdropTick));
+        }
+        if self.startup_scroll.is_some() {
+            subscriptions
+                .push(iced::time::every(FOCUS_ANIMATION_FRAME).map(Message::StartupScrollTick));
+        }
+        if self.launch_hold.is_some() {
+            subscriptions
+                .push(iced::time::every(FOCUS_ANIMATION_FRAME).map(Message::HoldToLaunchTick));
+        }
+
+        subscriptions.push(games_changed);
+
+        if self.scan_spinner_active() {
+            subscriptions.push(
+                iced::time::every(Duration::from_millis(150)).map(|_| Message::ScanSpinnerTick),
+            );
+        }
+
         // Clock subscription (every 1 second)
         subscriptions
             .push(iced::time::every(Duration::from_secs(1)).map(|_| Message::Tick(Local::now())));
@@ -1198,6 +2625,11 @@ impl Launcher {
         Subscription::batch(subscriptions)
     }
 
+    /// The Games row spins while the library is scanned for the first time
+    fn scan_spinner_active(&self) -> bool {
+        !self.games_loaded
+    }
+
     fn build_keyboard_subscription(&self) -> Subscription<Message> {
         iced::event::listen_with(|event, status, _window| {
             if let iced::event::Status::Captured = status {
@@ -1205,54 +2637,199 @@ impl Launcher {
             }
 
             match event {
-                Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => match key.as_ref() {
-                    Key::Named(Named::ArrowUp) => Some(Message::Input(Action::Up)),
-                    Key::Named(Named::ArrowDown) => Some(Message::Input(Action::Down)),
-                    Key::Named(Named::ArrowLeft) => Some(Message::Input(Action::Left)),
-                    Key::Named(Named::ArrowRight) => Some(Message::Input(Action::Right)),
-                    Key::Named(Named::Enter) => Some(Message::Input(Action::Select)),
-                    Key::Named(Named::Escape) => Some(Message::Input(Action::Back)),
-                    Key::Named(Named::Tab) => Some(Message::Input(Action::NextCategory)),
-                    Key::Named(Named::F4) => Some(Message::Input(Action::Quit)),
-                    Key::Character("c") => Some(Message::Input(Action::ContextMenu)),
-                    Key::Character("+") | Key::Character("a") => {
-                        Some(Message::Input(Action::AddApp))
-                    }
-                    Key::Character("-") => Some(Message::Input(Action::ShowHelp)),
-                    _ => None,
-                },
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Character(c),
+                    modifiers,
+                    ..
+                }) if modifiers.command() && c.eq_ignore_ascii_case("v") => {
+                    Some(Message::PasteRequested)
+                }
+                // Mapped to an action through the configured bindings in `update`
+                Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                    Some(Message::KeyPressed(key))
+                }
                 _ => None,
             }
         })
     }
 
     fn handle_modal_navigation(&mut self, action: Action) -> Option<Task<Message>> {
-        match &self.modal {
-            ModalState::Help => Some(self.handle_help_modal_navigation(action)),
+        match &mut self.modal {
+            ModalState::Help
+            | ModalState::ScanErrors(_)
+            | ModalState::LaunchFailed { .. }
+            | ModalState::GameDetails { .. } => Some(self.handle_help_modal_navigation(action)),
             ModalState::ContextMenu { .. } => Some(self.handle_context_menu_navigation(action)),
+            ModalState::AppActions { .. } => Some(self.handle_app_actions_navigation(action)),
+            ModalState::LaunchSource { .. } => Some(self.handle_launch_source_navigation(action)),
             ModalState::AppPicker(_) => Some(self.handle_app_picker_navigation(action)),
+            ModalState::CustomEntry(_) => Some(self.handle_custom_entry_navigation(action)),
+            ModalState::FixArtwork(state) => Some(
+                handle_fix_artwork_navigation(state, action)
+                    .map_or_else(Task::none, |message| self.update(message)),
+            ),
+            ModalState::MissingArtwork(state) => Some(
+                handle_list_navigation(state, action, |index, game| {
+                    Message::FixMissingArtwork(index, game.item_id)
+                })
+                .map_or_else(Task::none, |message| self.update(message)),
+            ),
+            ModalState::LaunchOptions(_) => Some(self.handle_launch_options_navigation(action)),
             ModalState::SystemUpdate(_) => Some(self.handle_system_update_navigation(action)),
             ModalState::SystemUpdateAuth { .. } => Some(self.handle_auth_navigation(action)),
             ModalState::AppUpdate(state) => {
                 handle_app_update_navigation(state, action).map(|message| self.update(message))
             }
             ModalState::SystemInfo(_) => Some(self.handle_system_info_navigation(action)),
+            ModalState::Display(state) => Some(
+                handle_list_navigation(state, action, |_, mode| {
+                    Message::ApplyDisplayMode(mode.clone())
+                })
+                .map_or_else(Task::none, |message| self.update(message)),
+            ),
+            ModalState::PowerProfiles(state) => Some(
+                handle_list_navigation(state, action, |_, profile| {
+                    Message::ApplyPowerProfile(profile.name.clone())
+                })
+                .map_or_else(Task::none, |message| self.update(message)),
+            ),
+            ModalState::ManageGames(_) => Some(self.handle_manage_games_navigation(action)),
             ModalState::AppNotFound { .. } => Some(self.handle_app_not_found_navigation(action)),
             ModalState::Auth(_) => Some(self.handle_auth_navigation(action)),
+            ModalState::Settings { .. } => Some(self.handle_settings_navigation(action)),
+            ModalState::Logs(_) => Some(self.handle_logs_navigation(action)),
+            ModalState::StickCalibration { .. } => {
+                Some(self.handle_stick_calibration_navigation(action))
+            }
+            ModalState::SetupWizard(state) => Some(
+                handle_setup_wizard_navigation(state, action)
+                    .map_or_else(Task::none, |message| self.update(message)),
+            ),
+            ModalState::LowMemory { .. } => Some(self.handle_low_memory_navigation(action)),
+            ModalState::ConfirmExit { .. } => Some(self.handle_confirm_exit_navigation(action)),
+            ModalState::ConfirmClearHistory { .. } => {
+                Some(self.handle_confirm_clear_history_navigation(action))
+            }
             ModalState::None => None,
         }
     }
 
     fn exit_app(&mut self) -> ! {
+        if self.settings.window_mode == WindowMode::Windowed {
+            self.save_apps_config("Saved", "saving", "window geometry");
+        }
+        self.flush_config(true);
         self.osk_manager.restore();
         self.sleep_inhibitor.release();
         std::process::exit(0);
     }
 
+    fn take_screenshot(&mut self) -> Task<Message> {
+        let Some(id) = self.window_id else {
+            return Task::none();
+        };
+        window::screenshot(id).then(|screenshot| {
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        save_screenshot(
+                            &screenshot.rgba,
+                            screenshot.size.width,
+                            screenshot.size.height,
+                        )
+                    })
+                    .await
+                    .map_err(|e| format!("Task join error: {}", e))
+                    .and_then(|r| r)
+                },
+                Message::ScreenshotSaved,
+            )
+        })
+    }
+
+    /// Switches between fullscreen and windowed and remembers the new mode.
+    fn toggle_fullscreen(&mut self) -> Task<Message> {
+        // Steam and gamescope manage the window themselves
+        if self.embedded_mode() {
+            return Task::none();
+        }
+        let Some(id) = self.window_id else {
+            return Task::none();
+        };
+
+        let previous = self.settings.window_mode;
+        let mode = previous.toggled();
+        self.settings.window_mode = mode;
+        self.save_apps_config("Switched", "switching", "window mode");
+
+        let mut tasks = Vec::new();
+        // Fullscreen and borderless windows are created without decorations
+        if (previous == WindowMode::Windowed) != (mode == WindowMode::Windowed) {
+            tasks.push(window::toggle_decorations(id));
+        }
+        if mode.is_fullscreen() {
+            tasks.push(window::set_mode(id, window::Mode::Fullscreen));
+        } else {
+            tasks.push(window::set_mode(id, window::Mode::Windowed));
+            tasks.push(window::resize(
+                id,
+                windowed_size(self.settings.window_geometry),
+            ));
+        }
+        Task::batch(tasks)
+    }
+
+    /// Gamepad Select on a game starts a hold when `hold_to_launch` is on; everywhere
+    /// else it acts like any other Select. Letting go early cancels the launch.
+    fn handle_gamepad_select(&mut self, pressed: bool) -> Task<Message> {
+        if !pressed {
+            return match self.launch_hold.take() {
+                Some(hold) if hold.is_complete(Instant::now()) => self.finish_launch_hold(),
+                _ => Task::none(),
+            };
+        }
+        if !self.hold_to_launch_applies() {
+            return self.update(Message::Input(Action::Select));
+        }
+        self.cursor_visibility.navigated();
+        if self.register_activity() {
+            return Task::none();
+        }
+        self.startup_scroll = None;
+        self.launch_hold = Some(HoldToLaunch::new(Instant::now()));
+        Task::none()
+    }
+
+    /// Launches the held game, whether the tick or the release noticed the completed hold
+    fn finish_launch_hold(&mut self) -> Task<Message> {
+        self.launch_hold = None;
+        self.play_ui_sound(UiSound::Select);
+        self.activate_selected()
+    }
+
+    fn hold_to_launch_applies(&self) -> bool {
+        self.settings.hold_to_launch
+            && matches!(self.modal, ModalState::None)
+            && self.category == Category::Games
+            && !self.games.is_empty()
+    }
+
     fn handle_navigation(&mut self, action: Action) -> Task<Message> {
+        // Any input ends the startup scroll where the user takes over
+        self.startup_scroll = None;
+        self.launch_hold = None;
+        if let Some(sound) = UiSound::for_action(action) {
+            self.play_ui_sound(sound);
+        }
         if action == Action::Quit {
             self.exit_app();
         }
+        if action == Action::Screenshot {
+            return self.take_screenshot();
+        }
+        if action == Action::ToggleFullscreen {
+            return self.toggle_fullscreen();
+        }
 
         // Modal navigation takes priority
         if let Some(task) = self.handle_modal_navigation(action) {
@@ -1269,13 +2846,41 @@ impl Launcher {
             Action::AddApp if self.category == Category::Apps => {
                 return self.update(Message::OpenAppPicker);
             }
+            Action::RelaunchLast => return self.relaunch_last(),
+            Action::CycleSort => return self.cycle_sort(),
+            Action::OpenConfigDir => return self.open_config_dir(),
+            Action::AddApp if self.scan_error_banner_visible() => {
+                self.modal = ModalState::ScanErrors(self.scan_errors.clone());
+                self.sync_overlay_alpha();
+                return Task::none();
+            }
             Action::ContextMenu if !self.current_category_list().is_empty() => {
                 self.modal = ModalState::ContextMenu { index: 0 };
                 self.sync_overlay_alpha();
                 return Task::none();
             }
+            Action::Back if self.category == Category::Favorites => {
+                // "Show all": leave quick launch for the full category view
+                self.category = Category::Games;
+                return self.snap_to_main_selection();
+            }
+            Action::Back if self.scan_error_banner_visible() => {
+                self.scan_errors_dismissed = true;
+                return Task::none();
+            }
+            Action::Back
+                if self.settings.start_in_quick_launch && self.status_message.is_none() =>
+            {
+                self.category = Category::Favorites;
+                return self.snap_to_main_selection();
+            }
             Action::Back => {
                 self.status_message = None;
+                if self.settings.double_back_to_exit && self.double_back.press(Instant::now()) {
+                    // Cancel is preselected so a third press doesn't quit by accident
+                    self.modal = ModalState::ConfirmExit { selected_index: 1 };
+                    self.sync_overlay_alpha();
+                }
                 return Task::none();
             }
             _ => {}
@@ -1287,37 +2892,50 @@ impl Launcher {
 
     /// Handles Up/Down/Left/Right and category cycling navigation.
     fn handle_directional_navigation(&mut self, action: Action) -> Task<Message> {
+        let wrap = self.settings.wrap_navigation;
         match action {
             Action::Up => {
-                let prev_cat = self.category.prev();
+                let prev_cat = self.step_category(false);
                 if prev_cat != self.category {
                     self.category = prev_cat;
-                    return self.snap_to_main_selection();
+                    return self.follow_selection();
                 }
             }
             Action::Down => {
-                let next_cat = self.category.next();
+                let next_cat = self.step_category(true);
                 if next_cat != self.category {
                     self.category = next_cat;
-                    return self.snap_to_main_selection();
+                    return self.follow_selection();
                 }
             }
-            Action::Left if self.current_category_list_mut().move_left() => {
-                return self.snap_to_main_selection();
+            Action::Left if self.current_category_list_mut().move_left(wrap) => {
+                if self.category == Category::Games {
+                    self.prioritize_image_fetches();
+                }
+                return self.follow_selection();
             }
-            Action::Right if self.current_category_list_mut().move_right() => {
-                return self.snap_to_main_selection();
+            Action::Right if self.current_category_list_mut().move_right(wrap) => {
+                if self.category == Category::Games {
+                    self.prioritize_image_fetches();
+                }
+                return self.follow_selection();
             }
             Action::Select if !self.current_category_list().is_empty() => {
                 return self.activate_selected();
             }
             Action::NextCategory => {
                 self.cycle_category();
-                return self.snap_to_main_selection();
+                return self.follow_selection();
+            }
+            // Also leaves the quick launch screen; hidden rows can't be jumped to
+            Action::JumpCategory(target) if self.main_rows().contains(&target) => {
+                self.category = target;
+                self.status_message = None;
+                return self.follow_selection();
             }
             Action::PrevCategory => {
                 self.cycle_category_back();
-                return self.snap_to_main_selection();
+                return self.follow_selection();
             }
             _ => {}
         }
@@ -1325,6 +2943,11 @@ impl Launcher {
         Task::none()
     }
 
+    /// Scrolls to the moved selection and reads the covers around it into memory
+    fn follow_selection(&mut self) -> Task<Message> {
+        Task::batch([self.snap_to_main_selection(), self.load_nearby_covers()])
+    }
+
     fn snap_to_main_selection(&self) -> Task<Message> {
         let list = self.current_category_list();
         let scroll_id = list.scroll_id.clone();
@@ -1332,7 +2955,8 @@ impl Launcher {
         let (item_width, _item_height, _image_width, _image_height) =
             get_category_dimensions(self.category, self.ui_scale);
 
-        let item_width_with_spacing = item_width + (ITEM_SPACING * self.ui_scale);
+        let item_width_with_spacing =
+            item_width + self.settings.density.item_spacing(self.ui_scale);
 
         let target_x = list.selected_index as f32 * item_width_with_spacing;
         let center_offset = target_x - (self.window_width / 2.0) + (item_width / 2.0);
@@ -1347,33 +2971,18 @@ impl Launcher {
         .chain(self.scroll_main_to_category())
     }
 
-    fn scroll_main_to_category(&self) -> Task<Message> {
-        let category_index = match self.category {
-            Category::Games => 0,
-            Category::Apps => 1,
-            Category::System => 2,
-        };
-
-        let title_height = BASE_FONT_TITLE * self.ui_scale;
-        let padding = BASE_PADDING_SMALL * self.ui_scale;
-        let spacing = CATEGORY_ROW_SPACING * self.ui_scale;
-
-        let mut target_y = 0.0;
-
-        for i in 0..category_index {
-            let cat = match i {
-                0 => Category::Games,
-                1 => Category::Apps,
-                _ => Category::System,
-            };
-
-            let (_item_width, item_height, _image_width, _image_height) =
-                get_category_dimensions(cat, self.ui_scale);
-
-            let row_height = item_height;
-
-            target_y += title_height + padding + row_height + padding + spacing;
+    /// Rows of the full category view, top to bottom
+    fn main_rows(&self) -> Vec<Category> {
+        let mut rows = Vec::with_capacity(4);
+        if self.recent_row_visible() {
+            rows.push(Category::Recent);
         }
+        rows.extend([Category::Games, Category::Apps, Category::System]);
+        rows
+    }
+
+    fn scroll_main_to_category(&self) -> Task<Message> {
+        let target_y = self.main_scroll_offset(self.category);
 
         operation::scroll_to(
             self.main_scroll_id.clone(),
@@ -1384,20 +2993,34 @@ impl Launcher {
         )
     }
 
+    /// Vertical offset of the main view that puts `category` at the top
+    fn main_scroll_offset(&self, category: Category) -> f32 {
+        let title_height = BASE_FONT_TITLE * self.ui_scale;
+        let padding = BASE_PADDING_SMALL * self.ui_scale;
+        let spacing = self.settings.density.category_spacing(self.ui_scale);
+
+        row_offset(&self.main_rows(), category, |cat| {
+            let (_item_width, item_height, _image_width, _image_height) =
+                get_category_dimensions(cat, self.ui_scale);
+
+            title_height + padding + item_height + padding + spacing
+        })
+    }
+
+    fn selected_context_menu_entries(&self) -> Vec<ContextMenuEntry> {
+        self.current_category_list()
+            .get_selected()
+            .map(|item| context_menu_entries(self.category, item, self.gamescope_available))
+            .unwrap_or_default()
+    }
+
     fn handle_context_menu_navigation(&mut self, action: Action) -> Task<Message> {
         let mut index = match &self.modal {
             ModalState::ContextMenu { index } => *index,
             _ => return Task::none(),
         };
 
-        // Context menu options vary by category:
-        // Apps: [Launch, Remove, Quit, Close] (indices 0-3)
-        // Games/System: [Launch, Quit, Close] (indices 0-2)
-        let max_index = if self.category == Category::Apps {
-            3
-        } else {
-            2
-        };
+        let max_index = self.selected_context_menu_entries().len().saturating_sub(1);
 
         match action {
             Action::Up => index = index.saturating_sub(1),
@@ -1412,146 +3035,888 @@ impl Launcher {
         Task::none()
     }
 
-    /// Executes the selected context menu action based on category and index.
-    fn execute_context_menu_action(&mut self, index: usize) -> Task<Message> {
-        // Index 0 is always "Launch" for all categories
-        if index == 0 {
-            self.modal = ModalState::None;
-            self.sync_overlay_alpha();
-            return self.activate_selected();
-        }
-
-        // For Apps category: index 1 = Remove, index 2 = Quit, index 3 = Close
-        // For Games/System: index 1 = Quit, index 2 = Close
-        let (remove_index, quit_index, close_index) = if self.category == Category::Apps {
-            (Some(1), 2, 3)
-        } else {
-            (None, 1, 2)
-        };
-
-        if remove_index == Some(index) {
-            self.close_modal();
-            if let Some(removed) = self.apps.remove_selected() {
-                self.save_apps_config("Removed", "removing", &removed.name);
-            }
+    /// Launches the chosen copy of a merged game; the last row cancels.
+    fn handle_launch_source_navigation(&mut self, action: Action) -> Task<Message> {
+        let ModalState::LaunchSource { index } = self.modal else {
             return Task::none();
-        }
-
-        if index == quit_index {
-            self.exit_app();
-        }
-
-        // close_index or any unhandled index -> close modal
-        if index == close_index {
+        };
+        let Some(item) = self.current_category_list().get_selected().cloned() else {
             return self.close_modal_none();
-        }
-
-        Task::none()
-    }
+        };
 
-    fn handle_help_modal_navigation(&mut self, action: Action) -> Task<Message> {
         match action {
-            Action::Back | Action::ShowHelp => self.close_modal_none(),
-            _ => Task::none(), // Ignore other inputs while modal is open
+            Action::Up => {
+                self.modal = ModalState::LaunchSource {
+                    index: index.saturating_sub(1),
+                }
+            }
+            Action::Down => {
+                self.modal = ModalState::LaunchSource {
+                    index: (index + 1).min(item.alternates.len() + 1),
+                }
+            }
+            Action::Back | Action::ContextMenu => return self.close_modal_none(),
+            Action::Select => {
+                let chosen = match index {
+                    0 => Some(item.clone()),
+                    i => item.alternates.get(i - 1).cloned(),
+                };
+                let Some(mut chosen) = chosen else {
+                    return self.close_modal_none();
+                };
+                self.close_modal();
+                self.status_message = None;
+                // Keep the merged tile's id so it gets the launch timestamp
+                chosen.id = item.id;
+                info!("Launching {} from {}", item.name, source_label(&chosen));
+                return match &chosen.action {
+                    LauncherAction::Launch { exec } => {
+                        self.launch_app(exec, &chosen, chosen.game_executable.as_ref())
+                    }
+                    _ => Task::none(),
+                };
+            }
+            _ => {}
         }
+        Task::none()
     }
 
-    fn handle_app_not_found_navigation(&mut self, action: Action) -> Task<Message> {
-        let (item_id, item_name, category, mut selected_index) = match &self.modal {
-            ModalState::AppNotFound {
-                item_id,
-                item_name,
-                category,
-                selected_index,
-            } => (*item_id, item_name.clone(), *category, *selected_index),
-            _ => return Task::none(),
+    /// Launches the chosen desktop entry action; the last row returns to the context menu.
+    fn handle_app_actions_navigation(&mut self, action: Action) -> Task<Message> {
+        let ModalState::AppActions { index } = self.modal else {
+            return Task::none();
+        };
+        let Some(item) = self.current_category_list().get_selected().cloned() else {
+            return self.close_modal_none();
         };
+        let actions_entry_index = self
+            .selected_context_menu_entries()
+            .iter()
+            .position(|entry| *entry == ContextMenuEntry::Actions)
+            .unwrap_or(0);
 
         match action {
-            Action::Left | Action::Right | Action::Up | Action::Down => {
-                // Toggle between the two options (Remove / Cancel)
-                selected_index = 1 - selected_index;
+            Action::Up => {
+                self.modal = ModalState::AppActions {
+                    index: index.saturating_sub(1),
+                }
             }
-            Action::Select => {
-                if selected_index == 0 {
-                    self.remove_missing_item(item_id, &item_name, category);
+            Action::Down => {
+                self.modal = ModalState::AppActions {
+                    index: (index + 1).min(item.actions.len()),
                 }
-                return self.close_modal_none();
             }
-            Action::Back | Action::ContextMenu | Action::ShowHelp => {
-                return self.close_modal_none();
+            Action::Back => {
+                self.modal = ModalState::ContextMenu {
+                    index: actions_entry_index,
+                }
             }
+            Action::ContextMenu => return self.close_modal_none(),
+            Action::Select => match item.actions.get(index) {
+                Some(app_action) => {
+                    self.modal = ModalState::None;
+                    self.sync_overlay_alpha();
+                    self.status_message = None;
+                    info!("Running action '{}' of {}", app_action.name, item.name);
+                    return self.launch_app(&app_action.exec, &item, None);
+                }
+                None => {
+                    self.modal = ModalState::ContextMenu {
+                        index: actions_entry_index,
+                    }
+                }
+            },
             _ => {}
         }
-
-        self.modal = ModalState::AppNotFound {
-            item_id,
-            item_name,
-            category,
-            selected_index,
-        };
-        self.sync_overlay_alpha();
         Task::none()
     }
 
-    fn handle_system_update_navigation(&mut self, action: Action) -> Task<Message> {
-        if let ModalState::SystemUpdate(state) = &self.modal {
-            match &state.status {
-                UpdateStatus::Completed { restart_required } if *restart_required => match action {
-                    Action::Select => return self.update(Message::RequestReboot),
-                    Action::Back | Action::ShowHelp => {
-                        return self.update(Message::CloseSystemUpdateModal)
-                    }
-                    _ => {}
-                },
-                // Finished states -> Close
-                status if status.is_finished() => match action {
-                    Action::Back | Action::Select | Action::ShowHelp => {
-                        return self.update(Message::CloseSystemUpdateModal);
+    /// Executes the context menu entry at the given index for the selected item.
+    fn execute_context_menu_action(&mut self, index: usize) -> Task<Message> {
+        let Some(entry) = self.selected_context_menu_entries().get(index).copied() else {
+            return self.close_modal_none();
+        };
+
+        match entry {
+            ContextMenuEntry::Launch => {
+                self.modal = ModalState::None;
+                self.sync_overlay_alpha();
+                self.activate_selected()
+            }
+            ContextMenuEntry::Actions => {
+                self.modal = ModalState::AppActions { index: 0 };
+                Task::none()
+            }
+            ContextMenuEntry::ToggleFavorite { .. } => {
+                self.close_modal();
+                self.toggle_selected_favorite();
+                Task::none()
+            }
+            ContextMenuEntry::ToggleForce16x9 { .. } => {
+                self.close_modal();
+                self.toggle_selected_force_16_9();
+                Task::none()
+            }
+            ContextMenuEntry::StorePage => {
+                self.close_modal();
+                let page = self
+                    .current_category_list()
+                    .get_selected()
+                    .and_then(store_page);
+                match page {
+                    Some(page) => {
+                        let (program, url) = page.command();
+                        self.system_command(program, &[&url], "open store page")
                     }
-                    _ => {}
-                },
-                // Running states -> Cancel if allowed
-                status if status.is_running() => {
-                    if !matches!(status, UpdateStatus::Installing { .. }) && action == Action::Back
-                    {
-                        return self.update(Message::CancelSystemUpdate);
+                    None => Task::none(),
+                }
+            }
+            ContextMenuEntry::Details => self.open_game_details(),
+            ContextMenuEntry::FixArtwork => self.open_fix_artwork(),
+            ContextMenuEntry::ToggleSourceArt { .. } => {
+                self.close_modal();
+                self.toggle_selected_source_art()
+            }
+            ContextMenuEntry::CreateShortcut => {
+                self.close_modal();
+                self.create_desktop_shortcut();
+                Task::none()
+            }
+            ContextMenuEntry::CopyLaunchCommand => {
+                self.close_modal();
+                self.copy_launch_command()
+            }
+            ContextMenuEntry::LaunchOptions => self.open_launch_options(),
+            ContextMenuEntry::RemoveEntry => {
+                self.close_modal();
+                if let Some(removed) = self.apps.remove_selected() {
+                    if let Some(launch_key) = removed.launch_key.as_ref() {
+                        self.favorites.remove(launch_key);
                     }
+                    self.refresh_quick_launch();
+                    self.save_apps_config("Removed", "removing", &removed.name);
                 }
-                _ => {}
+                Task::none()
             }
+            ContextMenuEntry::QuitLauncher => self.exit_app(),
+            ContextMenuEntry::Close => self.close_modal_none(),
         }
-        Task::none()
     }
 
-    fn handle_system_info_navigation(&mut self, action: Action) -> Task<Message> {
-        match action {
-            Action::Back | Action::Select | Action::ShowHelp => {
-                return self.update(Message::CloseSystemInfoModal);
-            }
-            _ => {}
+    /// Flips the favorite flag of the selected item in every list and persists it.
+    fn toggle_selected_favorite(&mut self) {
+        let Some(item) = self.current_category_list().get_selected().cloned() else {
+            return;
+        };
+        let Some(launch_key) = item.launch_key.clone() else {
+            return;
+        };
+
+        let favorite = !item.favorite;
+        if favorite {
+            self.favorites.insert(launch_key);
+        } else {
+            self.favorites.remove(&launch_key);
         }
-        Task::none()
-    }
 
-    fn handle_auth_navigation(&mut self, action: Action) -> Task<Message> {
-        enum NavAction {
-            Cancel,
-            Keyboard(KeyboardOutput),
+        for list in [&mut self.games, &mut self.apps, &mut self.system_items] {
+            list.update_item_by_id(item.id, |i| i.favorite = favorite);
         }
+        self.refresh_quick_launch();
 
-        let next_action = {
-            let state = match self.auth_state_mut() {
-                Some(state) => state,
-                None => return Task::none(),
-            };
+        if favorite {
+            self.save_apps_config("Favorited", "favoriting", &item.name);
+        } else {
+            self.save_apps_config("Unfavorited", "unfavoriting", &item.name);
+        }
+    }
 
-            match &state.flow.state {
-                AuthFlowState::AwaitingPassword { .. } => match action {
-                    Action::Up => {
-                        state.keyboard.move_up();
-                        None
-                    }
+    fn open_settings(&mut self) -> Task<Message> {
+        self.modal = ModalState::Settings { index: 0 };
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    fn handle_settings_navigation(&mut self, action: Action) -> Task<Message> {
+        let mut index = match &self.modal {
+            ModalState::Settings { index } => *index,
+            _ => return Task::none(),
+        };
+        let mut follow_up = Task::none();
+
+        match action {
+            Action::Up => index = index.saturating_sub(1),
+            Action::Down => index = (index + 1).min(SettingsEntry::ALL.len() - 1),
+            Action::Select
+                if SettingsEntry::ALL.get(index) == Some(&SettingsEntry::CalibrateStick) =>
+            {
+                self.modal = ModalState::StickCalibration {
+                    horizontal_drift: 0.0,
+                    vertical_drift: 0.0,
+                };
+                return Task::none();
+            }
+            Action::Select
+                if SettingsEntry::ALL.get(index) == Some(&SettingsEntry::ManageGames) =>
+            {
+                return self.open_manage_games();
+            }
+            Action::Select
+                if SettingsEntry::ALL.get(index) == Some(&SettingsEntry::MissingArtwork) =>
+            {
+                return self.open_missing_artwork(0);
+            }
+            Action::Select
+                if SettingsEntry::ALL.get(index) == Some(&SettingsEntry::ConfigFolder) =>
+            {
+                self.close_modal();
+                return self.open_config_dir();
+            }
+            Action::Select
+                if SettingsEntry::ALL.get(index) == Some(&SettingsEntry::ClearHistory) =>
+            {
+                // Cancel is preselected, clearing can't be undone
+                self.modal = ModalState::ConfirmClearHistory { selected_index: 1 };
+                self.sync_overlay_alpha();
+                return Task::none();
+            }
+            Action::Select | Action::Left | Action::Right => {
+                if let Some(entry) = SettingsEntry::ALL.get(index) {
+                    entry.adjust(&mut self.settings, action != Action::Left);
+                    if *entry == SettingsEntry::MergeDuplicates {
+                        self.regroup_duplicate_games();
+                    }
+                    if *entry == SettingsEntry::Autostart {
+                        self.apply_autostart();
+                    }
+                    if *entry == SettingsEntry::PlaceholderCovers {
+                        follow_up = self.sync_placeholder_covers();
+                    }
+                    if *entry == SettingsEntry::ReduceTransparency {
+                        self.sync_overlay_alpha();
+                    }
+                    if *entry == SettingsEntry::UiScale {
+                        self.sync_ui_scale();
+                    }
+                    if matches!(
+                        entry,
+                        SettingsEntry::UiSounds | SettingsEntry::UiSoundVolume
+                    ) {
+                        self.sync_ui_sounds();
+                    }
+                    if *entry == SettingsEntry::ProtonDbBadges && self.proton_tiers.is_empty() {
+                        follow_up = self.fetch_proton_tiers();
+                    }
+                    // Recent row visibility and length depend on settings
+                    self.refresh_quick_launch();
+                    self.save_apps_config("Changed", "changing", entry.label());
+                }
+            }
+            Action::Back | Action::ShowHelp => return self.close_modal_none(),
+            _ => {}
+        }
+
+        self.modal = ModalState::Settings { index };
+        self.sync_overlay_alpha();
+        follow_up
+    }
+
+    /// Applies the suggested deadzones on Select; either way returns to the Settings modal.
+    fn handle_stick_calibration_navigation(&mut self, action: Action) -> Task<Message> {
+        let (horizontal_drift, vertical_drift) = match &self.modal {
+            ModalState::StickCalibration {
+                horizontal_drift,
+                vertical_drift,
+            } => (*horizontal_drift, *vertical_drift),
+            _ => return Task::none(),
+        };
+
+        match action {
+            Action::Select => {
+                self.settings.stick_deadzone.horizontal = suggest_deadzone(horizontal_drift);
+                self.settings.stick_deadzone.vertical = suggest_deadzone(vertical_drift);
+                self.save_apps_config("Changed", "changing", SettingsEntry::CalibrateStick.label());
+            }
+            Action::Back | Action::ShowHelp => {}
+            _ => return Task::none(),
+        }
+
+        let index = SettingsEntry::ALL
+            .iter()
+            .position(|entry| *entry == SettingsEntry::CalibrateStick)
+            .unwrap_or(0);
+        self.modal = ModalState::Settings { index };
+        Task::none()
+    }
+
+    fn open_logs(&mut self) -> Task<Message> {
+        self.modal = ModalState::Logs(LogViewerState::new(recent_log_lines()));
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    /// Collects system info off the UI thread and writes it with the recent log
+    /// lines to a file for attaching to bug reports
+    fn save_bug_report(&mut self) -> Task<Message> {
+        self.status_message = Some("Collecting bug report…".to_string());
+        let api_key = self.api_key.clone();
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    let created = Local::now();
+                    let report = build_bug_report(
+                        env!("CARGO_PKG_VERSION"),
+                        created,
+                        &fetch_system_info(),
+                        &recent_log_lines(),
+                        api_key.as_deref(),
+                    );
+                    save_bug_report(&report, created)
+                })
+                .await
+                .map_err(|e| format!("Task join error: {}", e))
+                .and_then(|r| r)
+            },
+            Message::BugReportSaved,
+        )
+    }
+
+    fn open_display_modes(&mut self) -> Task<Message> {
+        let Some(backend) = self.display_backend else {
+            return Task::none();
+        };
+        self.modal = ModalState::Display(ListModalState::new(ListModalText {
+            title: "Display Mode",
+            loading: "Loading display modes...",
+            empty: "No display modes found",
+            hint: "A to apply · B to close",
+        }));
+        self.sync_overlay_alpha();
+        Self::load_display_modes(backend)
+    }
+
+    fn load_display_modes(backend: DisplayBackend) -> Task<Message> {
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || list_display_modes(backend))
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result)
+            },
+            Message::DisplayModesLoaded,
+        )
+    }
+
+    fn handle_display_modes_loaded(
+        &mut self,
+        result: Result<Vec<DisplayMode>, String>,
+    ) -> Task<Message> {
+        if let ModalState::Display(state) = &mut self.modal {
+            match result {
+                Ok(modes) => state.set_items(modes),
+                Err(err) => {
+                    error!("Failed to list display modes: {}", err);
+                    state.set_error(err);
+                }
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_display_mode_applied(&mut self, result: Result<(), String>) -> Task<Message> {
+        let (ModalState::Display(state), Some(backend)) = (&mut self.modal, self.display_backend)
+        else {
+            return Task::none();
+        };
+        match result {
+            // Re-list so the "Current" marker follows the new mode
+            Ok(()) => Self::load_display_modes(backend),
+            Err(err) => {
+                error!("{}", err);
+                state.error = Some(err);
+                Task::none()
+            }
+        }
+    }
+
+    fn apply_display_mode(&self, mode: DisplayMode) -> Task<Message> {
+        let Some(backend) = self.display_backend else {
+            return Task::none();
+        };
+        info!("Switching display mode to {}", mode.label());
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || apply_display_mode(backend, &mode))
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result)
+            },
+            Message::DisplayModeApplied,
+        )
+    }
+
+    fn open_power_profiles(&mut self) -> Task<Message> {
+        let Some(backend) = self.power_backend else {
+            return Task::none();
+        };
+        self.modal = ModalState::PowerProfiles(ListModalState::new(ListModalText {
+            title: "Power Profile",
+            loading: "Loading power profiles...",
+            empty: "No power profiles found",
+            hint: "A to apply · B to close",
+        }));
+        self.sync_overlay_alpha();
+        Self::load_power_profiles(backend)
+    }
+
+    fn load_power_profiles(backend: PowerBackend) -> Task<Message> {
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || list_power_profiles(backend))
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result)
+            },
+            Message::PowerProfilesLoaded,
+        )
+    }
+
+    fn apply_power_profile(backend: PowerBackend, name: String) -> Task<Message> {
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || set_power_profile(backend, &name))
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result)
+            },
+            Message::PowerProfileApplied,
+        )
+    }
+
+    fn handle_power_profiles_loaded(
+        &mut self,
+        result: Result<Vec<PowerProfile>, String>,
+    ) -> Task<Message> {
+        if let ModalState::PowerProfiles(state) = &mut self.modal {
+            match result {
+                Ok(profiles) => state.set_items(profiles),
+                Err(err) => {
+                    error!("Failed to list power profiles: {}", err);
+                    state.set_error(err);
+                }
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_power_profile_applied(&mut self, result: Result<(), String>) -> Task<Message> {
+        if let Err(err) = &result {
+            error!("{}", err);
+        }
+        let (ModalState::PowerProfiles(state), Some(backend)) =
+            (&mut self.modal, self.power_backend)
+        else {
+            return Task::none();
+        };
+        match result {
+            // Re-list so the "Current" marker follows the new profile
+            Ok(()) => Self::load_power_profiles(backend),
+            Err(err) => {
+                state.error = Some(err);
+                Task::none()
+            }
+        }
+    }
+
+    fn switch_power_profile(&self, name: String) -> Task<Message> {
+        let Some(backend) = self.power_backend else {
+            return Task::none();
+        };
+        info!("Switching power profile to {}", name);
+        Self::apply_power_profile(backend, name)
+    }
+
+    /// Switches to the performance profile for the game that was just launched
+    fn switch_power_for_game(&self) -> Task<Message> {
+        let Some(backend) = self
+            .power_backend
+            .filter(|_| self.settings.performance_while_gaming)
+        else {
+            return Task::none();
+        };
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || switch_to_performance(backend))
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result)
+            },
+            Message::GamePowerProfileSwitched,
+        )
+    }
+
+    fn handle_game_power_profile_switched(
+        &mut self,
+        result: Result<Option<String>, String>,
+    ) -> Task<Message> {
+        match result {
+            Ok(previous) => {
+                if previous.is_some() {
+                    info!("Switched to the performance power profile while playing");
+                }
+                self.power_profile_before_game = previous;
+                // The game may have exited before the switch finished
+                if self.game_running {
+                    Task::none()
+                } else {
+                    self.restore_power_profile()
+                }
+            }
+            Err(err) => {
+                error!("Failed to switch power profile for game: {}", err);
+                Task::none()
+            }
+        }
+    }
+
+    /// Switches back to the profile that was active before the game started
+    fn restore_power_profile(&mut self) -> Task<Message> {
+        match (self.power_backend, self.power_profile_before_game.take()) {
+            (Some(backend), Some(previous)) => {
+                info!("Restoring power profile {}", previous);
+                Self::apply_power_profile(backend, previous)
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn open_manage_games(&mut self) -> Task<Message> {
+        let items: Vec<LauncherItem> = self
+            .games
+            .items
+            .iter()
+            .chain(&self.hidden_game_items)
+            .cloned()
+            .collect();
+        self.modal = ModalState::ManageGames(ManageGamesState::new(&items, &self.hidden_games));
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    /// Select checks games; X asks to apply the chosen operation and Select confirms it.
+    fn handle_manage_games_navigation(&mut self, action: Action) -> Task<Message> {
+        let ModalState::ManageGames(state) = &mut self.modal else {
+            return Task::none();
+        };
+
+        if state.confirming {
+            match action {
+                Action::Select => {
+                    let operation = state.operation;
+                    let keys: Vec<String> = state.checked.iter().cloned().collect();
+                    self.apply_batch_operation(operation, &keys);
+                    return self.open_manage_games();
+                }
+                Action::Back | Action::ShowHelp => state.confirming = false,
+                _ => {}
+            }
+            return Task::none();
+        }
+
+        match action {
+            Action::Back | Action::ShowHelp => return self.close_modal_none(),
+            Action::Select => state.toggle_selected(),
+            Action::ContextMenu if !state.checked.is_empty() => state.confirming = true,
+            _ => state.navigate(action),
+        }
+        Task::none()
+    }
+
+    /// Applies a batch operation to the given games and saves the config once.
+    fn apply_batch_operation(&mut self, operation: BatchOperation, launch_keys: &[String]) {
+        operation.apply(&mut self.favorites, &mut self.hidden_games, launch_keys);
+
+        let mut items = std::mem::take(&mut self.games.items);
+        items.append(&mut self.hidden_game_items);
+        for item in &mut items {
+            item.favorite = Self::is_favorite(&self.favorites, item);
+        }
+        self.set_game_items(items);
+        self.refresh_quick_launch();
+        // Hidden games don't need their covers anymore
+        let visible: HashSet<Uuid> = self.games.items.iter().map(|game| game.id).collect();
+        self.image_fetch_queue
+            .retain_pending(|id| visible.contains(&id));
+
+        self.save_apps_config(
+            operation.label(),
+            "applying batch operation to",
+            &format!("{} games", launch_keys.len()),
+        );
+    }
+
+    fn handle_logs_navigation(&mut self, action: Action) -> Task<Message> {
+        let scale = self.ui_scale;
+        match action {
+            Action::Back | Action::ShowHelp | Action::Select => self.close_modal_none(),
+            Action::Up | Action::Down => match &mut self.modal {
+                ModalState::Logs(state) => state.scroll(action, scale),
+                _ => Task::none(),
+            },
+            _ => Task::none(),
+        }
+    }
+
+    /// Wraps the selected item with the gamescope 16:9 preset, or removes the wrapper again.
+    fn toggle_selected_force_16_9(&mut self) {
+        let Some(item) = self.current_category_list().get_selected().cloned() else {
+            return;
+        };
+        let Some(launch_key) = item.launch_key.clone() else {
+            return;
+        };
+
+        let prefix = if item.launch_prefix.as_deref() == Some(GAMESCOPE_16_9_PREFIX) {
+            self.launch_prefixes.remove(&launch_key);
+            None
+        } else {
+            let prefix = GAMESCOPE_16_9_PREFIX.to_string();
+            self.launch_prefixes.insert(launch_key, prefix.clone());
+            Some(prefix)
+        };
+
+        for list in [&mut self.games, &mut self.apps, &mut self.system_items] {
+            list.update_item_by_id(item.id, |i| i.launch_prefix = prefix.clone());
+        }
+        self.refresh_quick_launch();
+        self.save_apps_config(
+            "Changed launch mode of",
+            "changing launch mode of",
+            &item.name,
+        );
+    }
+
+    /// Switches the selected game between its store's image and SteamGridDB art and
+    /// fetches the cover again. Games with a "Fix Artwork" override keep that cover.
+    fn toggle_selected_source_art(&mut self) -> Task<Message> {
+        let Some(item) = self.current_category_list().get_selected().cloned() else {
+            return Task::none();
+        };
+        let Some(launch_key) = item.launch_key.clone() else {
+            return Task::none();
+        };
+
+        let prefer_source = !item.prefer_source_art;
+        if prefer_source {
+            self.prefer_source_art.insert(launch_key.clone());
+        } else {
+            self.prefer_source_art.remove(&launch_key);
+        }
+        for list in [&mut self.games, &mut self.recent] {
+            list.update_item_by_id(item.id, |i| i.prefer_source_art = prefer_source);
+        }
+        self.save_apps_config(
+            "Changed artwork source of",
+            "changing artwork source of",
+            &item.name,
+        );
+
+        let Some(pipeline) = self.image_fetcher() else {
+            return Task::none();
+        };
+        let sgdb_id = self.artwork_overrides.get(&launch_key).copied();
+        let item_id = item.id;
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    pipeline.fetch(
+                        item_id,
+                        &item.name,
+                        item.source_image_url.as_deref(),
+                        item.steam_appid.as_deref(),
+                        sgdb_id,
+                        prefer_source,
+                    )
+                })
+                .await
+                .ok()
+                .and_then(Result::ok)
+                .flatten()
+                .map(|(_, path)| path)
+            },
+            move |path| Message::ImageFetched(item_id, path),
+        )
+    }
+
+    fn handle_help_modal_navigation(&mut self, action: Action) -> Task<Message> {
+        match action {
+            Action::Back | Action::ShowHelp => self.close_modal_none(),
+            _ => Task::none(), // Ignore other inputs while modal is open
+        }
+    }
+
+    fn handle_app_not_found_navigation(&mut self, action: Action) -> Task<Message> {
+        let (item_id, item_name, category, mut selected_index) = match &self.modal {
+            ModalState::AppNotFound {
+                item_id,
+                item_name,
+                category,
+                selected_index,
+            } => (*item_id, item_name.clone(), *category, *selected_index),
+            _ => return Task::none(),
+        };
+
+        match action {
+            Action::Left | Action::Right | Action::Up | Action::Down => {
+                // Toggle between the two options (Remove / Cancel)
+                selected_index = 1 - selected_index;
+            }
+            Action::Select => {
+                if selected_index == 0 {
+                    self.remove_missing_item(item_id, &item_name, category);
+                }
+                return self.close_modal_none();
+            }
+            Action::Back | Action::ContextMenu | Action::ShowHelp => {
+                return self.close_modal_none();
+            }
+            _ => {}
+        }
+
+        self.modal = ModalState::AppNotFound {
+            item_id,
+            item_name,
+            category,
+            selected_index,
+        };
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    fn handle_low_memory_navigation(&mut self, action: Action) -> Task<Message> {
+        let ModalState::LowMemory { selected_index, .. } = &mut self.modal else {
+            return Task::none();
+        };
+
+        match action {
+            Action::Left | Action::Right | Action::Up | Action::Down => {
+                // Toggle between the two options (Launch Anyway / Cancel)
+                *selected_index = 1 - *selected_index;
+                Task::none()
+            }
+            Action::Select if *selected_index == 0 => {
+                let ModalState::LowMemory { item, exec, .. } =
+                    std::mem::replace(&mut self.modal, ModalState::None)
+                else {
+                    return Task::none();
+                };
+                self.close_modal();
+                self.start_app(&exec, &item, item.game_executable.as_ref())
+            }
+            Action::Select | Action::Back | Action::ContextMenu | Action::ShowHelp => {
+                self.close_modal_none()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn handle_confirm_exit_navigation(&mut self, action: Action) -> Task<Message> {
+        let ModalState::ConfirmExit { selected_index } = &mut self.modal else {
+            return Task::none();
+        };
+
+        match action {
+            Action::Left | Action::Right | Action::Up | Action::Down => {
+                *selected_index = 1 - *selected_index;
+                Task::none()
+            }
+            Action::Select if *selected_index == 0 => self.exit_app(),
+            Action::Select | Action::Back | Action::ContextMenu | Action::ShowHelp => {
+                self.close_modal_none()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn handle_confirm_clear_history_navigation(&mut self, action: Action) -> Task<Message> {
+        let ModalState::ConfirmClearHistory { selected_index } = &mut self.modal else {
+            return Task::none();
+        };
+
+        match action {
+            Action::Left | Action::Right | Action::Up | Action::Down => {
+                *selected_index = 1 - *selected_index;
+                Task::none()
+            }
+            Action::Select if *selected_index == 0 => {
+                self.clear_launch_history();
+                self.close_modal_none()
+            }
+            Action::Select | Action::Back | Action::ContextMenu | Action::ShowHelp => {
+                self.close_modal_none()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    fn handle_system_update_navigation(&mut self, action: Action) -> Task<Message> {
+        if let ModalState::SystemUpdate(state) = &self.modal {
+            match &state.status {
+                UpdateStatus::Completed { restart_required } if *restart_required => match action {
+                    Action::Select => return self.update(Message::RequestReboot),
+                    Action::Back | Action::ShowHelp => {
+                        return self.update(Message::CloseSystemUpdateModal)
+                    }
+                    _ => {}
+                },
+                // Finished states -> Close
+                status if status.is_finished() => match action {
+                    Action::Back | Action::Select | Action::ShowHelp => {
+                        return self.update(Message::CloseSystemUpdateModal);
+                    }
+                    _ => {}
+                },
+                // Running states -> Cancel if allowed
+                status if status.is_running() => {
+                    if !matches!(status, UpdateStatus::Installing { .. }) && action == Action::Back
+                    {
+                        return self.update(Message::CancelSystemUpdate);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Task::none()
+    }
+
+    fn handle_system_info_navigation(&mut self, action: Action) -> Task<Message> {
+        match action {
+            Action::Back | Action::Select | Action::ShowHelp => {
+                return self.update(Message::CloseSystemInfoModal);
+            }
+            _ => {}
+        }
+        Task::none()
+    }
+
+    fn handle_auth_navigation(&mut self, action: Action) -> Task<Message> {
+        enum NavAction {
+            Cancel,
+            Keyboard(KeyboardOutput),
+        }
+
+        let next_action = {
+            let state = match self.auth_state_mut() {
+                Some(state) => state,
+                None => return Task::none(),
+            };
+
+            match &state.flow.state {
+                AuthFlowState::AwaitingPassword { .. } => match action {
+                    Action::Up => {
+                        state.keyboard.move_up();
+                        None
+                    }
                     Action::Down => {
                         state.keyboard.move_down();
                         None
@@ -1588,17 +3953,506 @@ impl Launcher {
             }
         };
 
-        match next_action {
-            Some(NavAction::Cancel) => self.handle_auth_cancel(),
-            Some(NavAction::Keyboard(output)) => self.handle_auth_keyboard_output(output),
+        match next_action {
+            Some(NavAction::Cancel) => self.handle_auth_cancel(),
+            Some(NavAction::Keyboard(output)) => self.handle_auth_keyboard_output(output),
+            None => Task::none(),
+        }
+    }
+
+    fn handle_custom_entry_navigation(&mut self, action: Action) -> Task<Message> {
+        let Some(state) = self.custom_entry_state_mut() else {
+            return Task::none();
+        };
+
+        let output = match action {
+            Action::Up => {
+                state.keyboard.move_up();
+                KeyboardOutput::None
+            }
+            Action::Down => {
+                state.keyboard.move_down();
+                KeyboardOutput::None
+            }
+            Action::Left => {
+                state.keyboard.move_left();
+                KeyboardOutput::None
+            }
+            Action::Right => {
+                state.keyboard.move_right();
+                KeyboardOutput::None
+            }
+            Action::Select => state.keyboard.select_current(),
+            Action::Back => {
+                if !state.keyboard.value().is_empty() {
+                    state.keyboard.backspace()
+                } else if state.focus_prev() {
+                    KeyboardOutput::None
+                } else {
+                    return self.close_modal_none();
+                }
+            }
+            Action::ShowHelp => return self.close_modal_none(),
+            _ => KeyboardOutput::None,
+        };
+
+        self.handle_custom_entry_keyboard_output(output)
+    }
+
+    fn open_fix_artwork(&mut self) -> Task<Message> {
+        let Some(item) = self.current_category_list().get_selected().cloned() else {
+            return self.close_modal_none();
+        };
+        self.open_fix_artwork_for(&item, None)
+    }
+
+    /// `review_index` is the position in the "Games Without Artwork" list to return to.
+    fn open_fix_artwork_for(
+        &mut self,
+        item: &LauncherItem,
+        review_index: Option<usize>,
+    ) -> Task<Message> {
+        let Some(launch_key) = item.launch_key.clone() else {
+            return self.close_modal_none();
+        };
+        let current = self.artwork_overrides.get(&launch_key).copied();
+        let mut state = FixArtworkState::new(item.id, item.name.clone(), launch_key, current);
+        state.review_index = review_index;
+        self.modal = ModalState::FixArtwork(state);
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    fn open_missing_artwork(&mut self, selected_index: usize) -> Task<Message> {
+        let games = games_missing_artwork(
+            &self.games.items,
+            |id| self.image_fetch_queue.is_queued(id),
+            |icon| {
+                self.image_cache
+                    .as_ref()
+                    .is_some_and(|cache| cache.is_placeholder(Path::new(icon)))
+            },
+        );
+        let mut state = ListModalState::new(ListModalText {
+            title: "Games Without Artwork",
+            loading: "Loading games...",
+            empty: "All games have artwork",
+            hint: "A to fix artwork · B to close",
+        });
+        state.set_items(games);
+        state.select(selected_index);
+        self.modal = ModalState::MissingArtwork(state);
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    fn fix_missing_artwork(&mut self, index: usize, id: Uuid) -> Task<Message> {
+        let Some(item) = self.games.items.iter().find(|item| item.id == id).cloned() else {
+            return Task::none();
+        };
+        self.open_fix_artwork_for(&item, Some(index))
+    }
+
+    /// Generates placeholder covers for items without artwork in the background, or
+    /// takes them off the items again while placeholders are turned off
+    fn sync_placeholder_covers(&mut self) -> Task<Message> {
+        let Some(cache) = self.image_cache.clone() else {
+            return Task::none();
+        };
+        let items = self
+            .games
+            .items
+            .iter_mut()
+            .chain(self.hidden_game_items.iter_mut())
+            .chain(self.apps.items.iter_mut());
+
+        if !self.settings.placeholder_covers {
+            for item in items {
+                if item
+                    .icon
+                    .as_deref()
+                    .is_some_and(|icon| cache.is_placeholder(Path::new(icon)))
+                {
+                    item.icon = None;
+                }
+            }
+            self.refresh_quick_launch();
+            return Task::none();
+        }
+
+        let missing: Vec<(Uuid, String)> = items
+            .filter(|item| item.icon.is_none())
+            .map(|item| (item.id, item.name.clone()))
+            .collect();
+        if missing.is_empty() {
+            return Task::none();
+        }
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    missing
+                        .into_iter()
+                        .filter_map(|(id, name)| {
+                            let path = cache.generate_placeholder_cover(&name).ok()?;
+                            Some((id, path))
+                        })
+                        .collect()
+                })
+                .await
+                .unwrap_or_default()
+            },
+            Message::PlaceholderCoversGenerated,
+        )
+    }
+
+    fn handle_placeholder_covers_generated(
+        &mut self,
+        covers: Vec<(Uuid, PathBuf)>,
+    ) -> Task<Message> {
+        // Turned off again while they were generated
+        if !self.settings.placeholder_covers {
+            return Task::none();
+        }
+        for (id, path) in &covers {
+            let items = self
+                .games
+                .items
+                .iter_mut()
+                .chain(self.hidden_game_items.iter_mut())
+                .chain(self.apps.items.iter_mut());
+            // Real artwork may have arrived in the meantime
+            for item in items.filter(|item| item.id == *id && item.icon.is_none()) {
+                item.icon = Some(path.to_string_lossy().to_string());
+            }
+        }
+        self.refresh_quick_launch();
+        self.load_nearby_covers()
+    }
+
+    /// Writes or removes the autostart entry to match the setting
+    fn apply_autostart(&mut self) {
+        let enabled = self.settings.autostart;
+        match set_autostart(enabled) {
+            Ok(()) => info!(enabled, "Updated autostart entry"),
+            Err(err) => {
+                error!("{}", err);
+                self.settings.autostart = !enabled;
+                self.status_message = Some(err);
+            }
+        }
+    }
+
+    /// Puts the command a launch would run on the clipboard, for pasting into a
+    /// terminal to see its errors
+    fn copy_launch_command(&mut self) -> Task<Message> {
+        let Some(item) = self.current_category_list().get_selected() else {
+            return Task::none();
+        };
+        let LauncherAction::Launch { exec } = &item.action else {
+            return Task::none();
+        };
+        let terminal = item
+            .run_in_terminal
+            .then(|| resolve_terminal(self.settings.terminal.as_deref()))
+            .flatten();
+        let command = build_launch_command(
+            exec,
+            terminal.as_deref(),
+            item.launch_options.as_deref(),
+            item.launch_prefix.as_deref(),
+        );
+        info!("Launch command of {}: {}", item.name, command);
+        self.status_message = Some(format!("Copied the launch command of {}", item.name));
+        iced::clipboard::write(command)
+    }
+
+    /// Writes a desktop entry running the selected game with its launch options and prefix
+    fn create_desktop_shortcut(&mut self) {
+        let Some(item) = self.current_category_list().get_selected() else {
+            return;
+        };
+        let LauncherAction::Launch { exec } = &item.action else {
+            return;
+        };
+        let command = build_launch_command(
+            exec,
+            None,
+            item.launch_options.as_deref(),
+            item.launch_prefix.as_deref(),
+        );
+        self.status_message = Some(match write_desktop_shortcut(item, &command) {
+            Ok(path) => {
+                info!(path = %path.display(), "Created desktop shortcut");
+                format!("Created a desktop shortcut for {}", item.name)
+            }
+            Err(err) => {
+                error!("{}", err);
+                err
+            }
+        });
+    }
+
+    fn open_launch_options(&mut self) -> Task<Message> {
+        let Some(item) = self.current_category_list().get_selected() else {
+            return self.close_modal_none();
+        };
+        let Some(launch_key) = item.launch_key.clone() else {
+            return self.close_modal_none();
+        };
+        self.modal = ModalState::LaunchOptions(LaunchOptionsState::new(
+            item.id,
+            item.name.clone(),
+            launch_key,
+            item.launch_options.as_deref(),
+        ));
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    fn launch_options_state_mut(&mut self) -> Option<&mut LaunchOptionsState> {
+        match &mut self.modal {
+            ModalState::LaunchOptions(state) => Some(state),
+            _ => None,
+        }
+    }
+
+    fn handle_launch_options_keyboard_message(
+        &mut self,
+        message: KeyboardMessage,
+    ) -> Task<Message> {
+        let output = match self.launch_options_state_mut() {
+            Some(state) => state.keyboard.handle_message(message),
+            None => return Task::none(),
+        };
+        if matches!(output, KeyboardOutput::Submit) {
+            self.submit_launch_options();
+        }
+        Task::none()
+    }
+
+    fn handle_launch_options_navigation(&mut self, action: Action) -> Task<Message> {
+        let Some(state) = self.launch_options_state_mut() else {
+            return Task::none();
+        };
+
+        let output = match action {
+            Action::Up => {
+                state.keyboard.move_up();
+                KeyboardOutput::None
+            }
+            Action::Down => {
+                state.keyboard.move_down();
+                KeyboardOutput::None
+            }
+            Action::Left => {
+                state.keyboard.move_left();
+                KeyboardOutput::None
+            }
+            Action::Right => {
+                state.keyboard.move_right();
+                KeyboardOutput::None
+            }
+            Action::Select => state.keyboard.select_current(),
+            Action::Back if !state.keyboard.value().is_empty() => state.keyboard.backspace(),
+            Action::Back | Action::ShowHelp => return self.close_modal_none(),
+            _ => KeyboardOutput::None,
+        };
+
+        if matches!(output, KeyboardOutput::Submit) {
+            self.submit_launch_options();
+        }
+        Task::none()
+    }
+
+    /// Stores the entered launch options for the item; empty input removes them.
+    fn submit_launch_options(&mut self) {
+        let ModalState::LaunchOptions(state) = std::mem::replace(&mut self.modal, ModalState::None)
+        else {
+            return;
+        };
+        self.close_modal();
+
+        let options = state.options();
+        if let Some(options) = &options {
+            self.launch_options
+                .insert(state.launch_key.clone(), options.clone());
+        } else {
+            self.launch_options.remove(&state.launch_key);
+        }
+
+        for list in [&mut self.games, &mut self.apps, &mut self.system_items] {
+            list.update_item_by_id(state.item_id, |i| i.launch_options = options.clone());
+        }
+        self.refresh_quick_launch();
+        self.save_apps_config(
+            "Changed launch options of",
+            "changing launch options of",
+            &state.item_name,
+        );
+    }
+
+    fn setup_wizard_state_mut(&mut self) -> Option<&mut SetupWizardState> {
+        match &mut self.modal {
+            ModalState::SetupWizard(state) => Some(state),
+            _ => None,
+        }
+    }
+
+    /// Number of scanned games per source, in the order the sources were found
+    fn setup_source_summary(&self) -> Vec<(String, usize)> {
+        let mut summary: Vec<(String, usize)> = Vec::new();
+        let games = self
+            .games
+            .items
+            .iter()
+            .chain(self.games.items.iter().flat_map(|item| &item.alternates));
+        for game in games {
+            let source = game
+                .launch_key
+                .as_deref()
+                .and_then(source_badge)
+                .map_or("Other", |badge| badge.name());
+            match summary.iter_mut().find(|(name, _)| name == source) {
+                Some((_, count)) => *count += 1,
+                None => summary.push((source.to_string(), 1)),
+            }
+        }
+        summary
+    }
+
+    fn handle_setup_keyboard_message(&mut self, message: KeyboardMessage) -> Task<Message> {
+        if let Some(state) = self.setup_wizard_state_mut() {
+            handle_setup_keyboard_message(state, message);
+        }
+        Task::none()
+    }
+
+    /// Closes the wizard for good; `apply` keeps the choices made in it.
+    fn complete_setup(&mut self, apply: bool) -> Task<Message> {
+        let ModalState::SetupWizard(state) = std::mem::replace(&mut self.modal, ModalState::None)
+        else {
+            return Task::none();
+        };
+        if !apply {
+            info!("Setup wizard skipped");
+        }
+        self.close_modal();
+        self.setup_completed = true;
+
+        let mut task = Task::none();
+        if apply {
+            state.apply(&mut self.settings);
+            if let Some(key) = state.api_key() {
+                self.sgdb_client = SteamGridDbClient::new(key.clone());
+                self.api_key = Some(key.clone());
+                self.setup_api_key = Some(key);
+                // Covers not found without a key may be available now
+                task = self.create_image_fetch_tasks();
+            }
+        }
+        self.save_apps_config("Completed", "completing", "setup");
+        task
+    }
+
+    fn fix_artwork_state_mut(&mut self) -> Option<&mut FixArtworkState> {
+        match &mut self.modal {
+            ModalState::FixArtwork(state) => Some(state),
+            _ => None,
+        }
+    }
+
+    fn handle_fix_artwork_keyboard_message(&mut self, message: KeyboardMessage) -> Task<Message> {
+        let Some(state) = self.fix_artwork_state_mut() else {
+            return Task::none();
+        };
+        handle_fix_artwork_keyboard_message(state, message)
+            .map_or_else(Task::none, |message| self.update(message))
+    }
+
+    fn close_fix_artwork(&mut self) -> Task<Message> {
+        match self
+            .fix_artwork_state_mut()
+            .and_then(|state| state.review_index)
+        {
+            Some(index) => self.open_missing_artwork(index),
+            None => self.close_modal_none(),
+        }
+    }
+
+    /// Fetches the cover of the entered SteamGridDB game; the ID is only saved once
+    /// artwork was found for it.
+    fn submit_fix_artwork(&mut self) -> Task<Message> {
+        let Some(state) = self.fix_artwork_state_mut() else {
+            return Task::none();
+        };
+        let Some(sgdb_id) = parse_sgdb_id(state.keyboard.value()) else {
+            state.error = Some("Enter a numeric SteamGridDB game ID".to_string());
+            return Task::none();
+        };
+        let item_id = state.item_id;
+        let game_name = state.game_name.clone();
+        let launch_key = state.launch_key.clone();
+        let review_index = state.review_index;
+
+        let Some(pipeline) = self.image_fetcher() else {
+            if let Some(state) = self.fix_artwork_state_mut() {
+                state.error = Some("Image cache is unavailable".to_string());
+            }
+            return Task::none();
+        };
+        self.close_modal();
+        self.status_message = Some(format!("Fetching artwork for {}...", game_name));
+        // Coming from the review list, go back to it for the next game
+        let review = match review_index {
+            Some(index) => self.open_missing_artwork(index),
             None => Task::none(),
+        };
+
+        let fetch = Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    pipeline.fetch(item_id, &game_name, None, None, Some(sgdb_id), false)
+                })
+                .await
+                .ok()
+                .and_then(Result::ok)
+                .flatten()
+                .map(|(_, path)| path)
+            },
+            move |path| Message::ArtworkFixed(item_id, launch_key, sgdb_id, path),
+        );
+        Task::batch([review, fetch])
+    }
+
+    fn handle_artwork_fixed(
+        &mut self,
+        id: uuid::Uuid,
+        launch_key: String,
+        sgdb_id: u64,
+        path: Option<PathBuf>,
+    ) -> Task<Message> {
+        let Some(path) = path else {
+            self.status_message = Some(format!("No artwork found for SteamGridDB ID {}", sgdb_id));
+            return Task::none();
+        };
+
+        self.status_message = None;
+        self.artwork_overrides.insert(launch_key.clone(), sgdb_id);
+        self.save_apps_config("Set artwork for", "setting artwork for", &launch_key);
+        let fetched = self.handle_image_fetched(id, Some(path));
+        // Drop the fixed game from the review list if it is still open
+        if let ModalState::MissingArtwork(state) = &self.modal {
+            let index = state.selected_index;
+            return Task::batch([fetched, self.open_missing_artwork(index)]);
         }
+        fetched
     }
 
     fn snap_to_picker_selection(&self) -> Task<Message> {
         let scale = self.ui_scale;
+        let density = self.settings.density;
         self.app_picker_state()
-            .map(|state| state.snap_to_selection(scale))
+            .map(|state| state.snap_to_selection(scale, density))
             .unwrap_or(Task::none())
     }
 
@@ -1609,6 +4463,9 @@ impl Launcher {
         if matches!(action, Action::Back | Action::AddApp) {
             return self.update(Message::CloseAppPicker);
         }
+        if action == Action::ContextMenu {
+            return self.open_custom_entry();
+        }
 
         if list_len == 0 {
             return Task::none();
@@ -1635,16 +4492,96 @@ impl Launcher {
 
         let item = self.current_category_list().get_selected().unwrap().clone();
 
+        if !item.alternates.is_empty() {
+            self.modal = ModalState::LaunchSource { index: 0 };
+            self.sync_overlay_alpha();
+            return Task::none();
+        }
+
         match &item.action {
             LauncherAction::Launch { exec } => {
                 self.launch_app(exec, &item, item.game_executable.as_ref())
             }
             LauncherAction::SystemUpdate => self.update(Message::StartSystemUpdate),
             LauncherAction::SystemInfo => self.update(Message::OpenSystemInfo),
-            LauncherAction::Shutdown => self.system_command("systemctl", &["poweroff"], "shutdown"),
+            LauncherAction::OpenSettings => self.open_settings(),
+            LauncherAction::OpenLogs => self.open_logs(),
+            LauncherAction::SaveBugReport => self.save_bug_report(),
+            LauncherAction::OpenDisplayModes => self.open_display_modes(),
+            LauncherAction::OpenPowerProfiles => self.open_power_profiles(),
+            LauncherAction::Shutdown => {
+                self.flush_config(true);
+                self.system_command("systemctl", &["poweroff"], "shutdown")
+            }
             LauncherAction::Suspend => self.system_command("systemctl", &["suspend"], "suspend"),
             LauncherAction::Exit => self.exit_app(),
+            LauncherAction::Custom { command } => self.start_custom_action(&item.name, command),
+        }
+    }
+
+    /// Launches the most recently started game or app, whatever is selected
+    fn relaunch_last(&mut self) -> Task<Message> {
+        let Some(item) = most_recent(&[&self.games, &self.apps]) else {
+            self.status_message = Some("Nothing has been launched yet".to_string());
+            return Task::none();
+        };
+        let LauncherAction::Launch { exec } = &item.action else {
+            return Task::none();
+        };
+
+        info!("Relaunching {}", item.name);
+        self.status_message = None;
+        self.launch_app(exec, &item, item.game_executable.as_ref())
+    }
+
+    /// Switches the sort mode of the current row only and remembers it.
+    fn cycle_sort(&mut self) -> Task<Message> {
+        let Some(mode) = self.settings.sort_modes.cycle(self.category) else {
+            return Task::none();
+        };
+        match self.category {
+            Category::Games => self.games.set_sort_mode(mode),
+            Category::Apps => self.apps.set_sort_mode(mode),
+            _ => return Task::none(),
         }
+        self.status_message = Some(format!(
+            "{} sorted: {}",
+            self.category.title(),
+            mode.label()
+        ));
+        self.save_apps_config("Changed", "changing", "sort order");
+        self.snap_to_main_selection()
+    }
+
+    fn start_custom_action(&mut self, name: &str, command: &str) -> Task<Message> {
+        info!("Running custom action '{}': {}", name, command);
+        self.status_message = Some(format!("Running {}...", name));
+        let name = name.to_string();
+        let command = command.to_string();
+        Task::perform(
+            async move {
+                tokio::task::spawn_blocking(move || run_custom_action(&command))
+                    .await
+                    .map_err(|e| format!("Task join error: {}", e))
+                    .and_then(|r| r)
+            },
+            move |result| Message::CustomActionFinished(name, result),
+        )
+    }
+
+    fn handle_custom_action_finished(
+        &mut self,
+        name: String,
+        result: Result<String, String>,
+    ) -> Task<Message> {
+        self.status_message = Some(match result {
+            Ok(outcome) => format!("{}: {}", name, outcome),
+            Err(e) => {
+                error!("Custom action '{}' failed: {}", name, e);
+                format!("{} failed: {}", name, e)
+            }
+        });
+        Task::none()
     }
 
     /// Records the current timestamp for the launched item, updates the list, re-sorts, and persists
@@ -1657,32 +4594,51 @@ impl Launcher {
         let item_id = item.id;
         let item_name = item.name.clone();
 
-        match self.category {
-            Category::Apps => {
+        match self.category_of_item(item_id) {
+            Some(Category::Apps) => {
                 self.apps.update_item_by_id(item_id, |i| {
                     i.last_started = Some(now);
                 });
                 self.apps.sort_inplace();
-                // Reset selection to 0 so the just-launched item stays selected at top
-                self.apps.selected_index = 0;
+                // Keep the just-launched item selected; in recent order it moved to the front
+                self.apps.select_item_by_id(item_id);
                 self.save_apps_config("Launched", "launching", &item_name);
             }
-            Category::Games => {
+            Some(Category::Games) => {
                 self.games.update_item_by_id(item_id, |i| {
                     i.last_started = Some(now);
                 });
                 self.games.sort_inplace();
-                self.games.selected_index = 0;
+                self.games.select_item_by_id(item_id);
                 // Update game launch history and persist
                 if let Some(launch_key) = item.launch_key.as_ref() {
                     self.game_launch_history.insert(launch_key.clone(), now);
                 }
                 self.save_apps_config("Launched", "launching", &item_name);
             }
-            Category::System => {
+            _ => {
                 // System items don't need launch tracking
             }
         }
+
+        self.refresh_quick_launch();
+        self.quick_launch.selected_index = 0;
+        self.recent.selected_index = 0;
+    }
+
+    /// Forgets all launch timestamps, so recent sorting and the recent row start over
+    fn clear_launch_history(&mut self) {
+        self.game_launch_history.clear();
+        for list in [&mut self.apps, &mut self.games] {
+            for item in &mut list.items {
+                item.last_started = None;
+            }
+            list.sort_inplace();
+        }
+        self.refresh_quick_launch();
+        self.recent.selected_index = 0;
+        self.save_apps_config("Cleared", "clearing", "launch history");
+        self.status_message = Some("Launch history cleared".to_string());
     }
 
     fn remove_missing_item(&mut self, item_id: Uuid, item_name: &str, category: Category) {
@@ -1698,27 +4654,161 @@ impl Launcher {
                     false
                 }
             }
-            Category::System => false,
+            Category::System | Category::Favorites | Category::Recent => false,
         };
 
         if removed {
+            self.refresh_quick_launch();
             self.save_apps_config("Removed", "removing", item_name);
         }
     }
 
-    /// Launch an application with proper process monitoring
+    /// Launch an application, asking first if a heavy game meets low memory
     fn launch_app(
         &mut self,
         exec: &str,
         item: &LauncherItem,
         game_executable: Option<&String>,
     ) -> Task<Message> {
-        let monitor_target = resolve_monitor_target(exec, &item.name, game_executable);
+        if let Some(available) = self.low_memory_available(item) {
+            info!(
+                "Only {} memory available, asking before launching {}",
+                available, item.name
+            );
+            self.modal = ModalState::LowMemory {
+                item: Box::new(item.clone()),
+                exec: exec.to_string(),
+                available,
+                selected_index: 1,
+            };
+            self.sync_overlay_alpha();
+            return Task::none();
+        }
+        self.start_app(exec, item, game_executable)
+    }
+
+    /// Available memory when it is below the opt-in threshold and the item is a
+    /// Steam (often Proton) game
+    fn low_memory_available(&self, item: &LauncherItem) -> Option<String> {
+        let threshold_mb = self.settings.low_memory_warning_mb?;
+        if !item
+            .launch_key
+            .as_deref()
+            .is_some_and(|key| key.starts_with("steam:"))
+        {
+            return None;
+        }
+        get_available_memory().filter(|available| is_memory_below(available, threshold_mb))
+    }
+
+    /// Start an application with proper process monitoring
+    fn start_app(
+        &mut self,
+        exec: &str,
+        item: &LauncherItem,
+        game_executable: Option<&String>,
+    ) -> Task<Message> {
+        let terminal = if item.run_in_terminal {
+            if !verify_command_exists(exec) {
+                return self.show_app_not_found(item);
+            }
+            match resolve_terminal(self.settings.terminal.as_deref()) {
+                Some(terminal) => Some(terminal),
+                None => {
+                    self.status_message = Some(format!(
+                        "No terminal emulator found to run {}. Set `terminal` in the config.",
+                        item.name
+                    ));
+                    return Task::none();
+                }
+            }
+        } else {
+            None
+        };
+
+        let launch_command = |prefix: Option<&str>| {
+            build_launch_command(
+                exec,
+                terminal.as_deref(),
+                item.launch_options.as_deref(),
+                prefix,
+            )
+        };
+        // For terminal apps this resolves to the terminal window opened for the app; Steam
+        // and Heroic launches are found behind wrappers from the launch options
+        let monitor_target = resolve_monitor_target(
+            &launch_command(None),
+            &item.name,
+            game_executable,
+            item.wm_class.as_deref(),
+        )
+        .map(|target| with_monitor_env(target, &item.monitor_env));
+        let command = launch_command(item.launch_prefix.as_deref());
+
+        let minimize = self
+            .window_id
+            .filter(|_| !self.embedded_mode())
+            .map(|id| window::minimize(id, true));
+        let delay = Duration::from_millis(self.settings.launch_delay_ms);
+        match minimize {
+            // Some games pick the wrong display or lose focus while the launcher is
+            // still visible, so they are started once it is gone
+            Some(minimize) if !delay.is_zero() => {
+                self.pending_launch = Some(PendingLaunch {
+                    command,
+                    monitor_target,
+                    item: item.clone(),
+                });
+                Task::batch(vec![
+                    minimize,
+                    Task::perform(async move { tokio::time::sleep(delay).await }, |_| {
+                        Message::LaunchDelayElapsed
+                    }),
+                ])
+            }
+            Some(minimize) => {
+                let launch = self.spawn_launch(&command, monitor_target, item);
+                if self.game_running {
+                    Task::batch(vec![minimize, launch])
+                } else {
+                    launch
+                }
+            }
+            None => self.spawn_launch(&command, monitor_target, item),
+        }
+    }
+
+    /// Spawns the launch delayed by `launch_delay_ms`; the launcher comes back
+    /// if it fails to start
+    fn start_pending_launch(&mut self) -> Task<Message> {
+        let Some(pending) = self.pending_launch.take() else {
+            return Task::none();
+        };
+        let launch = self.spawn_launch(&pending.command, pending.monitor_target, &pending.item);
+        match self.window_id.filter(|_| !self.game_running) {
+            Some(id) => Task::batch(vec![window::minimize(id, false), launch]),
+            None => launch,
+        }
+    }
+
+    /// Starts the command and watches the process until it exits
+    fn spawn_launch(
+        &mut self,
+        command: &str,
+        monitor_target: Option<MonitorTarget>,
+        item: &LauncherItem,
+    ) -> Task<Message> {
+        // Only a direct child can be waited for; without diagnostics it is left running
+        let launched = self
+            .command_runner
+            .launch(command, self.settings.diagnose_launch_failures);
 
-        match launch_app(exec) {
-            Ok(pid) => {
+        match launched {
+            Ok((pid, child)) => {
                 self.game_running = true;
                 self.record_launch_timestamp(item);
+                // The clock tick that flushes deferred saves pauses while the game runs
+                self.flush_config(true);
 
                 // Optimization: Always check the main PID first.
                 // If the direct PID is running, we avoid the expensive full-system scan
@@ -1732,23 +4822,25 @@ impl Launcher {
                     Task::perform(async move { monitor_app_process(target).await }, |_| {
                         Message::GameExited
                     });
-
-                if let Some(id) = self.window_id {
-                    Task::batch(vec![window::minimize(id, true), monitor_task])
-                } else {
-                    monitor_task
-                }
-            }
-            Err(LaunchError::CommandNotFound { .. }) => {
-                self.modal = ModalState::AppNotFound {
-                    item_id: item.id,
-                    item_name: item.name.clone(),
-                    category: self.category,
-                    selected_index: 0,
-                };
-                self.sync_overlay_alpha();
-                Task::none()
+                let diagnose_task = child.map_or_else(Task::none, |child| {
+                    let item_name = item.name.clone();
+                    Task::perform(
+                        async move {
+                            tokio::task::spawn_blocking(move || watch_launch(child))
+                                .await
+                                .ok()
+                                .flatten()
+                        },
+                        move |failure| Message::LaunchDiagnosed(item_name, failure),
+                    )
+                });
+                Task::batch(vec![
+                    monitor_task,
+                    self.switch_power_for_game(),
+                    diagnose_task,
+                ])
             }
+            Err(LaunchError::CommandNotFound { .. }) => self.show_app_not_found(item),
             Err(err) => {
                 self.status_message = Some(err.to_string());
                 Task::none()
@@ -1756,25 +4848,133 @@ impl Launcher {
         }
     }
 
+    /// Config override first, then Steam's env markers
+    fn embedded_mode(&self) -> bool {
+        self.settings.embedded_mode.unwrap_or(self.steam_gamepad_ui)
+    }
+
+    /// Scale from the window height, clamped so the UI neither vanishes nor overflows,
+    /// unless the config fixes it
+    fn sync_ui_scale(&mut self) {
+        self.ui_scale = ui_scale_for(self.window_height, self.settings.ui_scale_override);
+    }
+
+    /// Writes the samples at the configured volume to the cache dir, or drops them
+    /// when sounds are off
+    fn sync_ui_sounds(&mut self) {
+        if !self.settings.ui_sounds {
+            self.ui_sounds = None;
+            return;
+        }
+        let dir = match project_dirs() {
+            Ok(dirs) => dirs.cache_dir().join("sounds"),
+            Err(e) => {
+                tracing::warn!("No cache directory for UI sounds: {}", e);
+                return;
+            }
+        };
+        self.ui_sounds = match UiSounds::load(&dir, self.settings.ui_sound_volume) {
+            Ok(sounds) => Some(sounds),
+            Err(e) => {
+                tracing::warn!("Failed to prepare UI sounds: {}", e);
+                None
+            }
+        };
+    }
+
+    fn play_ui_sound(&mut self, sound: UiSound) {
+        if let Some(sounds) = self.ui_sounds.as_mut() {
+            sounds.play(sound);
+        }
+    }
+
+    fn scan_error_banner_visible(&self) -> bool {
+        !self.scan_errors.is_empty() && !self.scan_errors_dismissed
+    }
+
+    fn show_app_not_found(&mut self, item: &LauncherItem) -> Task<Message> {
+        self.modal = ModalState::AppNotFound {
+            item_id: item.id,
+            item_name: item.name.clone(),
+            category: self.category_of_item(item.id).unwrap_or(self.category),
+            selected_index: 0,
+        };
+        self.sync_overlay_alpha();
+        Task::none()
+    }
+
+    /// Opens the config directory for hand-editing; the path stays visible in the
+    /// status line in case no file manager comes up.
+    fn open_config_dir(&mut self) -> Task<Message> {
+        let dir = match config_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                self.status_message = Some(format!("Failed to find the config folder: {}", e));
+                return Task::none();
+            }
+        };
+        self.status_message = Some(
+            match self
+                .command_runner
+                .spawn("xdg-open", &[&dir.to_string_lossy()])
+            {
+                Ok(_) => format!("Config folder: {}", dir.display()),
+                Err(e) => format!(
+                    "Could not open a file manager ({}). Config folder: {}",
+                    e,
+                    dir.display()
+                ),
+            },
+        );
+        Task::none()
+    }
+
     /// Execute a system command and handle errors
     fn system_command(&mut self, command: &str, args: &[&str], action: &str) -> Task<Message> {
-        if let Err(e) = std::process::Command::new(command).args(args).spawn() {
+        if let Err(e) = self.command_runner.spawn(command, args) {
             self.status_message = Some(format!("Failed to {}: {}", action, e));
         }
         Task::none()
     }
 
     fn cycle_category(&mut self) {
-        self.category = self.category.next();
+        self.category = self.step_category(true);
         self.status_message = None;
     }
 
     fn cycle_category_back(&mut self) {
-        self.category = self.category.prev();
+        self.category = self.step_category(false);
         self.status_message = None;
     }
 
+    fn tile_badges(&self) -> TileBadges {
+        TileBadges {
+            source: self.settings.show_source_badges,
+            pending_updates: self.pending_updates,
+            proton: self.settings.protondb_badges,
+            hold_progress: self.launch_hold.map(|hold| hold.progress(Instant::now())),
+        }
+    }
+
     fn render_category(&self) -> Element<'_, Message> {
+        if self.category == Category::Favorites {
+            return render_section_row(
+                self.category,
+                Category::Favorites,
+                &self.quick_launch,
+                "No favorites yet. Press B to show all.".to_string(),
+                self.default_icon_handle.clone(),
+                &self.cover_handles,
+                &self.focus_animation,
+                self.tile_badges(),
+                self.settings.show_category_counts,
+                self.settings.icon_fit.for_category(Category::Favorites),
+                self.settings.tile_style,
+                self.ui_scale,
+                self.settings.density,
+            );
+        }
+
         let apps_msg = if !self.apps_loaded {
             "Loading apps...".to_string()
         } else {
@@ -1787,11 +4987,21 @@ impl Launcher {
             &self.apps,
             apps_msg,
             self.default_icon_handle.clone(),
+            &self.cover_handles,
+            &self.focus_animation,
+            self.tile_badges(),
+            self.settings.show_category_counts,
+            self.settings.icon_fit.for_category(Category::Apps),
+            self.settings.tile_style,
             self.ui_scale,
+            self.settings.density,
         );
 
-        let games_msg = if !self.games_loaded {
-            "Scanning games...".to_string()
+        let games_msg = if self.scan_spinner_active() {
+            let spinner = SPINNER_CHARS[self.scan_spinner_tick % SPINNER_CHARS.len()];
+            format!("{} Scanning games...", spinner)
+        } else if let Some(error) = most_relevant_error(&self.scan_errors) {
+            format!("No games found. {}.", error)
         } else {
             "No games found.".to_string()
         };
@@ -1802,7 +5012,14 @@ impl Launcher {
             &self.games,
             games_msg,
             self.default_icon_handle.clone(),
+            &self.cover_handles,
+            &self.focus_animation,
+            self.tile_badges(),
+            self.settings.show_category_counts,
+            self.settings.icon_fit.for_category(Category::Games),
+            self.settings.tile_style,
             self.ui_scale,
+            self.settings.density,
         );
 
         let system_row = render_section_row(
@@ -1811,38 +5028,124 @@ impl Launcher {
             &self.system_items,
             "No system actions available.".to_string(),
             self.default_icon_handle.clone(),
+            &self.cover_handles,
+            &self.focus_animation,
+            self.tile_badges(),
+            self.settings.show_category_counts,
+            self.settings.icon_fit.for_category(Category::System),
+            self.settings.tile_style,
             self.ui_scale,
+            self.settings.density,
         );
 
-        Column::new()
+        let mut column = Column::new();
+        if self.recent_row_visible() {
+            column = column.push(render_section_row(
+                self.category,
+                Category::Recent,
+                &self.recent,
+                String::new(),
+                self.default_icon_handle.clone(),
+                &self.cover_handles,
+                &self.focus_animation,
+                self.tile_badges(),
+                self.settings.show_category_counts,
+                self.settings.icon_fit.for_category(Category::Recent),
+                self.settings.tile_style,
+                self.ui_scale,
+                self.settings.density,
+            ));
+        }
+
+        column
             .push(games_row)
             .push(apps_row)
             .push(system_row)
-            .spacing(40.0 * self.ui_scale) // Adjusted spacing with scale
+            .spacing(self.settings.density.category_spacing(self.ui_scale))
             .into()
     }
 
-    fn save_apps_config(&self, action_desc: &str, action_gerund: &str, item_name: &str) {
-        let mut config = load_config().unwrap_or_default();
+    /// Persists the config, deferring the write when the previous one was only
+    /// moments ago; deferred changes are flushed by the clock tick, and before the
+    /// launcher exits or restarts, a game starts, or the system powers off.
+    fn save_apps_config(&mut self, action_desc: &str, action_gerund: &str, item_name: &str) {
+        if !self.config_saver.request(Instant::now()) {
+            debug!("{} '{}', config save deferred.", action_desc, item_name);
+            return;
+        }
+
+        match self.write_config() {
+            Ok(_) => info!("{} '{}' and saved config.", action_desc, item_name),
+            Err(e) => error!(
+                "Error saving config after {} '{}': {}",
+                action_gerund, item_name, e
+            ),
+        }
+    }
+
+    /// Writes changes deferred by [`Self::save_apps_config`]; `force` skips the interval.
+    fn flush_config(&mut self, force: bool) {
+        let due = if force {
+            self.config_saver.take_pending()
+        } else {
+            self.config_saver.take_due(Instant::now())
+        };
+        if !due {
+            return;
+        }
+
+        match self.write_config() {
+            Ok(_) => info!("Saved deferred config changes."),
+            Err(e) => error!("Error saving deferred config changes: {}", e),
+        }
+    }
+
+    fn write_config(&self) -> anyhow::Result<()> {
+        let path = self
+            .config_file
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Could not determine the config path"))?;
+        let mut config = load_config_from(path).unwrap_or_default();
+        self.fill_config(&mut config);
+        save_config_to(path, &config)
+    }
 
+    /// Copies the launcher's persisted state into `config`
+    fn fill_config(&self, config: &mut AppConfig) {
         config.apps = self
             .apps
             .items
             .iter()
             .filter(|item| matches!(item.action, LauncherAction::Launch { .. }))
-            .map(|item| item.to_app_entry())
+            .map(|item| {
+                let mut entry = item.to_app_entry();
+                // Placeholders are generated again on the next start
+                let placeholder = |icon: &String| {
+                    self.image_cache
+                        .as_ref()
+                        .is_some_and(|cache| cache.is_placeholder(Path::new(icon)))
+                };
+                if entry.icon.as_ref().is_some_and(placeholder) {
+                    entry.icon = None;
+                }
+                entry
+            })
             .collect();
 
         // Also save game launch history
         config.game_launch_history = self.game_launch_history.clone();
-
-        match save_config(&config) {
-            Ok(_) => info!("{} '{}' and saved config.", action_desc, item_name),
-            Err(e) => error!(
-                "Error saving config after {} '{}': {}",
-                action_gerund, item_name, e
-            ),
+        config.favorites = self.favorites.clone();
+        config.launch_prefixes = self.launch_prefixes.clone();
+        config.launch_options = self.launch_options.clone();
+        config.hidden_games = self.hidden_games.clone();
+        config.artwork_overrides = self.artwork_overrides.clone();
+        config.prefer_source_art = self.prefer_source_art.clone();
+        config.setup_completed = self.setup_completed;
+        config.sgdb_key_hint_shown = self.sgdb_key_hint_shown;
+        if let Some(key) = &self.setup_api_key {
+            config.steamgriddb_api_key = Some(key.clone());
         }
+        config.settings = self.settings.clone();
     }
 
     fn apps_empty_message(&self) -> String {
@@ -1853,10 +5156,19 @@ impl Launcher {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::command_runner::FakeRunner;
+
+    /// Launcher whose config saves go to a fresh temp file instead of the user's config
+    fn test_launcher() -> Launcher {
+        let (mut launcher, _) = Launcher::new();
+        launcher.config_file =
+            Some(std::env::temp_dir().join(format!("rhinco-config-{}.json", uuid::Uuid::new_v4())));
+        launcher
+    }
 
     #[test]
     fn test_navigation_memory() {
-        let (mut launcher, _) = Launcher::new();
+        let mut launcher = test_launcher();
         // Setup mock data
         launcher.apps.set_items(vec![
             LauncherItem::exit(), // 0
@@ -1891,9 +5203,28 @@ mod tests {
         assert_eq!(launcher.apps.selected_index, 1); // REMEMBERED!
     }
 
+    #[test]
+    fn test_jump_category() {
+        let mut launcher = test_launcher();
+        launcher.category = Category::Favorites;
+
+        let task = launcher.handle_navigation(Action::JumpCategory(Category::System));
+        assert_eq!(launcher.category, Category::System);
+        // Scrolls the row and the main view to the selection
+        assert!(task.units() > 0);
+
+        let _ = launcher.handle_navigation(Action::JumpCategory(Category::Games));
+        assert_eq!(launcher.category, Category::Games);
+
+        // The recent row is empty and therefore hidden
+        let task = launcher.handle_navigation(Action::JumpCategory(Category::Recent));
+        assert_eq!(launcher.category, Category::Games);
+        assert_eq!(task.units(), 0);
+    }
+
     #[test]
     fn test_bounds_checking() {
-        let (mut launcher, _) = Launcher::new();
+        let mut launcher = test_launcher();
         launcher.apps.set_items(vec![LauncherItem::exit()]); // Len 1
         launcher.apps.selected_index = 0;
 
@@ -1905,4 +5236,182 @@ mod tests {
         let _ = launcher.handle_navigation(Action::Left);
         assert_eq!(launcher.apps.selected_index, 0);
     }
+
+    #[test]
+    fn test_quick_launch_show_all_toggle() {
+        let mut launcher = test_launcher();
+        launcher.settings.start_in_quick_launch = true;
+        launcher.category = Category::Favorites;
+
+        // Back on quick launch reveals the full category UI
+        let _ = launcher.handle_navigation(Action::Back);
+        assert_eq!(launcher.category, Category::Games);
+
+        // Back again returns to the favorites screen
+        let _ = launcher.handle_navigation(Action::Back);
+        assert_eq!(launcher.category, Category::Favorites);
+    }
+
+    #[test]
+    fn test_scan_spinner_only_while_scanning() {
+        let mut launcher = test_launcher();
+        assert!(launcher.scan_spinner_active());
+
+        let _ = launcher.handle_games_loaded(Vec::new(), Vec::new());
+        assert!(!launcher.scan_spinner_active());
+    }
+
+    #[test]
+    fn test_system_commands_go_through_runner() {
+        let mut launcher = test_launcher();
+        let runner = FakeRunner::default();
+        launcher.command_runner = Box::new(runner.clone());
+
+        let _ = launcher.request_reboot();
+        let _ = launcher.system_command("systemctl", &["suspend"], "suspend");
+
+        assert_eq!(
+            runner.issued(),
+            vec!["systemctl reboot", "systemctl suspend"]
+        );
+        assert_eq!(launcher.status_message, None);
+    }
+
+    #[test]
+    fn test_pending_config_save_is_flushed_before_system_action() {
+        let mut launcher = test_launcher();
+        let runner = FakeRunner::default();
+        launcher.command_runner = Box::new(runner.clone());
+
+        // The second change right after the first one is deferred
+        launcher.save_apps_config("Changed", "changing", "Clock");
+        launcher.settings.clock_24h = !launcher.settings.clock_24h;
+        launcher.save_apps_config("Changed", "changing", "Clock");
+        let _ = launcher.request_reboot();
+
+        let config_file = launcher.config_file.clone().unwrap();
+        let saved = load_config_from(&config_file);
+        let _ = std::fs::remove_file(config_file);
+
+        assert_eq!(runner.issued(), vec!["systemctl reboot"]);
+        assert_eq!(
+            saved.unwrap().settings.clock_24h,
+            launcher.settings.clock_24h
+        );
+    }
+
+    #[test]
+    fn test_launch_issues_prefixed_command() {
+        let mut launcher = test_launcher();
+        let runner = FakeRunner::default();
+        launcher.command_runner = Box::new(runner.clone());
+
+        let item = LauncherItem {
+            name: "Celeste".to_string(),
+            launch_options: Some("gamemoderun %command%".to_string()),
+            launch_prefix: Some(GAMESCOPE_16_9_PREFIX.to_string()),
+            ..Default::default()
+        };
+        let _ = launcher.start_app("/games/celeste/Celeste", &item, None);
+
+        assert_eq!(
+            runner.issued(),
+            vec!["gamescope -W 1920 -H 1080 -f -- gamemoderun /games/celeste/Celeste"]
+        );
+        assert!(launcher.game_running);
+    }
+
+    #[test]
+    fn test_launch_delay_defers_spawn_until_minimized() {
+        let mut launcher = test_launcher();
+        let runner = FakeRunner::default();
+        launcher.command_runner = Box::new(runner.clone());
+        launcher.window_id = Some(window::Id::unique());
+        launcher.settings.launch_delay_ms = 500;
+
+        let item = LauncherItem {
+            name: "Celeste".to_string(),
+            ..Default::default()
+        };
+        let task = launcher.start_app("/games/celeste/Celeste", &item, None);
+        // Minimizing and the timer run, the game doesn't yet
+        assert_eq!(task.units(), 2);
+        assert!(runner.issued().is_empty());
+        assert!(!launcher.game_running);
+
+        let _ = launcher.update(Message::LaunchDelayElapsed);
+        assert_eq!(runner.issued(), vec!["/games/celeste/Celeste"]);
+        assert!(launcher.game_running);
+        assert!(launcher.pending_launch.is_none());
+
+        // A stray timer without a pending launch starts nothing
+        let _ = launcher.update(Message::LaunchDelayElapsed);
+        assert_eq!(runner.issued().len(), 1);
+    }
+
+    #[test]
+    fn test_paste_fills_focused_custom_entry_field() {
+        let mut launcher = test_launcher();
+        let mut state = CustomEntryState::new();
+        state.focus_next();
+        launcher.modal = ModalState::CustomEntry(state);
+
+        let _ = launcher.update(Message::ClipboardPasted(Some(
+            "flatpak run net.retroarch.RetroArch\n".to_string(),
+        )));
+        let Some(state) = launcher.custom_entry_state_mut() else {
+            panic!("custom entry modal closed");
+        };
+        assert_eq!(state.command, "flatpak run net.retroarch.RetroArch");
+        assert_eq!(state.name, "");
+
+        // Without a text field there is nothing to paste into
+        launcher.modal = ModalState::None;
+        let _ = launcher.update(Message::ClipboardPasted(Some("ignored".to_string())));
+        assert!(matches!(launcher.modal, ModalState::None));
+    }
+
+    #[test]
+    fn test_clear_launch_history() {
+        let mut launcher = test_launcher();
+        let played = |name: &str, launch_key: &str| LauncherItem {
+            name: name.to_string(),
+            launch_key: Some(launch_key.to_string()),
+            action: LauncherAction::Launch {
+                exec: name.to_lowercase(),
+            },
+            last_started: Some(1_700_000_000),
+            ..Default::default()
+        };
+        launcher
+            .games
+            .set_items(vec![played("Hades", "steam:1145360")]);
+        launcher
+            .apps
+            .set_items(vec![played("Firefox", "desktop:firefox")]);
+        launcher
+            .game_launch_history
+            .insert("steam:1145360".to_string(), 1_700_000_000);
+        launcher.refresh_quick_launch();
+        assert!(!launcher.recent.is_empty());
+
+        launcher.clear_launch_history();
+        let _ = std::fs::remove_file(launcher.config_file.clone().unwrap());
+
+        assert!(launcher.game_launch_history.is_empty());
+        assert!(launcher.recent.is_empty());
+        assert_eq!(launcher.games.items[0].last_started, None);
+
+        let mut config = AppConfig {
+            game_launch_history: std::collections::HashMap::from([(
+                "steam:570".to_string(),
+                1_600_000_000,
+            )]),
+            ..Default::default()
+        };
+        launcher.fill_config(&mut config);
+        assert!(config.game_launch_history.is_empty());
+        assert_eq!(config.apps.len(), 1);
+        assert_eq!(config.apps[0].last_started, None);
+    }
 }
@@ -1,12 +1,13 @@
 use iced::alignment::Horizontal;
 use iced::widget::Id;
 use iced::widget::{operation, Column, Container, Grid, Scrollable, Text};
-use iced::{Color, Element, Length, Task};
+use iced::{Color, ContentFit, Element, Length, Task};
 
 use crate::desktop_apps::DesktopApp;
 use crate::input::Action;
 use crate::messages::Message;
 use crate::ui_components::render_icon;
+use crate::ui_density::Density;
 use crate::ui_theme::*;
 
 pub struct AppPickerState {
@@ -28,18 +29,18 @@ impl AppPickerState {
         }
     }
 
-    pub fn update_cols(&mut self, window_width: f32, scale: f32) {
+    pub fn update_cols(&mut self, window_width: f32, scale: f32, density: Density) {
         let available_width =
             window_width * APP_PICKER_WIDTH_RATIO - scaled(APP_PICKER_PADDING, scale);
-        let item_space = scaled(ICON_ITEM_WIDTH, scale) + scaled(ITEM_SPACING, scale);
+        let item_space = scaled(ICON_ITEM_WIDTH, scale) + density.item_spacing(scale);
         let cols = (available_width / item_space).floor() as usize;
         self.cols = cols.max(1);
     }
 
-    pub fn snap_to_selection(&self, scale: f32) -> Task<Message> {
+    pub fn snap_to_selection(&self, scale: f32, density: Density) -> Task<Message> {
         let row = self.selected_index / self.cols;
         let item_height_with_spacing =
-            scaled(ICON_ITEM_HEIGHT, scale) + scaled(ITEM_SPACING, scale);
+            scaled(ICON_ITEM_HEIGHT, scale) + density.item_spacing(scale);
 
         let item_top = row as f32 * item_height_with_spacing;
         let item_bottom = item_top + scaled(ICON_ITEM_HEIGHT, scale);
@@ -95,6 +96,7 @@ pub fn render_app_picker<'a>(
     state: &'a AppPickerState,
     available_apps: &'a [DesktopApp],
     scale: f32,
+    density: Density,
 ) -> Element<'a, Message> {
     let title = Text::new("Add Application")
         .font(SANSATION)
@@ -119,7 +121,7 @@ pub fn render_app_picker<'a>(
     } else {
         let mut grid = Grid::new()
             .columns(state.cols)
-            .spacing(scaled(ITEM_SPACING, scale))
+            .spacing(density.item_spacing(scale))
             .height(Length::Shrink);
 
         for (i, app) in available_apps.iter().enumerate() {
@@ -135,7 +137,7 @@ pub fn render_app_picker<'a>(
             .into()
     };
 
-    let hint = Text::new("Enter: Add | Escape: Close")
+    let hint = Text::new("Enter: Add | C: Custom Entry | Escape: Close")
         .font(SANSATION)
         .size(scaled(BASE_FONT_SMALL, scale))
         .color(COLOR_TEXT_HINT);
@@ -191,6 +193,7 @@ fn render_picker_item<'a>(
         "?",
         Some((48.0 * scale) as u32),
         None,
+        ContentFit::Contain,
     );
 
     let icon_container = Container::new(icon_widget).padding(scaled(BASE_PADDING_TINY, scale));
@@ -6,8 +6,6 @@ use crate::messages::Message;
 use crate::ui_state::{AppUpdatePhase, AppUpdateState};
 use crate::ui_theme::*;
 
-const SPINNER_CHARS: [&str; 4] = ["◐", "◓", "◑", "◒"];
-
 pub fn render_app_update_modal<'a>(state: &'a AppUpdateState, scale: f32) -> Element<'a, Message> {
     let spinner = SPINNER_CHARS[state.spinner_tick % SPINNER_CHARS.len()];
 
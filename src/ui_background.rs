@@ -1,8 +1,10 @@
 use iced::mouse::Cursor;
 use iced::widget::canvas::{self, Canvas, Geometry, Path};
+use iced::widget::{container, Container, Space};
 use iced::{Color, Element, Length, Point, Rectangle, Theme};
 use std::rc::Rc;
 
+use crate::settings::Settings;
 use crate::ui_theme::{COLOR_BACKGROUND, COLOR_SOFT_WHITE};
 
 #[derive(Debug, Clone)]
@@ -31,6 +33,37 @@ impl WhaleSharkBackground {
     }
 }
 
+/// What fills the screen behind the rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundKind {
+    /// The whale shark pattern
+    Pattern,
+    /// A plain `COLOR_BACKGROUND` fill
+    Solid,
+}
+
+impl BackgroundKind {
+    pub fn from_settings(settings: &Settings) -> Self {
+        if settings.background_enabled {
+            Self::Pattern
+        } else {
+            Self::Solid
+        }
+    }
+}
+
+/// Plain `COLOR_BACKGROUND` fill used when the pattern background is disabled.
+pub fn solid_background<'a, Message: 'a>() -> Element<'a, Message> {
+    Container::new(Space::new())
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(|_| container::Style {
+            background: Some(COLOR_BACKGROUND.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
 impl<Message> canvas::Program<Message> for WhaleSharkBackground {
     type State = ();
 
@@ -97,3 +130,23 @@ fn hash(x: u32, y: u32) -> f32 {
     // Normalize to 0.0 - 1.0 using u32 range
     (val as u32) as f32 / u32::MAX as f32
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solid_background_when_disabled() {
+        let mut settings = Settings::default();
+        assert_eq!(
+            BackgroundKind::from_settings(&settings),
+            BackgroundKind::Pattern
+        );
+
+        settings.background_enabled = false;
+        assert_eq!(
+            BackgroundKind::from_settings(&settings),
+            BackgroundKind::Solid
+        );
+    }
+}
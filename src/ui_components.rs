@@ -1,6 +1,6 @@
 use chrono::{DateTime, Local};
 use gilrs::PowerInfo;
-use iced::widget::{Container, Image, Row, Svg, Text};
+use iced::widget::{Container, Image, Row, Space, Svg, Text};
 use iced::{Alignment, Color, ContentFit, Element, Length};
 use std::path::{Path, PathBuf};
 
@@ -11,11 +11,19 @@ use crate::ui_theme::{
     COLOR_DEEP_SLATE, COLOR_TEXT_BRIGHT, SANSATION,
 };
 
-fn is_svg(path: &Path) -> bool {
+pub fn is_svg(path: &Path) -> bool {
     path.extension()
         .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
 }
 
+/// `overlay` while it is shown, otherwise an empty layer. A stack then keeps the
+/// same children either way, so the widgets below it keep their state.
+pub fn optional_layer<'a, Message: 'a>(
+    overlay: Option<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    overlay.unwrap_or_else(|| Space::new().into())
+}
+
 pub fn render_icon<'a, Message>(
     icon_path: Option<PathBuf>,
     width: f32,
@@ -23,6 +31,7 @@ pub fn render_icon<'a, Message>(
     fallback_text: &'static str,
     fallback_size: Option<u32>,
     default_icon_handle: Option<iced::widget::svg::Handle>,
+    fit: ContentFit,
 ) -> Element<'a, Message>
 where
     Message: 'a + Clone,
@@ -32,12 +41,13 @@ where
             Svg::from_path(path)
                 .width(Length::Fixed(width))
                 .height(Length::Fixed(height))
+                .content_fit(fit)
                 .into()
         } else {
             Image::new(path)
                 .width(Length::Fixed(width))
                 .height(Length::Fixed(height))
-                .content_fit(ContentFit::Contain)
+                .content_fit(fit)
                 .into()
         };
     }
@@ -46,6 +56,7 @@ where
         return Svg::new(handle)
             .width(Length::Fixed(width))
             .height(Length::Fixed(height))
+            .content_fit(ContentFit::Contain)
             .into();
     }
 
@@ -70,6 +81,8 @@ where
     Message: 'a,
 {
     let mut row = Row::new().spacing(24.0 * scale).align_y(Alignment::Center);
+    // Player numbers only matter once several pads share the screen
+    let show_players = infos.iter().filter(|info| !info.is_keyboard).count() > 1;
 
     for info in infos.iter().take(4) {
         // Gamepad icon
@@ -84,6 +97,15 @@ where
             .align_y(Alignment::Center)
             .push(gp_icon);
 
+        if let Some(player) = info.player.filter(|_| show_players) {
+            content = content.push(
+                Text::new(format!("P{}", player))
+                    .font(SANSATION)
+                    .size(14.0 * scale)
+                    .color(Color::WHITE),
+            );
+        }
+
         if let Some((battery_icon, _color)) = get_battery_visuals(info.power_info, scale) {
             content = content.push(battery_icon);
         }
@@ -166,11 +188,16 @@ where
     icon
 }
 
-pub fn render_clock<'a, Message>(time: &DateTime<Local>, scale: f32) -> Element<'a, Message>
+pub fn render_clock<'a, Message>(
+    time: &DateTime<Local>,
+    clock_24h: bool,
+    scale: f32,
+) -> Element<'a, Message>
 where
     Message: 'a,
 {
-    Text::new(time.format("%H:%M").to_string())
+    let format = if clock_24h { "%H:%M" } else { "%-I:%M %p" };
+    Text::new(time.format(format).to_string())
         .font(SANSATION)
         .size(32.0 * scale)
         .color(COLOR_TEXT_BRIGHT)
@@ -0,0 +1,130 @@
+use iced::widget::{Column, Container, Row, Text};
+use iced::{Color, Element, Length};
+
+use crate::custom_entry::{CustomEntryField, CustomEntryState};
+use crate::messages::Message;
+use crate::ui_theme::*;
+
+pub fn render_custom_entry_modal(state: &CustomEntryState, scale: f32) -> Element<'_, Message> {
+    let title = Text::new("Add Custom Entry")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let mut content_column = Column::new()
+        .spacing(scaled(BASE_PADDING_SMALL, scale))
+        .push(title_container);
+
+    for &field in CustomEntryField::ALL {
+        content_column = content_column.push(render_field(
+            field,
+            state.value(field),
+            field == state.field,
+            scale,
+        ));
+    }
+
+    if let Some(error) = &state.error {
+        content_column = content_column.push(
+            Container::new(
+                Text::new(error.to_string())
+                    .font(SANSATION)
+                    .size(scaled(BASE_FONT_MEDIUM, scale))
+                    .color(COLOR_ERROR),
+            )
+            .width(Length::Fill)
+            .center_x(Length::Fill),
+        );
+    }
+
+    let keyboard_view = state.keyboard.view(scale).map(Message::CustomEntryKeyboard);
+
+    let hint = Text::new("Enter: next field / save · B: delete, previous field or cancel")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT);
+
+    content_column = content_column
+        .push(Container::new(keyboard_view).center_x(Length::Fill))
+        .push(
+            Container::new(hint)
+                .padding(scaled(BASE_PADDING_SMALL, scale))
+                .width(Length::Fill)
+                .center_x(Length::Fill),
+        );
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(content_column)
+        .width(scaled_fixed(MODAL_WIDTH_LARGE, scale))
+        .height(Length::Shrink)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+fn render_field<'a>(
+    field: CustomEntryField,
+    value: &'a str,
+    is_focused: bool,
+    scale: f32,
+) -> Element<'a, Message> {
+    let label = Text::new(field.label())
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_MEDIUM, scale))
+        .color(COLOR_TEXT_MUTED)
+        .width(scaled_fixed(180.0, scale));
+
+    let value_text = Text::new(value)
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_LARGE, scale))
+        .color(COLOR_TEXT_BRIGHT);
+
+    let border_color = if is_focused {
+        COLOR_ACCENT
+    } else {
+        COLOR_TEXT_DIM
+    };
+    let border_radius = scaled(6.0, scale);
+    let value_box = Container::new(value_text)
+        .padding(scaled(BASE_PADDING_SMALL, scale))
+        .width(Length::Fill)
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: border_color,
+                width: if is_focused { 2.0 } else { 1.0 },
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Row::new()
+        .spacing(scaled(BASE_PADDING_SMALL, scale))
+        .align_y(iced::Alignment::Center)
+        .push(label)
+        .push(value_box)
+        .into()
+}
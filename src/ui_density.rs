@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ui_theme::{CATEGORY_ROW_SPACING, ITEM_SPACING, TILE_PADDING};
+
+/// How tightly tiles are packed on screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Density {
+    Compact,
+    #[default]
+    Normal,
+    Spacious,
+}
+
+impl Density {
+    pub fn label(self) -> &'static str {
+        match self {
+            Density::Compact => "Compact",
+            Density::Normal => "Normal",
+            Density::Spacious => "Spacious",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Density::Compact => Density::Normal,
+            Density::Normal => Density::Spacious,
+            Density::Spacious => Density::Compact,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            Density::Compact => Density::Spacious,
+            Density::Normal => Density::Compact,
+            Density::Spacious => Density::Normal,
+        }
+    }
+
+    fn factor(self) -> f32 {
+        match self {
+            Density::Compact => 0.5,
+            Density::Normal => 1.0,
+            Density::Spacious => 1.5,
+        }
+    }
+
+    /// Gap between tiles within a row
+    pub fn item_spacing(self, scale: f32) -> f32 {
+        ITEM_SPACING * self.factor() * scale
+    }
+
+    /// Gap between category rows
+    pub fn category_spacing(self, scale: f32) -> f32 {
+        CATEGORY_ROW_SPACING * self.factor() * scale
+    }
+
+    /// Inner padding of a single tile
+    pub fn tile_padding(self, scale: f32) -> f32 {
+        TILE_PADDING * self.factor() * scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spacing_per_density_at_fixed_scale() {
+        let scale = 2.0;
+
+        assert_eq!(Density::Compact.item_spacing(scale), 10.0);
+        assert_eq!(Density::Normal.item_spacing(scale), 20.0);
+        assert_eq!(Density::Spacious.item_spacing(scale), 30.0);
+
+        assert_eq!(Density::Compact.category_spacing(scale), 40.0);
+        assert_eq!(Density::Normal.category_spacing(scale), 80.0);
+        assert_eq!(Density::Spacious.category_spacing(scale), 120.0);
+
+        assert_eq!(Density::Compact.tile_padding(scale), 6.0);
+        assert_eq!(Density::Normal.tile_padding(scale), 12.0);
+        assert_eq!(Density::Spacious.tile_padding(scale), 18.0);
+    }
+
+    #[test]
+    fn test_cycling_wraps_around() {
+        assert_eq!(Density::Spacious.next(), Density::Compact);
+        assert_eq!(Density::Compact.prev(), Density::Spacious);
+        assert_eq!(Density::Normal.next().prev(), Density::Normal);
+    }
+
+    #[test]
+    fn test_serialized_as_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&Density::Spacious).unwrap(),
+            "\"spacious\""
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use iced::widget::{Column, Container, Text};
+use iced::{Color, Element, Length};
+
+use crate::fix_artwork::FixArtworkState;
+use crate::input::Action;
+use crate::messages::Message;
+use crate::ui_theme::*;
+use crate::virtual_keyboard::{KeyboardMessage, KeyboardOutput};
+
+pub fn render_fix_artwork_modal(state: &FixArtworkState, scale: f32) -> Element<'_, Message> {
+    let title = Text::new(format!("Fix Artwork: {}", state.game_name))
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let description = Text::new("Enter the SteamGridDB game ID (the number in the game's URL)")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_MEDIUM, scale))
+        .color(COLOR_TEXT_MUTED);
+
+    let border_radius = scaled(6.0, scale);
+    let value_box = Container::new(
+        Text::new(state.keyboard.value())
+            .font(SANSATION)
+            .size(scaled(BASE_FONT_LARGE, scale))
+            .color(COLOR_TEXT_BRIGHT),
+    )
+    .padding(scaled(BASE_PADDING_SMALL, scale))
+    .width(Length::Fill)
+    .style(move |_| iced::widget::container::Style {
+        background: Some(COLOR_PANEL.into()),
+        border: iced::Border {
+            color: COLOR_ACCENT,
+            width: 2.0,
+            radius: border_radius.into(),
+        },
+        ..Default::default()
+    });
+
+    let mut content_column = Column::new()
+        .spacing(scaled(BASE_PADDING_SMALL, scale))
+        .push(title_container)
+        .push(Container::new(description).center_x(Length::Fill))
+        .push(value_box);
+
+    if let Some(error) = &state.error {
+        content_column = content_column.push(
+            Container::new(
+                Text::new(error.as_str())
+                    .font(SANSATION)
+                    .size(scaled(BASE_FONT_MEDIUM, scale))
+                    .color(COLOR_ERROR),
+            )
+            .width(Length::Fill)
+            .center_x(Length::Fill),
+        );
+    }
+
+    let keyboard_view = state.keyboard.view(scale).map(Message::FixArtworkKeyboard);
+
+    let hint = Text::new("Enter: fetch artwork · B: delete or cancel")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT);
+
+    content_column = content_column
+        .push(Container::new(keyboard_view).center_x(Length::Fill))
+        .push(
+            Container::new(hint)
+                .padding(scaled(BASE_PADDING_SMALL, scale))
+                .width(Length::Fill)
+                .center_x(Length::Fill),
+        );
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(content_column)
+        .width(scaled_fixed(MODAL_WIDTH_LARGE, scale))
+        .height(Length::Shrink)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// B deletes the last digit, or leaves the modal once the field is empty
+pub fn handle_fix_artwork_navigation(
+    state: &mut FixArtworkState,
+    action: Action,
+) -> Option<Message> {
+    let output = match action {
+        Action::Up => {
+            state.keyboard.move_up();
+            KeyboardOutput::None
+        }
+        Action::Down => {
+            state.keyboard.move_down();
+            KeyboardOutput::None
+        }
+        Action::Left => {
+            state.keyboard.move_left();
+            KeyboardOutput::None
+        }
+        Action::Right => {
+            state.keyboard.move_right();
+            KeyboardOutput::None
+        }
+        Action::Select => state.keyboard.select_current(),
+        Action::Back if !state.keyboard.value().is_empty() => state.keyboard.backspace(),
+        Action::Back | Action::ShowHelp => return Some(Message::CloseFixArtwork),
+        _ => KeyboardOutput::None,
+    };
+
+    handle_keyboard_output(state, output)
+}
+
+pub fn handle_fix_artwork_keyboard_message(
+    state: &mut FixArtworkState,
+    message: KeyboardMessage,
+) -> Option<Message> {
+    let output = state.keyboard.handle_message(message);
+    handle_keyboard_output(state, output)
+}
+
+fn handle_keyboard_output(state: &mut FixArtworkState, output: KeyboardOutput) -> Option<Message> {
+    match output {
+        KeyboardOutput::Input(_) => {
+            state.error = None;
+            None
+        }
+        KeyboardOutput::Submit => Some(Message::SubmitFixArtwork),
+        KeyboardOutput::None => None,
+    }
+}
@@ -0,0 +1,96 @@
+use iced::widget::{Column, Container, Text};
+use iced::{Color, Element, Length};
+
+use crate::launch_options::LaunchOptionsState;
+use crate::launcher::COMMAND_PLACEHOLDER;
+use crate::messages::Message;
+use crate::ui_theme::*;
+
+pub fn render_launch_options_modal(state: &LaunchOptionsState, scale: f32) -> Element<'_, Message> {
+    let title = Text::new(format!("Launch Options: {}", state.item_name))
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let description = Text::new(format!(
+        "Appended to the command, or put {} where the original command goes",
+        COMMAND_PLACEHOLDER
+    ))
+    .font(SANSATION)
+    .size(scaled(BASE_FONT_MEDIUM, scale))
+    .color(COLOR_TEXT_MUTED);
+
+    let border_radius = scaled(6.0, scale);
+    let value_box = Container::new(
+        Text::new(state.keyboard.value())
+            .font(SANSATION)
+            .size(scaled(BASE_FONT_LARGE, scale))
+            .color(COLOR_TEXT_BRIGHT),
+    )
+    .padding(scaled(BASE_PADDING_SMALL, scale))
+    .width(Length::Fill)
+    .style(move |_| iced::widget::container::Style {
+        background: Some(COLOR_PANEL.into()),
+        border: iced::Border {
+            color: COLOR_ACCENT,
+            width: 2.0,
+            radius: border_radius.into(),
+        },
+        ..Default::default()
+    });
+
+    let keyboard_view = state
+        .keyboard
+        .view(scale)
+        .map(Message::LaunchOptionsKeyboard);
+
+    let hint = Text::new("Enter: save · B: delete or cancel")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT);
+
+    let content_column = Column::new()
+        .spacing(scaled(BASE_PADDING_SMALL, scale))
+        .push(title_container)
+        .push(Container::new(description).center_x(Length::Fill))
+        .push(value_box)
+        .push(Container::new(keyboard_view).center_x(Length::Fill))
+        .push(
+            Container::new(hint)
+                .padding(scaled(BASE_PADDING_SMALL, scale))
+                .width(Length::Fill)
+                .center_x(Length::Fill),
+        );
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(content_column)
+        .width(scaled_fixed(MODAL_WIDTH_LARGE, scale))
+        .height(Length::Shrink)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
@@ -0,0 +1,349 @@
+use iced::alignment::Horizontal;
+use iced::widget::{Column, Container, Row, Scrollable, Space, Text};
+use iced::{Color, Element, Length};
+
+use crate::display_modes::DisplayMode;
+use crate::fix_artwork::MissingArtwork;
+use crate::input::Action;
+use crate::messages::Message;
+use crate::power::PowerProfile;
+use crate::ui_theme::*;
+
+/// A row of a list modal
+pub trait ListEntry {
+    fn label(&self) -> String;
+
+    /// Marked "Current" and selected when the list is loaded
+    fn is_current(&self) -> bool {
+        false
+    }
+}
+
+impl ListEntry for DisplayMode {
+    fn label(&self) -> String {
+        DisplayMode::label(self)
+    }
+
+    fn is_current(&self) -> bool {
+        self.current
+    }
+}
+
+impl ListEntry for MissingArtwork {
+    fn label(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl ListEntry for PowerProfile {
+    fn label(&self) -> String {
+        PowerProfile::label(self)
+    }
+
+    fn is_current(&self) -> bool {
+        self.current
+    }
+}
+
+/// Fixed texts of a list modal
+pub struct ListModalText {
+    pub title: &'static str,
+    pub loading: &'static str,
+    pub empty: &'static str,
+    pub hint: &'static str,
+}
+
+/// A modal picking one entry of a list, e.g. a display mode or a game to fix
+pub struct ListModalState<T> {
+    pub text: ListModalText,
+    /// `None` while the list is loading
+    pub items: Option<Vec<T>>,
+    pub selected_index: usize,
+    pub error: Option<String>,
+}
+
+impl<T: ListEntry> ListModalState<T> {
+    pub fn new(text: ListModalText) -> Self {
+        Self {
+            text,
+            items: None,
+            selected_index: 0,
+            error: None,
+        }
+    }
+
+    /// Stores a freshly listed set of entries and selects the current one
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.selected_index = items.iter().position(T::is_current).unwrap_or(0);
+        self.items = Some(items);
+    }
+
+    /// Keeps the list empty and shows why it couldn't be loaded
+    pub fn set_error(&mut self, error: String) {
+        self.items = Some(Vec::new());
+        self.error = Some(error);
+    }
+
+    /// Selects the entry at `index`, clamped to the list
+    pub fn select(&mut self, index: usize) {
+        let len = self.items.as_ref().map_or(0, Vec::len);
+        self.selected_index = index.min(len.saturating_sub(1));
+    }
+
+    pub fn navigate(&mut self, action: Action) {
+        let len = self.items.as_ref().map_or(0, Vec::len);
+        match action {
+            Action::Up => self.selected_index = self.selected_index.saturating_sub(1),
+            Action::Down if self.selected_index + 1 < len => self.selected_index += 1,
+            _ => {}
+        }
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.items.as_ref()?.get(self.selected_index)
+    }
+}
+
+/// Input handling of the list modals: B closes the modal and A turns the selected
+/// entry and its position into the message acting on it
+pub fn handle_list_navigation<T: ListEntry>(
+    state: &mut ListModalState<T>,
+    action: Action,
+    on_select: impl FnOnce(usize, &T) -> Message,
+) -> Option<Message> {
+    match action {
+        Action::Back | Action::ShowHelp => Some(Message::CloseListModal),
+        Action::Select => {
+            let message = on_select(state.selected_index, state.selected()?);
+            state.error = None;
+            Some(message)
+        }
+        _ => {
+            state.navigate(action);
+            None
+        }
+    }
+}
+
+pub fn render_list_modal<T: ListEntry>(
+    state: &ListModalState<T>,
+    scale: f32,
+) -> Element<'_, Message> {
+    let title = Text::new(state.text.title)
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let content: Element<'_, Message> = match &state.items {
+        None => muted_text(state.text.loading, scale),
+        Some(items) if items.is_empty() => muted_text(state.text.empty, scale),
+        Some(items) => {
+            let mut rows = Column::new().spacing(scaled(BASE_PADDING_TINY, scale));
+            for (i, item) in items.iter().enumerate() {
+                rows = rows.push(render_row(item, i == state.selected_index, scale));
+            }
+            Scrollable::new(rows)
+                .width(Length::Fill)
+                .height(Length::Shrink)
+                .into()
+        }
+    };
+
+    let mut modal_column = Column::new()
+        .push(title_container)
+        .push(content)
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    if let Some(error) = &state.error {
+        modal_column = modal_column.push(
+            Text::new(error.as_str())
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_MEDIUM, scale))
+                .color(COLOR_ERROR),
+        );
+    }
+
+    let hint = Text::new(state.text.hint)
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    modal_column = modal_column.push(
+        Container::new(hint)
+            .padding(scaled(BASE_PADDING_SMALL, scale))
+            .width(Length::Fill)
+            .center_x(Length::Fill),
+    );
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_MEDIUM, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+fn muted_text(text: &str, scale: f32) -> Element<'_, Message> {
+    Container::new(
+        Text::new(text)
+            .font(SANSATION)
+            .size(scaled(BASE_FONT_LARGE, scale))
+            .color(COLOR_TEXT_MUTED),
+    )
+    .padding(scaled(BASE_PADDING_LARGE, scale))
+    .center_x(Length::Fill)
+    .into()
+}
+
+fn render_row<'a, T: ListEntry>(item: &T, is_selected: bool, scale: f32) -> Element<'a, Message> {
+    let text_color = if is_selected {
+        Color::WHITE
+    } else {
+        COLOR_TEXT_MUTED
+    };
+
+    let mut row = Row::new()
+        .push(
+            Text::new(item.label())
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_LARGE, scale))
+                .color(text_color),
+        )
+        .push(Space::new().width(Length::Fill))
+        .align_y(iced::Alignment::Center);
+
+    if item.is_current() {
+        row = row.push(
+            Text::new("Current")
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_MEDIUM, scale))
+                .color(COLOR_ACCENT),
+        );
+    }
+
+    let background = if is_selected {
+        COLOR_ACCENT_OVERLAY
+    } else {
+        Color::TRANSPARENT
+    };
+    let border_radius = scaled(6.0, scale);
+
+    Container::new(row)
+        .padding([
+            scaled(BASE_PADDING_SMALL, scale),
+            scaled(BASE_PADDING_MEDIUM, scale),
+        ])
+        .width(Length::Fill)
+        .style(move |_| iced::widget::container::Style {
+            background: Some(background.into()),
+            border: iced::Border {
+                color: if is_selected {
+                    COLOR_ACCENT
+                } else {
+                    Color::TRANSPARENT
+                },
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn missing(names: &[&str]) -> Vec<MissingArtwork> {
+        names
+            .iter()
+            .map(|name| MissingArtwork {
+                item_id: Uuid::new_v4(),
+                name: name.to_string(),
+            })
+            .collect()
+    }
+
+    fn state() -> ListModalState<MissingArtwork> {
+        ListModalState::new(ListModalText {
+            title: "",
+            loading: "",
+            empty: "",
+            hint: "",
+        })
+    }
+
+    #[test]
+    fn test_select_clamps_to_list() {
+        let mut state = state();
+        assert!(state.selected().is_none());
+
+        // Returning from Fix Artwork keeps the position, clamped to the shorter list
+        state.set_items(missing(&["Celeste", "Doom", "Tunic"]));
+        state.select(5);
+        assert_eq!(state.selected().unwrap().name, "Tunic");
+
+        state.navigate(Action::Down);
+        assert_eq!(state.selected_index, 2);
+        state.navigate(Action::Up);
+        assert_eq!(state.selected().unwrap().name, "Doom");
+    }
+
+    #[test]
+    fn test_set_error_shows_empty_list() {
+        let mut state = state();
+        state.set_error("wlr-randr not found".to_string());
+        assert_eq!(state.items.as_deref(), Some(&[][..]));
+        assert_eq!(state.error.as_deref(), Some("wlr-randr not found"));
+        state.select(3);
+        assert_eq!(state.selected_index, 0);
+    }
+
+    #[test]
+    fn test_list_navigation_picks_selected_entry() {
+        let mut state = state();
+        let pick =
+            |index: usize, game: &MissingArtwork| Message::FixMissingArtwork(index, game.item_id);
+        assert!(handle_list_navigation(&mut state, Action::Select, pick).is_none());
+
+        state.set_items(missing(&["Celeste", "Doom"]));
+        state.error = Some("Image cache is unavailable".to_string());
+        assert!(handle_list_navigation(&mut state, Action::Down, pick).is_none());
+        let doom = state.selected().unwrap().item_id;
+        assert!(matches!(
+            handle_list_navigation(&mut state, Action::Select, pick),
+            Some(Message::FixMissingArtwork(1, id)) if id == doom
+        ));
+        assert!(state.error.is_none());
+        assert!(matches!(
+            handle_list_navigation(&mut state, Action::Back, pick),
+            Some(Message::CloseListModal)
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+use iced::widget::{operation, Column, Container, Id, Row, Scrollable, Text};
+use iced::{Color, Element, Font, Length, Task};
+use tracing::Level;
+
+use crate::input::Action;
+use crate::log_buffer::LogLine;
+use crate::messages::Message;
+use crate::ui_theme::*;
+
+/// Vertical distance scrolled per D-pad press (at 1080p reference)
+const LOG_SCROLL_STEP: f32 = 120.0;
+/// Approximate height of one log line, used to stop scrolling past the end
+const LOG_LINE_HEIGHT: f32 = 20.0;
+
+pub struct LogViewerState {
+    /// Captured lines, newest first
+    pub lines: Vec<LogLine>,
+    pub scrollable_id: Id,
+    pub scroll_offset: f32,
+}
+
+impl LogViewerState {
+    pub fn new(mut lines: Vec<LogLine>) -> Self {
+        lines.reverse();
+        Self {
+            lines,
+            scrollable_id: Id::unique(),
+            scroll_offset: 0.0,
+        }
+    }
+
+    pub fn scroll(&mut self, action: Action, scale: f32) -> Task<Message> {
+        let step = scaled(LOG_SCROLL_STEP, scale);
+        self.scroll_offset = match action {
+            Action::Up => (self.scroll_offset - step).max(0.0),
+            Action::Down => {
+                let max_offset = self.lines.len() as f32 * scaled(LOG_LINE_HEIGHT, scale);
+                (self.scroll_offset + step).min(max_offset)
+            }
+            _ => return Task::none(),
+        };
+
+        operation::scroll_to(
+            self.scrollable_id.clone(),
+            iced::widget::scrollable::AbsoluteOffset {
+                x: 0.0,
+                y: self.scroll_offset,
+            },
+        )
+    }
+}
+
+fn level_color(level: Level) -> Color {
+    match level {
+        Level::ERROR => COLOR_ERROR,
+        Level::WARN => COLOR_WARNING,
+        Level::INFO => COLOR_TEXT_BRIGHT,
+        _ => COLOR_TEXT_MUTED,
+    }
+}
+
+pub fn render_logs_modal<'a>(state: &'a LogViewerState, scale: f32) -> Element<'a, Message> {
+    let title = Text::new("Logs")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let content: Element<'a, Message> = if state.lines.is_empty() {
+        Container::new(
+            Text::new("No log messages captured yet.")
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_LARGE, scale))
+                .color(COLOR_TEXT_MUTED),
+        )
+        .padding(scaled(BASE_PADDING_LARGE, scale))
+        .center_x(Length::Fill)
+        .into()
+    } else {
+        let mut lines = Column::new().spacing(scaled(2.0, scale));
+        for line in &state.lines {
+            let color = level_color(line.level);
+            lines = lines.push(
+                Row::new()
+                    .spacing(scaled(BASE_PADDING_SMALL, scale))
+                    .push(
+                        Text::new(line.timestamp.format("%H:%M:%S").to_string())
+                            .font(Font::MONOSPACE)
+                            .size(scaled(BASE_FONT_SMALL, scale))
+                            .color(COLOR_TEXT_DIM),
+                    )
+                    .push(
+                        Text::new(format!("{:<5}", line.level))
+                            .font(Font::MONOSPACE)
+                            .size(scaled(BASE_FONT_SMALL, scale))
+                            .color(color),
+                    )
+                    .push(
+                        Text::new(line.message.as_str())
+                            .font(Font::MONOSPACE)
+                            .size(scaled(BASE_FONT_SMALL, scale))
+                            .color(color),
+                    ),
+            );
+        }
+
+        Scrollable::new(lines)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .id(state.scrollable_id.clone())
+            .into()
+    };
+
+    let hint = Text::new("Newest first · Up/Down to scroll · B to close")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT);
+
+    let hint_container = Container::new(hint)
+        .padding(scaled(BASE_PADDING_SMALL, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let modal_column = Column::new()
+        .push(title_container)
+        .push(content)
+        .push(hint_container)
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
@@ -1,19 +1,26 @@
 use iced::alignment::Horizontal;
-use iced::widget::{scrollable, text, Column, Container, Row, Scrollable, Text};
+use iced::widget::{
+    scrollable, text, Column, Container, Image, Row, Scrollable, Space, Stack, Text,
+};
 use iced::{Background, Border, Color, Element, Length, Shadow};
 use iced_anim::{spring::Motion, AnimationBuilder};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::category_list::CategoryList;
+use crate::focus_animation::FocusAnimation;
+use crate::icon_fit::IconFit;
 use crate::icons;
+use crate::image_cache::CoverHandleCache;
 use crate::messages::Message;
-use crate::model::{Category, LauncherItem, SystemIcon};
-use crate::ui_components::render_icon;
+use crate::model::{source_badge, Category, LauncherAction, LauncherItem, SystemIcon};
+use crate::tile_style::{TileStyle, TitleOverflow};
+use crate::ui_components::{is_svg, render_icon};
+use crate::ui_density::Density;
 use crate::ui_theme::*;
 
 pub fn get_category_dimensions(category: Category, scale: f32) -> (f32, f32, f32, f32) {
     let (w, h, img_w, img_h) = match category {
-        Category::Games => (
+        Category::Games | Category::Favorites | Category::Recent => (
             GAME_POSTER_WIDTH + 16.0,
             GAME_POSTER_HEIGHT + 140.0,
             GAME_POSTER_WIDTH,
@@ -25,16 +32,45 @@ pub fn get_category_dimensions(category: Category, scale: f32) -> (f32, f32, f32
     (w * scale, h * scale, img_w * scale, img_h * scale)
 }
 
+/// What the small overlays on tiles show
+#[derive(Debug, Clone, Copy)]
+pub struct TileBadges {
+    /// Store or emulator a game comes from
+    pub source: bool,
+    /// Count shown on the System Update tile; hidden when zero
+    pub pending_updates: usize,
+    /// ProtonDB rating on Steam games
+    pub proton: bool,
+    /// Fill of the hold to launch bar on the selected tile of the active row
+    pub hold_progress: Option<f32>,
+}
+
+/// Row title, with the number of items when counts are shown
+fn section_title(category: Category, count: Option<usize>) -> String {
+    match count {
+        Some(count) => format!("{} ({})", category.title(), count),
+        None => category.title().to_string(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn render_section_row<'a>(
     active_category: Category,
     target_category: Category,
     list: &'a CategoryList,
     empty_msg: String,
     default_icon_handle: Option<iced::widget::svg::Handle>,
+    covers: &CoverHandleCache,
+    focus: &FocusAnimation,
+    badges: TileBadges,
+    show_count: bool,
+    icon_fit: IconFit,
+    tile_style: TileStyle,
     scale: f32,
+    density: Density,
 ) -> Element<'a, Message> {
     let is_active = active_category == target_category;
-    let selected_index = if is_active { list.selected_index } else { 0 };
+    let title_text = section_title(target_category, show_count.then_some(list.items.len()));
 
     let target_color = if is_active {
         Color::WHITE
@@ -42,7 +78,7 @@ pub fn render_section_row<'a>(
         COLOR_TEXT_DIM
     };
     let title: Element<'a, Message> = AnimationBuilder::new(target_color, move |color| {
-        Text::new(target_category.title())
+        Text::new(title_text.clone())
             .font(SANSATION)
             .size(24.0 * scale)
             .color(color)
@@ -66,21 +102,28 @@ pub fn render_section_row<'a>(
         .padding(20.0 * scale)
         .into()
     } else {
-        let mut row = Row::new().spacing(ITEM_SPACING * scale);
+        let mut row = Row::new().spacing(density.item_spacing(scale));
 
         for (i, item) in list.items.iter().enumerate() {
-            let is_selected = is_active && (i == selected_index);
-
             let dims = ItemDimensions {
                 image_width,
                 image_height,
                 item_width,
+                tile_padding: density.tile_padding(scale),
+                icon_fit,
+                tile_style,
             };
+            let selected = is_active && i == list.selected_index;
             row = row.push(render_item(
                 item,
-                is_selected,
+                focus.highlight(target_category, i),
                 &dims,
                 default_icon_handle.clone(),
+                covers,
+                TileBadges {
+                    hold_progress: badges.hold_progress.filter(|_| selected),
+                    ..badges
+                },
                 scale,
             ));
         }
@@ -142,109 +185,183 @@ pub struct ItemDimensions {
     pub image_width: f32,
     pub image_height: f32,
     pub item_width: f32,
+    pub tile_padding: f32,
+    pub icon_fit: IconFit,
+    pub tile_style: TileStyle,
 }
 
 #[allow(clippy::too_many_arguments)]
 fn render_item<'a>(
     item: &LauncherItem,
-    is_selected: bool,
+    highlight: f32,
     dims: &ItemDimensions,
     default_icon_handle: Option<iced::widget::svg::Handle>,
+    covers: &CoverHandleCache,
+    badges: TileBadges,
     scale: f32,
 ) -> Element<'a, Message> {
     let image_width = dims.image_width;
     let image_height = dims.image_height;
     let item_width = dims.item_width;
+    let tile_padding = dims.tile_padding;
+    let tile_style = dims.tile_style;
+    // Only the draw opacity changes; the decoded cover handle is reused as is
+    let opacity = tile_style.content_opacity(highlight);
+
+    let icon_widget: Element<'_, Message> = if let Some(ref sys_icon) = item.system_icon {
+        let icon_size = image_width * 0.6;
+        let icon = match sys_icon {
+            SystemIcon::PowerOff => icons::power_off_icon(icon_size),
+            SystemIcon::Pause => icons::pause_icon(icon_size),
+            SystemIcon::ArrowsRotate => icons::arrows_rotate_icon(icon_size),
+            SystemIcon::ExitBracket => icons::exit_icon(icon_size),
+            SystemIcon::Info => icons::info_icon(icon_size),
+            SystemIcon::Gear => icons::gear_icon(icon_size),
+            SystemIcon::FileLines => icons::file_lines_icon(icon_size),
+            SystemIcon::Tv => icons::tv_icon(icon_size),
+            SystemIcon::Terminal => icons::terminal_icon(icon_size),
+            SystemIcon::Bolt => icons::bolt_icon(icon_size, Color::WHITE),
+            SystemIcon::Bug => icons::bug_icon(icon_size),
+        };
+        Container::new(icon)
+            .width(Length::Fixed(image_width))
+            .height(Length::Fixed(image_height))
+            .align_x(Horizontal::Center)
+            .align_y(iced::alignment::Vertical::Center)
+            .into()
+    } else if let Some(handle) = match item.icon.as_deref() {
+        Some(icon) => Some(Path::new(icon))
+            .filter(|path| !is_svg(path))
+            .and_then(|path| covers.get(path)),
+        None => None,
+    } {
+        Image::new(handle)
+            .width(Length::Fixed(image_width))
+            .height(Length::Fixed(image_height))
+            .content_fit(dims.icon_fit.content_fit())
+            .opacity(opacity)
+            .into()
+    } else {
+        render_icon(
+            item.icon.as_ref().map(PathBuf::from),
+            image_width,
+            image_height,
+            "ICON",
+            None,
+            default_icon_handle,
+            dims.icon_fit.content_fit(),
+        )
+    };
 
-    let target = if is_selected {
-        (1.0f32, 10.0f32)
+    let badge = item
+        .launch_key
+        .as_deref()
+        .and_then(source_badge)
+        .filter(|_| badges.source);
+    let proton =
+        (item.runs_under_proton && badges.source).then(|| icons::proton_badge_icon(11.0 * scale));
+    let source_badges: Vec<Element<'_, Message>> = badge
+        .map(|badge| icons::source_badge_icon(badge, 11.0 * scale))
+        .into_iter()
+        .chain(proton)
+        .collect();
+    let icon_widget: Element<'_, Message> = if source_badges.is_empty() {
+        icon_widget
     } else {
-        (0.0f32, 0.0f32)
+        Stack::new()
+            .push(icon_widget)
+            .push(
+                Container::new(Row::with_children(source_badges).spacing(4.0 * scale))
+                    .padding(4.0 * scale),
+            )
+            .into()
     };
 
-    // Clone data needed inside the Fn closure (called multiple times during animation)
-    let item_name = item.name.clone();
-    let item_system_icon = item.system_icon;
-    let item_icon = item.icon.clone();
-    let default_icon = default_icon_handle.clone();
+    let icon_widget: Element<'_, Message> = match item.proton_tier.filter(|_| badges.proton) {
+        Some(tier) => Stack::new()
+            .push(icon_widget)
+            .push(
+                Container::new(icons::tier_badge(
+                    tier.label(),
+                    tier.badge_color(),
+                    11.0 * scale,
+                ))
+                .width(Length::Fill)
+                .align_x(Horizontal::Right)
+                .padding(4.0 * scale),
+            )
+            .into(),
+        None => icon_widget,
+    };
 
-    AnimationBuilder::new(target, move |(border_alpha, shadow_blur)| {
-        // Rebuild entire widget tree inside closure — Element is NOT Clone
-        let icon_widget: Element<'_, Message> = if let Some(ref sys_icon) = item_system_icon {
-            let icon_size = image_width * 0.6;
-            let icon = match sys_icon {
-                SystemIcon::PowerOff => icons::power_off_icon(icon_size),
-                SystemIcon::Pause => icons::pause_icon(icon_size),
-                SystemIcon::ArrowsRotate => icons::arrows_rotate_icon(icon_size),
-                SystemIcon::ExitBracket => icons::exit_icon(icon_size),
-                SystemIcon::Info => icons::info_icon(icon_size),
-            };
-            Container::new(icon)
-                .width(Length::Fixed(image_width))
-                .height(Length::Fixed(image_height))
-                .align_x(Horizontal::Center)
-                .align_y(iced::alignment::Vertical::Center)
+    let icon_widget: Element<'_, Message> =
+        if matches!(item.action, LauncherAction::SystemUpdate) && badges.pending_updates > 0 {
+            Stack::new()
+                .push(icon_widget)
+                .push(
+                    Container::new(icons::count_badge(badges.pending_updates, 13.0 * scale))
+                        .width(Length::Fill)
+                        .align_x(Horizontal::Right),
+                )
                 .into()
         } else {
-            render_icon(
-                item_icon.as_ref().map(PathBuf::from),
-                image_width,
-                image_height,
-                "ICON",
-                None,
-                default_icon.clone(),
-            )
+            icon_widget
         };
 
-        let icon_container = Container::new(icon_widget).padding(6.0 * scale);
+    let icon_widget: Element<'_, Message> = match badges.hold_progress {
+        Some(progress) => Stack::new()
+            .push(icon_widget)
+            .push(
+                Container::new(
+                    Container::new(Space::new())
+                        .width(Length::Fixed(image_width * progress))
+                        .height(Length::Fixed(6.0 * scale))
+                        .style(|_theme| iced::widget::container::Style {
+                            background: Some(COLOR_ACCENT.into()),
+                            ..Default::default()
+                        }),
+                )
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_y(iced::alignment::Vertical::Bottom),
+            )
+            .into(),
+        None => icon_widget,
+    };
 
-        let label = Text::new(item_name.clone())
-            .font(SANSATION)
-            .width(Length::Fixed(item_width))
-            .wrapping(text::Wrapping::Word)
-            .align_x(Horizontal::Center)
-            .color(Color::WHITE)
-            .size(14.0 * scale);
+    let icon_container = Container::new(icon_widget).padding(tile_padding);
 
-        let content = Column::new()
-            .push(icon_container)
-            .push(label)
-            .align_x(iced::Alignment::Center)
-            .spacing(5.0 * scale);
+    let font_size = 14.0 * scale;
+    let wrapping = match tile_style.title_overflow {
+        TitleOverflow::Wrap => text::Wrapping::Word,
+        TitleOverflow::Ellipsize => text::Wrapping::None,
+    };
+    let label = Text::new(tile_style.title(&item.name, item_width, font_size))
+        .font(SANSATION)
+        .width(Length::Fixed(item_width))
+        .wrapping(wrapping)
+        .align_x(Horizontal::Center)
+        .color(Color {
+            a: opacity,
+            ..Color::WHITE
+        })
+        .size(font_size);
 
-        Container::new(content)
-            .width(Length::Fixed(item_width))
-            .height(Length::Shrink)
-            .padding(6.0 * scale)
-            .align_x(Horizontal::Center)
-            .align_y(iced::alignment::Vertical::Center)
-            .style(move |_theme| iced::widget::container::Style {
-                border: iced::Border {
-                    color: Color {
-                        r: COLOR_ACCENT.r,
-                        g: COLOR_ACCENT.g,
-                        b: COLOR_ACCENT.b,
-                        a: border_alpha,
-                    },
-                    width: 1.0 * scale.max(1.0),
-                    radius: (4.0 * scale).into(),
-                },
-                shadow: iced::Shadow {
-                    color: Color {
-                        r: COLOR_ACCENT.r,
-                        g: COLOR_ACCENT.g,
-                        b: COLOR_ACCENT.b,
-                        a: border_alpha * 0.5,
-                    },
-                    offset: iced::Vector::ZERO,
-                    blur_radius: shadow_blur * scale,
-                },
-                ..Default::default()
-            })
-            .into()
-    })
-    .animation(Motion::SNAPPY)
-    .into()
+    let content = Column::new()
+        .push(icon_container)
+        .push(label)
+        .align_x(iced::Alignment::Center)
+        .spacing(5.0 * scale);
+
+    Container::new(content)
+        .width(Length::Fixed(item_width))
+        .height(Length::Shrink)
+        .padding(tile_padding)
+        .align_x(Horizontal::Center)
+        .align_y(iced::alignment::Vertical::Center)
+        // Driven by the launcher's focus animation timer
+        .style(move |_theme| tile_style.container_style(highlight, scale))
+        .into()
 }
 
 pub fn render_status<'a>(
@@ -269,6 +386,30 @@ pub fn render_status<'a>(
     )
 }
 
+/// Dismissible notice that some game sources could not be scanned.
+pub fn render_scan_error_banner<'a>(failed_sources: usize, scale: f32) -> Element<'a, Message> {
+    let noun = if failed_sources == 1 {
+        "source"
+    } else {
+        "sources"
+    };
+    Container::new(
+        Text::new(format!(
+            "{} {} failed — Y: view details · B: dismiss",
+            failed_sources, noun
+        ))
+        .font(SANSATION)
+        .size(16.0 * scale)
+        .color(COLOR_WARNING),
+    )
+    .padding(8.0 * scale)
+    .style(|_theme| iced::widget::container::Style {
+        background: Some(COLOR_STATUS_BACKGROUND.into()),
+        ..Default::default()
+    })
+    .into()
+}
+
 pub fn render_controls_hint<'a>(scale: f32) -> Element<'a, Message> {
     let hint = Text::new("Press  −  for controls")
         .font(SANSATION)
@@ -281,3 +422,37 @@ pub fn render_controls_hint<'a>(scale: f32) -> Element<'a, Message> {
         .padding(10.0 * scale)
         .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_manager::partition_hidden;
+    use std::collections::HashSet;
+
+    fn game(name: &str, launch_key: &str) -> LauncherItem {
+        LauncherItem {
+            name: name.to_string(),
+            launch_key: Some(launch_key.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_section_title_counts_visible_items() {
+        let items = vec![
+            game("Portal 2", "steam:620"),
+            game("Hades", "steam:1145360"),
+            game("Celeste", "heroic:legendary:Salt"),
+        ];
+        let hidden = HashSet::from(["steam:1145360".to_string()]);
+
+        let (visible, hidden_items) = partition_hidden(items, &hidden);
+        let list = CategoryList::new(visible);
+        assert_eq!(hidden_items.len(), 1);
+        assert_eq!(
+            section_title(Category::Games, Some(list.items.len())),
+            "Games (2)"
+        );
+        assert_eq!(section_title(Category::Games, None), "Games");
+    }
+}
@@ -0,0 +1,190 @@
+use iced::alignment::Horizontal;
+use iced::widget::{Column, Container, Row, Scrollable, Space, Text};
+use iced::{Color, Element, Length};
+
+use crate::game_manager::{ManageGamesState, ManagedGame};
+use crate::messages::Message;
+use crate::ui_theme::*;
+
+pub fn render_manage_games_modal(state: &ManageGamesState, scale: f32) -> Element<'_, Message> {
+    let title = Text::new("Manage Games")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let content: Element<'_, Message> = if state.games.is_empty() {
+        Container::new(
+            Text::new("No games found")
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_LARGE, scale))
+                .color(COLOR_TEXT_MUTED),
+        )
+        .padding(scaled(BASE_PADDING_LARGE, scale))
+        .center_x(Length::Fill)
+        .into()
+    } else {
+        let mut rows = Column::new().spacing(scaled(BASE_PADDING_TINY, scale));
+        for (i, game) in state.games.iter().enumerate() {
+            let checked = state.checked.contains(&game.launch_key);
+            rows = rows.push(render_game_row(
+                game,
+                checked,
+                i == state.selected_index,
+                scale,
+            ));
+        }
+        Scrollable::new(rows)
+            .width(Length::Fill)
+            .height(Length::Fixed(scaled(420.0, scale)))
+            .into()
+    };
+
+    let operation = Text::new(format!("◀  {}  ▶", state.operation.label()))
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_LARGE, scale))
+        .color(COLOR_ACCENT);
+
+    let status = if state.confirming {
+        format!(
+            "{} {} game(s)? A to confirm · B to cancel",
+            state.operation.label(),
+            state.checked.len()
+        )
+    } else {
+        format!("{} selected", state.checked.len())
+    };
+    let status_color = if state.confirming {
+        Color::WHITE
+    } else {
+        COLOR_TEXT_MUTED
+    };
+
+    let footer = Row::new()
+        .push(operation)
+        .push(Space::new().width(Length::Fill))
+        .push(
+            Text::new(status)
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_MEDIUM, scale))
+                .color(status_color),
+        )
+        .align_y(iced::Alignment::Center);
+
+    let hint = Text::new("A to check · ◀ ▶ operation · X to apply · B to close")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let modal_column = Column::new()
+        .push(title_container)
+        .push(content)
+        .push(footer)
+        .push(
+            Container::new(hint)
+                .padding(scaled(BASE_PADDING_SMALL, scale))
+                .width(Length::Fill)
+                .center_x(Length::Fill),
+        )
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_MEDIUM, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+fn render_game_row(
+    game: &ManagedGame,
+    checked: bool,
+    is_selected: bool,
+    scale: f32,
+) -> Element<'_, Message> {
+    let text_color = if is_selected {
+        Color::WHITE
+    } else {
+        COLOR_TEXT_MUTED
+    };
+    let checkbox = if checked { "☑" } else { "☐" };
+
+    let mut row = Row::new()
+        .push(
+            Text::new(checkbox)
+                .size(scaled(BASE_FONT_LARGE, scale))
+                .color(text_color),
+        )
+        .push(
+            Text::new(game.name.as_str())
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_LARGE, scale))
+                .color(text_color),
+        )
+        .push(Space::new().width(Length::Fill))
+        .spacing(scaled(BASE_PADDING_SMALL, scale))
+        .align_y(iced::Alignment::Center);
+
+    for (flag, label) in [(game.favorite, "Favorite"), (game.hidden, "Hidden")] {
+        if flag {
+            row = row.push(
+                Text::new(label)
+                    .font(SANSATION)
+                    .size(scaled(BASE_FONT_MEDIUM, scale))
+                    .color(COLOR_ACCENT),
+            );
+        }
+    }
+
+    let background = if is_selected {
+        COLOR_ACCENT_OVERLAY
+    } else {
+        Color::TRANSPARENT
+    };
+    let border_radius = scaled(6.0, scale);
+
+    Container::new(row)
+        .padding([
+            scaled(BASE_PADDING_SMALL, scale),
+            scaled(BASE_PADDING_MEDIUM, scale),
+        ])
+        .width(Length::Fill)
+        .style(move |_| iced::widget::container::Style {
+            background: Some(background.into()),
+            border: iced::Border {
+                color: if is_selected {
+                    COLOR_ACCENT
+                } else {
+                    Color::TRANSPARENT
+                },
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        })
+        .into()
+}
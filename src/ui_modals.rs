@@ -1,21 +1,58 @@
 use iced::alignment::Horizontal;
 use iced::widget::{Column, Container, Row, Scrollable, Text};
-use iced::{Color, Element, Length};
+use iced::{Color, Element, Font, Length};
 use iced_anim::{spring::Motion, AnimationBuilder};
 
+use crate::context_menu::ContextMenuEntry;
+use crate::duplicate_games::source_label;
+use crate::game_sources::ScanError;
+use crate::launch_diagnostics::LaunchFailure;
 use crate::messages::Message;
-use crate::model::Category;
+use crate::model::{AppAction, LauncherItem};
+use crate::steam_metadata::GameMetadata;
 use crate::ui_theme::*;
 
 pub fn render_context_menu<'a>(
+    entries: &[ContextMenuEntry],
+    selected_index: usize,
+    scale: f32,
+) -> Element<'a, Message> {
+    let labels = entries.iter().map(|entry| entry.label().to_string());
+    render_menu(labels.collect(), selected_index, scale)
+}
+
+/// Submenu listing the desktop entry actions of an app, followed by "Back"
+pub fn render_app_actions_menu<'a>(
+    actions: &[AppAction],
+    selected_index: usize,
+    scale: f32,
+) -> Element<'a, Message> {
+    let labels = actions
+        .iter()
+        .map(|action| action.name.clone())
+        .chain(std::iter::once("Back".to_string()));
+    render_menu(labels.collect(), selected_index, scale)
+}
+
+/// Source choice for a game merged from several stores; the last row cancels.
+pub fn render_launch_source_menu<'a>(
+    item: Option<&LauncherItem>,
+    selected_index: usize,
+    scale: f32,
+) -> Element<'a, Message> {
+    let labels = item
+        .into_iter()
+        .flat_map(|item| std::iter::once(item).chain(&item.alternates))
+        .map(|copy| format!("Launch from {}", source_label(copy)))
+        .chain(std::iter::once("Cancel".to_string()));
+    render_menu(labels.collect(), selected_index, scale)
+}
+
+fn render_menu<'a>(
+    menu_items: Vec<String>,
     selected_index: usize,
-    category: Category,
     scale: f32,
 ) -> Element<'a, Message> {
-    let menu_items: Vec<&str> = match category {
-        Category::Apps => vec!["Launch", "Remove Entry", "Quit Launcher", "Close"],
-        Category::Games | Category::System => vec!["Launch", "Quit Launcher", "Close"],
-    };
     let mut column = Column::new()
         .spacing(scaled(BASE_PADDING_SMALL, scale))
         .padding(scaled(BASE_PADDING_MEDIUM, scale));
@@ -33,7 +70,7 @@ pub fn render_context_menu<'a>(
             COLOR_TEXT_MUTED
         };
 
-        let item_text = item.to_string();
+        let item_text = item.clone();
 
         let animated_item: Element<'a, Message> =
             AnimationBuilder::new((target_bg, target_text), move |(bg_color, txt_color)| {
@@ -84,7 +121,7 @@ pub fn render_context_menu<'a>(
         .into()
 }
 
-pub fn render_help_modal<'a>(scale: f32) -> Element<'a, Message> {
+pub fn render_help_modal<'a>(swap_ab: bool, scale: f32) -> Element<'a, Message> {
     let title = Text::new("Controller Bindings")
         .font(SANSATION)
         .size(scaled(BASE_FONT_HEADER, scale))
@@ -95,15 +132,25 @@ pub fn render_help_modal<'a>(scale: f32) -> Element<'a, Message> {
         .width(Length::Fill)
         .center_x(Length::Fill);
 
+    let (confirm_button, back_button) = if swap_ab {
+        ("B / East", "A / South")
+    } else {
+        ("A / South", "B / East")
+    };
+
     let gamepad_bindings = vec![
-        ("A / South", "Select / Confirm"),
-        ("B / East", "Back / Cancel"),
+        (confirm_button, "Select / Confirm"),
+        (back_button, "Back / Cancel"),
         ("X / West", "Context Menu"),
         ("Y / North", "Add App (in Apps)"),
         ("D-Pad / Left Stick", "Navigate"),
         ("LB / LT", "Previous Category"),
         ("RB / RT", "Next Category"),
         ("− / Select", "Show/Hide Controls"),
+        ("Guide + South", "Screenshot"),
+        ("Guide + North", "Toggle Fullscreen"),
+        ("Guide + West", "Relaunch Last Played"),
+        ("Guide + East", "Change Sort Order"),
     ];
 
     let keyboard_bindings = vec![
@@ -111,10 +158,17 @@ pub fn render_help_modal<'a>(scale: f32) -> Element<'a, Message> {
         ("Enter", "Select / Confirm"),
         ("Escape", "Back / Cancel"),
         ("Tab", "Next Category"),
+        ("1 / 2 / 3", "Games / Apps / System"),
         ("C", "Context Menu"),
         ("+ / A", "Add App (in Apps)"),
         ("−", "Show/Hide Controls"),
         ("F4", "Quit Launcher"),
+        ("Print Screen", "Screenshot"),
+        ("F11", "Toggle Fullscreen"),
+        ("R", "Relaunch Last Played"),
+        ("S", "Change Sort Order"),
+        ("F9", "Open Config Folder"),
+        ("Ctrl + V", "Paste into Text Field"),
     ];
 
     let mut content_column = Column::new().spacing(scaled(8.0, scale));
@@ -231,7 +285,69 @@ pub fn render_app_not_found_modal<'a>(
     selected_index: usize,
     scale: f32,
 ) -> Element<'a, Message> {
-    let title = Text::new("App Not Found")
+    render_confirm_modal(
+        "App Not Found",
+        format!(
+            "{} is no longer installed. Remove it from your list?",
+            item_name
+        ),
+        &["Remove", "Cancel"],
+        selected_index,
+        scale,
+    )
+}
+
+pub fn render_low_memory_modal<'a>(
+    item_name: &str,
+    available: &str,
+    selected_index: usize,
+    scale: f32,
+) -> Element<'a, Message> {
+    render_confirm_modal(
+        "Low Memory",
+        format!(
+            "Only {} of memory is available. {} may run out of memory and crash.",
+            available, item_name
+        ),
+        &["Launch Anyway", "Cancel"],
+        selected_index,
+        scale,
+    )
+}
+
+pub fn render_confirm_exit_modal<'a>(selected_index: usize, scale: f32) -> Element<'a, Message> {
+    render_confirm_modal(
+        "Quit Launcher",
+        "Quit RhincoTV and return to the desktop?".to_string(),
+        &["Quit", "Cancel"],
+        selected_index,
+        scale,
+    )
+}
+
+pub fn render_confirm_clear_history_modal<'a>(
+    selected_index: usize,
+    scale: f32,
+) -> Element<'a, Message> {
+    render_confirm_modal(
+        "Clear Launch History",
+        "Forget when games and apps were last played? The recently played row will be empty."
+            .to_string(),
+        &["Clear", "Cancel"],
+        selected_index,
+        scale,
+    )
+}
+
+/// A question with a row of buttons, the selected one highlighted
+fn render_confirm_modal<'a>(
+    title: &str,
+    message: String,
+    options: &[&'a str],
+    selected_index: usize,
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new(title.to_string())
         .font(SANSATION)
         .size(scaled(26.0, scale))
         .color(Color::WHITE);
@@ -241,22 +357,17 @@ pub fn render_app_not_found_modal<'a>(
         .width(Length::Fill)
         .center_x(Length::Fill);
 
-    let message = Text::new(format!(
-        "{} is no longer installed. Remove it from your list?",
-        item_name
-    ))
-    .font(SANSATION)
-    .size(scaled(BASE_FONT_LARGE, scale))
-    .color(COLOR_TEXT_BRIGHT)
-    .align_x(Horizontal::Center);
+    let message = Text::new(message)
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_LARGE, scale))
+        .color(COLOR_TEXT_BRIGHT)
+        .align_x(Horizontal::Center);
 
     let message_container = Container::new(message)
         .padding(scaled(BASE_PADDING_SMALL, scale))
         .width(Length::Fill)
         .center_x(Length::Fill);
 
-    let options = ["Remove", "Cancel"];
-
     let options_row = Row::with_children(
         options
             .iter()
@@ -302,6 +413,273 @@ pub fn render_app_not_found_modal<'a>(
         .into()
 }
 
+pub fn render_scan_errors_modal<'a>(errors: &[ScanError], scale: f32) -> Element<'a, Message> {
+    let title = Text::new("Scanning Failed")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let mut error_column = Column::new().spacing(scaled(BASE_PADDING_SMALL, scale));
+    for error in errors {
+        error_column = error_column.push(
+            Column::new()
+                .push(
+                    Text::new(error.source_name)
+                        .font(SANSATION)
+                        .size(scaled(BASE_FONT_LARGE, scale))
+                        .color(COLOR_WARNING),
+                )
+                .push(
+                    Text::new(error.diagnostic.to_string())
+                        .font(SANSATION)
+                        .size(scaled(BASE_FONT_MEDIUM, scale))
+                        .color(COLOR_TEXT_BRIGHT),
+                ),
+        );
+    }
+
+    let hint = Text::new("Games from these sources are missing. B to close")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let hint_container = Container::new(hint)
+        .padding(scaled(BASE_PADDING_SMALL, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let modal_column = Column::new()
+        .push(title_container)
+        .push(Scrollable::new(error_column).height(Length::Shrink))
+        .push(hint_container)
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_LARGE, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+pub fn render_launch_failed_modal<'a>(
+    item_name: &str,
+    failure: &LaunchFailure,
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new(format!("{} Failed to Start", item_name))
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let summary = Text::new(failure.summary())
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_LARGE, scale))
+        .color(COLOR_WARNING);
+
+    let stderr = if failure.stderr_tail.is_empty() {
+        "No error output".to_string()
+    } else {
+        failure.stderr_tail.clone()
+    };
+    let output = Text::new(stderr)
+        .font(Font::MONOSPACE)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_BRIGHT);
+
+    let hint = Text::new("The full output is in the logs. B to close")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let hint_container = Container::new(hint)
+        .padding(scaled(BASE_PADDING_SMALL, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let modal_column = Column::new()
+        .push(title_container)
+        .push(summary)
+        .push(Scrollable::new(output).height(Length::Shrink))
+        .push(hint_container)
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_LARGE, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Store details of a Steam game, a loading note, or why they're unavailable
+pub fn render_game_details_modal<'a>(
+    item_name: &str,
+    details: Option<&Result<GameMetadata, String>>,
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new(item_name.to_string())
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let note = |message: String, color: Color| -> Element<'a, Message> {
+        Text::new(message)
+            .font(SANSATION)
+            .size(scaled(BASE_FONT_LARGE, scale))
+            .color(color)
+            .into()
+    };
+    let body: Element<'a, Message> = match details {
+        None => note("Loading store details...".to_string(), COLOR_TEXT_DIM),
+        Some(Err(error)) => note(error.clone(), COLOR_WARNING),
+        Some(Ok(metadata)) if metadata.is_empty() => note(
+            "The Steam store has no details for this game".to_string(),
+            COLOR_TEXT_DIM,
+        ),
+        Some(Ok(metadata)) => render_game_metadata(metadata, scale),
+    };
+
+    let hint = Text::new("B to close")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let hint_container = Container::new(hint)
+        .padding(scaled(BASE_PADDING_SMALL, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let modal_column = Column::new()
+        .push(title_container)
+        .push(Scrollable::new(body).height(Length::Shrink))
+        .push(hint_container)
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_LARGE, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Labeled rows for the known fields, then the description
+fn render_game_metadata<'a>(metadata: &GameMetadata, scale: f32) -> Element<'a, Message> {
+    let rows = [
+        ("Developer", metadata.developers.join(", ")),
+        ("Genres", metadata.genres.join(", ")),
+        (
+            "Released",
+            metadata.release_date.clone().unwrap_or_default(),
+        ),
+    ];
+
+    let mut column = Column::new().spacing(scaled(BASE_PADDING_SMALL, scale));
+    for (label, value) in rows.into_iter().filter(|(_, value)| !value.is_empty()) {
+        column = column.push(
+            Row::new()
+                .push(
+                    Text::new(label)
+                        .font(SANSATION)
+                        .size(scaled(BASE_FONT_MEDIUM, scale))
+                        .color(COLOR_TEXT_DIM)
+                        .width(scaled_fixed(140.0, scale)),
+                )
+                .push(
+                    Text::new(value)
+                        .font(SANSATION)
+                        .size(scaled(BASE_FONT_MEDIUM, scale))
+                        .color(COLOR_TEXT_BRIGHT),
+                ),
+        );
+    }
+    if let Some(description) = &metadata.short_description {
+        column = column.push(
+            Text::new(description.clone())
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_MEDIUM, scale))
+                .color(COLOR_TEXT_BRIGHT),
+        );
+    }
+    column.into()
+}
+
 fn modal_button<'a>(label: &'a str, is_selected: bool, scale: f32) -> Element<'a, Message> {
     let text = Text::new(label)
         .font(SANSATION)
@@ -345,3 +723,169 @@ fn modal_button<'a>(label: &'a str, is_selected: bool, scale: f32) -> Element<'a
         })
         .into()
 }
+
+/// Shown over everything, including open dialogs, before an idle auto-suspend.
+pub fn render_auto_suspend_countdown<'a>(
+    seconds_left: u64,
+    reduce_transparency: bool,
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new(format!("Suspending in {}s", seconds_left))
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let hint = Text::new("No input for a while. Press any button to stay awake")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_MEDIUM, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let modal_column = Column::new()
+        .push(title)
+        .push(hint)
+        .align_x(iced::Alignment::Center)
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .style(move |_| iced::widget::container::Style {
+            background: Some(modal_overlay_color(false, reduce_transparency).into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Shown over a running game after its last controller disconnected; goes away on
+/// its own once one reconnects
+pub fn render_controller_lost<'a>(reduce_transparency: bool, scale: f32) -> Element<'a, Message> {
+    let title = Text::new("Controller disconnected")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let hint = Text::new("Reconnect a controller to return to the game")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_MEDIUM, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let modal_column = Column::new()
+        .push(title)
+        .push(hint)
+        .align_x(iced::Alignment::Center)
+        .spacing(scaled(BASE_PADDING_MEDIUM, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_MEDIUM, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: COLOR_WARNING,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .style(move |_| iced::widget::container::Style {
+            background: Some(modal_overlay_color(false, reduce_transparency).into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Shown while no gamepad is connected, with the keyboard controls as a fallback
+pub fn render_controller_splash<'a>(
+    keyboard_reference: Vec<(String, &'static str)>,
+    reduce_transparency: bool,
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new("Connect a controller")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let hint = Text::new("Or use the keyboard. Press any key to continue")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_MEDIUM, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let mut bindings = Column::new().spacing(scaled(4.0, scale));
+    for (keys, label) in keyboard_reference {
+        bindings = bindings.push(
+            Row::new()
+                .push(
+                    Text::new(keys)
+                        .font(SANSATION)
+                        .size(scaled(BASE_FONT_MEDIUM, scale))
+                        .color(COLOR_TEXT_BRIGHT)
+                        .width(Length::FillPortion(1)),
+                )
+                .push(
+                    Text::new(label)
+                        .font(SANSATION)
+                        .size(scaled(BASE_FONT_MEDIUM, scale))
+                        .color(COLOR_TEXT_HINT)
+                        .width(Length::FillPortion(1)),
+                )
+                .spacing(scaled(BASE_PADDING_MEDIUM, scale)),
+        );
+    }
+
+    let modal_column = Column::new()
+        .push(title)
+        .push(hint)
+        .push(bindings)
+        .align_x(iced::Alignment::Center)
+        .spacing(scaled(BASE_PADDING_MEDIUM, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_MEDIUM, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .style(move |_| iced::widget::container::Style {
+            background: Some(modal_overlay_color(false, reduce_transparency).into()),
+            ..Default::default()
+        })
+        .into()
+}
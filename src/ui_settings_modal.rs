@@ -0,0 +1,217 @@
+use iced::alignment::Horizontal;
+use iced::widget::{Column, Container, Row, Scrollable, Space, Text};
+use iced::{Color, Element, Length};
+
+use crate::gamepad::suggest_deadzone;
+use crate::messages::Message;
+use crate::settings::{Settings, SettingsEntry};
+use crate::ui_theme::*;
+
+pub fn render_settings_modal<'a>(
+    settings: &Settings,
+    selected_index: usize,
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new("Settings")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let title_container = Container::new(title)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let mut rows = Column::new().spacing(scaled(BASE_PADDING_TINY, scale));
+
+    for (i, entry) in SettingsEntry::ALL.iter().enumerate() {
+        let is_selected = i == selected_index;
+        let text_color = if is_selected {
+            Color::WHITE
+        } else {
+            COLOR_TEXT_MUTED
+        };
+
+        let row = Row::new()
+            .push(
+                Text::new(entry.label())
+                    .font(SANSATION)
+                    .size(scaled(BASE_FONT_LARGE, scale))
+                    .color(text_color),
+            )
+            .push(Space::new().width(Length::Fill))
+            .push(
+                Text::new(entry.value_label(settings))
+                    .font(SANSATION)
+                    .size(scaled(BASE_FONT_LARGE, scale))
+                    .color(if is_selected {
+                        Color::WHITE
+                    } else {
+                        COLOR_TEXT_BRIGHT
+                    }),
+            )
+            .align_y(iced::Alignment::Center);
+
+        let background = if is_selected {
+            COLOR_ACCENT_OVERLAY
+        } else {
+            Color::TRANSPARENT
+        };
+        let border_radius = scaled(6.0, scale);
+
+        rows = rows.push(
+            Container::new(row)
+                .padding([
+                    scaled(BASE_PADDING_SMALL, scale),
+                    scaled(BASE_PADDING_MEDIUM, scale),
+                ])
+                .width(Length::Fill)
+                .style(move |_| iced::widget::container::Style {
+                    background: Some(background.into()),
+                    border: iced::Border {
+                        color: if is_selected {
+                            COLOR_ACCENT
+                        } else {
+                            Color::TRANSPARENT
+                        },
+                        width: 1.0,
+                        radius: border_radius.into(),
+                    },
+                    ..Default::default()
+                }),
+        );
+    }
+
+    let scrollable_rows = Scrollable::new(rows)
+        .width(Length::Fill)
+        .height(Length::Shrink);
+
+    let hint = Text::new("A / ◀ ▶ to change · B to close")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT)
+        .align_x(Horizontal::Center);
+
+    let hint_container = Container::new(hint)
+        .padding(scaled(BASE_PADDING_SMALL, scale))
+        .width(Length::Fill)
+        .center_x(Length::Fill);
+
+    let modal_column = Column::new()
+        .push(title_container)
+        .push(scrollable_rows)
+        .push(hint_container)
+        .spacing(scaled(BASE_PADDING_SMALL, scale));
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_MEDIUM, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Shows the resting drift observed so far and the deadzone that would be applied.
+pub fn render_stick_calibration_modal<'a>(
+    horizontal_drift: f32,
+    vertical_drift: f32,
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new("Calibrate Stick")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let instructions = Text::new(
+        "Leave the left stick untouched for a few seconds so its resting drift can be measured.",
+    )
+    .font(SANSATION)
+    .size(scaled(BASE_FONT_LARGE, scale))
+    .color(COLOR_TEXT_BRIGHT)
+    .align_x(Horizontal::Center);
+
+    let mut rows = Column::new().spacing(scaled(BASE_PADDING_TINY, scale));
+    for (label, drift) in [
+        ("Horizontal", horizontal_drift),
+        ("Vertical", vertical_drift),
+    ] {
+        rows = rows.push(
+            Row::new()
+                .push(
+                    Text::new(label)
+                        .font(SANSATION)
+                        .size(scaled(BASE_FONT_LARGE, scale))
+                        .color(COLOR_TEXT_MUTED),
+                )
+                .push(Space::new().width(Length::Fill))
+                .push(
+                    Text::new(format!(
+                        "drift {:.2} → deadzone {:.2}",
+                        drift,
+                        suggest_deadzone(drift)
+                    ))
+                    .font(SANSATION)
+                    .size(scaled(BASE_FONT_LARGE, scale))
+                    .color(Color::WHITE),
+                ),
+        );
+    }
+
+    let hint = Text::new("A to apply · B to cancel")
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_SMALL, scale))
+        .color(COLOR_TEXT_HINT);
+
+    let modal_column = Column::new()
+        .push(title)
+        .push(instructions)
+        .push(rows)
+        .push(hint)
+        .spacing(scaled(BASE_PADDING_MEDIUM, scale))
+        .align_x(iced::Alignment::Center);
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(modal_column)
+        .width(scaled_fixed(MODAL_WIDTH_MEDIUM, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
@@ -0,0 +1,282 @@
+use iced::widget::{Column, Container, Text};
+use iced::{Color, Element, Length};
+
+use crate::game_sources::ScanError;
+use crate::input::Action;
+use crate::messages::Message;
+use crate::setup_wizard::{SetupStep, SetupWizardState};
+use crate::ui_theme::*;
+use crate::virtual_keyboard::{KeyboardMessage, KeyboardOutput};
+
+/// Games found per source by the first scan; `None` while still scanning
+pub type SourceSummary = Option<Vec<(String, usize)>>;
+
+pub fn render_setup_wizard<'a>(
+    state: &'a SetupWizardState,
+    sources: SourceSummary,
+    scan_errors: &'a [ScanError],
+    scale: f32,
+) -> Element<'a, Message> {
+    let title = Text::new(state.step.title())
+        .font(SANSATION)
+        .size(scaled(BASE_FONT_HEADER, scale))
+        .color(Color::WHITE);
+
+    let progress = Text::new(format!(
+        "Step {} of {}",
+        state.step.number(),
+        SetupStep::ALL.len()
+    ))
+    .font(SANSATION)
+    .size(scaled(BASE_FONT_SMALL, scale))
+    .color(COLOR_TEXT_HINT);
+
+    let mut content_column = Column::new()
+        .spacing(scaled(BASE_PADDING_SMALL, scale))
+        .push(
+            Container::new(title)
+                .padding(scaled(BASE_PADDING_MEDIUM, scale))
+                .center_x(Length::Fill),
+        )
+        .push(Container::new(progress).center_x(Length::Fill));
+
+    let body: Element<'a, Message> = match state.step {
+        SetupStep::Welcome => paragraph(
+            "Let's set up the launcher. This takes about a minute and every step can be \
+             changed later under System → Settings.",
+            scale,
+        ),
+        SetupStep::GameSources => render_sources(sources, scan_errors, scale),
+        SetupStep::ApiKey => render_api_key(state, scale),
+        SetupStep::ClockFormat => {
+            let label = if state.clock_24h {
+                "24-hour (21:30)"
+            } else {
+                "12-hour (9:30 PM)"
+            };
+            choice(label, scale)
+        }
+        SetupStep::Controls => {
+            let label = if state.swap_ab {
+                "Nintendo layout: East confirms, South goes back"
+            } else {
+                "Standard layout: South confirms, East goes back"
+            };
+            choice(label, scale)
+        }
+        SetupStep::Finish => paragraph(
+            "You're all set. Press Select on a tile to launch it and X for more options.",
+            scale,
+        ),
+    };
+    content_column = content_column.push(body);
+
+    let hint = match state.step {
+        SetupStep::ApiKey => "Enter: next · B: delete or go back · X: skip setup",
+        SetupStep::ClockFormat | SetupStep::Controls => {
+            "◀ ▶ change · A: next · B: back · X: skip setup"
+        }
+        SetupStep::Finish => "A: finish · B: back",
+        _ => "A: next · B: back · X: skip setup",
+    };
+    content_column = content_column.push(
+        Container::new(
+            Text::new(hint)
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_SMALL, scale))
+                .color(COLOR_TEXT_HINT),
+        )
+        .padding(scaled(BASE_PADDING_SMALL, scale))
+        .center_x(Length::Fill),
+    );
+
+    let border_radius = scaled(10.0, scale);
+    let modal_box = Container::new(content_column)
+        .width(scaled_fixed(MODAL_WIDTH_LARGE, scale))
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .style(move |_| iced::widget::container::Style {
+            background: Some(COLOR_PANEL.into()),
+            border: iced::Border {
+                color: Color::WHITE,
+                width: 1.0,
+                radius: border_radius.into(),
+            },
+            ..Default::default()
+        });
+
+    Container::new(modal_box)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .padding(scaled(MODAL_OVERLAY_PADDING, scale))
+        .style(|_| iced::widget::container::Style {
+            background: Some(Color::TRANSPARENT.into()),
+            ..Default::default()
+        })
+        .into()
+}
+
+fn paragraph<'a>(text: impl Into<String>, scale: f32) -> Element<'a, Message> {
+    Container::new(
+        Text::new(text.into())
+            .font(SANSATION)
+            .size(scaled(BASE_FONT_LARGE, scale))
+            .color(COLOR_TEXT_BRIGHT),
+    )
+    .padding(scaled(BASE_PADDING_MEDIUM, scale))
+    .center_x(Length::Fill)
+    .into()
+}
+
+fn choice<'a>(label: &str, scale: f32) -> Element<'a, Message> {
+    Container::new(
+        Text::new(format!("◀  {}  ▶", label))
+            .font(SANSATION)
+            .size(scaled(BASE_FONT_LARGE, scale))
+            .color(COLOR_ACCENT),
+    )
+    .padding(scaled(BASE_PADDING_MEDIUM, scale))
+    .center_x(Length::Fill)
+    .into()
+}
+
+fn render_sources<'a>(
+    sources: SourceSummary,
+    scan_errors: &'a [ScanError],
+    scale: f32,
+) -> Element<'a, Message> {
+    let Some(sources) = sources else {
+        return paragraph("Scanning for games...", scale);
+    };
+
+    let mut column = Column::new().spacing(scaled(BASE_PADDING_TINY, scale));
+    if sources.is_empty() {
+        column = column.push(paragraph(
+            "No games found yet. Install games with Steam, Heroic, Mupen64Plus, or SNES9x \
+             and they will show up automatically.",
+            scale,
+        ));
+    }
+    for (source, count) in sources {
+        column = column.push(
+            Text::new(format!("{}: {} game(s)", source, count))
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_LARGE, scale))
+                .color(COLOR_TEXT_BRIGHT),
+        );
+    }
+    for error in scan_errors {
+        column = column.push(
+            Text::new(error.to_string())
+                .font(SANSATION)
+                .size(scaled(BASE_FONT_MEDIUM, scale))
+                .color(COLOR_ERROR),
+        );
+    }
+
+    Container::new(column)
+        .padding(scaled(BASE_PADDING_MEDIUM, scale))
+        .center_x(Length::Fill)
+        .into()
+}
+
+fn render_api_key(state: &SetupWizardState, scale: f32) -> Element<'_, Message> {
+    let description = Text::new(
+        "Optional: a SteamGridDB API key finds better cover art. \
+         Create one at steamgriddb.com/profile/preferences/api, or leave it empty.",
+    )
+    .font(SANSATION)
+    .size(scaled(BASE_FONT_MEDIUM, scale))
+    .color(COLOR_TEXT_MUTED);
+
+    let border_radius = scaled(6.0, scale);
+    let value_box = Container::new(
+        Text::new(state.api_key.value())
+            .font(SANSATION)
+            .size(scaled(BASE_FONT_LARGE, scale))
+            .color(COLOR_TEXT_BRIGHT),
+    )
+    .padding(scaled(BASE_PADDING_SMALL, scale))
+    .width(Length::Fill)
+    .style(move |_| iced::widget::container::Style {
+        background: Some(COLOR_PANEL.into()),
+        border: iced::Border {
+            color: COLOR_ACCENT,
+            width: 2.0,
+            radius: border_radius.into(),
+        },
+        ..Default::default()
+    });
+
+    Column::new()
+        .spacing(scaled(BASE_PADDING_SMALL, scale))
+        .push(description)
+        .push(value_box)
+        .push(
+            Container::new(state.api_key.view(scale).map(Message::SetupKeyboard))
+                .center_x(Length::Fill),
+        )
+        .into()
+}
+
+/// The context menu button skips the wizard; confirming the last step completes it
+pub fn handle_setup_wizard_navigation(
+    state: &mut SetupWizardState,
+    action: Action,
+) -> Option<Message> {
+    if action == Action::ContextMenu && state.step != SetupStep::Finish {
+        return Some(Message::CompleteSetup(false));
+    }
+
+    if state.step == SetupStep::ApiKey {
+        let output = match action {
+            Action::Up => {
+                state.api_key.move_up();
+                KeyboardOutput::None
+            }
+            Action::Down => {
+                state.api_key.move_down();
+                KeyboardOutput::None
+            }
+            Action::Left => {
+                state.api_key.move_left();
+                KeyboardOutput::None
+            }
+            Action::Right => {
+                state.api_key.move_right();
+                KeyboardOutput::None
+            }
+            Action::Select => state.api_key.select_current(),
+            Action::NextCategory => KeyboardOutput::Submit,
+            Action::Back if !state.api_key.value().is_empty() => state.api_key.backspace(),
+            Action::Back | Action::PrevCategory => {
+                state.back();
+                KeyboardOutput::None
+            }
+            _ => KeyboardOutput::None,
+        };
+        if matches!(output, KeyboardOutput::Submit) {
+            state.advance();
+        }
+        return None;
+    }
+
+    match action {
+        Action::Left | Action::Right => state.toggle_choice(),
+        Action::Select | Action::NextCategory => {
+            return (!state.advance()).then_some(Message::CompleteSetup(true));
+        }
+        Action::Back | Action::PrevCategory => {
+            state.back();
+        }
+        _ => {}
+    }
+    None
+}
+
+pub fn handle_setup_keyboard_message(state: &mut SetupWizardState, message: KeyboardMessage) {
+    if state.api_key.handle_message(message) == KeyboardOutput::Submit {
+        state.advance();
+    }
+}
@@ -0,0 +1,173 @@
+//! Short UI sound effects for navigating, confirming and going back.
+//!
+//! The bundled samples are written once to the cache directory, with the volume
+//! applied, and played by the desktop's sound player on a background thread so a
+//! slow audio server never stalls the UI.
+
+use crate::assets::get_ui_sound;
+use crate::input::Action;
+use crate::launcher::verify_command_exists;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Players tried in order: PipeWire, PulseAudio, then plain ALSA
+const PLAYERS: &[&str] = &["pw-play", "paplay", "aplay"];
+
+/// Size of the canonical WAV header of the bundled samples
+const WAV_HEADER_LEN: usize = 44;
+
+/// Held directions repeat faster than this; skipping some keeps it from buzzing
+const MIN_NAVIGATE_GAP: Duration = Duration::from_millis(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiSound {
+    Navigate,
+    Select,
+    Back,
+}
+
+impl UiSound {
+    /// Sound of an input action; actions like screenshots stay silent
+    pub fn for_action(action: Action) -> Option<Self> {
+        match action {
+            Action::Up
+            | Action::Down
+            | Action::Left
+            | Action::Right
+            | Action::NextCategory
+            | Action::PrevCategory
+            | Action::JumpCategory(_) => Some(UiSound::Navigate),
+            Action::Select => Some(UiSound::Select),
+            Action::Back => Some(UiSound::Back),
+            _ => None,
+        }
+    }
+
+    const ALL: [UiSound; 3] = [UiSound::Navigate, UiSound::Select, UiSound::Back];
+
+    fn file_name(self) -> &'static str {
+        match self {
+            UiSound::Navigate => "navigate.wav",
+            UiSound::Select => "select.wav",
+            UiSound::Back => "back.wav",
+        }
+    }
+}
+
+pub struct UiSounds {
+    /// `None` when no supported player is installed; sounds are skipped then
+    player: Option<&'static str>,
+    dir: PathBuf,
+    last_navigate: Option<Instant>,
+}
+
+impl UiSounds {
+    /// Writes the sample set at `volume` (0-100) to `dir`
+    pub fn load(dir: &Path, volume: u8) -> std::io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        for sound in UiSound::ALL {
+            let sample = get_ui_sound(sound.file_name()).ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::NotFound, sound.file_name())
+            })?;
+            fs::write(dir.join(sound.file_name()), scale_volume(sample, volume))?;
+        }
+
+        let player = PLAYERS.iter().copied().find(|p| verify_command_exists(p));
+        if player.is_none() {
+            tracing::warn!("No sound player (pw-play, paplay, aplay) found; UI sounds stay silent");
+        }
+        Ok(Self {
+            player,
+            dir: dir.to_path_buf(),
+            last_navigate: None,
+        })
+    }
+
+    pub fn play(&mut self, sound: UiSound) {
+        if sound == UiSound::Navigate {
+            let now = Instant::now();
+            if self
+                .last_navigate
+                .is_some_and(|last| now.duration_since(last) < MIN_NAVIGATE_GAP)
+            {
+                return;
+            }
+            self.last_navigate = Some(now);
+        }
+        let Some(player) = self.player else {
+            return;
+        };
+
+        let path = self.sample_path(sound);
+        // Waiting on the thread reaps the player process once the sound is done
+        std::thread::spawn(move || {
+            let _ = Command::new(player)
+                .arg(path)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status();
+        });
+    }
+
+    fn sample_path(&self, sound: UiSound) -> PathBuf {
+        self.dir.join(sound.file_name())
+    }
+}
+
+/// Scales the 16-bit PCM samples after the WAV header
+fn scale_volume(mut wav: Vec<u8>, volume: u8) -> Vec<u8> {
+    let factor = f32::from(volume.min(100)) / 100.0;
+    if wav.len() > WAV_HEADER_LEN {
+        for chunk in wav[WAV_HEADER_LEN..].chunks_exact_mut(2) {
+            let sample = i16::from_le_bytes([chunk[0], chunk[1]]);
+            let scaled = (f32::from(sample) * factor) as i16;
+            chunk.copy_from_slice(&scaled.to_le_bytes());
+        }
+    }
+    wav
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_load_writes_sample_set() {
+        let dir = std::env::temp_dir().join(format!("launcher_test_sounds_{}", Uuid::new_v4()));
+
+        let sounds = UiSounds::load(&dir, 50).unwrap();
+        for sound in UiSound::ALL {
+            let data = fs::read(sounds.sample_path(sound)).unwrap();
+            assert!(data.len() > WAV_HEADER_LEN);
+            assert_eq!(&data[..4], b"RIFF");
+            assert_eq!(&data[8..12], b"WAVE");
+        }
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_scale_volume_keeps_header() {
+        let mut wav = vec![0u8; WAV_HEADER_LEN];
+        wav[..4].copy_from_slice(b"RIFF");
+        wav.extend_from_slice(&1000i16.to_le_bytes());
+        wav.extend_from_slice(&(-2000i16).to_le_bytes());
+
+        let half = scale_volume(wav.clone(), 50);
+        assert_eq!(&half[..4], b"RIFF");
+        assert_eq!(
+            i16::from_le_bytes([half[WAV_HEADER_LEN], half[WAV_HEADER_LEN + 1]]),
+            500
+        );
+        assert_eq!(
+            i16::from_le_bytes([half[WAV_HEADER_LEN + 2], half[WAV_HEADER_LEN + 3]]),
+            -1000
+        );
+
+        let muted = scale_volume(wav, 0);
+        assert!(muted[WAV_HEADER_LEN..].iter().all(|b| *b == 0));
+    }
+}
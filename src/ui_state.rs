@@ -1,10 +1,23 @@
 use uuid::Uuid;
 
 use crate::auth_flow::AuthFlow;
-use crate::model::Category;
+use crate::custom_entry::CustomEntryState;
+use crate::display_modes::DisplayMode;
+use crate::fix_artwork::{FixArtworkState, MissingArtwork};
+use crate::focus_manager::MonitorTarget;
+use crate::game_manager::ManageGamesState;
+use crate::game_sources::ScanError;
+use crate::launch_diagnostics::LaunchFailure;
+use crate::launch_options::LaunchOptionsState;
+use crate::model::{Category, LauncherItem};
+use crate::power::PowerProfile;
+use crate::setup_wizard::SetupWizardState;
+use crate::steam_metadata::GameMetadata;
 use crate::system_info::GamingSystemInfo;
 use crate::system_update_state::SystemUpdateState;
 use crate::ui_app_picker::AppPickerState;
+use crate::ui_list_modal::ListModalState;
+use crate::ui_logs_modal::LogViewerState;
 use crate::updater::ReleaseInfo;
 use crate::virtual_keyboard::VirtualKeyboard;
 
@@ -13,7 +26,19 @@ pub enum ModalState {
     ContextMenu {
         index: usize,
     },
+    /// Desktop entry actions of the selected app, opened from the context menu
+    AppActions {
+        index: usize,
+    },
+    /// Which copy of a merged duplicate game to launch
+    LaunchSource {
+        index: usize,
+    },
     AppPicker(AppPickerState),
+    CustomEntry(CustomEntryState),
+    FixArtwork(FixArtworkState),
+    MissingArtwork(ListModalState<MissingArtwork>),
+    LaunchOptions(LaunchOptionsState),
     SystemUpdate(SystemUpdateState),
     SystemUpdateAuth {
         update: SystemUpdateState,
@@ -21,6 +46,9 @@ pub enum ModalState {
     },
     AppUpdate(AppUpdateState),
     SystemInfo(Box<Option<GamingSystemInfo>>),
+    Display(ListModalState<DisplayMode>),
+    PowerProfiles(ListModalState<PowerProfile>),
+    ManageGames(ManageGamesState),
     Auth(AuthState),
     AppNotFound {
         item_id: Uuid,
@@ -29,6 +57,51 @@ pub enum ModalState {
         selected_index: usize,
     },
     Help,
+    Settings {
+        index: usize,
+    },
+    Logs(LogViewerState),
+    ScanErrors(Vec<ScanError>),
+    /// Largest left stick deflection observed per axis while the user leaves the stick at rest
+    StickCalibration {
+        horizontal_drift: f32,
+        vertical_drift: f32,
+    },
+    /// First run setup, shown until finished or skipped
+    SetupWizard(SetupWizardState),
+    /// Asks before launching a game while little memory is available
+    LowMemory {
+        item: Box<LauncherItem>,
+        exec: String,
+        available: String,
+        selected_index: usize,
+    },
+    /// A game that failed right after launch, with the end of its stderr
+    LaunchFailed {
+        item_name: String,
+        failure: LaunchFailure,
+    },
+    /// Steam store details of a game; `None` while they load
+    GameDetails {
+        appid: String,
+        item_name: String,
+        details: Option<Result<GameMetadata, String>>,
+    },
+    /// Asks before quitting the launcher after a double Back press
+    ConfirmExit {
+        selected_index: usize,
+    },
+    /// Asks before forgetting when games and apps were last played
+    ConfirmClearHistory {
+        selected_index: usize,
+    },
+}
+
+/// A launch waiting for `launch_delay_ms` after the launcher was minimized
+pub struct PendingLaunch {
+    pub command: String,
+    pub monitor_target: Option<MonitorTarget>,
+    pub item: LauncherItem,
 }
 
 pub struct AppUpdateState {
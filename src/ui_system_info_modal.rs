@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local};
 use iced::widget::{Column, Container, ProgressBar, Row, Scrollable, Space, Text};
 use iced::{Color, Element, Length, Padding};
 
@@ -5,6 +6,9 @@ use crate::messages::Message;
 use crate::system_info::GamingSystemInfo;
 use crate::ui_theme::*;
 
+/// The full history stays in `update_history.json` for bug reports
+const MAX_SHOWN_UPDATES: usize = 5;
+
 pub fn render_system_info_modal<'a>(
     info: &'a Option<GamingSystemInfo>,
     scale: f32,
@@ -146,6 +150,28 @@ fn build_left_column(info: &GamingSystemInfo, scale: f32) -> Element<'_, Message
 
     column = column.push(section_spacer(scale));
 
+    column = column.push(section_header_accent("Thermals", scale));
+    if info.thermals.is_empty() {
+        column = column.push(
+            Text::new("No sensors found")
+                .font(SANSATION)
+                .size(scaled(17.0, scale))
+                .color(COLOR_TEXT_DIM),
+        );
+    } else {
+        if let Some(temp) = info.thermals.cpu_temp {
+            column = column.push(info_row("CPU", format_temperature(temp), scale));
+        }
+        if let Some(temp) = info.thermals.gpu_temp {
+            column = column.push(info_row("GPU", format_temperature(temp), scale));
+        }
+        for fan in &info.thermals.fans {
+            column = column.push(info_row(&fan.label, format!("{} RPM", fan.rpm), scale));
+        }
+    }
+
+    column = column.push(section_spacer(scale));
+
     column = column.push(section_header_accent("Storage", scale));
 
     if info.disks.is_empty() {
@@ -244,6 +270,33 @@ fn build_right_column(info: &GamingSystemInfo, scale: f32) -> Element<'_, Messag
 
     column = column.push(section_spacer(scale));
 
+    column = column.push(section_header_accent("Display", scale));
+    if info.displays.is_empty() {
+        column = column.push(
+            Text::new("No displays detected")
+                .font(SANSATION)
+                .size(scaled(17.0, scale))
+                .color(COLOR_TEXT_DIM),
+        );
+    } else {
+        for display in &info.displays {
+            column = column.push(info_row_with_status(
+                format!("{} VRR", display.output),
+                display.vrr_status().to_string(),
+                display.vrr_capable,
+                scale,
+            ));
+            column = column.push(info_row_with_status(
+                format!("{} HDR", display.output),
+                display.hdr_status().to_string(),
+                display.hdr_capable,
+                scale,
+            ));
+        }
+    }
+
+    column = column.push(section_spacer(scale));
+
     column = column.push(section_header_accent("Kernel Tweaks", scale));
 
     let governor_ok = info.cpu_governor == "performance";
@@ -298,6 +351,25 @@ fn build_right_column(info: &GamingSystemInfo, scale: f32) -> Element<'_, Messag
         }
     }
 
+    if !info.update_history.is_empty() {
+        column = column.push(section_spacer(scale));
+        column = column.push(section_header_accent("Launcher Updates", scale));
+        for update in info.update_history.iter().rev().take(MAX_SHOWN_UPDATES) {
+            let versions = format!("{} → {}", update.from_version, update.to_version);
+            let value = if update.success {
+                versions
+            } else {
+                format!("{} (failed)", versions)
+            };
+            column = column.push(info_row_with_status(
+                format_update_time(update.timestamp),
+                value,
+                update.success,
+                scale,
+            ));
+        }
+    }
+
     column.into()
 }
 
@@ -450,6 +522,20 @@ fn info_row_with_bar(
         .into()
 }
 
+fn format_update_time(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|time| {
+            time.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+fn format_temperature(celsius: f32) -> String {
+    format!("{:.0} °C", celsius)
+}
+
 fn format_large_number(n: u64) -> String {
     if n >= 1_000_000_000 {
         format!("{:.1}B", n as f64 / 1_000_000_000.0)
@@ -9,8 +9,7 @@ pub fn render_system_update_modal<'a>(
     state: &SystemUpdateState,
     scale: f32,
 ) -> Element<'a, Message> {
-    let spinner_chars = ["◐", "◓", "◑", "◒"];
-    let spinner = spinner_chars[state.spinner_tick % 4];
+    let spinner = SPINNER_CHARS[state.spinner_tick % SPINNER_CHARS.len()];
 
     let mut progress_bar_value: Option<f32> = None;
 
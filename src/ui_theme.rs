@@ -40,6 +40,21 @@ pub const COLOR_ACCENT_OVERLAY: Color = Color::from_rgba(0.30, 0.79, 0.94, 0.3);
 pub const COLOR_OVERLAY: Color = Color::from_rgba(0.04, 0.06, 0.09, 0.7); // Abyss Dark @ 70%
 pub const COLOR_OVERLAY_STRONG: Color = Color::from_rgba(0.04, 0.06, 0.09, 0.85); // Abyss Dark @ 85%
 
+/// Backdrop behind a modal: the lighter overlay for the context menu, the stronger one
+/// for everything else, and fully opaque with `reduce_transparency`
+pub fn modal_overlay_color(context_menu: bool, reduce_transparency: bool) -> Color {
+    let color = if context_menu {
+        COLOR_OVERLAY
+    } else {
+        COLOR_OVERLAY_STRONG
+    };
+    if reduce_transparency {
+        Color { a: 1.0, ..color }
+    } else {
+        color
+    }
+}
+
 // Status Colors
 pub const COLOR_STATUS_TEXT: Color = Color::from_rgb(0.9, 0.8, 0.4);
 
@@ -57,6 +72,7 @@ pub const COLOR_BATTERY_CHARGING: Color = Color::from_rgb(0.13, 0.59, 0.95);
 // Layout Constants
 pub const MAIN_CONTENT_VERTICAL_PADDING: f32 = 80.0;
 pub const ITEM_SPACING: f32 = 10.0;
+pub const TILE_PADDING: f32 = 6.0;
 pub const APP_PICKER_WIDTH_RATIO: f32 = 0.8;
 pub const APP_PICKER_PADDING: f32 = 80.0;
 pub const DEFAULT_VIEWPORT_HEIGHT: f32 = 600.0;
@@ -107,6 +123,43 @@ pub fn scaled_fixed(base: f32, scale: f32) -> iced::Length {
     iced::Length::Fixed(base * scale)
 }
 
+/// Scale for a window of the given height, unless the config overrides it for TVs
+/// that report a misleading height. Both are clamped to the supported range.
+pub fn ui_scale_for(window_height: f32, override_scale: Option<f32>) -> f32 {
+    override_scale
+        .unwrap_or(window_height / REFERENCE_WINDOW_HEIGHT)
+        .clamp(MIN_UI_SCALE, MAX_UI_SCALE)
+}
+
+/// Frames of the progress spinner, advanced on every spinner tick
+pub const SPINNER_CHARS: [&str; 4] = ["◐", "◓", "◑", "◒"];
+
 // Timing Constants (in seconds)
 pub const BATTERY_CHECK_INTERVAL_SECS: u64 = 60;
+pub const UPDATE_COUNT_CHECK_INTERVAL_SECS: u64 = 6 * 60 * 60;
 pub const RESTART_DELAY_SECS: u64 = 2;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ui_scale_override_takes_precedence() {
+        assert_eq!(ui_scale_for(2160.0, None), 2.0);
+        assert_eq!(ui_scale_for(2160.0, Some(1.2)), 1.2);
+        assert_eq!(ui_scale_for(720.0, Some(1.5)), 1.5);
+        // Overrides stay within the supported range too
+        assert_eq!(ui_scale_for(1080.0, Some(10.0)), MAX_UI_SCALE);
+        assert_eq!(ui_scale_for(100.0, None), MIN_UI_SCALE);
+    }
+
+    #[test]
+    fn test_reduce_transparency_makes_overlay_opaque() {
+        assert!(modal_overlay_color(false, false).a < 1.0);
+        assert!(modal_overlay_color(true, false).a < modal_overlay_color(false, false).a);
+
+        assert_eq!(modal_overlay_color(false, true).a, 1.0);
+        assert_eq!(modal_overlay_color(true, true).a, 1.0);
+        assert_eq!(modal_overlay_color(true, true).r, COLOR_OVERLAY.r);
+    }
+}
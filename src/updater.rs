@@ -1,6 +1,14 @@
+use crate::storage::project_dirs;
 use self_update::cargo_crate_version;
 use self_update::update::ReleaseUpdate;
 use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const HISTORY_FILE: &str = "update_history.json";
+/// Plenty for bug reports while keeping the file small
+const MAX_HISTORY_ENTRIES: usize = 20;
 
 #[derive(Debug, Clone)]
 pub struct ReleaseInfo {
@@ -40,12 +48,79 @@ pub fn check_update_available() -> Result<Option<ReleaseInfo>, String> {
     }))
 }
 
-pub fn apply_update() -> Result<(), String> {
-    let updater = build_updater()?;
-    updater
-        .update()
-        .map_err(|e| format!("Update failed: {}", e))?;
-    Ok(())
+/// Installs the latest release and records the attempt in the update history.
+/// `version` is the release the user confirmed, recorded if the update fails.
+pub fn apply_update(version: String) -> Result<(), String> {
+    let result = build_updater().and_then(|updater| {
+        updater
+            .update()
+            .map(|status| status.version().to_string())
+            .map_err(|e| format!("Update failed: {}", e))
+    });
+
+    let record = UpdateRecord {
+        from_version: cargo_crate_version!().to_string(),
+        to_version: result.clone().unwrap_or(version),
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0),
+        success: result.is_ok(),
+        error: result.as_ref().err().cloned(),
+    };
+    if let Some(path) = history_path() {
+        if let Err(e) = append_update_record(&path, record) {
+            tracing::warn!("Failed to write update history: {}", e);
+        }
+    }
+
+    result.map(|_| ())
+}
+
+/// One self-update attempt, kept in `update_history.json` next to the config
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateRecord {
+    pub from_version: String,
+    pub to_version: String,
+    /// Seconds since the Unix epoch
+    pub timestamp: i64,
+    pub success: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn history_path() -> Option<PathBuf> {
+    project_dirs()
+        .ok()
+        .map(|dirs| dirs.config_dir().join(HISTORY_FILE))
+}
+
+/// Past self-updates, oldest first; empty if there were none or the file is unreadable
+pub fn load_update_history() -> Vec<UpdateRecord> {
+    history_path()
+        .map(|path| read_update_history(&path))
+        .unwrap_or_default()
+}
+
+fn read_update_history(path: &Path) -> Vec<UpdateRecord> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Appends `record`, dropping the oldest entries beyond `MAX_HISTORY_ENTRIES`
+fn append_update_record(path: &Path, record: UpdateRecord) -> std::io::Result<()> {
+    let mut history = read_update_history(path);
+    history.push(record);
+    let excess = history.len().saturating_sub(MAX_HISTORY_ENTRIES);
+    history.drain(..excess);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&history).map_err(std::io::Error::other)?;
+    fs::write(path, json)
 }
 
 fn build_updater() -> Result<Box<dyn ReleaseUpdate>, String> {
@@ -60,3 +135,61 @@ fn build_updater() -> Result<Box<dyn ReleaseUpdate>, String> {
         .build()
         .map_err(|e| format!("Failed to configure updater: {}", e))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn record(to_version: &str, success: bool) -> UpdateRecord {
+        UpdateRecord {
+            from_version: "1.0.0".to_string(),
+            to_version: to_version.to_string(),
+            timestamp: 1_700_000_000,
+            success,
+            error: (!success).then(|| "Update failed: timed out".to_string()),
+        }
+    }
+
+    fn temp_history_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("rhinco-update-history-{}", Uuid::new_v4()))
+            .join(HISTORY_FILE)
+    }
+
+    #[test]
+    fn test_append_update_record() {
+        let path = temp_history_path();
+        assert!(read_update_history(&path).is_empty());
+
+        append_update_record(&path, record("1.1.0", false)).unwrap();
+        append_update_record(&path, record("1.1.0", true)).unwrap();
+
+        let history = read_update_history(&path);
+        assert_eq!(history, vec![record("1.1.0", false), record("1.1.0", true)]);
+        // Successful updates don't write an empty error
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content.matches("\"error\"").count(), 1);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_update_history_is_capped() {
+        let path = temp_history_path();
+        for i in 0..MAX_HISTORY_ENTRIES + 5 {
+            append_update_record(&path, record(&format!("1.{}.0", i), true)).unwrap();
+        }
+
+        let history = read_update_history(&path);
+        assert_eq!(history.len(), MAX_HISTORY_ENTRIES);
+        // The oldest entries were dropped
+        assert_eq!(history[0].to_version, "1.5.0");
+        assert_eq!(
+            history.last().unwrap().to_version,
+            format!("1.{}.0", MAX_HISTORY_ENTRIES + 4)
+        );
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
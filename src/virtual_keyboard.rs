@@ -6,6 +6,8 @@ use crate::ui_theme;
 #[derive(Debug, Clone, PartialEq)]
 pub enum KeyboardMessage {
     Press(usize, usize),
+    /// Clipboard text pasted with Ctrl+V on a physical keyboard
+    Paste(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -158,6 +160,28 @@ impl VirtualKeyboard {
                 self.cursor = (row, col);
                 self.select_current()
             }
+            KeyboardMessage::Paste(text) => self.paste(&text),
+        }
+    }
+
+    /// Appends pasted text up to the max length. All fields are single line, so
+    /// line breaks and other control characters (e.g. a copied key's trailing
+    /// newline) are dropped.
+    fn paste(&mut self, text: &str) -> KeyboardOutput {
+        let before = self.value.len();
+        for c in text.chars().filter(|c| !c.is_control()) {
+            if let Some(max) = self.max_length {
+                if self.value.len() + c.len_utf8() > max {
+                    break;
+                }
+            }
+            self.value.push(c);
+        }
+
+        if self.value.len() == before {
+            KeyboardOutput::None
+        } else {
+            KeyboardOutput::Input(self.value.clone())
         }
     }
 
@@ -419,3 +443,34 @@ const SYMBOLS_LAYOUT: &[&[KeyDef]] = &[
         KeyDef::special("OK", KeyType::Submit, 2),
     ],
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paste_appends_without_line_breaks() {
+        let mut keyboard = VirtualKeyboard::new("steam ".to_string());
+        let output =
+            keyboard.handle_message(KeyboardMessage::Paste("-applaunch\r\n 620\n".to_string()));
+        assert_eq!(
+            output,
+            KeyboardOutput::Input("steam -applaunch 620".to_string())
+        );
+        assert_eq!(keyboard.value(), "steam -applaunch 620");
+
+        // Nothing left after stripping
+        let output = keyboard.handle_message(KeyboardMessage::Paste("\n".to_string()));
+        assert_eq!(output, KeyboardOutput::None);
+        assert_eq!(keyboard.value(), "steam -applaunch 620");
+
+        // Cut off at the max length
+        let mut keyboard = VirtualKeyboard::new("ab".to_string()).with_max_length(5);
+        keyboard.handle_message(KeyboardMessage::Paste("cdefg".to_string()));
+        assert_eq!(keyboard.value(), "abcde");
+        assert_eq!(
+            keyboard.handle_message(KeyboardMessage::Paste("h".to_string())),
+            KeyboardOutput::None
+        );
+    }
+}
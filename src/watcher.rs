@@ -0,0 +1,159 @@
+//! Watches the Steam and Heroic directories for installed or removed games so the
+//! library can be rescanned without restarting.
+//!
+//! The watched paths are polled for modification times; a rescan is requested once
+//! they have been quiet for a while, so a download touching files for minutes
+//! results in a single scan.
+
+use crate::game_sources::{heroic_roots, steam_library_dirs};
+use directories::BaseDirs;
+use iced::futures::{SinkExt, Stream};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the watched paths are checked
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Time without further changes before a rescan is requested
+const QUIET_PERIOD: Duration = Duration::from_secs(10);
+
+/// Heroic files rewritten when a game is installed or uninstalled
+const HEROIC_FILES: &[&str] = &[
+    "store_cache/legendary_library.json",
+    "store_cache/gog_library.json",
+    "store_cache/nile_library.json",
+    "sideload_apps/library.json",
+    "legendaryConfig/legendary/installed.json",
+    "gog_store/installed.json",
+    "nile_config/nile/installed.json",
+];
+
+/// Coalesces bursts of changes into one notification after a quiet period
+#[derive(Debug)]
+pub struct Debouncer {
+    quiet: Duration,
+    last_change: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(quiet: Duration) -> Self {
+        Self {
+            quiet,
+            last_change: None,
+        }
+    }
+
+    /// Records a change; each one restarts the quiet period
+    pub fn change(&mut self, now: Instant) {
+        self.last_change = Some(now);
+    }
+
+    /// Returns true once per burst, when the last change is `quiet` old
+    pub fn fire(&mut self, now: Instant) -> bool {
+        let due = self
+            .last_change
+            .is_some_and(|last| now.saturating_duration_since(last) >= self.quiet);
+        if due {
+            self.last_change = None;
+        }
+        due
+    }
+}
+
+/// Emits an item whenever the installed games changed; runs until dropped
+pub fn games_changed_stream() -> impl Stream<Item = ()> {
+    iced::stream::channel(
+        1,
+        |mut output: iced::futures::channel::mpsc::Sender<()>| async move {
+            let mut debouncer = Debouncer::new(QUIET_PERIOD);
+            let mut paths = watched_paths();
+            let mut snapshot = take_snapshot(&paths);
+
+            loop {
+                tokio::time::sleep(POLL_INTERVAL).await;
+
+                let current = take_snapshot(&paths);
+                let now = Instant::now();
+                if current != snapshot {
+                    debouncer.change(now);
+                    snapshot = current;
+                }
+                if debouncer.fire(now) {
+                    tracing::info!("Game directories changed");
+                    if output.send(()).await.is_err() {
+                        return;
+                    }
+                    // Steam libraries may have been added or removed with the change
+                    paths = watched_paths();
+                    snapshot = take_snapshot(&paths);
+                }
+            }
+        },
+    )
+}
+
+/// Modification times of all watched paths; missing paths are part of the
+/// snapshot too, so a path appearing or vanishing counts as a change
+fn take_snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    paths
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Resolving the Steam libraries parses `libraryfolders.vdf`, so the paths are looked
+/// up once per change instead of on every poll. The file itself is watched, so an
+/// added library counts as a change too. A directory's mtime changes when files are
+/// added or removed in it.
+fn watched_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    for steamapps in steam_library_dirs() {
+        paths.push(steamapps.join("libraryfolders.vdf"));
+        paths.push(steamapps);
+    }
+    paths.sort();
+
+    if let Some(base_dirs) = BaseDirs::new() {
+        for root in heroic_roots(&base_dirs) {
+            paths.extend(HEROIC_FILES.iter().map(|file| root.join(file)));
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debouncer_coalesces_rapid_changes() {
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_secs(10));
+        assert!(!debouncer.fire(start));
+
+        for secs in [0, 2, 4, 6] {
+            debouncer.change(start + Duration::from_secs(secs));
+        }
+        // Measured from the last change, not the first
+        assert!(!debouncer.fire(start + Duration::from_secs(12)));
+        assert!(debouncer.fire(start + Duration::from_secs(16)));
+        assert!(!debouncer.fire(start + Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_debouncer_fires_again_for_later_burst() {
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_secs(10));
+
+        debouncer.change(start);
+        assert!(debouncer.fire(start + Duration::from_secs(10)));
+
+        debouncer.change(start + Duration::from_secs(60));
+        assert!(!debouncer.fire(start + Duration::from_secs(65)));
+        assert!(debouncer.fire(start + Duration::from_secs(70)));
+    }
+}
@@ -0,0 +1,163 @@
+//! How the launcher window is presented, and the saved geometry used in windowed mode.
+
+use iced::window;
+use iced::{Point, Size};
+use serde::{Deserialize, Serialize};
+
+/// Size used in windowed mode until a geometry has been saved
+const DEFAULT_WINDOWED_SIZE: Size = Size::new(1280.0, 720.0);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowMode {
+    #[default]
+    Fullscreen,
+    /// Decorated window whose size and position are restored across runs
+    Windowed,
+    /// Undecorated window maximized to the screen, leaving panels visible
+    BorderlessMax,
+}
+
+impl WindowMode {
+    pub fn is_fullscreen(self) -> bool {
+        self == WindowMode::Fullscreen
+    }
+
+    /// Mode after pressing the fullscreen toggle: fullscreen becomes windowed,
+    /// anything else becomes fullscreen.
+    pub fn toggled(self) -> Self {
+        if self.is_fullscreen() {
+            WindowMode::Windowed
+        } else {
+            WindowMode::Fullscreen
+        }
+    }
+}
+
+/// Last size and position of the window in windowed mode
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub width: f32,
+    pub height: f32,
+    /// Unset when the compositor doesn't report window positions (e.g. Wayland)
+    #[serde(default)]
+    pub x: Option<f32>,
+    #[serde(default)]
+    pub y: Option<f32>,
+}
+
+impl WindowGeometry {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            x: None,
+            y: None,
+        }
+    }
+}
+
+/// Size of the window in windowed mode
+pub fn windowed_size(geometry: Option<WindowGeometry>) -> Size {
+    geometry.map_or(DEFAULT_WINDOWED_SIZE, |g| Size::new(g.width, g.height))
+}
+
+/// Builds the window settings for `mode`, restoring `geometry` in windowed mode.
+pub fn window_settings(mode: WindowMode, geometry: Option<WindowGeometry>) -> window::Settings {
+    match mode {
+        WindowMode::Fullscreen => window::Settings {
+            decorations: false,
+            fullscreen: true,
+            ..Default::default()
+        },
+        WindowMode::BorderlessMax => window::Settings {
+            decorations: false,
+            maximized: true,
+            ..Default::default()
+        },
+        WindowMode::Windowed => {
+            let size = windowed_size(geometry);
+            let position = match geometry.and_then(|g| g.x.zip(g.y)) {
+                Some((x, y)) => window::Position::Specific(Point::new(x, y)),
+                None => window::Position::default(),
+            };
+            window::Settings {
+                size,
+                position,
+                // Close requests are handled by the launcher so the geometry gets saved first
+                exit_on_close_request: false,
+                ..Default::default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometry_roundtrip() {
+        let geometry = WindowGeometry {
+            width: 1600.0,
+            height: 900.0,
+            x: Some(40.0),
+            y: Some(20.0),
+        };
+
+        let json = serde_json::to_string(&geometry).unwrap();
+        let loaded: WindowGeometry = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, geometry);
+    }
+
+    #[test]
+    fn test_geometry_without_position() {
+        let loaded: WindowGeometry =
+            serde_json::from_str(r#"{ "width": 1280.0, "height": 720.0 }"#).unwrap();
+        assert_eq!(loaded, WindowGeometry::new(1280.0, 720.0));
+    }
+
+    #[test]
+    fn test_window_mode_serialization() {
+        assert_eq!(
+            serde_json::to_string(&WindowMode::BorderlessMax).unwrap(),
+            r#""borderless_max""#
+        );
+        let mode: WindowMode = serde_json::from_str(r#""windowed""#).unwrap();
+        assert_eq!(mode, WindowMode::Windowed);
+    }
+
+    #[test]
+    fn test_window_settings_restore_geometry() {
+        let geometry = WindowGeometry {
+            width: 1024.0,
+            height: 600.0,
+            x: Some(10.0),
+            y: Some(30.0),
+        };
+        let settings = window_settings(WindowMode::Windowed, Some(geometry));
+        assert_eq!(settings.size, Size::new(1024.0, 600.0));
+        assert!(matches!(
+            settings.position,
+            window::Position::Specific(point) if point == Point::new(10.0, 30.0)
+        ));
+        assert!(!settings.fullscreen);
+
+        let settings = window_settings(WindowMode::Windowed, None);
+        assert_eq!(settings.size, DEFAULT_WINDOWED_SIZE);
+
+        assert!(window_settings(WindowMode::Fullscreen, Some(geometry)).fullscreen);
+    }
+
+    #[test]
+    fn test_toggle_flips_fullscreen() {
+        let mut mode = WindowMode::Fullscreen;
+        mode = mode.toggled();
+        assert!(!mode.is_fullscreen());
+        assert_eq!(mode, WindowMode::Windowed);
+        mode = mode.toggled();
+        assert!(mode.is_fullscreen());
+
+        assert!(WindowMode::BorderlessMax.toggled().is_fullscreen());
+    }
+}